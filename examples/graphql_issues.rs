@@ -1,7 +1,7 @@
 //! Run this to update `github_schema.graphql`:
 //!
 //! ```sh
-//! curl -L https://docs.github.com/public/fpt/schema.docs.graphql -o examples/github_schema.graphql
+//! curl -L https://docs.github.com/public/fpt/schema.docs.graphql -o schema/github_schema.graphql
 //! ```
 use graphql_client::GraphQLQuery;
 
@@ -10,7 +10,7 @@ type URI = String;
 
 #[derive(GraphQLQuery)]
 #[graphql(
-    schema_path = "examples/github_schema.graphql",
+    schema_path = "schema/github_schema.graphql",
     query_path = "examples/issues_query.graphql",
     variables_derives = "Clone, Debug",
     response_derives = "Clone, Debug"
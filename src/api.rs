@@ -1,23 +1,32 @@
 pub mod actions;
 pub mod activity;
+pub mod advisories;
 pub mod apps;
+pub mod billing;
 pub mod checks;
 pub mod classroom;
 pub mod code_scannings;
 pub mod codes_of_conduct;
+pub mod codespaces;
 pub mod commits;
 pub mod current;
+pub mod enterprises;
 pub mod events;
 pub mod gists;
 pub mod gitignore;
 pub mod hooks;
+#[cfg(feature = "models-api")]
+pub mod inference;
 pub mod issues;
 pub mod licenses;
 pub mod markdown;
+pub mod meta;
+pub mod migrations;
 pub mod orgs;
 pub mod projects;
 pub mod pulls;
 pub mod ratelimit;
+pub mod reactions;
 pub mod repos;
 pub mod search;
 pub mod teams;
@@ -1,5 +1,6 @@
 //! Github Activity API
 
+use crate::models::activity::Feed;
 use crate::Octocrab;
 
 pub mod notifications;
@@ -20,4 +21,17 @@ impl<'octo> ActivityHandler<'octo> {
     pub fn notifications(&self) -> notifications::NotificationsHandler<'octo> {
         notifications::NotificationsHandler::new(self.crab)
     }
+
+    /// Lists the Atom feeds available to the authenticated user. The private
+    /// feeds are only included when authenticated with basic auth.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let feeds = octocrab::instance().activity().feeds().await?;
+    /// println!("{}", feeds.links.timeline.href);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn feeds(&self) -> crate::Result<Feed> {
+        self.crab.get("/feeds", None::<&()>).await
+    }
 }
@@ -1,12 +1,14 @@
 //! Github Notifications API
 
 use crate::error::HttpSnafu;
+use crate::etag::Polled;
 use crate::models::activity::Notification;
-use crate::models::activity::ThreadSubscription;
+use crate::models::activity::{RepositorySubscription, ThreadSubscription};
 use crate::models::{NotificationId, ThreadId};
 use crate::Octocrab;
-use crate::Page;
-use http::Uri;
+use crate::{FromResponse, Page};
+use http::request::Builder;
+use http::{Method, StatusCode, Uri};
 use snafu::ResultExt;
 
 type DateTime = chrono::DateTime<chrono::Utc>;
@@ -67,6 +69,29 @@ impl<'octo> NotificationsHandler<'octo> {
         crate::map_github_error(response).await.map(drop)
     }
 
+    /// Marks a single thread as done, removing it from the inbox.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .activity()
+    ///     .notifications()
+    ///     .mark_as_done(123u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mark_as_done(&self, id: NotificationId) -> crate::Result<()> {
+        let route = format!("/notifications/threads/{id}");
+        let uri = Uri::builder()
+            .path_and_query(route)
+            .build()
+            .context(HttpSnafu)?;
+
+        let response = self.crab._delete(uri, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
     /// Marks all notifications in a repository as read.
     ///
     /// ```no_run
@@ -211,6 +236,105 @@ impl<'octo> NotificationsHandler<'octo> {
         crate::map_github_error(response).await.map(drop)
     }
 
+    /// Gets whether the current user watches or ignores a repository. Returns
+    /// a 404 error if they neither watch nor ignore it.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let subscription = octocrab::instance()
+    ///     .activity()
+    ///     .notifications()
+    ///     .get_repo_subscription("owner", "repo")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_repo_subscription(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> crate::Result<RepositorySubscription> {
+        let route = format!(
+            "/repos/{owner}/{repo}/subscription",
+            owner = owner.as_ref(),
+            repo = repo.as_ref(),
+        );
+
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Watches a repository, or ignores all of its notifications if `ignored`
+    /// is `true`, e.g. to mute a repository whose threads a bot resolves on
+    /// its own.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let subscription = octocrab::instance()
+    ///     .activity()
+    ///     .notifications()
+    ///     .set_repo_subscription("owner", "repo", false, true)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_repo_subscription(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+        subscribed: bool,
+        ignored: bool,
+    ) -> crate::Result<RepositorySubscription> {
+        #[derive(serde::Serialize)]
+        struct Inner {
+            subscribed: bool,
+            ignored: bool,
+        }
+
+        let route = format!(
+            "/repos/{owner}/{repo}/subscription",
+            owner = owner.as_ref(),
+            repo = repo.as_ref(),
+        );
+        let body = Inner {
+            subscribed,
+            ignored,
+        };
+
+        self.crab.put(route, Some(&body)).await
+    }
+
+    /// Stops watching or ignoring a repository, so that the current user is
+    /// only notified of the threads they take part in or are mentioned in.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .activity()
+    ///     .notifications()
+    ///     .delete_repo_subscription("owner", "repo")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_repo_subscription(
+        &self,
+        owner: impl AsRef<str>,
+        repo: impl AsRef<str>,
+    ) -> crate::Result<()> {
+        let route = format!(
+            "/repos/{owner}/{repo}/subscription",
+            owner = owner.as_ref(),
+            repo = repo.as_ref(),
+        );
+
+        let uri = Uri::builder()
+            .path_and_query(route)
+            .build()
+            .context(HttpSnafu)?;
+        let response = self.crab._delete(uri, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
     /// List all notifications for the current user, that are in a given repository.
     ///
     /// ```no_run
@@ -276,6 +400,8 @@ pub struct ListNotificationsBuilder<'octo> {
     per_page: Option<u8>,
     #[serde(skip_serializing_if = "Option::is_none")]
     page: Option<u8>,
+    #[serde(skip)]
+    if_modified_since: Option<String>,
 }
 
 impl<'octo> ListNotificationsBuilder<'octo> {
@@ -283,6 +409,7 @@ impl<'octo> ListNotificationsBuilder<'octo> {
         Self {
             url,
             crab,
+            if_modified_since: None,
             all: None,
             participating: None,
             since: None,
@@ -328,8 +455,82 @@ impl<'octo> ListNotificationsBuilder<'octo> {
         self
     }
 
+    /// The `Last-Modified` value of a previous [`poll`], so that GitHub only
+    /// responds with notifications if something changed since then.
+    ///
+    /// [`poll`]: ListNotificationsBuilder::poll
+    pub fn if_modified_since(mut self, last_modified: impl Into<String>) -> Self {
+        self.if_modified_since = Some(last_modified.into());
+        self
+    }
+
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Page<Notification>> {
         self.crab.get(&self.url, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Notification>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+
+    /// Sends the request as a conditional poll, returning the
+    /// `Last-Modified` and `X-Poll-Interval` headers alongside the page. The
+    /// page is `None` when nothing changed since [`if_modified_since`].
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let mut last_modified = None;
+    /// loop {
+    ///     let mut request = octocrab.activity().notifications().list();
+    ///     if let Some(last_modified) = last_modified.take() {
+    ///         request = request.if_modified_since(last_modified);
+    ///     }
+    ///     let polled = request.poll().await?;
+    ///     if let Some(page) = polled.value {
+    ///         // handle the new notifications ...
+    ///     }
+    ///     last_modified = polled.last_modified;
+    ///     // wait `polled.poll_interval` before polling again
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`if_modified_since`]: ListNotificationsBuilder::if_modified_since
+    pub async fn poll(self) -> crate::Result<Polled<Page<Notification>>> {
+        let uri = self.crab.parameterized_uri(&self.url, Some(&self))?;
+        let mut builder = Builder::new().method(Method::GET).uri(uri);
+        if let Some(last_modified) = &self.if_modified_since {
+            builder = builder.header(http::header::IF_MODIFIED_SINCE, last_modified.as_str());
+        }
+        let request = self.crab.build_request(builder, None::<&()>)?;
+
+        let response = self.crab.execute(request).await?;
+        if response.status() == StatusCode::NOT_MODIFIED {
+            return Ok(Polled::extract_from_response(&response, None));
+        }
+        let response = crate::map_github_error(response).await?;
+        let polled = Polled::extract_from_response(&response, None);
+        let page = <Page<Notification>>::from_response(response).await?;
+        Ok(Polled {
+            value: Some(page),
+            ..polled
+        })
+    }
+
+    /// Creates a [`Poller`](crate::poller::Poller) which streams the new
+    /// notifications as they arrive.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub fn poller(self) -> crate::Result<crate::poller::Poller<Notification>> {
+        let uri = self.crab.parameterized_uri(&self.url, Some(&self))?;
+        Ok(crate::poller::Poller::from_uri(self.crab, uri))
+    }
 }
@@ -0,0 +1,195 @@
+//! GitHub's global security advisory database.
+
+use crate::models::security_advisories::GlobalAdvisory;
+use crate::params::security_advisories::{AdvisoryType, Ecosystem, Severity, Sort};
+use crate::params::Direction;
+use crate::Octocrab;
+
+/// Handler for GitHub's global security advisories API.
+///
+/// Created with [`Octocrab::advisories`].
+pub struct AdvisoriesHandler<'octo> {
+    crab: &'octo Octocrab,
+}
+
+impl<'octo> AdvisoriesHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self { crab }
+    }
+
+    /// Lists advisories from the global advisory database. Only reviewed
+    /// advisories are returned unless [`ListGlobalAdvisoriesBuilder::advisory_type`]
+    /// is set.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::security_advisories::{Ecosystem, Severity};
+    ///
+    /// let advisories = octocrab::instance()
+    ///     .advisories()
+    ///     .list()
+    ///     .ecosystem(Ecosystem::Rust)
+    ///     .severity(Severity::Critical)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListGlobalAdvisoriesBuilder<'octo, '_> {
+        ListGlobalAdvisoriesBuilder::new(self)
+    }
+
+    /// Gets an advisory from the global advisory database by its GHSA id.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let advisory = octocrab::instance()
+    ///     .advisories()
+    ///     .get("GHSA-abcd-1234-efgh")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, ghsa_id: impl AsRef<str>) -> crate::Result<GlobalAdvisory> {
+        self.crab
+            .get(format!("/advisories/{}", ghsa_id.as_ref()), None::<&()>)
+            .await
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct ListGlobalAdvisoriesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r AdvisoriesHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ghsa_id: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    advisory_type: Option<AdvisoryType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cve_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ecosystem: Option<Ecosystem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<Severity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cwes: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_withdrawn: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    affects: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<Sort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+}
+
+impl<'octo, 'r> ListGlobalAdvisoriesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r AdvisoriesHandler<'octo>) -> Self {
+        Self {
+            handler,
+            ghsa_id: None,
+            advisory_type: None,
+            cve_id: None,
+            ecosystem: None,
+            severity: None,
+            cwes: None,
+            is_withdrawn: None,
+            affects: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+        }
+    }
+
+    /// Only return the advisory with this GHSA id.
+    pub fn ghsa_id(mut self, ghsa_id: impl Into<String>) -> Self {
+        self.ghsa_id = Some(ghsa_id.into());
+        self
+    }
+
+    /// Only return advisories of this type.
+    pub fn advisory_type(mut self, advisory_type: AdvisoryType) -> Self {
+        self.advisory_type = Some(advisory_type);
+        self
+    }
+
+    /// Only return the advisory with this CVE id.
+    pub fn cve_id(mut self, cve_id: impl Into<String>) -> Self {
+        self.cve_id = Some(cve_id.into());
+        self
+    }
+
+    /// Only return advisories affecting packages of this ecosystem.
+    pub fn ecosystem(mut self, ecosystem: Ecosystem) -> Self {
+        self.ecosystem = Some(ecosystem);
+        self
+    }
+
+    /// Only return advisories of this severity.
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// Only return advisories with any of these CWEs, e.g. `79`.
+    pub fn cwes(mut self, cwes: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.cwes = Some(join(cwes));
+        self
+    }
+
+    /// Whether to only return withdrawn advisories, or none of them.
+    pub fn is_withdrawn(mut self, is_withdrawn: bool) -> Self {
+        self.is_withdrawn = Some(is_withdrawn);
+        self
+    }
+
+    /// Only return advisories affecting these packages, optionally pinned to
+    /// a version, e.g. `tokio@1.0.0`.
+    pub fn affects(mut self, packages: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.affects = Some(join(packages));
+        self
+    }
+
+    /// What to sort results by. Only [`Sort::Updated`] and
+    /// [`Sort::Published`] are supported.
+    pub fn sort(mut self, sort: Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// The direction of the sort.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<GlobalAdvisory>> {
+        self.handler.crab.get("/advisories", Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<GlobalAdvisory>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+fn join(values: impl IntoIterator<Item = impl Into<String>>) -> String {
+    values
+        .into_iter()
+        .map(Into::into)
+        .collect::<Vec<String>>()
+        .join(",")
+}
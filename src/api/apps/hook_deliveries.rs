@@ -0,0 +1,58 @@
+use super::*;
+use crate::models::hooks::Delivery;
+use crate::Page;
+
+/// A builder pattern struct for listing the deliveries of the app's webhook.
+///
+/// created by [`AppsRequestHandler::list_hook_deliveries`]
+///
+/// [`AppsRequestHandler::list_hook_deliveries`]: ./struct.AppsRequestHandler.html#method.list_hook_deliveries
+#[derive(serde::Serialize)]
+pub struct ListAppHookDeliveriesBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b AppsRequestHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cursor: Option<String>,
+}
+
+impl<'octo, 'b> ListAppHookDeliveriesBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b AppsRequestHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            cursor: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// The cursor to start listing from, taken from the `Link` header of a
+    /// previous page. Following [`Page::next`] sets it automatically.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<Delivery>> {
+        let route = "/app/hook/deliveries";
+        self.handler.http_get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Delivery>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
@@ -0,0 +1,204 @@
+use crate::models::marketplace::{MarketplaceAccount, MarketplacePlan};
+use crate::{params, Octocrab, Page};
+
+/// A client to the GitHub Marketplace API of the authenticated app.
+///
+/// Created with [`AppsRequestHandler::marketplace`] or
+/// [`AppsRequestHandler::marketplace_stubbed`].
+///
+/// [`AppsRequestHandler::marketplace`]: super::AppsRequestHandler::marketplace
+/// [`AppsRequestHandler::marketplace_stubbed`]: super::AppsRequestHandler::marketplace_stubbed
+pub struct MarketplaceHandler<'octo> {
+    crab: &'octo Octocrab,
+    route: &'static str,
+}
+
+impl<'octo> MarketplaceHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: &'static str) -> Self {
+        Self { crab, route }
+    }
+
+    /// Lists the plans of the app's Marketplace listing.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let plans = octocrab::instance()
+    ///     .apps()
+    ///     .marketplace()
+    ///     .list_plans()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_plans(&self) -> ListPlansBuilder<'octo, '_> {
+        ListPlansBuilder::new(self)
+    }
+
+    /// Lists the accounts subscribed to a plan, including those with a
+    /// pending change to the plan.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::apps::MarketplaceAccountSort;
+    ///
+    /// let accounts = octocrab::instance()
+    ///     .apps()
+    ///     .marketplace()
+    ///     .list_accounts_for_plan(1313)
+    ///     .sort(MarketplaceAccountSort::Updated)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_accounts_for_plan(&self, plan_id: u64) -> ListPlanAccountsBuilder<'octo, '_> {
+        ListPlanAccountsBuilder::new(self, plan_id)
+    }
+
+    /// Gets the subscription of an account to the app's Marketplace listing.
+    /// Returns a 404 error if the account has no subscription.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let account = octocrab::instance()
+    ///     .apps()
+    ///     .marketplace()
+    ///     .get_subscription_for_account(4)
+    ///     .await?;
+    ///
+    /// println!("{:?}", account.marketplace_purchase.billing_cycle);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_subscription_for_account(
+        &self,
+        account_id: u64,
+    ) -> crate::Result<MarketplaceAccount> {
+        let route = format!("{}/accounts/{account_id}", self.route);
+        self.crab.get(route, None::<&()>).await
+    }
+}
+
+/// A builder pattern struct for listing the plans of a Marketplace listing.
+///
+/// Created with [`MarketplaceHandler::list_plans`].
+#[derive(serde::Serialize)]
+pub struct ListPlansBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r MarketplaceHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListPlansBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r MarketplaceHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<MarketplacePlan>> {
+        let route = format!("{}/plans", self.handler.route);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<MarketplacePlan>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+/// A builder pattern struct for listing the accounts subscribed to a
+/// Marketplace plan.
+///
+/// Created with [`MarketplaceHandler::list_accounts_for_plan`].
+#[derive(serde::Serialize)]
+pub struct ListPlanAccountsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r MarketplaceHandler<'octo>,
+    #[serde(skip)]
+    plan_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<params::apps::MarketplaceAccountSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListPlanAccountsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r MarketplaceHandler<'octo>, plan_id: u64) -> Self {
+        Self {
+            handler,
+            plan_id,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// What to sort the accounts by.
+    pub fn sort(mut self, sort: params::apps::MarketplaceAccountSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// The direction of the sort.
+    pub fn direction(mut self, direction: params::Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<MarketplaceAccount>> {
+        let route = format!("{}/plans/{}/accounts", self.handler.route, self.plan_id);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<MarketplaceAccount>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
@@ -0,0 +1,163 @@
+//! The billing API of organizations, users and enterprises.
+
+use crate::models::billing::{
+    ActionsBillingUsage, BillingUsageReport, PackagesBillingUsage, SharedStorageBillingUsage,
+};
+use crate::Octocrab;
+
+/// Handler for the billing of an organization, a user or an enterprise.
+///
+/// Created with [`OrgHandler::billing`](crate::orgs::OrgHandler::billing),
+/// [`UserHandler::billing`](crate::users::UserHandler::billing) or
+/// [`EnterpriseHandler::billing`](crate::enterprises::EnterpriseHandler::billing).
+pub struct BillingHandler<'octo> {
+    crab: &'octo Octocrab,
+    route: String,
+    usage_route: String,
+}
+
+impl<'octo> BillingHandler<'octo> {
+    /// `route` is the prefix of the billing summary endpoints, while
+    /// `usage_route` is that of the usage report, which organizations serve
+    /// under `/organizations` rather than `/orgs`.
+    pub(crate) fn new(crab: &'octo Octocrab, route: String, usage_route: String) -> Self {
+        Self {
+            crab,
+            route,
+            usage_route,
+        }
+    }
+
+    /// Gets the GitHub Actions minutes used in the current billing cycle.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .orgs("org")
+    ///     .billing()
+    ///     .actions()
+    ///     .await?;
+    ///
+    /// println!("{}/{} minutes", usage.total_minutes_used, usage.included_minutes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn actions(&self) -> crate::Result<ActionsBillingUsage> {
+        let route = format!("{}/actions", self.route);
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets the GitHub Packages bandwidth used in the current billing cycle.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .users("octocat")
+    ///     .billing()
+    ///     .packages()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn packages(&self) -> crate::Result<PackagesBillingUsage> {
+        let route = format!("{}/packages", self.route);
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets the estimated storage used by GitHub Actions and GitHub Packages
+    /// in the current billing cycle.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .enterprises("acme")
+    ///     .billing()
+    ///     .shared_storage()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shared_storage(&self) -> crate::Result<SharedStorageBillingUsage> {
+        let route = format!("{}/shared-storage", self.route);
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets a report of billable usage from the enhanced billing platform,
+    /// for the current year unless a period is given.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let report = octocrab::instance()
+    ///     .orgs("org")
+    ///     .billing()
+    ///     .usage()
+    ///     .year(2024)
+    ///     .month(6)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for item in report.usage_items {
+    ///     println!("{} {}: {}", item.product, item.sku, item.net_amount);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn usage(&self) -> BillingUsageReportBuilder<'octo, '_> {
+        BillingUsageReportBuilder::new(self)
+    }
+}
+
+/// A builder pattern struct for getting a billing usage report.
+///
+/// Created with [`BillingHandler::usage`].
+#[derive(serde::Serialize)]
+pub struct BillingUsageReportBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r BillingHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    year: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    month: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    day: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hour: Option<u8>,
+}
+
+impl<'octo, 'r> BillingUsageReportBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r BillingHandler<'octo>) -> Self {
+        Self {
+            handler,
+            year: None,
+            month: None,
+            day: None,
+            hour: None,
+        }
+    }
+
+    /// The year to report usage for.
+    pub fn year(mut self, year: u16) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// The month to report usage for, from 1 to 12.
+    pub fn month(mut self, month: u8) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// The day of the month to report usage for.
+    pub fn day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// The hour of the day to report usage for, from 0 to 23.
+    pub fn hour(mut self, hour: u8) -> Self {
+        self.hour = Some(hour);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<BillingUsageReport> {
+        let route = format!("{}/usage", self.handler.usage_route);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
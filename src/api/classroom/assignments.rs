@@ -55,6 +55,7 @@ impl<'octo> AssignmentsHandler<'octo> {
     ///         .await;
     ///     Ok(())
     /// }
+    /// ```
     pub async fn get(
         &self,
         assignment_id: AssignmentId,
@@ -78,6 +79,20 @@ impl<'octo> AssignmentsHandler<'octo> {
     ///
     /// This endpoint can be used without authentication if only public resources are requested.
     ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let accepted = octocrab::instance()
+    ///     .assignments()
+    ///     .per_page(100)
+    ///     .list_accepted(42u64.into())
+    ///     .await?;
+    ///
+    /// for accepted_assignment in accepted {
+    ///     println!("{:?}", accepted_assignment.students);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn list_accepted(
         &self,
         assignment_id: AssignmentId,
@@ -101,6 +116,19 @@ impl<'octo> AssignmentsHandler<'octo> {
     ///
     /// This endpoint can be used without authentication if only public resources are requested.
     ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let grades = octocrab::instance()
+    ///     .assignments()
+    ///     .get_grades(42u64.into())
+    ///     .await?;
+    ///
+    /// for grade in grades {
+    ///     println!("{}: {}/{}", grade.github_username, grade.points_awarded, grade.points_available);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn get_grades(
         &self,
         assignment_id: AssignmentId,
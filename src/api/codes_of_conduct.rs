@@ -1,6 +1,10 @@
+//! The codes of conduct API
 use crate::models::codes_of_conduct::CodeOfConduct;
 use crate::Octocrab;
 
+/// Handler for GitHub's codes of conduct API.
+///
+/// Created with [`Octocrab::codes_of_conduct`].
 #[derive(serde::Serialize)]
 pub struct CodesOfConductHandler<'octo> {
     #[serde(skip)]
@@ -26,6 +30,15 @@ impl<'octo> CodesOfConductHandler<'octo> {
     /// The fine-grained token does not require any permissions.
     ///
     /// This endpoint can be used without authentication if only public resources are requested.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let codes = octocrab::instance()
+    ///     .codes_of_conduct()
+    ///     .list_all_codes_of_conduct()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn list_all_codes_of_conduct(&self) -> crate::Result<Vec<CodeOfConduct>> {
         let route = String::from("/codes_of_conduct");
         self.crab.get(route, Some(&self)).await
@@ -46,6 +59,16 @@ impl<'octo> CodesOfConductHandler<'octo> {
     /// The fine-grained token does not require any permissions.
     ///
     /// This endpoint can be used without authentication if only public resources are requested.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let code = octocrab::instance()
+    ///     .codes_of_conduct()
+    ///     .get_code_of_conduct("contributor_covenant".to_string())
+    ///     .await?;
+    /// println!("{}", code.body.unwrap_or_default());
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn get_code_of_conduct(&self, key: String) -> crate::Result<CodeOfConduct> {
         let route = format!("/codes_of_conduct/{}", key);
         self.crab.get(route, Some(&self)).await
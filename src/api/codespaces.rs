@@ -0,0 +1,295 @@
+//! The codespaces API of the authenticated user.
+
+use http::StatusCode;
+
+use crate::models::codespaces::{
+    Codespace, CodespaceMachine, CodespacesUserSecret, CodespacesUserSecrets,
+    CreateCodespacesUserSecret,
+};
+use crate::models::repos::secrets::CreateRepositorySecretResponse;
+use crate::models::RepositoryId;
+use crate::Octocrab;
+
+/// Handler for the codespaces of the authenticated user.
+///
+/// Created with [`Octocrab::codespaces`].
+pub struct CodespacesHandler<'octo> {
+    crab: &'octo Octocrab,
+}
+
+impl<'octo> CodespacesHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self { crab }
+    }
+
+    /// Lists the codespaces of the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let codespaces = octocrab::instance()
+    ///     .codespaces()
+    ///     .list()
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListCodespacesBuilder<'octo> {
+        ListCodespacesBuilder::new(self.crab, String::from("/user/codespaces"))
+    }
+
+    /// Gets a codespace of the authenticated user by its name.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let codespace = octocrab::instance()
+    ///     .codespaces()
+    ///     .get("monalisa-octocat-hello-world-g4wpq6h95q")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, name: impl AsRef<str>) -> crate::Result<Codespace> {
+        let route = format!("/user/codespaces/{}", name.as_ref());
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Starts a codespace of the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let codespace = octocrab::instance()
+    ///     .codespaces()
+    ///     .start("monalisa-octocat-hello-world-g4wpq6h95q")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn start(&self, name: impl AsRef<str>) -> crate::Result<Codespace> {
+        let route = format!("/user/codespaces/{}/start", name.as_ref());
+        self.crab.post(route, None::<&()>).await
+    }
+
+    /// Stops a codespace of the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let codespace = octocrab::instance()
+    ///     .codespaces()
+    ///     .stop("monalisa-octocat-hello-world-g4wpq6h95q")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn stop(&self, name: impl AsRef<str>) -> crate::Result<Codespace> {
+        let route = format!("/user/codespaces/{}/stop", name.as_ref());
+        self.crab.post(route, None::<&()>).await
+    }
+
+    /// Deletes a codespace of the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .codespaces()
+    ///     .delete("monalisa-octocat-hello-world-g4wpq6h95q")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, name: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!("/user/codespaces/{}", name.as_ref());
+        let response = self.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Lists the machine types a codespace can be switched to.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let machines = octocrab::instance()
+    ///     .codespaces()
+    ///     .list_machines("monalisa-octocat-hello-world-g4wpq6h95q")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_machines(
+        &self,
+        name: impl AsRef<str>,
+    ) -> crate::Result<crate::Page<CodespaceMachine>> {
+        let route = format!("/user/codespaces/{}/machines", name.as_ref());
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Lists the Codespaces secrets of the authenticated user without
+    /// revealing their encrypted values.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let secrets = octocrab::instance()
+    ///     .codespaces()
+    ///     .get_secrets()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_secrets(&self) -> crate::Result<CodespacesUserSecrets> {
+        self.crab.get("/user/codespaces/secrets", None::<&()>).await
+    }
+
+    /// Gets the public key of the authenticated user, which you need to
+    /// encrypt secrets.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let public_key = octocrab::instance()
+    ///     .codespaces()
+    ///     .get_public_key()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_public_key(&self) -> crate::Result<crate::models::PublicKey> {
+        self.crab
+            .get("/user/codespaces/secrets/public-key", None::<&()>)
+            .await
+    }
+
+    /// Gets a single Codespaces secret of the authenticated user without
+    /// revealing its encrypted value.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let secret = octocrab::instance()
+    ///     .codespaces()
+    ///     .get_secret("TOKEN")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_secret(
+        &self,
+        secret_name: impl AsRef<str>,
+    ) -> crate::Result<CodespacesUserSecret> {
+        let route = crate::route::Route::new("/user/codespaces/secrets").segment(secret_name);
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates or updates a Codespaces secret of the authenticated user with
+    /// an encrypted value.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::codespaces::CreateCodespacesUserSecret;
+    ///
+    /// let result = octocrab::instance()
+    ///     .codespaces()
+    ///     .create_or_update_secret("TOKEN", &CreateCodespacesUserSecret {
+    ///         key_id: "123456",
+    ///         encrypted_value: "some-b64-encrypted-string",
+    ///         selected_repository_ids: None,
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_or_update_secret(
+        &self,
+        secret_name: impl AsRef<str>,
+        secret: &CreateCodespacesUserSecret<'_>,
+    ) -> crate::Result<CreateRepositorySecretResponse> {
+        let route = crate::route::Route::new("/user/codespaces/secrets").segment(secret_name);
+
+        let resp = {
+            let resp = self.crab._put(route, Some(secret)).await?;
+            crate::map_github_error(resp).await?
+        };
+
+        match resp.status() {
+            StatusCode::CREATED => Ok(CreateRepositorySecretResponse::Created),
+            StatusCode::NO_CONTENT => Ok(CreateRepositorySecretResponse::Updated),
+            status_code => Err(crate::Error::Other {
+                source: format!(
+                    "Unexpected status code from request: {}",
+                    status_code.as_str()
+                )
+                .into(),
+                backtrace: snafu::Backtrace::capture(),
+            }),
+        }
+    }
+
+    /// Deletes a Codespaces secret of the authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .codespaces()
+    ///     .delete_secret("TOKEN")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_secret(&self, secret_name: impl AsRef<str>) -> crate::Result<()> {
+        let route = crate::route::Route::new("/user/codespaces/secrets").segment(secret_name);
+        let resp = self.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
+}
+
+/// A builder pattern struct for listing codespaces, of either the
+/// authenticated user or a repository.
+///
+/// Created with [`CodespacesHandler::list`] or
+/// [`RepoCodespacesHandler::list`](crate::repos::RepoCodespacesHandler::list).
+#[derive(serde::Serialize)]
+pub struct ListCodespacesBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip)]
+    route: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository_id: Option<RepositoryId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo> ListCodespacesBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: String) -> Self {
+        Self {
+            crab,
+            route,
+            repository_id: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list the codespaces of this repository. Only applies to the
+    /// codespaces of the authenticated user.
+    pub fn repository_id(mut self, repository_id: impl Into<RepositoryId>) -> Self {
+        self.repository_id = Some(repository_id.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<Codespace>> {
+        self.crab.get(&self.route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Codespace>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
@@ -55,13 +55,44 @@ impl<'octo> CommitHandler<'octo> {
         create_comment::CreateCommentBuilder::new(self, sha.into(), body.into())
     }
 
+    /// Creates a [`ReactionsHandler`] to list, add and remove reactions on a
+    /// commit comment.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::reactions::ReactionContent;
+    ///
+    /// octocrab::instance()
+    ///     .commits("owner", "repo")
+    ///     .comment_reactions(42)
+    ///     .create(ReactionContent::Eyes)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ReactionsHandler`]: crate::reactions::ReactionsHandler
+    pub fn comment_reactions(
+        &self,
+        comment_id: impl Into<models::CommentId>,
+    ) -> crate::reactions::ReactionsHandler<'octo> {
+        crate::reactions::ReactionsHandler::new(
+            self.crab,
+            format!(
+                "/repos/{owner}/{repo}/comments/{comment_id}",
+                owner = self.owner,
+                repo = self.repo,
+                comment_id = comment_id.into(),
+            ),
+        )
+    }
+
     pub async fn get(&self, reference: impl Into<String>) -> Result<models::repos::RepoCommit> {
-        let route = format!(
-            "/repos/{owner}/{repo}/commits/{reference}",
+        let route = crate::route::Route::new(format_args!(
+            "/repos/{owner}/{repo}/commits",
             owner = self.owner,
             repo = self.repo,
-            reference = reference.into(),
-        );
+        ))
+        .segments(reference.into());
         self.crab.get(route, None::<&()>).await
     }
 }
@@ -39,12 +39,13 @@ impl<'octo, 'r> AssociatedCheckRunsBuilder<'octo, 'r> {
 
     /// Send the actual request.
     pub async fn send(self) -> Result<ListCheckRuns> {
-        let route = format!(
-            "/repos/{owner}/{repo}/commits/{reference}/check-runs",
+        let route = crate::route::Route::new(format_args!(
+            "/repos/{owner}/{repo}/commits",
             owner = self.handler.owner,
             repo = self.handler.repo,
-            reference = self.reference.full_ref_url()
-        );
+        ))
+        .segments(self.reference.full_ref_url())
+        .path("check-runs");
 
         self.handler.crab.get(route, Some(&self)).await
     }
@@ -57,15 +57,28 @@ impl<'octo, 'r> AssociatedPullRequestsBuilder<'octo, 'r> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<models::pulls::PullRequest>> {
-        let route = format!(
-            "/repos/{owner}/{repo}/commits/{target}/pulls",
+        let route = crate::route::Route::new(format_args!(
+            "/repos/{owner}/{repo}/commits",
             owner = self.handler.owner,
             repo = self.handler.repo,
-            target = self.target,
-        );
+        ))
+        .segments(self.target.to_string())
+        .path("pulls");
 
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::pulls::PullRequest>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 #[cfg(test)]
@@ -41,13 +41,12 @@ impl<'octo, 'r> CompareCommitsBuilder<'octo, 'r> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<models::commits::CommitComparison> {
-        let route = format!(
-            "/repos/{owner}/{repo}/compare/{base}...{head}",
+        let route = crate::route::Route::new(format_args!(
+            "/repos/{owner}/{repo}/compare",
             owner = self.handler.owner,
             repo = self.handler.repo,
-            base = self.base,
-            head = self.head,
-        );
+        ))
+        .segments(format!("{}...{}", self.base, self.head));
 
         self.handler.crab.get(route, Some(&self)).await
     }
@@ -44,6 +44,26 @@ impl<'octo> CurrentAuthHandler<'octo> {
         self.crab.patch("/user", Some(&params)).await
     }
 
+    /// Updates the authenticated user's profile. Unlike
+    /// [`CurrentAuthHandler::update_user`], only the fields set on the
+    /// builder are sent, so everything else is left untouched.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let profile = octocrab::instance()
+    ///     .current()
+    ///     .update_profile()
+    ///     .bio("Rustacean")
+    ///     .hireable(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_profile(&self) -> UpdateProfileBuilder<'octo> {
+        UpdateProfileBuilder::new(self.crab)
+    }
+
     /// Fetches information about the currently authenticated app.
     ///
     /// ```no_run
@@ -253,6 +273,22 @@ impl<'octo> CurrentAuthHandler<'octo> {
         let response = self.crab._delete(route, None::<&()>).await?;
         crate::map_github_error(response).await.map(drop)
     }
+
+    /// Handle the migrations of the authenticated user's repositories.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let migration = octocrab::instance()
+    ///     .current()
+    ///     .migrations()
+    ///     .start(["repo"])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn migrations(&self) -> crate::migrations::MigrationsHandler<'octo> {
+        crate::migrations::MigrationsHandler::new(self.crab, String::from("/user/migrations"))
+    }
 }
 
 /// A builder pattern struct for listing starred repositories.
@@ -325,6 +361,17 @@ impl<'octo> ListStarredReposBuilder<'octo> {
     pub async fn send(self) -> crate::Result<Page<Repository>> {
         self.crab.get("/user/starred", Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Repository>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// A builder pattern struct for listing repositories for authenticated user.
@@ -462,6 +509,17 @@ impl<'octo> ListReposForAuthenticatedUserBuilder<'octo> {
     pub async fn send(self) -> crate::Result<Page<Repository>> {
         self.crab.get("/user/repos", (&self).into()).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Repository>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// A builder struct for initializing query parameters for use with the
@@ -525,6 +583,17 @@ impl<'octo> ListGistsForAuthenticatedUserBuilder<'octo> {
     pub async fn send(self) -> crate::Result<Page<Gist>> {
         self.crab.get("/gists", Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Gist>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -578,6 +647,17 @@ impl<'octo> ListStarredGistsBuilder<'octo> {
     pub async fn send(self) -> crate::Result<Page<Gist>> {
         self.crab.get("/gists/starred", Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Gist>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// A builder pattern struct for listing organizations the authenticated user is a member of.
@@ -628,6 +708,17 @@ impl<'octo> ListOrgMembershipsForAuthenticatedUserBuilder<'octo> {
             .get("/user/memberships/orgs", (&self).into())
             .await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<MembershipInvitation>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// A builder pattern struct for listing the installations accessible to a user access token.
@@ -676,4 +767,110 @@ impl<'octo> ListAppInstallationsAccessibleToUserBuilder<'octo> {
     pub async fn send(self) -> crate::Result<Page<Installation>> {
         self.crab.get("/user/installations", (&self).into()).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Installation>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+/// A builder pattern struct for updating the authenticated user's profile.
+///
+/// Created by: [`CurrentAuthHandler::update_profile`].
+#[derive(serde::Serialize)]
+pub struct UpdateProfileBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    blog: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    twitter_username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    company: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    location: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hireable: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bio: Option<String>,
+}
+
+impl<'octo> UpdateProfileBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self {
+            crab,
+            name: None,
+            email: None,
+            blog: None,
+            twitter_username: None,
+            company: None,
+            location: None,
+            hireable: None,
+            bio: None,
+        }
+    }
+
+    /// The new name of the user.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The publicly visible email address of the user. It must be one of the
+    /// user's verified addresses.
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// The new blog URL of the user.
+    pub fn blog(mut self, blog: impl Into<String>) -> Self {
+        self.blog = Some(blog.into());
+        self
+    }
+
+    /// The new Twitter username of the user.
+    pub fn twitter_username(mut self, twitter_username: impl Into<String>) -> Self {
+        self.twitter_username = Some(twitter_username.into());
+        self
+    }
+
+    /// The new company of the user.
+    pub fn company(mut self, company: impl Into<String>) -> Self {
+        self.company = Some(company.into());
+        self
+    }
+
+    /// The new location of the user.
+    pub fn location(mut self, location: impl Into<String>) -> Self {
+        self.location = Some(location.into());
+        self
+    }
+
+    /// Whether the user is available for hire.
+    pub fn hireable(mut self, hireable: bool) -> Self {
+        self.hireable = Some(hireable);
+        self
+    }
+
+    /// The new short biography of the user.
+    pub fn bio(mut self, bio: impl Into<String>) -> Self {
+        self.bio = Some(bio.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<models::UserProfile> {
+        self.crab.patch("/user", Some(&self)).await
+    }
 }
@@ -0,0 +1,115 @@
+//! The enterprises API.
+
+use crate::models::enterprises::{ConsumedLicenses, LicenseSyncStatus};
+use crate::Octocrab;
+
+/// Handler for GitHub's enterprise API.
+///
+/// Created with [`Octocrab::enterprises`].
+pub struct EnterpriseHandler<'octo> {
+    crab: &'octo Octocrab,
+    enterprise: String,
+}
+
+impl<'octo> EnterpriseHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, enterprise: String) -> Self {
+        Self { crab, enterprise }
+    }
+
+    /// Handle the billing of the enterprise.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .enterprises("acme")
+    ///     .billing()
+    ///     .actions()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn billing(&self) -> crate::billing::BillingHandler<'octo> {
+        let route = format!("/enterprises/{}/settings/billing", self.enterprise);
+        crate::billing::BillingHandler::new(self.crab, route.clone(), route)
+    }
+
+    /// Lists the licenses the enterprise purchased and the users consuming
+    /// them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let licenses = octocrab::instance()
+    ///     .enterprises("acme")
+    ///     .consumed_licenses()
+    ///     .per_page(100)
+    ///     .page(1u32)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// println!(
+    ///     "{}/{} seats",
+    ///     licenses.total_seats_consumed, licenses.total_seats_purchased
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn consumed_licenses(&self) -> ListConsumedLicensesBuilder<'_, 'octo> {
+        ListConsumedLicensesBuilder::new(self)
+    }
+
+    /// Gets when the GitHub Enterprise Server instances of the enterprise
+    /// last synced their license usage, and whether it worked.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let status = octocrab::instance()
+    ///     .enterprises("acme")
+    ///     .license_sync_status()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn license_sync_status(&self) -> crate::Result<LicenseSyncStatus> {
+        let route = format!("/enterprises/{}/license-sync-status", self.enterprise);
+        self.crab.get(route, None::<&()>).await
+    }
+}
+
+/// A builder pattern struct for listing the consumed licenses of an
+/// enterprise.
+///
+/// Created by [`EnterpriseHandler::consumed_licenses`].
+#[derive(serde::Serialize)]
+pub struct ListConsumedLicensesBuilder<'r, 'octo> {
+    #[serde(skip)]
+    handler: &'r EnterpriseHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'r, 'octo> ListConsumedLicensesBuilder<'r, 'octo> {
+    pub(crate) fn new(handler: &'r EnterpriseHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Users per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the users to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<ConsumedLicenses> {
+        let route = format!("/enterprises/{}/consumed-licenses", self.handler.enterprise);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+}
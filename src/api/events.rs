@@ -1,15 +1,21 @@
 //! GitHub Events
 
 use crate::{
-    etag::{EntityTag, Etagged},
+    etag::{EntityTag, Etagged, Polled},
     models::events,
     FromResponse, Octocrab, Page,
 };
 use http::request::Builder;
 use http::{header::HeaderMap, Method, StatusCode};
 
+/// A builder pattern struct for listing events, either the public events of
+/// GitHub or those of a user.
+///
+/// Created with [`Octocrab::events`] or the event methods of
+/// [`UserHandler`](crate::users::UserHandler).
 pub struct EventsBuilder<'octo> {
     crab: &'octo Octocrab,
+    route: String,
     headers: Headers,
     params: Params,
 }
@@ -27,9 +33,10 @@ struct Params {
 }
 
 impl<'octo> EventsBuilder<'octo> {
-    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+    pub(crate) fn new(crab: &'octo Octocrab, route: String) -> Self {
         Self {
             crab,
+            route,
             headers: Headers { etag: None },
             params: Params {
                 per_page: None,
@@ -58,8 +65,7 @@ impl<'octo> EventsBuilder<'octo> {
 
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Etagged<Page<events::Event>>> {
-        let route = "/events".to_string();
-        let uri = self.crab.parameterized_uri(route, Some(&self.params))?;
+        let uri = self.crab.list_uri(&self.route, Some(&self.params))?;
 
         let mut headers = HeaderMap::new();
         if let Some(etag) = self.headers.etag {
@@ -84,4 +90,66 @@ impl<'octo> EventsBuilder<'octo> {
                 })
         }
     }
+
+    /// Sends the request as a conditional poll, returning the `ETag` and
+    /// `X-Poll-Interval` headers alongside the page. The page is `None` when
+    /// nothing changed since [`etag`](Self::etag).
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let mut etag = None;
+    /// loop {
+    ///     let polled = octocrab.events().etag(etag.take()).poll().await?;
+    ///     if let Some(page) = polled.value {
+    ///         // handle the new events ...
+    ///     }
+    ///     etag = polled.etag;
+    ///     // wait `polled.poll_interval` before polling again
+    /// }
+    /// # }
+    /// ```
+    pub async fn poll(self) -> crate::Result<Polled<Page<events::Event>>> {
+        let uri = self.crab.list_uri(&self.route, Some(&self.params))?;
+        poll_events(self.crab, uri, self.headers.etag).await
+    }
+
+    /// Creates a [`Poller`](crate::poller::Poller) which streams the new
+    /// events as they arrive.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub fn poller(self) -> crate::Result<crate::poller::Poller<events::Event>> {
+        let uri = self.crab.list_uri(&self.route, Some(&self.params))?;
+        Ok(crate::poller::Poller::from_uri(self.crab, uri))
+    }
+}
+
+/// Sends a conditional request for a page of events, shared by all event
+/// listing builders.
+pub(crate) async fn poll_events(
+    crab: &Octocrab,
+    uri: http::Uri,
+    etag: Option<EntityTag>,
+) -> crate::Result<Polled<Page<events::Event>>> {
+    let mut headers = HeaderMap::new();
+    if let Some(etag) = etag {
+        EntityTag::insert_if_none_match_header(&mut headers, etag)?;
+    }
+    let mut builder = Builder::new().method(Method::GET).uri(uri);
+    for (key, value) in headers.iter() {
+        builder = builder.header(key, value);
+    }
+    let request = crab.build_request(builder, None::<&()>)?;
+
+    let response = crab.execute(request).await?;
+    if response.status() == StatusCode::NOT_MODIFIED {
+        return Ok(Polled::extract_from_response(&response, None));
+    }
+    let response = crate::map_github_error(response).await?;
+    let polled = Polled::extract_from_response(&response, None);
+    let page = <Page<events::Event>>::from_response(response).await?;
+    Ok(Polled {
+        value: Some(page),
+        ..polled
+    })
 }
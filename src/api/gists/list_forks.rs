@@ -47,4 +47,15 @@ impl<'octo, 'b> ListGistForksBuilder<'octo, 'b> {
         let route = format!("/gists/{gist_id}/forks", gist_id = self.gist_id);
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Gist>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
@@ -71,6 +71,18 @@ impl<'octo, T: EndpointSelector> ListGistsBuilder<'octo, T> {
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::gists::Gist>> {
         self.crab.get(T::ENDPOINT, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<crate::models::gists::Gist>>>
+    {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// Handles query data for the `GET /gists` endpoint.
@@ -138,4 +150,15 @@ impl<'octo> ListUserGistsBuilder<'octo> {
             )
             .await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Gist>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
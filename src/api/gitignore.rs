@@ -38,9 +38,9 @@ impl<'octo> GitignoreHandler<'octo> {
     /// # }
     /// ```
     pub async fn get(&self, name: impl AsRef<str>) -> crate::Result<String> {
-        let route = format!("/gitignore/templates/{name}", name = name.as_ref());
+        let route = crate::route::Route::new("/gitignore/templates").segment(name);
         let uri = Uri::builder()
-            .path_and_query(route)
+            .path_and_query(String::from(route))
             .build()
             .context(HttpSnafu)?;
         let mut request = request::Builder::new().method("GET").uri(uri);
@@ -1,11 +1,19 @@
 //! The hooks API.
+use crate::models::hooks::{Config, Delivery, Hook};
 use crate::models::{HookDeliveryId, HookId};
-use crate::Octocrab;
+use crate::{Octocrab, Result};
 
 mod list_deliveries;
+mod list_hooks;
+mod redeliver_failed;
 mod retry_delivery;
+mod update_hook;
 
-pub use self::{list_deliveries::ListHooksDeliveriesBuilder, retry_delivery::RetryDeliveryBuilder};
+pub use self::{
+    list_deliveries::ListHooksDeliveriesBuilder, list_hooks::ListHooksBuilder,
+    redeliver_failed::RedeliverFailedBuilder, retry_delivery::RetryDeliveryBuilder,
+    update_hook::UpdateHookBuilder,
+};
 
 /// A client to GitHub's webhooks API.
 ///
@@ -30,6 +38,187 @@ impl<'octo> HooksHandler<'octo> {
         self
     }
 
+    /// The route of the hooks collection, either for the repository if one
+    /// was set, or for the organization.
+    fn route(&self) -> String {
+        match &self.repo {
+            Some(repo) => format!("/repos/{}/{}/hooks", self.owner, repo),
+            None => format!("/orgs/{}/hooks", self.owner),
+        }
+    }
+
+    fn hook_route(&self, hook_id: HookId) -> String {
+        format!("{}/{}", self.route(), hook_id)
+    }
+
+    /// Lists the webhooks of the organization, or of the repository if one
+    /// was set.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hooks = octocrab::instance()
+    ///     .hooks("owner")
+    ///     //.repo("repo")
+    ///     .list()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListHooksBuilder<'_, '_> {
+        ListHooksBuilder::new(self)
+    }
+
+    /// Gets a single webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hook = octocrab::instance()
+    ///     .hooks("owner")
+    ///     .get(21u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, hook_id: HookId) -> Result<Hook> {
+        self.crab.get(self.hook_route(hook_id), None::<&()>).await
+    }
+
+    /// Creates a webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::hooks::{Config, ContentType, Hook};
+    ///
+    /// let hook = Hook {
+    ///     name: "web".to_string(),
+    ///     active: true,
+    ///     config: Config {
+    ///         url: "https://example.com".to_string(),
+    ///         content_type: Some(ContentType::Json),
+    ///         ..Config::default()
+    ///     },
+    ///     ..Hook::default()
+    /// };
+    ///
+    /// let hook = octocrab::instance().hooks("owner").create(&hook).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(&self, hook: &Hook) -> Result<Hook> {
+        self.crab.post(self.route(), Some(hook)).await
+    }
+
+    /// Updates a webhook. Only the fields that are set on the builder are
+    /// changed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::webhook_events::WebhookEventType;
+    ///
+    /// let hook = octocrab::instance()
+    ///     .hooks("owner")
+    ///     .update(21u64.into())
+    ///     .events(vec![WebhookEventType::Push, WebhookEventType::PullRequest])
+    ///     .active(false)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, hook_id: HookId) -> UpdateHookBuilder<'_, '_> {
+        UpdateHookBuilder::new(self, hook_id)
+    }
+
+    /// Deletes a webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .hooks("owner")
+    ///     .delete(21u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, hook_id: HookId) -> Result<()> {
+        let response = self
+            .crab
+            ._delete(self.hook_route(hook_id), None::<&()>)
+            .await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Triggers a `ping` event to be sent to the webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .hooks("owner")
+    ///     .ping(21u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ping(&self, hook_id: HookId) -> Result<()> {
+        let route = format!("{}/pings", self.hook_route(hook_id));
+        let response = self.crab._post(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Gets the delivery configuration of a webhook. The secret, if any, is
+    /// redacted by GitHub.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let config = octocrab::instance()
+    ///     .hooks("owner")
+    ///     .get_config(21u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_config(&self, hook_id: HookId) -> Result<Config> {
+        let route = format!("{}/config", self.hook_route(hook_id));
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Updates the delivery configuration of a webhook.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::hooks::{Config, ContentType};
+    ///
+    /// let config = Config {
+    ///     url: "https://example.com/webhook".to_string(),
+    ///     content_type: Some(ContentType::Json),
+    ///     ..Config::default()
+    /// };
+    /// let config = octocrab::instance()
+    ///     .hooks("owner")
+    ///     .update_config(21u64.into(), &config)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_config(&self, hook_id: HookId, config: &Config) -> Result<Config> {
+        let route = format!("{}/config", self.hook_route(hook_id));
+        self.crab.patch(route, Some(config)).await
+    }
+
+    /// Gets a single delivery of a webhook, including its request and
+    /// response.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let delivery = octocrab::instance()
+    ///     .hooks("owner")
+    ///     .get_delivery(20u64.into(), 21u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_delivery(
+        &self,
+        hook_id: HookId,
+        delivery_id: HookDeliveryId,
+    ) -> Result<Delivery> {
+        let route = format!("{}/deliveries/{}", self.hook_route(hook_id), delivery_id);
+        self.crab.get(route, None::<&()>).await
+    }
+
     /// Lists all of the `Delivery`s associated with the hook.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -67,4 +256,30 @@ impl<'octo> HooksHandler<'octo> {
     ) -> RetryDeliveryBuilder<'_, '_> {
         RetryDeliveryBuilder::new(self, hook_id, delivery_id)
     }
+
+    /// Redelivers the deliveries of a webhook that failed, e.g. during an
+    /// outage of the receiving server, see [`RedeliverFailedBuilder::send`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let since = chrono::Utc::now() - chrono::Duration::hours(6);
+    /// let results = octocrab::instance()
+    ///     .hooks("owner")
+    ///     //.repo("repo")
+    ///     .redeliver_failed(21u64.into())
+    ///     .since(since)
+    ///     .concurrency(2)
+    ///     .send()
+    ///     .await?;
+    /// for (delivery, result) in results {
+    ///     if let Err(error) = result {
+    ///         println!("failed to redeliver {}: {error}", delivery.guid);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn redeliver_failed(&self, hook_id: HookId) -> RedeliverFailedBuilder<'octo> {
+        let route = format!("{}/deliveries", self.hook_route(hook_id));
+        RedeliverFailedBuilder::new(self.crab, route)
+    }
 }
@@ -40,16 +40,20 @@ impl<'octo, 'r> ListHooksDeliveriesBuilder<'octo, 'r> {
 
     /// Send the actual request.
     pub async fn send(self) -> crate::Result<crate::Page<crate::models::hooks::Delivery>> {
-        let route = match self.handler.repo.clone() {
-            Some(repo) => format!(
-                "/repos/{}/{}/hooks/{}/deliveries",
-                self.handler.owner, repo, self.hook_id
-            ),
-            None => format!(
-                "/orgs/{}/hooks/{}/deliveries",
-                self.handler.owner, self.hook_id
-            ),
-        };
+        let route = format!("{}/deliveries", self.handler.hook_route(self.hook_id));
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<
+        impl futures_core::Stream<Item = crate::Result<crate::models::hooks::Delivery>>,
+    > {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
@@ -0,0 +1,57 @@
+use super::*;
+
+/// A builder pattern struct for listing webhooks.
+///
+/// created by [`HooksHandler::list`]
+///
+/// [`HooksHandler::list`]: ./struct.HooksHandler.html#method.list
+#[derive(serde::Serialize)]
+pub struct ListHooksBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r HooksHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListHooksBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r HooksHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<Hook>> {
+        self.handler
+            .crab
+            .get(self.handler.route(), Some(&self))
+            .await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Hook>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
@@ -0,0 +1,125 @@
+use std::collections::HashSet;
+
+use chrono::{DateTime, Utc};
+
+use crate::models::hooks::Delivery;
+use crate::{Octocrab, Page};
+
+/// A builder pattern struct for redelivering the failed deliveries of a
+/// webhook, e.g. after the receiving server had an outage.
+///
+/// Created with [`HooksHandler::redeliver_failed`] or
+/// [`AppsRequestHandler::redeliver_failed_hook_deliveries`].
+///
+/// [`HooksHandler::redeliver_failed`]: super::HooksHandler::redeliver_failed
+/// [`AppsRequestHandler::redeliver_failed_hook_deliveries`]: crate::apps::AppsRequestHandler::redeliver_failed_hook_deliveries
+pub struct RedeliverFailedBuilder<'octo> {
+    crab: &'octo Octocrab,
+    route: String,
+    since: Option<DateTime<Utc>>,
+    until: Option<DateTime<Utc>>,
+    concurrency: usize,
+}
+
+impl<'octo> RedeliverFailedBuilder<'octo> {
+    /// `route` is the route of the hook's deliveries.
+    pub(crate) fn new(crab: &'octo Octocrab, route: String) -> Self {
+        Self {
+            crab,
+            route,
+            since: None,
+            until: None,
+            concurrency: 4,
+        }
+    }
+
+    /// Only redeliver deliveries made at or after this time. Without it,
+    /// every delivery GitHub still keeps is considered, which covers the
+    /// last few days.
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    /// Only redeliver deliveries made at or before this time.
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    /// The maximum number of redeliveries requested at the same time.
+    /// Default: 4
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Lists the failed deliveries in the time window and redelivers them.
+    ///
+    /// A delivery failed if GitHub didn't get a 2xx response to it. Each
+    /// event is redelivered once, and not at all if one of its deliveries
+    /// already succeeded, so running this again after a partial failure
+    /// only retries what is still missing.
+    ///
+    /// Every delivery is redelivered independently, so a failed redelivery
+    /// doesn't abort the remaining ones; the results are returned next to
+    /// the delivery they retried. Build the client with
+    /// [`OctocrabBuilder::throttle_rate_limits`](crate::OctocrabBuilder::throttle_rate_limits)
+    /// to wait for the rate limit to reset instead of failing once it's
+    /// exhausted.
+    pub async fn send(self) -> crate::Result<Vec<(Delivery, crate::Result<()>)>> {
+        use futures::stream::StreamExt;
+
+        let failed = self.failed_deliveries().await?;
+        let crab = self.crab;
+        let route = self.route.as_str();
+
+        Ok(futures::stream::iter(failed)
+            .map(|delivery| async move {
+                let route = format!("{route}/{}/attempts", delivery.id);
+                let result = match crab._post(route, None::<&()>).await {
+                    Ok(response) => crate::map_github_error(response).await.map(drop),
+                    Err(error) => Err(error),
+                };
+                (delivery, result)
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await)
+    }
+
+    /// The latest failed delivery of each event in the time window whose
+    /// deliveries all failed. GitHub lists deliveries most recent first.
+    async fn failed_deliveries(&self) -> crate::Result<Vec<Delivery>> {
+        // Events that already succeeded or are already being redelivered.
+        let mut handled = HashSet::new();
+        let mut failed = Vec::new();
+        let mut page: Option<Page<Delivery>> = Some(
+            self.crab
+                .get(&self.route, Some(&[("per_page", 100)]))
+                .await?,
+        );
+        while let Some(mut current) = page {
+            for delivery in current.take_items() {
+                if self
+                    .since
+                    .is_some_and(|since| delivery.delivered_at < since)
+                {
+                    return Ok(failed);
+                }
+                // Later successes still count, e.g. a manual redelivery.
+                if (200..300).contains(&delivery.status_code) {
+                    handled.insert(delivery.guid);
+                } else if !self
+                    .until
+                    .is_some_and(|until| delivery.delivered_at > until)
+                    && handled.insert(delivery.guid.clone())
+                {
+                    failed.push(delivery);
+                }
+            }
+            page = self.crab.get_page(&current.next).await?;
+        }
+        Ok(failed)
+    }
+}
@@ -32,16 +32,11 @@ impl<'octo, 'r> RetryDeliveryBuilder<'octo, 'r> {
 
     /// Send the actual request.
     pub async fn send(self) -> crate::Result<()> {
-        let route = match self.handler.repo.clone() {
-            Some(repo) => format!(
-                "/repos/{}/{}/hooks/{}/deliveries/{}/attempts",
-                self.handler.owner, repo, self.hook_id, self.delivery_id
-            ),
-            None => format!(
-                "/orgs/{}/hooks/{}/deliveries/{}/attempts",
-                self.handler.owner, self.hook_id, self.delivery_id
-            ),
-        };
+        let route = format!(
+            "{}/deliveries/{}/attempts",
+            self.handler.hook_route(self.hook_id),
+            self.delivery_id
+        );
 
         let uri = Uri::builder()
             .path_and_query(route)
@@ -0,0 +1,97 @@
+use super::*;
+use crate::models::webhook_events::WebhookEventType;
+
+/// A builder pattern struct for updating a webhook.
+///
+/// created by [`HooksHandler::update`]
+///
+/// [`HooksHandler::update`]: ./struct.HooksHandler.html#method.update
+#[derive(serde::Serialize)]
+pub struct UpdateHookBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r HooksHandler<'octo>,
+    #[serde(skip)]
+    hook_id: HookId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: Option<Config>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<WebhookEventType>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl<'octo, 'r> UpdateHookBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r HooksHandler<'octo>, hook_id: HookId) -> Self {
+        Self {
+            handler,
+            hook_id,
+            config: None,
+            events: None,
+            active: None,
+            name: None,
+        }
+    }
+
+    /// The delivery configuration of the webhook.
+    pub fn config(mut self, config: Config) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// The events the webhook is triggered for. Replaces the existing list.
+    pub fn events(mut self, events: impl Into<Vec<WebhookEventType>>) -> Self {
+        self.events = Some(events.into());
+        self
+    }
+
+    /// Whether notifications are sent when the webhook is triggered.
+    pub fn active(mut self, active: impl Into<bool>) -> Self {
+        self.active = Some(active.into());
+        self
+    }
+
+    /// The name of the webhook. Only `web` is accepted.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> crate::Result<Hook> {
+        let route = self.handler.hook_route(self.hook_id);
+        self.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::hooks::Config;
+    use crate::models::webhook_events::WebhookEventType;
+
+    #[tokio::test]
+    async fn serialize() {
+        #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+        crate::ensure_crypto_provider_initialized();
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.hooks("owner");
+        let update = handler
+            .update(1u64.into())
+            .config(Config {
+                url: "https://example.com".to_string(),
+                ..Config::default()
+            })
+            .events(vec![WebhookEventType::Push])
+            .active(false);
+
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({
+                "config": { "url": "https://example.com" },
+                "events": ["push"],
+                "active": false,
+            })
+        )
+    }
+}
@@ -0,0 +1,169 @@
+//! The GitHub Models catalog and inference API.
+use crate::models::inference::{CatalogModel, ChatCompletion, ChatMessage};
+use crate::Octocrab;
+
+/// A client to the GitHub Models catalog and inference API, which is served
+/// from its own host, see [`OctocrabBuilder::models_uri`](crate::OctocrabBuilder::models_uri).
+///
+/// Inference is authenticated like the rest of the API, with a token which
+/// has the `models: read` permission, and is rate limited per model.
+///
+/// Created with [`Octocrab::models_api`].
+pub struct ModelsHandler<'octo> {
+    crab: &'octo Octocrab,
+}
+
+impl<'octo> ModelsHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self { crab }
+    }
+
+    /// Lists the models of the catalog.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let models = octocrab::instance().models_api().list().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> crate::Result<Vec<CatalogModel>> {
+        let route = self.crab.models_route("/catalog/models");
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Runs a chat with a model of the catalog.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::inference::ChatMessage;
+    ///
+    /// let completion = octocrab::instance()
+    ///     .models_api()
+    ///     .chat_completion("openai/gpt-4.1")
+    ///     .message(ChatMessage::system("You are a helpful assistant."))
+    ///     .message(ChatMessage::user("What is the capital of France?"))
+    ///     .temperature(0.2)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn chat_completion(&self, model: impl Into<String>) -> ChatCompletionBuilder<'_, 'octo> {
+        ChatCompletionBuilder::new(self, model.into())
+    }
+}
+
+/// A builder pattern struct for running a chat with a model.
+///
+/// Created by [`ModelsHandler::chat_completion`].
+#[derive(serde::Serialize)]
+pub struct ChatCompletionBuilder<'r, 'octo> {
+    #[serde(skip)]
+    handler: &'r ModelsHandler<'octo>,
+    #[serde(skip)]
+    org: Option<String>,
+    model: String,
+    messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    temperature: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    top_p: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    frequency_penalty: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presence_penalty: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    seed: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stop: Option<Vec<String>>,
+}
+
+impl<'r, 'octo> ChatCompletionBuilder<'r, 'octo> {
+    pub(crate) fn new(handler: &'r ModelsHandler<'octo>, model: String) -> Self {
+        Self {
+            handler,
+            org: None,
+            model,
+            messages: Vec::new(),
+            temperature: None,
+            top_p: None,
+            max_tokens: None,
+            frequency_penalty: None,
+            presence_penalty: None,
+            seed: None,
+            stop: None,
+        }
+    }
+
+    /// Attributes the request to an organization, which it's then billed to
+    /// and rate limited with.
+    pub fn org(mut self, org: impl Into<String>) -> Self {
+        self.org = Some(org.into());
+        self
+    }
+
+    /// Appends a message to the chat.
+    pub fn message(mut self, message: ChatMessage) -> Self {
+        self.messages.push(message);
+        self
+    }
+
+    /// Appends messages to the chat.
+    pub fn messages(mut self, messages: impl IntoIterator<Item = ChatMessage>) -> Self {
+        self.messages.extend(messages);
+        self
+    }
+
+    /// How random the answer is, from 0 to 1.
+    pub fn temperature(mut self, temperature: f64) -> Self {
+        self.temperature = Some(temperature);
+        self
+    }
+
+    /// Only samples from the tokens making up this probability mass, from 0
+    /// to 1.
+    pub fn top_p(mut self, top_p: f64) -> Self {
+        self.top_p = Some(top_p);
+        self
+    }
+
+    /// The most tokens the answer may have.
+    pub fn max_tokens(mut self, max_tokens: u32) -> Self {
+        self.max_tokens = Some(max_tokens);
+        self
+    }
+
+    /// Penalizes tokens by how often they already appear, from -2 to 2.
+    pub fn frequency_penalty(mut self, penalty: f64) -> Self {
+        self.frequency_penalty = Some(penalty);
+        self
+    }
+
+    /// Penalizes tokens which already appear, from -2 to 2.
+    pub fn presence_penalty(mut self, penalty: f64) -> Self {
+        self.presence_penalty = Some(penalty);
+        self
+    }
+
+    /// Makes the sampling deterministic, as far as the model allows.
+    pub fn seed(mut self, seed: i64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Sequences the model stops generating at.
+    pub fn stop(mut self, stop: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.stop = Some(stop.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<ChatCompletion> {
+        let route = match &self.org {
+            Some(org) => format!("/orgs/{org}/inference/chat/completions"),
+            None => String::from("/inference/chat/completions"),
+        };
+        let route = self.handler.crab.models_route(&route);
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
@@ -3,19 +3,28 @@
 mod create;
 mod list;
 mod list_labels;
+mod milestones;
+mod sub_issues;
+mod transfer;
 mod update;
 
 use crate::error::HttpSnafu;
-use crate::models::{CommentId, ReactionId};
+use crate::models::{CommentId, NodeId, ReactionId};
+use crate::route::Route;
 use crate::{models, params, Octocrab, Result};
 use http::Uri;
-use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
 use snafu::ResultExt;
 
 pub use self::{
     create::CreateIssueBuilder,
     list::ListIssuesBuilder,
     list_labels::{ListLabelsForIssueBuilder, ListLabelsForRepoBuilder},
+    milestones::{
+        CreateMilestoneBuilder, ListLabelsForMilestoneBuilder, ListMilestonesBuilder,
+        UpdateMilestoneBuilder,
+    },
+    sub_issues::{AddSubIssueBuilder, ListSubIssuesBuilder, ReprioritizeSubIssueBuilder},
+    transfer::TransferIssueBuilder,
     update::UpdateIssueBuilder,
 };
 
@@ -194,7 +203,8 @@ impl<'octo> IssueHandler<'octo> {
 /// # Assignees
 impl IssueHandler<'_> {
     /// Adds up to 10 assignees to an issue. Users already assigned to an issue
-    /// are not replaced.
+    /// are not replaced. As every pull request is an issue, this also works
+    /// with pull request numbers.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let octocrab = octocrab::Octocrab::default();
@@ -214,7 +224,7 @@ impl IssueHandler<'_> {
             .await
     }
 
-    /// Removes one or more assignees from an issue.
+    /// Removes one or more assignees from an issue or pull request.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// # let octocrab = octocrab::Octocrab::default();
@@ -250,6 +260,32 @@ impl IssueHandler<'_> {
             assignee = assignee.as_ref()
         );
 
+        self.check_assignee_route(route).await
+    }
+
+    /// Checks if a user can be assigned to a specific issue or pull request.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// assert!(octocrab.issues("owner", "repo").check_assignee_for_issue(101, "ferris").await?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn check_assignee_for_issue(
+        &self,
+        number: u64,
+        assignee: impl AsRef<str>,
+    ) -> Result<bool> {
+        let route = format!(
+            "/{}/issues/{number}/assignees/{assignee}",
+            self.repo,
+            assignee = assignee.as_ref()
+        );
+
+        self.check_assignee_route(route).await
+    }
+
+    async fn check_assignee_route(&self, route: String) -> Result<bool> {
         let uri = Uri::builder()
             .path_and_query(route)
             .build()
@@ -286,6 +322,10 @@ impl IssueHandler<'_> {
     }
 }
 
+/// A builder pattern struct for listing the users that can be assigned to
+/// issues in a repository.
+///
+/// created by [`IssueHandler::list_assignees`]
 #[derive(serde::Serialize)]
 pub struct ListAssigneesBuilder<'octo, 'r> {
     #[serde(skip)]
@@ -323,6 +363,17 @@ impl<'octo, 'r> ListAssigneesBuilder<'octo, 'r> {
 
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::Author>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// # Labels
@@ -360,12 +411,9 @@ impl IssueHandler<'_> {
         number: u64,
         label: impl AsRef<str>,
     ) -> Result<Vec<models::Label>> {
-        let route = format!(
-            "/{}/issues/{issue_number}/labels/{name}",
-            self.repo,
-            issue_number = number,
-            name = utf8_percent_encode(label.as_ref(), NON_ALPHANUMERIC),
-        );
+        let route = Route::new(format_args!("/{}/issues/{number}", self.repo))
+            .path("labels")
+            .segment(label);
 
         self.crab.delete(route, None::<&()>).await
     }
@@ -433,7 +481,9 @@ impl IssueHandler<'_> {
     /// # }
     /// ```
     pub async fn get_label(&self, name: impl AsRef<str>) -> Result<models::Label> {
-        let route = format!("/{}/labels/{name}", self.repo, name = name.as_ref(),);
+        let route = Route::new(format_args!("/{}", self.repo))
+            .path("labels")
+            .segment(name);
 
         self.crab.get(route, None::<&()>).await
     }
@@ -449,7 +499,9 @@ impl IssueHandler<'_> {
     /// # }
     /// ```
     pub async fn delete_label(&self, name: impl AsRef<str>) -> Result<()> {
-        let route = format!("/{}/labels/{name}", self.repo, name = name.as_ref(),);
+        let route = Route::new(format_args!("/{}", self.repo))
+            .path("labels")
+            .segment(name);
 
         self.crab._delete(route, None::<&()>).await?;
         Ok(())
@@ -492,6 +544,323 @@ impl IssueHandler<'_> {
     }
 }
 
+/// # Milestones
+impl<'octo> IssueHandler<'octo> {
+    /// Lists milestones in the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// let page = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .list_milestones()
+    ///     // Optional Parameters
+    ///     .state(params::State::All)
+    ///     .sort(params::issues::MilestoneSort::DueOn)
+    ///     .direction(params::Direction::Descending)
+    ///     .per_page(20)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_milestones(&self) -> ListMilestonesBuilder<'octo, '_> {
+        ListMilestonesBuilder::new(self)
+    }
+
+    /// Gets a milestone by its number.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let milestone = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .get_milestone(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_milestone(&self, number: u64) -> Result<models::Milestone> {
+        let route = format!("/{}/milestones/{number}", self.repo);
+
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a milestone in the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use chrono::{TimeZone, Utc};
+    ///
+    /// let milestone = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .create_milestone("v1.0")
+    ///     // Optional Parameters
+    ///     .description("The first stable release")
+    ///     .due_on(Utc.with_ymd_and_hms(2025, 6, 1, 0, 0, 0).unwrap())
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_milestone(&self, title: impl Into<String>) -> CreateMilestoneBuilder<'octo, '_> {
+        CreateMilestoneBuilder::new(self, title.into())
+    }
+
+    /// Updates a milestone in the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::IssueState;
+    ///
+    /// let milestone = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .update_milestone(1)
+    ///     .state(IssueState::Closed)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update_milestone(&self, number: u64) -> UpdateMilestoneBuilder<'octo, '_> {
+        UpdateMilestoneBuilder::new(self, number)
+    }
+
+    /// Deletes a milestone from the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .delete_milestone(1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_milestone(&self, number: u64) -> Result<()> {
+        let route = format!("/{}/milestones/{number}", self.repo);
+
+        let response = self.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Lists the labels of every issue in a milestone.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .list_labels_for_milestone(1)
+    ///     // Optional Parameters
+    ///     .per_page(20)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_labels_for_milestone(
+        &self,
+        number: u64,
+    ) -> ListLabelsForMilestoneBuilder<'octo, '_> {
+        ListLabelsForMilestoneBuilder::new(self, number)
+    }
+}
+
+/// # Transferring and pinning
+///
+/// These go through the GraphQL API, as the REST API doesn't cover them.
+impl<'octo> IssueHandler<'octo> {
+    /// Transfers an issue to another repository owned by the same user or
+    /// organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let issue = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .transfer(101, "owner", "other-repo")
+    ///     // Optional Parameters
+    ///     .create_labels_if_missing(true)
+    ///     .send()
+    ///     .await?;
+    /// println!("moved to {}", issue.html_url);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn transfer(
+        &self,
+        number: u64,
+        new_owner: impl Into<String>,
+        new_repo: impl Into<String>,
+    ) -> TransferIssueBuilder<'octo, '_> {
+        TransferIssueBuilder::new(self, number, new_owner.into(), new_repo.into())
+    }
+
+    /// Pins an issue to the top of the repository's issue list. A repository
+    /// can have up to three pinned issues.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().issues("owner", "repo").pin(101).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn pin(&self, number: u64) -> Result<()> {
+        let issue_id = self.node_id(number).await?;
+
+        self.crab
+            .graphql_mutation(
+                "mutation($input: PinIssueInput!) {
+  pinIssue(input: $input) { clientMutationId }
+}",
+                &serde_json::json!({ "issueId": issue_id }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Unpins a pinned issue.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().issues("owner", "repo").unpin(101).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unpin(&self, number: u64) -> Result<()> {
+        let issue_id = self.node_id(number).await?;
+
+        self.crab
+            .graphql_mutation(
+                "mutation($input: UnpinIssueInput!) {
+  unpinIssue(input: $input) { clientMutationId }
+}",
+                &serde_json::json!({ "issueId": issue_id }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Looks up the GraphQL node id of an issue with a GraphQL query, which
+    /// is cheaper than fetching the issue. The GraphQL API can't look up
+    /// repositories by their REST id though, so for those it's fetched.
+    pub(crate) async fn node_id(&self, number: u64) -> Result<NodeId> {
+        let RepoRef::ByOwnerAndName(owner, name) = &self.repo else {
+            return Ok(self.get(number).await?.node_id);
+        };
+        let mut data = self
+            .crab
+            .graphql_query(
+                "query($owner: String!, $name: String!, $number: Int!) {
+  repository(owner: $owner, name: $name) { issue(number: $number) { id } }
+}",
+                &serde_json::json!({ "owner": owner, "name": name, "number": number }),
+            )
+            .await?;
+        serde_json::from_value(data["repository"]["issue"]["id"].take())
+            .context(crate::error::SerdeSnafu)
+    }
+}
+
+/// # Sub-issues
+impl<'octo> IssueHandler<'octo> {
+    /// Lists the sub-issues of an issue, in priority order.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .list_sub_issues(101)
+    ///     // Optional Parameters
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_sub_issues(&self, number: u64) -> ListSubIssuesBuilder<'octo, '_> {
+        ListSubIssuesBuilder::new(self, number)
+    }
+
+    /// Adds an existing issue as a sub-issue of issue `number`. Note that the
+    /// sub-issue is identified by its ID, not its number.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let issues = octocrab.issues("owner", "repo");
+    /// let child = issues.get(102).await?;
+    /// let parent = issues
+    ///     .add_sub_issue(101, child.id)
+    ///     // Optional Parameters
+    ///     .replace_parent(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn add_sub_issue(
+        &self,
+        number: u64,
+        sub_issue_id: impl Into<models::IssueId>,
+    ) -> AddSubIssueBuilder<'octo, '_> {
+        AddSubIssueBuilder::new(self, number, sub_issue_id.into())
+    }
+
+    /// Removes a sub-issue from issue `number`, returning the parent issue.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::IssueId;
+    ///
+    /// let parent = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .remove_sub_issue(101, IssueId(1234))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_sub_issue(
+        &self,
+        number: u64,
+        sub_issue_id: impl Into<models::IssueId>,
+    ) -> Result<models::issues::Issue> {
+        let route = format!("/{}/issues/{number}/sub_issue", self.repo);
+
+        self.crab
+            .delete(
+                route,
+                Some(&serde_json::json!({ "sub_issue_id": sub_issue_id.into() })),
+            )
+            .await
+    }
+
+    /// Moves a sub-issue before or after one of its siblings.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::IssueId;
+    ///
+    /// let parent = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .reprioritize_sub_issue(101, IssueId(1234))
+    ///     .after(IssueId(5678))
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reprioritize_sub_issue(
+        &self,
+        number: u64,
+        sub_issue_id: impl Into<models::IssueId>,
+    ) -> ReprioritizeSubIssueBuilder<'octo, '_> {
+        ReprioritizeSubIssueBuilder::new(self, number, sub_issue_id.into())
+    }
+
+    /// Gets the parent of a sub-issue.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let parent = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .get_parent(102)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_parent(&self, number: u64) -> Result<models::issues::Issue> {
+        let route = format!("/{}/issues/{number}/parent", self.repo);
+
+        self.crab.get(route, None::<&()>).await
+    }
+}
+
 /// # Comments
 impl IssueHandler<'_> {
     /// Creates a comment in the issue.
@@ -667,6 +1036,18 @@ impl<'octo, 'r> ListCommentsBuilder<'octo, 'r> {
 
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::issues::Comment>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -733,6 +1114,18 @@ impl<'octo, 'r> ListIssueCommentsBuilder<'octo, 'r> {
 
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::issues::Comment>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -778,6 +1171,19 @@ impl<'octo, 'r> ListTimelineEventsBuilder<'octo, 'r> {
 
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<
+        impl futures_core::Stream<Item = crate::Result<models::timelines::TimelineEvent>>,
+    > {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 // Timeline
@@ -800,7 +1206,32 @@ impl IssueHandler<'_> {
     }
 }
 
-impl IssueHandler<'_> {
+impl<'octo> IssueHandler<'octo> {
+    /// Creates a [`ReactionsHandler`] to list, add and remove reactions on
+    /// an issue.
+    ///
+    /// [`ReactionsHandler`]: crate::reactions::ReactionsHandler
+    pub fn reactions(&self, issue_number: u64) -> crate::reactions::ReactionsHandler<'octo> {
+        crate::reactions::ReactionsHandler::new(
+            self.crab,
+            format!("/{}/issues/{issue_number}", self.repo),
+        )
+    }
+
+    /// Creates a [`ReactionsHandler`] to list, add and remove reactions on
+    /// an issue comment.
+    ///
+    /// [`ReactionsHandler`]: crate::reactions::ReactionsHandler
+    pub fn comment_reactions(
+        &self,
+        comment_id: impl Into<CommentId>,
+    ) -> crate::reactions::ReactionsHandler<'octo> {
+        crate::reactions::ReactionsHandler::new(
+            self.crab,
+            format!("/{}/issues/comments/{}", self.repo, comment_id.into()),
+        )
+    }
+
     /// Lists reactions for an issue.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -882,6 +1313,18 @@ impl<'octo, 'r> ListReactionsBuilder<'octo, 'r> {
 
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::reactions::Reaction>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -927,6 +1370,18 @@ impl<'octo, 'r> ListCommentReactionsBuilder<'octo, 'r> {
 
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::reactions::Reaction>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 impl IssueHandler<'_> {
@@ -0,0 +1,293 @@
+use super::*;
+
+/// A builder pattern struct for listing milestones.
+///
+/// created by [`IssueHandler::list_milestones`]
+#[derive(serde::Serialize)]
+pub struct ListMilestonesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<params::State>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<params::issues::MilestoneSort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListMilestonesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>) -> Self {
+        Self {
+            handler,
+            state: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Filter milestones by `state`. Default: `open`.
+    pub fn state(mut self, state: impl Into<params::State>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// What to sort results by, either the due date or the completeness.
+    /// Default: `due_on`.
+    pub fn sort(mut self, sort: impl Into<params::issues::MilestoneSort>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// The direction of the sort. Default: ascending.
+    pub fn direction(mut self, direction: impl Into<params::Direction>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> Result<crate::Page<models::Milestone>> {
+        let route = format!("/{}/milestones", self.handler.repo);
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::Milestone>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+/// A builder pattern struct for creating a milestone.
+///
+/// created by [`IssueHandler::create_milestone`]
+#[derive(serde::Serialize)]
+pub struct CreateMilestoneBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<models::IssueState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<'octo, 'r> CreateMilestoneBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>, title: String) -> Self {
+        Self {
+            handler,
+            title,
+            state: None,
+            description: None,
+            due_on: None,
+        }
+    }
+
+    /// The state of the milestone. Default: `open`.
+    pub fn state(mut self, state: impl Into<models::IssueState>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// A description of the milestone.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The date the milestone is due. GitHub only keeps the date, the time
+    /// of day is dropped.
+    pub fn due_on(mut self, due_on: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.due_on = Some(due_on.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> Result<models::Milestone> {
+        let route = format!("/{}/milestones", self.handler.repo);
+
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for updating a milestone.
+///
+/// created by [`IssueHandler::update_milestone`]
+#[derive(serde::Serialize)]
+pub struct UpdateMilestoneBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    #[serde(skip)]
+    number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<models::IssueState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    due_on: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+impl<'octo, 'r> UpdateMilestoneBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>, number: u64) -> Self {
+        Self {
+            handler,
+            number,
+            title: None,
+            state: None,
+            description: None,
+            due_on: None,
+        }
+    }
+
+    /// The title of the milestone.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// The state of the milestone.
+    pub fn state(mut self, state: impl Into<models::IssueState>) -> Self {
+        self.state = Some(state.into());
+        self
+    }
+
+    /// A description of the milestone.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The date the milestone is due.
+    pub fn due_on(mut self, due_on: impl Into<chrono::DateTime<chrono::Utc>>) -> Self {
+        self.due_on = Some(due_on.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> Result<models::Milestone> {
+        let route = format!(
+            "/{}/milestones/{number}",
+            self.handler.repo,
+            number = self.number,
+        );
+
+        self.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for listing the labels of the issues in a
+/// milestone.
+///
+/// created by [`IssueHandler::list_labels_for_milestone`]
+#[derive(serde::Serialize)]
+pub struct ListLabelsForMilestoneBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    #[serde(skip)]
+    number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListLabelsForMilestoneBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>, number: u64) -> Self {
+        Self {
+            handler,
+            number,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> Result<crate::Page<models::Label>> {
+        let route = format!(
+            "/{}/milestones/{number}/labels",
+            self.handler.repo,
+            number = self.number,
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::Label>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+        crate::ensure_crypto_provider_initialized();
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let list = handler
+            .list_milestones()
+            .state(crate::params::State::All)
+            .sort(crate::params::issues::MilestoneSort::Completeness)
+            .direction(crate::params::Direction::Descending)
+            .per_page(100)
+            .page(2u32);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "state": "all",
+                "sort": "completeness",
+                "direction": "desc",
+                "per_page": 100,
+                "page": 2,
+            })
+        )
+    }
+}
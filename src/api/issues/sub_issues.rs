@@ -0,0 +1,189 @@
+use super::*;
+
+/// A builder pattern struct for listing the sub-issues of an issue.
+///
+/// created by [`IssueHandler::list_sub_issues`]
+#[derive(serde::Serialize)]
+pub struct ListSubIssuesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    #[serde(skip)]
+    number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListSubIssuesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r IssueHandler<'octo>, number: u64) -> Self {
+        Self {
+            handler,
+            number,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> Result<crate::Page<models::issues::Issue>> {
+        let route = format!(
+            "/{}/issues/{number}/sub_issues",
+            self.handler.repo,
+            number = self.number,
+        );
+
+        self.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::issues::Issue>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+/// A builder pattern struct for adding a sub-issue to an issue.
+///
+/// created by [`IssueHandler::add_sub_issue`]
+#[derive(serde::Serialize)]
+pub struct AddSubIssueBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    #[serde(skip)]
+    number: u64,
+    sub_issue_id: models::IssueId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replace_parent: Option<bool>,
+}
+
+impl<'octo, 'r> AddSubIssueBuilder<'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'r IssueHandler<'octo>,
+        number: u64,
+        sub_issue_id: models::IssueId,
+    ) -> Self {
+        Self {
+            handler,
+            number,
+            sub_issue_id,
+            replace_parent: None,
+        }
+    }
+
+    /// Move the sub-issue over if it already has a different parent, instead
+    /// of failing.
+    pub fn replace_parent(mut self, replace_parent: impl Into<bool>) -> Self {
+        self.replace_parent = Some(replace_parent.into());
+        self
+    }
+
+    /// Send the actual request. Returns the parent issue.
+    pub async fn send(self) -> Result<models::issues::Issue> {
+        let route = format!(
+            "/{}/issues/{number}/sub_issues",
+            self.handler.repo,
+            number = self.number,
+        );
+
+        self.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+/// A builder pattern struct for moving a sub-issue within its parent's list
+/// of sub-issues.
+///
+/// created by [`IssueHandler::reprioritize_sub_issue`]
+#[derive(serde::Serialize)]
+pub struct ReprioritizeSubIssueBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r IssueHandler<'octo>,
+    #[serde(skip)]
+    number: u64,
+    sub_issue_id: models::IssueId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after_id: Option<models::IssueId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before_id: Option<models::IssueId>,
+}
+
+impl<'octo, 'r> ReprioritizeSubIssueBuilder<'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'r IssueHandler<'octo>,
+        number: u64,
+        sub_issue_id: models::IssueId,
+    ) -> Self {
+        Self {
+            handler,
+            number,
+            sub_issue_id,
+            after_id: None,
+            before_id: None,
+        }
+    }
+
+    /// Place the sub-issue directly after the sub-issue with this ID.
+    pub fn after(mut self, after_id: impl Into<models::IssueId>) -> Self {
+        self.after_id = Some(after_id.into());
+        self.before_id = None;
+        self
+    }
+
+    /// Place the sub-issue directly before the sub-issue with this ID.
+    pub fn before(mut self, before_id: impl Into<models::IssueId>) -> Self {
+        self.before_id = Some(before_id.into());
+        self.after_id = None;
+        self
+    }
+
+    /// Send the actual request. Returns the parent issue.
+    pub async fn send(self) -> Result<models::issues::Issue> {
+        let route = format!(
+            "/{}/issues/{number}/sub_issues/priority",
+            self.handler.repo,
+            number = self.number,
+        );
+
+        self.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+        crate::ensure_crypto_provider_initialized();
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust");
+        let reprioritize = handler
+            .reprioritize_sub_issue(1, crate::models::IssueId(3))
+            .before(crate::models::IssueId(4))
+            .after(crate::models::IssueId(2));
+
+        assert_eq!(
+            serde_json::to_value(reprioritize).unwrap(),
+            serde_json::json!({
+                "sub_issue_id": 3,
+                "after_id": 2,
+            })
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use super::*;
+
+/// A builder pattern struct for transferring an issue to another repository.
+///
+/// created by [`IssueHandler::transfer`]
+pub struct TransferIssueBuilder<'octo, 'r> {
+    handler: &'r IssueHandler<'octo>,
+    number: u64,
+    new_owner: String,
+    new_repo: String,
+    create_labels_if_missing: Option<bool>,
+}
+
+impl<'octo, 'r> TransferIssueBuilder<'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'r IssueHandler<'octo>,
+        number: u64,
+        new_owner: String,
+        new_repo: String,
+    ) -> Self {
+        Self {
+            handler,
+            number,
+            new_owner,
+            new_repo,
+            create_labels_if_missing: None,
+        }
+    }
+
+    /// Create the issue's labels in the new repository if they don't exist
+    /// there yet, instead of dropping them.
+    pub fn create_labels_if_missing(mut self, create: impl Into<bool>) -> Self {
+        self.create_labels_if_missing = Some(create.into());
+        self
+    }
+
+    /// Send the actual request. Returns the issue as it is in the new
+    /// repository, where it has a new number.
+    pub async fn send(self) -> Result<models::issues::Issue> {
+        let crab = self.handler.crab;
+        let mut variables = serde_json::json!({
+            "newOwner": self.new_owner,
+            "newName": self.new_repo,
+        });
+        // Both ids are looked up in one query, unless the repository is
+        // referred to by its REST id, which the GraphQL API can't look up.
+        let query = match &self.handler.repo {
+            RepoRef::ByOwnerAndName(owner, name) => {
+                variables["owner"] = owner.as_str().into();
+                variables["name"] = name.as_str().into();
+                variables["number"] = self.number.into();
+                "query($owner: String!, $name: String!, $number: Int!, $newOwner: String!, $newName: String!) {
+  repository(owner: $owner, name: $name) { issue(number: $number) { id } }
+  target: repository(owner: $newOwner, name: $newName) { id }
+}"
+            }
+            RepoRef::ById(_) => {
+                "query($newOwner: String!, $newName: String!) {
+  target: repository(owner: $newOwner, name: $newName) { id }
+}"
+            }
+        };
+        let mut data = crab.graphql_query(query, &variables).await?;
+        let issue_id = match &self.handler.repo {
+            RepoRef::ByOwnerAndName(..) => data["repository"]["issue"]["id"].take(),
+            RepoRef::ById(_) => String::from(self.handler.node_id(self.number).await?).into(),
+        };
+
+        let mut input = serde_json::json!({
+            "issueId": issue_id,
+            "repositoryId": data["target"]["id"].take(),
+        });
+        if let Some(create) = self.create_labels_if_missing {
+            input["createLabelsIfMissing"] = create.into();
+        }
+
+        let data = crab
+            .graphql_mutation(
+                "mutation($input: TransferIssueInput!) {
+  transferIssue(input: $input) { issue { number } }
+}",
+                &input,
+            )
+            .await?;
+        let number = data["transferIssue"]["issue"]["number"]
+            .as_u64()
+            .ok_or_else(|| crate::Error::Other {
+                source: "transferIssue did not return the new issue number".into(),
+                backtrace: snafu::Backtrace::capture(),
+            })?;
+
+        crab.issues(self.new_owner, self.new_repo).get(number).await
+    }
+}
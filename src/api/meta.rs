@@ -0,0 +1,93 @@
+//! The meta API, with information about GitHub itself.
+
+use std::collections::HashMap;
+
+use http::Uri;
+use snafu::ResultExt;
+
+use crate::error::HttpSnafu;
+use crate::models::meta::Meta;
+use crate::Octocrab;
+
+/// Handler for GitHub's meta API.
+///
+/// Created with [`Octocrab::meta`].
+pub struct MetaHandler<'octo> {
+    crab: &'octo Octocrab,
+}
+
+impl<'octo> MetaHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self { crab }
+    }
+
+    /// Gets information about GitHub, including the IP ranges its webhooks,
+    /// Actions runners and other services connect from.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let meta = octocrab::instance().meta().get().await?;
+    /// for range in meta.hooks.unwrap_or_default() {
+    ///     println!("allow {range}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self) -> crate::Result<Meta> {
+        self.crab.get("/meta", None::<&()>).await
+    }
+
+    /// Lists the emojis available on GitHub, keyed by name with the URL of
+    /// their image as value.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let emojis = octocrab::instance().meta().emojis().await?;
+    /// println!("{:?}", emojis.get("octocat"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn emojis(&self) -> crate::Result<HashMap<String, String>> {
+        self.crab.get("/emojis", None::<&()>).await
+    }
+
+    /// Gets the octocat as ASCII art, saying the given words or a random
+    /// quote.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocat = octocrab::instance().meta().octocat(Some("Hello")).await?;
+    /// println!("{octocat}");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn octocat(&self, s: Option<&str>) -> crate::Result<String> {
+        #[derive(serde::Serialize)]
+        struct Params<'a> {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            s: Option<&'a str>,
+        }
+
+        let uri = self
+            .crab
+            .parameterized_uri("/octocat", Some(&Params { s }))?;
+        self.get_text(uri).await
+    }
+
+    /// Gets a random sentence from GitHub's zen of design.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let zen = octocrab::instance().meta().zen().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn zen(&self) -> crate::Result<String> {
+        let uri = Uri::builder()
+            .path_and_query("/zen")
+            .build()
+            .context(HttpSnafu)?;
+        self.get_text(uri).await
+    }
+
+    async fn get_text(&self, uri: Uri) -> crate::Result<String> {
+        let response = crate::map_github_error(self.crab._get(uri).await?).await?;
+        self.crab.body_to_string(response).await
+    }
+}
@@ -0,0 +1,358 @@
+//! The migrations API, which exports repositories of an organization or of
+//! the authenticated user into an archive.
+
+use bytes::Bytes;
+use http::Uri;
+use http_body_util::combinators::BoxBody;
+use snafu::ResultExt;
+
+use crate::error::HttpSnafu;
+use crate::models::migrations::Migration;
+use crate::models::{MigrationId, Repository};
+use crate::{Octocrab, Page};
+
+/// Handler for the migrations of an organization or of the authenticated
+/// user.
+///
+/// Created with [`OrgHandler::migrations`](crate::orgs::OrgHandler::migrations)
+/// or [`CurrentAuthHandler::migrations`](crate::current::CurrentAuthHandler::migrations).
+pub struct MigrationsHandler<'octo> {
+    crab: &'octo Octocrab,
+    route: String,
+}
+
+impl<'octo> MigrationsHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: String) -> Self {
+        Self { crab, route }
+    }
+
+    /// Starts a migration which exports the given repositories.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let migration = octocrab::instance()
+    ///     .orgs("org")
+    ///     .migrations()
+    ///     .start(["repo-a", "repo-b"])
+    ///     .lock_repositories(true)
+    ///     .exclude_releases(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn start<I>(&self, repositories: I) -> StartMigrationBuilder<'octo, '_>
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        StartMigrationBuilder::new(self, repositories.into_iter().map(Into::into).collect())
+    }
+
+    /// Lists the most recent migrations.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let migrations = octocrab::instance()
+    ///     .current()
+    ///     .migrations()
+    ///     .list()
+    ///     .per_page(10)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListMigrationsBuilder<'octo, '_> {
+        ListMigrationsBuilder::new(self)
+    }
+
+    /// Gets a migration, which is how its status is polled.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::migrations::MigrationState;
+    ///
+    /// let migration = octocrab::instance()
+    ///     .orgs("org")
+    ///     .migrations()
+    ///     .get(79u64.into())
+    ///     .await?;
+    ///
+    /// if migration.state == MigrationState::Exported {
+    ///     // The archive is ready to be downloaded.
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, id: MigrationId) -> crate::Result<Migration> {
+        let route = format!("{}/{id}", self.route);
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Lists the repositories of a migration.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repositories = octocrab::instance()
+    ///     .orgs("org")
+    ///     .migrations()
+    ///     .list_repositories(79u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_repositories(&self, id: MigrationId) -> crate::Result<Page<Repository>> {
+        let route = format!("{}/{id}/repositories", self.route);
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Downloads the archive of an exported migration into memory. Prefer
+    /// [`MigrationsHandler::stream_archive`] for large archives.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let archive = octocrab::instance()
+    ///     .orgs("org")
+    ///     .migrations()
+    ///     .download_archive(79u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download_archive(&self, id: MigrationId) -> crate::Result<Bytes> {
+        use http_body_util::BodyExt;
+
+        let response = self.archive_response(id).await?;
+        Ok(response.into_body().collect().await?.to_bytes())
+    }
+
+    /// Streams the archive of an exported migration.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use futures_util::StreamExt;
+    ///
+    /// let mut stream = octocrab::instance()
+    ///     .orgs("org")
+    ///     .migrations()
+    ///     .stream_archive(79u64.into())
+    ///     .await?;
+    ///
+    /// while let Some(chunk) = stream.next().await {
+    ///     println!("{:?}", chunk?.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn stream_archive(
+        &self,
+        id: MigrationId,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Bytes>>> {
+        use futures_util::TryStreamExt;
+
+        let response = self.archive_response(id).await?;
+        Ok(http_body_util::BodyStream::new(response.into_body())
+            .try_filter_map(|frame| futures_util::future::ok(frame.into_data().ok())))
+    }
+
+    /// Requests the archive of a migration, following the redirect to the
+    /// storage backend GitHub serves archives from.
+    async fn archive_response(
+        &self,
+        id: MigrationId,
+    ) -> crate::Result<http::Response<BoxBody<Bytes, crate::Error>>> {
+        let uri = Uri::builder()
+            .path_and_query(format!("{}/{id}/archive", self.route))
+            .build()
+            .context(HttpSnafu)?;
+        let response = self.crab._get(uri).await?;
+        let response = self.crab.follow_location_to_data(response).await?;
+        crate::map_github_error(response).await
+    }
+
+    /// Deletes the archive of a migration. Archives are otherwise deleted
+    /// automatically after seven days.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .migrations()
+    ///     .delete_archive(79u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_archive(&self, id: MigrationId) -> crate::Result<()> {
+        let route = format!("{}/{id}/archive", self.route);
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Unlocks a repository which was locked by a migration started with
+    /// `lock_repositories`, once it's no longer needed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .migrations()
+    ///     .unlock_repository(79u64.into(), "repo-a")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unlock_repository(
+        &self,
+        id: MigrationId,
+        repo_name: impl AsRef<str>,
+    ) -> crate::Result<()> {
+        let route = format!("{}/{id}/repos/{}/lock", self.route, repo_name.as_ref());
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+}
+
+/// A builder pattern struct for starting a migration.
+///
+/// Created with [`MigrationsHandler::start`].
+#[derive(serde::Serialize)]
+pub struct StartMigrationBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r MigrationsHandler<'octo>,
+    repositories: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lock_repositories: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_metadata: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_git_data: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_attachments: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_releases: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    exclude_owner_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    org_metadata_only: Option<bool>,
+}
+
+impl<'octo, 'r> StartMigrationBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r MigrationsHandler<'octo>, repositories: Vec<String>) -> Self {
+        Self {
+            handler,
+            repositories,
+            lock_repositories: None,
+            exclude_metadata: None,
+            exclude_git_data: None,
+            exclude_attachments: None,
+            exclude_releases: None,
+            exclude_owner_projects: None,
+            org_metadata_only: None,
+        }
+    }
+
+    /// Lock the repositories while they're migrated, so they can't be
+    /// changed in the meantime.
+    pub fn lock_repositories(mut self, lock_repositories: bool) -> Self {
+        self.lock_repositories = Some(lock_repositories);
+        self
+    }
+
+    /// Don't export metadata such as issues and pull requests, only the git
+    /// data.
+    pub fn exclude_metadata(mut self, exclude_metadata: bool) -> Self {
+        self.exclude_metadata = Some(exclude_metadata);
+        self
+    }
+
+    /// Don't export the git data, only the metadata.
+    pub fn exclude_git_data(mut self, exclude_git_data: bool) -> Self {
+        self.exclude_git_data = Some(exclude_git_data);
+        self
+    }
+
+    /// Don't export attachments of issues and pull requests.
+    pub fn exclude_attachments(mut self, exclude_attachments: bool) -> Self {
+        self.exclude_attachments = Some(exclude_attachments);
+        self
+    }
+
+    /// Don't export releases.
+    pub fn exclude_releases(mut self, exclude_releases: bool) -> Self {
+        self.exclude_releases = Some(exclude_releases);
+        self
+    }
+
+    /// Don't export projects owned by the organization or user.
+    pub fn exclude_owner_projects(mut self, exclude_owner_projects: bool) -> Self {
+        self.exclude_owner_projects = Some(exclude_owner_projects);
+        self
+    }
+
+    /// Only export the metadata of the organization, ignoring the
+    /// repositories. Only applies to migrations of an organization.
+    pub fn org_metadata_only(mut self, org_metadata_only: bool) -> Self {
+        self.org_metadata_only = Some(org_metadata_only);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Migration> {
+        self.handler
+            .crab
+            .post(&self.handler.route, Some(&self))
+            .await
+    }
+}
+
+/// A builder pattern struct for listing migrations.
+///
+/// Created with [`MigrationsHandler::list`].
+#[derive(serde::Serialize)]
+pub struct ListMigrationsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r MigrationsHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListMigrationsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r MigrationsHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<Migration>> {
+        self.handler
+            .crab
+            .get(&self.handler.route, Some(&self))
+            .await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Migration>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
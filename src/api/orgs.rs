@@ -1,16 +1,25 @@
 //! The Organization API.
 
+mod blocks;
 mod copilot;
 mod copilot_seat_manager;
 mod events;
 mod list_members;
 mod list_repos;
+mod roles;
 mod secrets;
+mod variables;
 
+pub use self::blocks::ListOrgBlockedUsersBuilder;
 pub use self::events::ListOrgEventsBuilder;
 pub use self::list_members::ListOrgMembersBuilder;
 pub use self::list_repos::ListReposBuilder;
+pub use self::roles::{
+    CreateCustomRepositoryRoleBuilder, CustomRepositoryRolesHandler, OrgRolesHandler,
+    UpdateCustomRepositoryRoleBuilder,
+};
 pub use self::secrets::OrgSecretsHandler;
+pub use self::variables::OrgVariablesHandler;
 use crate::error::HttpSnafu;
 use crate::models::interaction_limits;
 use crate::models::interaction_limits::InteractionLimit;
@@ -214,6 +223,25 @@ impl<'octo> OrgHandler<'octo> {
         Ok(res)
     }
 
+    /// Creates a [`HooksHandler`] to list, update, delete and ping the
+    /// organization's webhooks and to inspect and redeliver their deliveries.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let hooks = octocrab::instance()
+    ///     .orgs("org")
+    ///     .hooks()
+    ///     .list()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`HooksHandler`]: crate::hooks::HooksHandler
+    pub fn hooks(&self) -> crate::hooks::HooksHandler<'octo> {
+        crate::hooks::HooksHandler::new(self.crab, self.owner.clone())
+    }
+
     /// Lists members of the specified organization.
     ///
     /// # Notes
@@ -230,6 +258,152 @@ impl<'octo> OrgHandler<'octo> {
         list_members::ListOrgMembersBuilder::new(self)
     }
 
+    /// Lists the users blocked by the organization.
+    ///
+    /// # Examples
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let blocked = octocrab::instance()
+    ///     .orgs("org")
+    ///     .list_blocked_users()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_blocked_users(&self) -> blocks::ListOrgBlockedUsersBuilder<'octo, '_> {
+        blocks::ListOrgBlockedUsersBuilder::new(self)
+    }
+
+    /// Checks whether a user is blocked by the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let is_blocked = octocrab::instance()
+    ///     .orgs("org")
+    ///     .is_blocked("spammer")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_blocked(&self, username: impl AsRef<str>) -> crate::Result<bool> {
+        let route = format!(
+            "/orgs/{org}/blocks/{username}",
+            org = self.owner,
+            username = username.as_ref(),
+        );
+        let response = self.crab._get(route).await?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => crate::map_github_error(response).await.map(|_| true),
+        }
+    }
+
+    /// Blocks a user from the organization, which also removes them from
+    /// its teams and stops them from interacting with its repositories.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .block_user("spammer")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn block_user(&self, username: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!(
+            "/orgs/{org}/blocks/{username}",
+            org = self.owner,
+            username = username.as_ref(),
+        );
+        let response = self.crab._put(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Unblocks a user from the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .unblock_user("spammer")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unblock_user(&self, username: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!(
+            "/orgs/{org}/blocks/{username}",
+            org = self.owner,
+            username = username.as_ref(),
+        );
+        let response = self.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Lists the secret scanning push protection bypass requests across all
+    /// repositories of the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::secret_scanning::{BypassRequestStatus, TimePeriod};
+    ///
+    /// let requests = octocrab::instance()
+    ///     .orgs("org")
+    ///     .list_secret_scanning_bypass_requests()
+    ///     .request_status(BypassRequestStatus::Open)
+    ///     .time_period(TimePeriod::Week)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_secret_scanning_bypass_requests(
+        &self,
+    ) -> crate::repos::ListBypassRequestsBuilder<'octo> {
+        let route = format!(
+            "/orgs/{org}/bypass-requests/secret-scanning",
+            org = self.owner
+        );
+        crate::repos::ListBypassRequestsBuilder::new(self.crab, route)
+    }
+
+    /// Handle the billing of the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .orgs("org")
+    ///     .billing()
+    ///     .actions()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn billing(&self) -> crate::billing::BillingHandler<'octo> {
+        crate::billing::BillingHandler::new(
+            self.crab,
+            format!("/orgs/{org}/settings/billing", org = self.owner),
+            format!("/organizations/{org}/settings/billing", org = self.owner),
+        )
+    }
+
+    /// Handle the migrations of the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let migrations = octocrab::instance()
+    ///     .orgs("org")
+    ///     .migrations()
+    ///     .list()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn migrations(&self) -> crate::migrations::MigrationsHandler<'octo> {
+        let route = format!("/orgs/{org}/migrations", org = self.owner);
+        crate::migrations::MigrationsHandler::new(self.crab, route)
+    }
+
     /// Handle secrets on the organizaton
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -242,6 +416,76 @@ impl<'octo> OrgHandler<'octo> {
         secrets::OrgSecretsHandler::new(self)
     }
 
+    /// Handle Dependabot secrets on the organization, which Dependabot uses
+    /// to access private registries. They are managed like the Actions
+    /// secrets of [`OrgHandler::secrets`], with the organization's own
+    /// Dependabot public key to encrypt their values.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let secrets = octocrab.orgs("org").dependabot_secrets();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn dependabot_secrets(&self) -> secrets::OrgSecretsHandler<'_> {
+        secrets::OrgSecretsHandler::for_product(self, "dependabot")
+    }
+
+    /// Handle Codespaces secrets on the organization, which are available as
+    /// environment variables in the codespaces of its repositories. They
+    /// are managed like the Actions secrets of [`OrgHandler::secrets`], with
+    /// the organization's own Codespaces public key to encrypt their values.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let secrets = octocrab.orgs("org").codespaces_secrets();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn codespaces_secrets(&self) -> secrets::OrgSecretsHandler<'_> {
+        secrets::OrgSecretsHandler::for_product(self, "codespaces")
+    }
+
+    /// Handle GitHub Actions variables on the organization, which unlike
+    /// secrets are stored and returned in plain text. Like secrets, they can
+    /// be visible to all, private or selected repositories only.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let variables = octocrab.orgs("org").variables();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn variables(&self) -> variables::OrgVariablesHandler<'_> {
+        variables::OrgVariablesHandler::new(self)
+    }
+
+    /// Handle the custom repository roles of the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let roles = octocrab::instance()
+    ///     .orgs("org")
+    ///     .custom_repository_roles()
+    ///     .list()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn custom_repository_roles(&self) -> roles::CustomRepositoryRolesHandler<'octo, '_> {
+        roles::CustomRepositoryRolesHandler::new(self)
+    }
+
+    /// Handle the organization roles and their assignment to users and teams.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let roles = octocrab::instance().orgs("org").roles().list().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn roles(&self) -> roles::OrgRolesHandler<'octo, '_> {
+        roles::OrgRolesHandler::new(self)
+    }
+
     /// ### Get interaction restrictions for an organization
     ///
     /// Shows which type of GitHub user can interact with this organization and when the restriction expires. If there is no restrictions, you will see an empty response.
@@ -335,4 +579,52 @@ impl<'octo> OrgHandler<'octo> {
         let response = self.crab._delete(route, None::<&()>).await?;
         crate::map_github_error(response).await.map(drop)
     }
+
+    /// Enables a security feature for all repositories of the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::orgs::SecurityProduct;
+    ///
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .enable_security_product(SecurityProduct::SecretScanning)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable_security_product(
+        &self,
+        product: crate::params::orgs::SecurityProduct,
+    ) -> crate::Result<()> {
+        self.set_security_product(product, "enable_all").await
+    }
+
+    /// Disables a security feature for all repositories of the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::orgs::SecurityProduct;
+    ///
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .disable_security_product(SecurityProduct::DependabotSecurityUpdates)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable_security_product(
+        &self,
+        product: crate::params::orgs::SecurityProduct,
+    ) -> crate::Result<()> {
+        self.set_security_product(product, "disable_all").await
+    }
+
+    async fn set_security_product(
+        &self,
+        product: crate::params::orgs::SecurityProduct,
+        enablement: &str,
+    ) -> crate::Result<()> {
+        let route = format!("/orgs/{}/{product}/{enablement}", self.owner);
+        let response = self.crab._post(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
 }
@@ -1,6 +1,6 @@
 //! GitHub Organization Events
 use crate::{
-    etag::{EntityTag, Etagged},
+    etag::{EntityTag, Etagged, Polled},
     models::events,
     orgs::OrgHandler,
     FromResponse, Page,
@@ -89,4 +89,36 @@ impl<'octo, 'handler> ListOrgEventsBuilder<'octo, 'handler> {
                 })
         }
     }
+
+    /// Sends the request as a conditional poll, returning the `ETag` and
+    /// `X-Poll-Interval` headers alongside the page. The page is `None` when
+    /// nothing changed since [`etag`](Self::etag).
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let mut etag = None;
+    /// loop {
+    ///     let polled = octocrab
+    ///         .orgs("org")
+    ///         .events()
+    ///         .etag(etag.take())
+    ///         .poll()
+    ///         .await?;
+    ///     if let Some(page) = polled.value {
+    ///         // handle the new events ...
+    ///     }
+    ///     etag = polled.etag;
+    ///     // wait `polled.poll_interval` before polling again
+    /// }
+    /// # }
+    /// ```
+    pub async fn poll(self) -> crate::Result<Polled<Page<events::Event>>> {
+        let route = format!("/orgs/{owner}/events", owner = self.handler.owner);
+        let uri = self
+            .handler
+            .crab
+            .parameterized_uri(route, Some(&self.params))?;
+        crate::events::poll_events(self.handler.crab, uri, self.headers.etag).await
+    }
 }
@@ -0,0 +1,410 @@
+use super::*;
+use crate::models::orgs::roles::{
+    CustomRepositoryRole, CustomRepositoryRoles, OrganizationRole, OrganizationRoles,
+};
+use crate::models::RoleId;
+use crate::params::orgs::BaseRole;
+use crate::{models, Page, Result};
+
+/// A client for managing an organization's custom repository roles.
+///
+/// Created with [`OrgHandler::custom_repository_roles`].
+pub struct CustomRepositoryRolesHandler<'octo, 'r> {
+    handler: &'r OrgHandler<'octo>,
+}
+
+impl<'octo, 'r> CustomRepositoryRolesHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r OrgHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    fn route(&self) -> String {
+        format!(
+            "/orgs/{org}/custom-repository-roles",
+            org = self.handler.owner
+        )
+    }
+
+    /// Lists the custom repository roles available in the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let roles = octocrab::instance()
+    ///     .orgs("org")
+    ///     .custom_repository_roles()
+    ///     .list()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> Result<CustomRepositoryRoles> {
+        self.handler.crab.get(self.route(), None::<&()>).await
+    }
+
+    /// Gets a custom repository role.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let role = octocrab::instance()
+    ///     .orgs("org")
+    ///     .custom_repository_roles()
+    ///     .get(8030u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, role_id: RoleId) -> Result<CustomRepositoryRole> {
+        let route = format!("{}/{role_id}", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a custom repository role building on `base_role`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::orgs::BaseRole;
+    ///
+    /// let role = octocrab::instance()
+    ///     .orgs("org")
+    ///     .custom_repository_roles()
+    ///     .create("Security Engineer", BaseRole::Maintain)
+    ///     .description("Maintainers who can also manage security alerts")
+    ///     .permissions(vec!["security_events".to_string()])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(
+        &self,
+        name: impl Into<String>,
+        base_role: BaseRole,
+    ) -> CreateCustomRepositoryRoleBuilder<'_, 'octo, 'r> {
+        CreateCustomRepositoryRoleBuilder::new(self, name.into(), base_role)
+    }
+
+    /// Updates a custom repository role. Only the fields set on the builder
+    /// are changed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let role = octocrab::instance()
+    ///     .orgs("org")
+    ///     .custom_repository_roles()
+    ///     .update(8030u64.into())
+    ///     .permissions(vec!["security_events".to_string(), "add_label".to_string()])
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self, role_id: RoleId) -> UpdateCustomRepositoryRoleBuilder<'_, 'octo, 'r> {
+        UpdateCustomRepositoryRoleBuilder::new(self, role_id)
+    }
+
+    /// Deletes a custom repository role.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .custom_repository_roles()
+    ///     .delete(8030u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, role_id: RoleId) -> Result<()> {
+        let route = format!("{}/{role_id}", self.route());
+        let response = self.handler.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct CreateCustomRepositoryRoleBuilder<'h, 'octo, 'r> {
+    #[serde(skip)]
+    handler: &'h CustomRepositoryRolesHandler<'octo, 'r>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    base_role: BaseRole,
+    permissions: Vec<String>,
+}
+
+impl<'h, 'octo, 'r> CreateCustomRepositoryRoleBuilder<'h, 'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'h CustomRepositoryRolesHandler<'octo, 'r>,
+        name: String,
+        base_role: BaseRole,
+    ) -> Self {
+        Self {
+            handler,
+            name,
+            description: None,
+            base_role,
+            permissions: Vec::new(),
+        }
+    }
+
+    /// A short description of who or what the role is for.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The additional fine-grained permissions granted on top of the base
+    /// role.
+    pub fn permissions(mut self, permissions: impl Into<Vec<String>>) -> Self {
+        self.permissions = permissions.into();
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<CustomRepositoryRole> {
+        self.handler
+            .handler
+            .crab
+            .post(self.handler.route(), Some(&self))
+            .await
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct UpdateCustomRepositoryRoleBuilder<'h, 'octo, 'r> {
+    #[serde(skip)]
+    handler: &'h CustomRepositoryRolesHandler<'octo, 'r>,
+    #[serde(skip)]
+    role_id: RoleId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_role: Option<BaseRole>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<Vec<String>>,
+}
+
+impl<'h, 'octo, 'r> UpdateCustomRepositoryRoleBuilder<'h, 'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'h CustomRepositoryRolesHandler<'octo, 'r>,
+        role_id: RoleId,
+    ) -> Self {
+        Self {
+            handler,
+            role_id,
+            name: None,
+            description: None,
+            base_role: None,
+            permissions: None,
+        }
+    }
+
+    /// The new name of the role.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// The new description of the role.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The new base role.
+    pub fn base_role(mut self, base_role: BaseRole) -> Self {
+        self.base_role = Some(base_role);
+        self
+    }
+
+    /// Replaces the additional fine-grained permissions of the role.
+    pub fn permissions(mut self, permissions: impl Into<Vec<String>>) -> Self {
+        self.permissions = Some(permissions.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<CustomRepositoryRole> {
+        let route = format!("{}/{}", self.handler.route(), self.role_id);
+        self.handler.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+/// A client for listing an organization's roles and assigning them to
+/// users and teams.
+///
+/// Created with [`OrgHandler::roles`].
+pub struct OrgRolesHandler<'octo, 'r> {
+    handler: &'r OrgHandler<'octo>,
+}
+
+impl<'octo, 'r> OrgRolesHandler<'octo, 'r> {
+    pub(crate) fn new(handler: &'r OrgHandler<'octo>) -> Self {
+        Self { handler }
+    }
+
+    fn route(&self) -> String {
+        format!("/orgs/{org}/organization-roles", org = self.handler.owner)
+    }
+
+    async fn put(&self, route: String) -> Result<()> {
+        let response = self.handler.crab._put(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    async fn delete(&self, route: String) -> Result<()> {
+        let response = self.handler.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Lists the roles available in the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let roles = octocrab::instance().orgs("org").roles().list().await?;
+    /// for role in roles.roles {
+    ///     println!("{}: {}", role.id, role.name);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list(&self) -> Result<OrganizationRoles> {
+        self.handler.crab.get(self.route(), None::<&()>).await
+    }
+
+    /// Gets an organization role.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let role = octocrab::instance()
+    ///     .orgs("org")
+    ///     .roles()
+    ///     .get(8030u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, role_id: RoleId) -> Result<OrganizationRole> {
+        let route = format!("{}/{role_id}", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Lists the teams the role is assigned to.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let teams = octocrab::instance()
+    ///     .orgs("org")
+    ///     .roles()
+    ///     .list_teams(8030u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_teams(&self, role_id: RoleId) -> Result<Page<models::teams::RequestedTeam>> {
+        let route = format!("{}/{role_id}/teams", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Lists the users the role is assigned to, directly or through a team.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let users = octocrab::instance()
+    ///     .orgs("org")
+    ///     .roles()
+    ///     .list_users(8030u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_users(&self, role_id: RoleId) -> Result<Page<models::Author>> {
+        let route = format!("{}/{role_id}/users", self.route());
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Assigns a role to a team.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .roles()
+    ///     .assign_to_team("security", 8030u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn assign_to_team(&self, team_slug: impl AsRef<str>, role_id: RoleId) -> Result<()> {
+        let route = format!("{}/teams/{}/{role_id}", self.route(), team_slug.as_ref());
+        self.put(route).await
+    }
+
+    /// Removes a role from a team.
+    pub async fn revoke_from_team(
+        &self,
+        team_slug: impl AsRef<str>,
+        role_id: RoleId,
+    ) -> Result<()> {
+        let route = format!("{}/teams/{}/{role_id}", self.route(), team_slug.as_ref());
+        self.delete(route).await
+    }
+
+    /// Removes every organization role from a team.
+    pub async fn revoke_all_from_team(&self, team_slug: impl AsRef<str>) -> Result<()> {
+        let route = format!("{}/teams/{}", self.route(), team_slug.as_ref());
+        self.delete(route).await
+    }
+
+    /// Assigns a role to a user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .orgs("org")
+    ///     .roles()
+    ///     .assign_to_user("ferris", 8030u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn assign_to_user(&self, username: impl AsRef<str>, role_id: RoleId) -> Result<()> {
+        let route = format!("{}/users/{}/{role_id}", self.route(), username.as_ref());
+        self.put(route).await
+    }
+
+    /// Removes a role from a user.
+    pub async fn revoke_from_user(&self, username: impl AsRef<str>, role_id: RoleId) -> Result<()> {
+        let route = format!("{}/users/{}/{role_id}", self.route(), username.as_ref());
+        self.delete(route).await
+    }
+
+    /// Removes every organization role from a user.
+    pub async fn revoke_all_from_user(&self, username: impl AsRef<str>) -> Result<()> {
+        let route = format!("{}/users/{}", self.route(), username.as_ref());
+        self.delete(route).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::params::orgs::BaseRole;
+
+    #[tokio::test]
+    async fn serialize() {
+        #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+        crate::ensure_crypto_provider_initialized();
+        let octocrab = crate::Octocrab::default();
+        let org = octocrab.orgs("org");
+        let roles = org.custom_repository_roles();
+
+        let create = roles
+            .create("Security Engineer", BaseRole::Maintain)
+            .permissions(vec!["security_events".to_string()]);
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "name": "Security Engineer",
+                "base_role": "maintain",
+                "permissions": ["security_events"],
+            })
+        );
+
+        let update = roles.update(1u64.into()).description("Updated");
+        assert_eq!(
+            serde_json::to_value(update).unwrap(),
+            serde_json::json!({ "description": "Updated" })
+        );
+    }
+}
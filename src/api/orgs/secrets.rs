@@ -1,24 +1,45 @@
 use http::StatusCode;
 
 use super::OrgHandler;
-use crate::models::orgs::secrets::{CreateOrganizationSecret, CreateOrganizationSecretResponse};
+use crate::models::orgs::secrets::{
+    CreateOrganizationSecret, CreateOrganizationSecretResponse, SelectedRepositories,
+};
+use crate::models::RepositoryId;
 
 /// A client to GitHub's organization API.
 ///
 /// Created with [`crate::Octocrab::orgs`].
+///
+/// Manages the organization secrets of GitHub Actions, Dependabot or
+/// Codespaces, depending on whether it was created with
+/// [`OrgHandler::secrets`], [`OrgHandler::dependabot_secrets`] or
+/// [`OrgHandler::codespaces_secrets`].
 pub struct OrgSecretsHandler<'octo> {
     org: &'octo OrgHandler<'octo>,
+    product: &'static str,
 }
 
 impl<'octo> OrgSecretsHandler<'octo> {
     pub(crate) fn new(org: &'octo OrgHandler<'octo>) -> Self {
-        Self { org }
+        Self::for_product(org, "actions")
+    }
+
+    pub(crate) fn for_product(org: &'octo OrgHandler<'octo>, product: &'static str) -> Self {
+        Self { org, product }
     }
 
     fn owner(&self) -> &String {
         &self.org.owner
     }
 
+    fn route(&self) -> String {
+        format!(
+            "/orgs/{org}/{product}/secrets",
+            org = self.owner(),
+            product = self.product
+        )
+    }
+
     /// Lists all secrets available in an organization without revealing their encrypted values.
     /// You must authenticate using an access token with the admin:org scope to use this endpoint.
     /// GitHub Apps must have the secrets organization permission to use this endpoint.
@@ -33,7 +54,7 @@ impl<'octo> OrgSecretsHandler<'octo> {
     pub async fn get_secrets(
         &self,
     ) -> crate::Result<crate::models::orgs::secrets::OrganizationSecrets> {
-        let route = format!("/orgs/{org}/actions/secrets", org = self.owner());
+        let route = self.route();
         self.org.crab.get(route, None::<&()>).await
     }
 
@@ -49,7 +70,7 @@ impl<'octo> OrgSecretsHandler<'octo> {
     /// # Ok(())
     /// # }
     pub async fn get_public_key(&self) -> crate::Result<crate::models::PublicKey> {
-        let route = format!("/orgs/{org}/actions/secrets/public-key", org = self.owner());
+        let route = format!("{}/public-key", self.route());
         self.org.crab.get(route, None::<&()>).await
     }
 
@@ -68,11 +89,7 @@ impl<'octo> OrgSecretsHandler<'octo> {
         &self,
         secret_name: impl AsRef<str>,
     ) -> crate::Result<crate::models::orgs::secrets::OrganizationSecret> {
-        let route = format!(
-            "/orgs/{org}/actions/secrets/{secret_name}",
-            org = self.owner(),
-            secret_name = secret_name.as_ref()
-        );
+        let route = crate::route::Route::new(self.route()).segment(secret_name);
         self.org.crab.get(route, None::<&()>).await
     }
 
@@ -108,11 +125,7 @@ impl<'octo> OrgSecretsHandler<'octo> {
         secret_name: impl AsRef<str>,
         secret: &CreateOrganizationSecret<'_>,
     ) -> crate::Result<crate::models::orgs::secrets::CreateOrganizationSecretResponse> {
-        let route = format!(
-            "/orgs/{org}/actions/secrets/{secret_name}",
-            org = self.owner(),
-            secret_name = secret_name.as_ref()
-        );
+        let route = crate::route::Route::new(self.route()).segment(secret_name);
 
         let resp = {
             let resp = self.org.crab._put(route, Some(secret)).await?;
@@ -147,14 +160,107 @@ impl<'octo> OrgSecretsHandler<'octo> {
     /// # Ok(())
     /// # }
     pub async fn delete_secret(&self, secret_name: impl AsRef<str>) -> crate::Result<()> {
-        let route = format!(
-            "/orgs/{org}/actions/secrets/{secret_name}",
-            org = self.owner(),
-            secret_name = secret_name.as_ref()
-        );
+        let route = crate::route::Route::new(self.route()).segment(secret_name);
 
         let resp = self.org.crab._delete(route, None::<&()>).await?;
         crate::map_github_error(resp).await?;
         Ok(())
     }
+
+    /// Lists the repositories that can access a secret whose visibility is
+    /// `selected`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// let repos = org
+    ///     .dependabot_secrets()
+    ///     .list_selected_repositories("GH_TOKEN")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_selected_repositories(
+        &self,
+        secret_name: impl AsRef<str>,
+    ) -> crate::Result<SelectedRepositories> {
+        let route = crate::route::Route::new(self.route())
+            .segment(secret_name)
+            .path("repositories");
+        self.org.crab.get(route, None::<&()>).await
+    }
+
+    /// Replaces the repositories that can access a secret whose visibility
+    /// is `selected`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// org.codespaces_secrets()
+    ///     .set_selected_repositories("GH_TOKEN", &[1234u64.into(), 5678u64.into()])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_selected_repositories(
+        &self,
+        secret_name: impl AsRef<str>,
+        repository_ids: &[RepositoryId],
+    ) -> crate::Result<()> {
+        let route = crate::route::Route::new(self.route())
+            .segment(secret_name)
+            .path("repositories");
+        let body = serde_json::json!({ "selected_repository_ids": repository_ids });
+        let resp = self.org.crab._put(route, Some(&body)).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
+
+    /// Gives a repository access to a secret whose visibility is `selected`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// org.dependabot_secrets()
+    ///     .add_selected_repository("GH_TOKEN", 1234u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_selected_repository(
+        &self,
+        secret_name: impl AsRef<str>,
+        repository_id: RepositoryId,
+    ) -> crate::Result<()> {
+        let route = crate::route::Route::new(self.route())
+            .segment(secret_name)
+            .path("repositories")
+            .segment(repository_id.to_string());
+        let resp = self.org.crab._put(route, None::<&()>).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
+
+    /// Revokes a repository's access to a secret whose visibility is
+    /// `selected`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// org.dependabot_secrets()
+    ///     .remove_selected_repository("GH_TOKEN", 1234u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_selected_repository(
+        &self,
+        secret_name: impl AsRef<str>,
+        repository_id: RepositoryId,
+    ) -> crate::Result<()> {
+        let route = crate::route::Route::new(self.route())
+            .segment(secret_name)
+            .path("repositories")
+            .segment(repository_id.to_string());
+        let resp = self.org.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
 }
@@ -0,0 +1,223 @@
+use super::OrgHandler;
+use crate::models::orgs::secrets::SelectedRepositories;
+use crate::models::orgs::variables::{
+    CreateOrganizationVariable, OrganizationVariable, OrganizationVariables,
+    UpdateOrganizationVariable,
+};
+use crate::models::RepositoryId;
+
+/// A client to the organization variables of GitHub Actions.
+///
+/// Created with [`OrgHandler::variables`].
+pub struct OrgVariablesHandler<'octo> {
+    org: &'octo OrgHandler<'octo>,
+}
+
+impl<'octo> OrgVariablesHandler<'octo> {
+    pub(crate) fn new(org: &'octo OrgHandler<'octo>) -> Self {
+        Self { org }
+    }
+
+    fn route(&self) -> String {
+        format!("/orgs/{org}/actions/variables", org = self.org.owner)
+    }
+
+    /// Lists all variables available in an organization, including their values.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// let variables = org.variables().get_variables().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_variables(&self) -> crate::Result<OrganizationVariables> {
+        self.org.crab.get(self.route(), None::<&()>).await
+    }
+
+    /// Gets a specific variable from the organization.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// let variable = org.variables().get_variable("USERNAME").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_variable(
+        &self,
+        variable_name: impl AsRef<str>,
+    ) -> crate::Result<OrganizationVariable> {
+        let route = crate::route::Route::new(self.route()).segment(variable_name);
+        self.org.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates an organization variable. Unlike secrets, variables are
+    /// stored in plain text.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::orgs::secrets::Visibility;
+    /// use octocrab::models::orgs::variables::CreateOrganizationVariable;
+    ///
+    /// let org = octocrab.orgs("owner");
+    /// org.variables()
+    ///     .create_variable(&CreateOrganizationVariable {
+    ///         name: "USERNAME",
+    ///         value: "octocat",
+    ///         visibility: Visibility::Private,
+    ///         selected_repository_ids: None,
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_variable(
+        &self,
+        variable: &CreateOrganizationVariable<'_>,
+    ) -> crate::Result<()> {
+        let resp = self.org.crab._post(self.route(), Some(variable)).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
+
+    /// Updates an organization variable.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::models::orgs::variables::UpdateOrganizationVariable;
+    ///
+    /// let org = octocrab.orgs("owner");
+    /// org.variables()
+    ///     .update_variable(
+    ///         "USERNAME",
+    ///         &UpdateOrganizationVariable {
+    ///             value: Some("ferris"),
+    ///             ..Default::default()
+    ///         },
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_variable(
+        &self,
+        variable_name: impl AsRef<str>,
+        variable: &UpdateOrganizationVariable<'_>,
+    ) -> crate::Result<()> {
+        let route = crate::route::Route::new(self.route()).segment(variable_name);
+        let resp = self.org.crab._patch(route, Some(variable)).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
+
+    /// Deletes an organization variable.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// org.variables().delete_variable("USERNAME").await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_variable(&self, variable_name: impl AsRef<str>) -> crate::Result<()> {
+        let route = crate::route::Route::new(self.route()).segment(variable_name);
+        let resp = self.org.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
+
+    /// Lists the repositories that can access a variable whose visibility is
+    /// `selected`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// let repos = org
+    ///     .variables()
+    ///     .list_selected_repositories("USERNAME")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_selected_repositories(
+        &self,
+        variable_name: impl AsRef<str>,
+    ) -> crate::Result<SelectedRepositories> {
+        let route = crate::route::Route::new(self.route())
+            .segment(variable_name)
+            .path("repositories");
+        self.org.crab.get(route, None::<&()>).await
+    }
+
+    /// Replaces the repositories that can access a variable whose visibility
+    /// is `selected`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// org.variables()
+    ///     .set_selected_repositories("USERNAME", &[1234u64.into(), 5678u64.into()])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_selected_repositories(
+        &self,
+        variable_name: impl AsRef<str>,
+        repository_ids: &[RepositoryId],
+    ) -> crate::Result<()> {
+        let route = crate::route::Route::new(self.route())
+            .segment(variable_name)
+            .path("repositories");
+        let body = serde_json::json!({ "selected_repository_ids": repository_ids });
+        let resp = self.org.crab._put(route, Some(&body)).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
+
+    /// Gives a repository access to a variable whose visibility is `selected`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// org.variables()
+    ///     .add_selected_repository("USERNAME", 1234u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_selected_repository(
+        &self,
+        variable_name: impl AsRef<str>,
+        repository_id: RepositoryId,
+    ) -> crate::Result<()> {
+        let route = crate::route::Route::new(self.route())
+            .segment(variable_name)
+            .path("repositories")
+            .segment(repository_id.to_string());
+        let resp = self.org.crab._put(route, None::<&()>).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
+
+    /// Revokes a repository's access to a variable whose visibility is
+    /// `selected`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// let org = octocrab.orgs("owner");
+    /// org.variables()
+    ///     .remove_selected_repository("USERNAME", 1234u64.into())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_selected_repository(
+        &self,
+        variable_name: impl AsRef<str>,
+        repository_id: RepositoryId,
+    ) -> crate::Result<()> {
+        let route = crate::route::Route::new(self.route())
+            .segment(variable_name)
+            .path("repositories")
+            .segment(repository_id.to_string());
+        let resp = self.org.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(resp).await.map(drop)
+    }
+}
@@ -5,6 +5,7 @@
 
 #[allow(clippy::module_inception)]
 mod projects;
+pub mod v2;
 
 use self::projects::{
     CreateOrgProjectsBuilder, CreateRepositoryProjectsBuilder, CreateUserProjectBuilder,
@@ -26,6 +27,23 @@ impl<'octo> ProjectHandler<'octo> {
         Self { crab }
     }
 
+    /// Creates a [`v2::ProjectV2Handler`] for projects (v2), which replace
+    /// the classic projects of this handler and are only available through
+    /// GitHub's GraphQL API.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let project = octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .get_org_project("github", 1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn v2(&self) -> v2::ProjectV2Handler<'octo> {
+        v2::ProjectV2Handler::new(self.crab)
+    }
+
     /// Get a project by its id.    
     ///
     /// # Arguments
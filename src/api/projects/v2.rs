@@ -0,0 +1,444 @@
+//! Projects (v2), backed by GitHub's GraphQL API.
+
+use snafu::ResultExt;
+
+use crate::error::SerdeSnafu;
+use crate::models::projects_v2::{ProjectV2, ProjectV2Items};
+use crate::models::NodeId;
+use crate::params::projects_v2::FieldValue;
+use crate::Octocrab;
+
+const PROJECT_FIELDS: &str =
+    "id number title shortDescription readme public closed url createdAt updatedAt
+fields(first: 100) {
+  nodes {
+    ... on ProjectV2FieldCommon { id name dataType }
+    ... on ProjectV2SingleSelectField { options { id name } }
+    ... on ProjectV2IterationField {
+      configuration {
+        iterations { id title startDate duration }
+        completedIterations { id title startDate duration }
+      }
+    }
+  }
+}";
+
+const LIST_ITEMS: &str = "query($id: ID!, $first: Int!, $after: String) {
+  node(id: $id) {
+    ... on ProjectV2 {
+      items(first: $first, after: $after) {
+        pageInfo { hasNextPage endCursor }
+        nodes {
+          id type isArchived
+          content {
+            __typename
+            ... on Issue { id number title url }
+            ... on PullRequest { id number title url }
+            ... on DraftIssue { id title body }
+          }
+          fieldValues(first: 50) {
+            nodes {
+              __typename
+              ... on ProjectV2ItemFieldTextValue { text field { ... on ProjectV2FieldCommon { id name } } }
+              ... on ProjectV2ItemFieldNumberValue { number field { ... on ProjectV2FieldCommon { id name } } }
+              ... on ProjectV2ItemFieldDateValue { date field { ... on ProjectV2FieldCommon { id name } } }
+              ... on ProjectV2ItemFieldSingleSelectValue { name optionId field { ... on ProjectV2FieldCommon { id name } } }
+              ... on ProjectV2ItemFieldIterationValue { title iterationId startDate duration field { ... on ProjectV2FieldCommon { id name } } }
+            }
+          }
+        }
+      }
+    }
+  }
+}";
+
+/// A client to projects (v2).
+///
+/// Created with [`ProjectHandler::v2`](super::ProjectHandler::v2).
+pub struct ProjectV2Handler<'octo> {
+    crab: &'octo Octocrab,
+}
+
+impl<'octo> ProjectV2Handler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self { crab }
+    }
+
+    /// Gets a project of an organization by its number, including its fields.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let project = octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .get_org_project("github", 1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_org_project(
+        &self,
+        org: impl AsRef<str>,
+        number: u64,
+    ) -> crate::Result<ProjectV2> {
+        self.get_project("organization", org.as_ref(), number).await
+    }
+
+    /// Gets a project of a user by its number, including its fields.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let project = octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .get_user_project("octocat", 1)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_user_project(
+        &self,
+        user: impl AsRef<str>,
+        number: u64,
+    ) -> crate::Result<ProjectV2> {
+        self.get_project("user", user.as_ref(), number).await
+    }
+
+    async fn get_project(
+        &self,
+        owner_type: &str,
+        login: &str,
+        number: u64,
+    ) -> crate::Result<ProjectV2> {
+        let query = format!(
+            "query($login: String!, $number: Int!) {{
+  {owner_type}(login: $login) {{ projectV2(number: $number) {{ {PROJECT_FIELDS} }} }}
+}}"
+        );
+        let mut data = self
+            .crab
+            .graphql_query(
+                &query,
+                &serde_json::json!({ "login": login, "number": number }),
+            )
+            .await?;
+        serde_json::from_value(data[owner_type]["projectV2"].take()).context(SerdeSnafu)
+    }
+
+    /// Lists the items of a project along with their field values. Use the
+    /// returned `page_info` to fetch further pages.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let items = octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .list_items("PVT_kwDOAAABcM4AAbcd")
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// if items.page_info.has_next_page {
+    ///     // Fetch the next page with `.after(items.page_info.end_cursor.unwrap())`.
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_items(&self, project_id: impl Into<String>) -> ListProjectV2ItemsBuilder<'octo> {
+        ListProjectV2ItemsBuilder::new(self.crab, project_id.into())
+    }
+
+    /// Adds an existing issue or pull request, by its node id, to a project.
+    /// Returns the node id of the new project item.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let item_id = octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .add_item("PVT_kwDOAAABcM4AAbcd", "I_kwDOAAABcM5AAbcd")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_item(
+        &self,
+        project_id: impl AsRef<str>,
+        content_id: impl AsRef<str>,
+    ) -> crate::Result<NodeId> {
+        let data = self
+            .crab
+            .graphql_mutation(
+                "mutation($input: AddProjectV2ItemByIdInput!) {
+  addProjectV2ItemById(input: $input) { item { id } }
+}",
+                &serde_json::json!({
+                    "projectId": project_id.as_ref(),
+                    "contentId": content_id.as_ref(),
+                }),
+            )
+            .await?;
+        node_id(&data["addProjectV2ItemById"]["item"]["id"])
+    }
+
+    /// Removes an item from a project.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .remove_item("PVT_kwDOAAABcM4AAbcd", "PVTI_lADOAAABcM4AAbcd")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove_item(
+        &self,
+        project_id: impl AsRef<str>,
+        item_id: impl AsRef<str>,
+    ) -> crate::Result<()> {
+        self.crab
+            .graphql_mutation(
+                "mutation($input: DeleteProjectV2ItemInput!) {
+  deleteProjectV2Item(input: $input) { deletedItemId }
+}",
+                &serde_json::json!({
+                    "projectId": project_id.as_ref(),
+                    "itemId": item_id.as_ref(),
+                }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Sets the value of a text, number, date, single select or iteration
+    /// field of a project item.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::projects_v2::FieldValue;
+    ///
+    /// octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .update_field_value(
+    ///         "PVT_kwDOAAABcM4AAbcd",
+    ///         "PVTI_lADOAAABcM4AAbcd",
+    ///         "PVTSSF_lADOAAABcM4AAbcd",
+    ///         FieldValue::SingleSelectOptionId("47fc9ee4".to_string()),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_field_value(
+        &self,
+        project_id: impl AsRef<str>,
+        item_id: impl AsRef<str>,
+        field_id: impl AsRef<str>,
+        value: FieldValue,
+    ) -> crate::Result<()> {
+        self.crab
+            .graphql_mutation(
+                "mutation($input: UpdateProjectV2ItemFieldValueInput!) {
+  updateProjectV2ItemFieldValue(input: $input) { projectV2Item { id } }
+}",
+                &serde_json::json!({
+                    "projectId": project_id.as_ref(),
+                    "itemId": item_id.as_ref(),
+                    "fieldId": field_id.as_ref(),
+                    "value": value,
+                }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Clears the value of a field of a project item.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .clear_field_value(
+    ///         "PVT_kwDOAAABcM4AAbcd",
+    ///         "PVTI_lADOAAABcM4AAbcd",
+    ///         "PVTF_lADOAAABcM4AAbcd",
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn clear_field_value(
+        &self,
+        project_id: impl AsRef<str>,
+        item_id: impl AsRef<str>,
+        field_id: impl AsRef<str>,
+    ) -> crate::Result<()> {
+        self.crab
+            .graphql_mutation(
+                "mutation($input: ClearProjectV2ItemFieldValueInput!) {
+  clearProjectV2ItemFieldValue(input: $input) { projectV2Item { id } }
+}",
+                &serde_json::json!({
+                    "projectId": project_id.as_ref(),
+                    "itemId": item_id.as_ref(),
+                    "fieldId": field_id.as_ref(),
+                }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Adds a draft issue to a project. Returns the node id of the new
+    /// project item.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let item_id = octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .add_draft_issue("PVT_kwDOAAABcM4AAbcd", "Investigate flaky test", None)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_draft_issue(
+        &self,
+        project_id: impl AsRef<str>,
+        title: impl AsRef<str>,
+        body: Option<&str>,
+    ) -> crate::Result<NodeId> {
+        let data = self
+            .crab
+            .graphql_mutation(
+                "mutation($input: AddProjectV2DraftIssueInput!) {
+  addProjectV2DraftIssue(input: $input) { projectItem { id } }
+}",
+                &serde_json::json!({
+                    "projectId": project_id.as_ref(),
+                    "title": title.as_ref(),
+                    "body": body,
+                }),
+            )
+            .await?;
+        node_id(&data["addProjectV2DraftIssue"]["projectItem"]["id"])
+    }
+
+    /// Updates the title and body of a draft issue, identified by the node id
+    /// of the draft issue rather than of its project item.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .update_draft_issue("DI_lADOAAABcM4AAbcd", "Fix flaky test", Some("Seen on CI"))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_draft_issue(
+        &self,
+        draft_issue_id: impl AsRef<str>,
+        title: impl AsRef<str>,
+        body: Option<&str>,
+    ) -> crate::Result<()> {
+        self.crab
+            .graphql_mutation(
+                "mutation($input: UpdateProjectV2DraftIssueInput!) {
+  updateProjectV2DraftIssue(input: $input) { draftIssue { id } }
+}",
+                &serde_json::json!({
+                    "draftIssueId": draft_issue_id.as_ref(),
+                    "title": title.as_ref(),
+                    "body": body,
+                }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Converts a draft issue item into an issue in the repository with the
+    /// given node id.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .projects()
+    ///     .v2()
+    ///     .convert_draft_issue("PVTI_lADOAAABcM4AAbcd", "R_kgDOAAABcM")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_draft_issue(
+        &self,
+        item_id: impl AsRef<str>,
+        repository_id: impl AsRef<str>,
+    ) -> crate::Result<()> {
+        self.crab
+            .graphql_mutation(
+                "mutation($input: ConvertProjectV2DraftIssueItemToIssueInput!) {
+  convertProjectV2DraftIssueItemToIssue(input: $input) { item { id } }
+}",
+                &serde_json::json!({
+                    "itemId": item_id.as_ref(),
+                    "repositoryId": repository_id.as_ref(),
+                }),
+            )
+            .await
+            .map(drop)
+    }
+}
+
+/// A builder pattern struct for listing the items of a project.
+///
+/// Created with [`ProjectV2Handler::list_items`].
+pub struct ListProjectV2ItemsBuilder<'octo> {
+    crab: &'octo Octocrab,
+    project_id: String,
+    per_page: u8,
+    after: Option<String>,
+}
+
+impl<'octo> ListProjectV2ItemsBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, project_id: String) -> Self {
+        Self {
+            crab,
+            project_id,
+            per_page: 30,
+            after: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = per_page.into();
+        self
+    }
+
+    /// The cursor after which to start, from a previous page's `end_cursor`.
+    pub fn after(mut self, after: impl Into<String>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<ProjectV2Items> {
+        let mut data = self
+            .crab
+            .graphql_query(
+                LIST_ITEMS,
+                &serde_json::json!({
+                    "id": self.project_id,
+                    "first": self.per_page,
+                    "after": self.after,
+                }),
+            )
+            .await?;
+        serde_json::from_value(data["node"]["items"].take()).context(SerdeSnafu)
+    }
+}
+
+fn node_id(value: &serde_json::Value) -> crate::Result<NodeId> {
+    value
+        .as_str()
+        .map(NodeId::from)
+        .ok_or_else(|| crate::Error::Other {
+            source: "mutation did not return the id of the project item".into(),
+            backtrace: snafu::Backtrace::capture(),
+        })
+}
@@ -7,18 +7,21 @@ use snafu::ResultExt;
 
 use crate::error::HttpSnafu;
 use crate::models::pulls::ReviewComment;
-use crate::models::CommentId;
+use crate::models::{CommentId, NodeId};
 use crate::pulls::specific_pr::pr_reviews::specific_review::SpecificReviewBuilder;
 use crate::pulls::specific_pr::{SpecificPullRequestBuilder, SpecificPullRequestCommitBuilder};
 use crate::{Octocrab, Page};
 
 pub use self::{
-    create::CreatePullRequestBuilder, list::ListPullRequestsBuilder,
+    auto_merge::EnableAutoMergeBuilder, comment::CreateCommentBuilder,
+    create::CreatePullRequestBuilder, files::ListFilesBuilder, list::ListPullRequestsBuilder,
     update::UpdatePullRequestBuilder,
 };
 
+mod auto_merge;
 mod comment;
 mod create;
+mod files;
 mod list;
 mod merge;
 mod specific_pr;
@@ -60,6 +63,61 @@ impl<'octo> PullRequestHandler<'octo> {
         self
     }
 
+    /// Polls a pull request until GitHub has finished computing whether it
+    /// can be merged, and returns the result. `mergeable` stays `null` while
+    /// GitHub computes it in the background, so polling backs off from half a
+    /// second up to eight seconds between attempts, giving up with an error
+    /// once `timeout` has elapsed.
+    ///
+    /// Mergeability is never computed for closed pull requests, so waiting on
+    /// one will always time out.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::time::Duration;
+    /// use octocrab::models::pulls::MergeableState;
+    ///
+    /// let state = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .wait_for_mergeability(101, Duration::from_secs(60))
+    ///     .await?;
+    /// if state == MergeableState::Clean {
+    ///     println!("ready to merge");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn wait_for_mergeability(
+        &self,
+        pr: u64,
+        timeout: std::time::Duration,
+    ) -> crate::Result<crate::models::pulls::MergeableState> {
+        use crate::internal::async_runtime::{sleep, time::instant_now, TimeoutError};
+        use crate::models::pulls::MergeableState;
+        use std::time::Duration;
+
+        let start = instant_now();
+        let mut delay = Duration::from_millis(500);
+        loop {
+            let pull_request = self.get(pr).await?;
+            if pull_request.mergeable.is_some() {
+                match pull_request.mergeable_state {
+                    Some(MergeableState::Unknown) | None => {}
+                    Some(state) => return Ok(state),
+                }
+            }
+
+            let remaining = timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                return Err(crate::Error::Other {
+                    source: Box::new(TimeoutError),
+                    backtrace: snafu::Backtrace::capture(),
+                });
+            }
+            sleep(delay.min(remaining)).await;
+            delay = (delay * 2).min(Duration::from_secs(8));
+        }
+    }
+
     /// Checks if a given pull request has been merged.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -277,6 +335,32 @@ impl<'octo> PullRequestHandler<'octo> {
         ListReviewsBuilder::new(self, pr_number)
     }
 
+    /// List the users and teams whose review is currently requested on a
+    /// pull request. Reviewers drop off this list once they submit a review.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let requested = octocrab::instance().pulls("owner", "repo")
+    ///    .list_requested_reviewers(101)
+    ///    .await?;
+    /// for team in requested.teams {
+    ///     println!("{}", team.slug);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_requested_reviewers(
+        &self,
+        pr: u64,
+    ) -> crate::Result<crate::models::teams::RequestedReviewers> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/requested_reviewers",
+            owner = self.owner,
+            repo = self.repo,
+        );
+
+        self.crab.get(route, None::<&()>).await
+    }
+
     /// Request a review from users or teams.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -352,6 +436,34 @@ impl<'octo> PullRequestHandler<'octo> {
         self.http_get(route, None::<&()>).await
     }
 
+    /// Creates a new `ListFilesBuilder` that lists the files changed by a
+    /// pull request, at most 3000 in total. With the `stream` feature the
+    /// remaining pages can be walked with [`Page::into_stream`]. Use
+    /// [`DiffEntry::parsed_patch`] to get at the hunks and line numbers of
+    /// each file's diff.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .files(101)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// for file in page {
+    ///     if let Some(Ok(patch)) = file.parsed_patch() {
+    ///         println!("{}: {} hunks", file.filename, patch.hunks.len());
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Page::into_stream`]: crate::Page::into_stream
+    /// [`DiffEntry::parsed_patch`]: crate::models::repos::DiffEntry::parsed_patch
+    pub fn files(&self, pr: u64) -> files::ListFilesBuilder<'octo, '_> {
+        files::ListFilesBuilder::new(self, pr)
+    }
+
     /// Creates a new `ListCommentsBuilder` that can be configured to list and
     /// filter `Comments` for a particular pull request. If no pull request is
     /// specified, lists comments for the whole repo.
@@ -377,6 +489,35 @@ impl<'octo> PullRequestHandler<'octo> {
         comment::ListCommentsBuilder::new(self, pr)
     }
 
+    /// Creates a review comment on the diff of a pull request. Use
+    /// [`CreateCommentBuilder::start_line`]
+    /// together with [`CreateCommentBuilder::line`]
+    /// to comment on a range of lines.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::pulls::Side;
+    ///
+    /// let comment = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .create_comment(5, "Consider extracting this into a function")
+    ///     .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+    ///     .path("src/lib.rs")
+    ///     .start_line(10u64)
+    ///     .line(14u64)
+    ///     .side(Side::Right)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_comment(
+        &self,
+        pr: u64,
+        body: impl Into<String>,
+    ) -> comment::CreateCommentBuilder<'octo, '_> {
+        comment::CreateCommentBuilder::new(self, pr, body.into())
+    }
+
     ///creates a new `CommentBuilder` for GET/PATCH/DELETE requests
     /// to the `/repos/{owner}/{repo}/pulls/{pr}/comments/{comment_id}` endpoint
     /// ```no_run
@@ -395,6 +536,36 @@ impl<'octo> PullRequestHandler<'octo> {
         comment::CommentBuilder::new(self, comment_id)
     }
 
+    /// Creates a [`ReactionsHandler`] to list, add and remove reactions on a
+    /// pull request review comment.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let reactions = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .comment_reactions(42)
+    ///     .list()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ReactionsHandler`]: crate::reactions::ReactionsHandler
+    pub fn comment_reactions(
+        &self,
+        comment_id: impl Into<CommentId>,
+    ) -> crate::reactions::ReactionsHandler<'octo> {
+        crate::reactions::ReactionsHandler::new(
+            self.crab,
+            format!(
+                "/repos/{owner}/{repo}/pulls/comments/{comment_id}",
+                owner = self.owner,
+                repo = self.repo,
+                comment_id = comment_id.into(),
+            ),
+        )
+    }
+
     /// creates a builder for the `/repos/{owner}/{repo}/pulls/{pull_number}/......` endpoint
     /// working with particular pull request, e.g.
     /// * /repos/{owner}/{repo}/pulls/{pull_number}/reviews/{review_id}/events
@@ -502,6 +673,91 @@ impl<'octo> PullRequestHandler<'octo> {
     pub fn merge(&self, pr: u64) -> merge::MergePullRequestsBuilder<'_, '_> {
         merge::MergePullRequestsBuilder::new(self, pr)
     }
+
+    /// Enables auto-merge on a pull request, so it's merged as soon as all
+    /// required reviews and status checks pass. This goes through the GraphQL
+    /// API, as there's no REST equivalent.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::params;
+    ///
+    /// octocrab.pulls("owner", "repo").enable_auto_merge(101)
+    ///     .method(params::pulls::MergeMethod::Squash)
+    ///     .title("cool title")
+    ///     // Only enable auto-merge if the head is still at this commit
+    ///     .sha("0123456")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn enable_auto_merge(&self, pr: u64) -> auto_merge::EnableAutoMergeBuilder<'octo, '_> {
+        auto_merge::EnableAutoMergeBuilder::new(self, pr)
+    }
+
+    /// Disables auto-merge on a pull request.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().pulls("owner", "repo").disable_auto_merge(101).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable_auto_merge(&self, pr: u64) -> crate::Result<()> {
+        let node_id = self.node_id(pr).await?;
+
+        self.crab
+            .graphql_mutation(
+                "mutation($input: DisablePullRequestAutoMergeInput!) {
+  disablePullRequestAutoMerge(input: $input) { clientMutationId }
+}",
+                &json!({ "pullRequestId": node_id }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Marks a draft pull request as ready for review.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().pulls("owner", "repo").ready_for_review(101).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn ready_for_review(&self, pr: u64) -> crate::Result<()> {
+        let node_id = self.node_id(pr).await?;
+
+        self.crab
+            .graphql_mutation(
+                "mutation($input: MarkPullRequestReadyForReviewInput!) {
+  markPullRequestReadyForReview(input: $input) { clientMutationId }
+}",
+                &json!({ "pullRequestId": node_id }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Converts a pull request back to a draft.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().pulls("owner", "repo").convert_to_draft(101).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn convert_to_draft(&self, pr: u64) -> crate::Result<()> {
+        let node_id = self.node_id(pr).await?;
+
+        self.crab
+            .graphql_mutation(
+                "mutation($input: ConvertPullRequestToDraftInput!) {
+  convertPullRequestToDraft(input: $input) { clientMutationId }
+}",
+                &json!({ "pullRequestId": node_id }),
+            )
+            .await
+            .map(drop)
+    }
 }
 
 impl<'octo, 'r> ListReviewsBuilder<'octo, 'r> {
@@ -537,6 +793,18 @@ impl<'octo, 'r> ListReviewsBuilder<'octo, 'r> {
 
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<crate::models::pulls::Review>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -562,7 +830,7 @@ impl PullRequestHandler<'_> {
         P: serde::Serialize + ?Sized,
         R: crate::FromResponse,
     {
-        let uri = self.crab.parameterized_uri(route, parameters)?;
+        let uri = self.crab.get_uri::<R, _, _>(route, parameters)?;
 
         let mut request = Builder::new().uri(uri);
         if let Some(media_type) = self.media_type {
@@ -628,6 +896,17 @@ impl PullRequestHandler<'_> {
         R::from_response(crate::map_github_error(self.crab.execute(request).await?).await?).await
     }
 
+    /// Looks up the GraphQL node ID of a pull request.
+    pub(crate) async fn node_id(&self, pr: u64) -> crate::Result<NodeId> {
+        self.get(pr)
+            .await?
+            .node_id
+            .ok_or_else(|| crate::Error::Other {
+                source: format!("pull request #{pr} has no node_id").into(),
+                backtrace: snafu::Backtrace::capture(),
+            })
+    }
+
     fn build_request(&self, mut request: http::request::Builder) -> http::request::Builder
 where {
         if let Some(media_type) = self.media_type {
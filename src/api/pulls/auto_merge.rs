@@ -0,0 +1,105 @@
+use super::*;
+use crate::params::pulls::MergeMethod;
+
+const ENABLE_AUTO_MERGE: &str = "mutation($input: EnablePullRequestAutoMergeInput!) {
+  enablePullRequestAutoMerge(input: $input) { clientMutationId }
+}";
+
+/// A builder pattern struct for enabling auto-merge on a pull request.
+///
+/// created by [`PullRequestHandler::enable_auto_merge`]
+///
+/// [`PullRequestHandler::enable_auto_merge`]: ./struct.PullRequestHandler.html#method.enable_auto_merge
+pub struct EnableAutoMergeBuilder<'octo, 'b> {
+    handler: &'b PullRequestHandler<'octo>,
+    pr_number: u64,
+    commit_title: Option<String>,
+    commit_message: Option<String>,
+    sha: Option<String>,
+    merge_method: Option<MergeMethod>,
+    author_email: Option<String>,
+}
+
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct EnableAutoMergeInput<'a> {
+    pull_request_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_headline: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_body: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected_head_oid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    merge_method: Option<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author_email: Option<&'a str>,
+}
+
+impl<'octo, 'b> EnableAutoMergeBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b PullRequestHandler<'octo>, pr_number: u64) -> Self {
+        Self {
+            handler,
+            pr_number,
+            commit_title: None,
+            commit_message: None,
+            sha: None,
+            merge_method: None,
+            author_email: None,
+        }
+    }
+
+    /// Title for the merge commit.
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.commit_title = Some(title.into());
+        self
+    }
+
+    /// Body for the merge commit.
+    pub fn message(mut self, msg: impl Into<String>) -> Self {
+        self.commit_message = Some(msg.into());
+        self
+    }
+
+    /// SHA that pull request head must match for auto-merge to be enabled.
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.sha = Some(sha.into());
+        self
+    }
+
+    /// Merge method to use once the requirements are met. Defaults to the
+    /// repository's default merge method.
+    pub fn method(mut self, method: impl Into<MergeMethod>) -> Self {
+        self.merge_method = Some(method.into());
+        self
+    }
+
+    /// Email address to use as the merge commit's author.
+    pub fn author_email(mut self, email: impl Into<String>) -> Self {
+        self.author_email = Some(email.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<()> {
+        let node_id = self.handler.node_id(self.pr_number).await?;
+        let input = EnableAutoMergeInput {
+            pull_request_id: &node_id,
+            commit_headline: self.commit_title.as_deref(),
+            commit_body: self.commit_message.as_deref(),
+            expected_head_oid: self.sha.as_deref(),
+            merge_method: self.merge_method.map(|method| match method {
+                MergeMethod::Merge => "MERGE",
+                MergeMethod::Squash => "SQUASH",
+                MergeMethod::Rebase => "REBASE",
+            }),
+            author_email: self.author_email.as_deref(),
+        };
+
+        self.handler
+            .crab
+            .graphql_mutation(ENABLE_AUTO_MERGE, &input)
+            .await
+            .map(drop)
+    }
+}
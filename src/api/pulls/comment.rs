@@ -86,6 +86,18 @@ impl<'octo, 'b> ListCommentsBuilder<'octo, 'b> {
         );
         self.handler.http_get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<crate::models::pulls::Comment>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// A builder pattern struct for working with specific comment.
@@ -142,7 +154,8 @@ impl<'octo, 'b> CommentBuilder<'octo, 'b> {
 
     ///https://docs.github.com/en/rest/pulls/comments?apiVersion=2022-11-28#delete-a-review-comment-for-a-pull-request
     pub async fn delete(self) -> crate::Result<()> {
-        self.handler
+        let response = self
+            .handler
             .crab
             ._delete(
                 format!(
@@ -154,7 +167,121 @@ impl<'octo, 'b> CommentBuilder<'octo, 'b> {
                 None::<&()>,
             )
             .await?;
-        Ok(())
+        crate::map_github_error(response).await.map(drop)
+    }
+}
+
+/// A builder pattern struct for creating a review comment.
+///
+/// created by [`PullRequestHandler::create_comment`]
+///
+/// [`PullRequestHandler::create_comment`]: ./struct.PullRequestHandler.html#method.create_comment
+#[derive(serde::Serialize)]
+pub struct CreateCommentBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr: u64,
+    body: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    side: Option<crate::models::pulls::Side>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_side: Option<crate::models::pulls::Side>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    in_reply_to: Option<CommentId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    subject_type: Option<crate::params::pulls::comments::SubjectType>,
+}
+
+impl<'octo, 'b> CreateCommentBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b PullRequestHandler<'octo>, pr: u64, body: String) -> Self {
+        Self {
+            handler,
+            pr,
+            body,
+            commit_id: None,
+            path: None,
+            line: None,
+            side: None,
+            start_line: None,
+            start_side: None,
+            in_reply_to: None,
+            subject_type: None,
+        }
+    }
+
+    /// The SHA of the commit needing a comment. Using the SHA of an older
+    /// commit may render the comment outdated if a subsequent commit modifies
+    /// the commented line.
+    pub fn commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.commit_id = Some(commit_id.into());
+        self
+    }
+
+    /// The relative path to the file that necessitates a comment.
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// The line of the blob in the pull request diff that the comment applies
+    /// to. For a multi-line comment, the last line of the range.
+    pub fn line(mut self, line: impl Into<u64>) -> Self {
+        self.line = Some(line.into());
+        self
+    }
+
+    /// Which side of the diff the (last) line of the comment is on.
+    pub fn side(mut self, side: impl Into<crate::models::pulls::Side>) -> Self {
+        self.side = Some(side.into());
+        self
+    }
+
+    /// The first line of the range for a multi-line comment.
+    pub fn start_line(mut self, start_line: impl Into<u64>) -> Self {
+        self.start_line = Some(start_line.into());
+        self
+    }
+
+    /// Which side of the diff the first line of a multi-line comment is on.
+    pub fn start_side(mut self, start_side: impl Into<crate::models::pulls::Side>) -> Self {
+        self.start_side = Some(start_side.into());
+        self
+    }
+
+    /// The ID of the review comment to reply to. When set, all parameters
+    /// other than the body are ignored by GitHub.
+    pub fn in_reply_to(mut self, in_reply_to: impl Into<CommentId>) -> Self {
+        self.in_reply_to = Some(in_reply_to.into());
+        self
+    }
+
+    /// Whether the comment applies to a single line or to the whole file.
+    pub fn subject_type(
+        mut self,
+        subject_type: impl Into<crate::params::pulls::comments::SubjectType>,
+    ) -> Self {
+        self.subject_type = Some(subject_type.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Comment> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/comments",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr,
+        );
+        self.handler.crab.post(route, Some(&self)).await
     }
 }
 
@@ -186,4 +313,33 @@ mod tests {
             })
         )
     }
+
+    #[tokio::test]
+    async fn serialize_create_multi_line_comment() {
+        #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+        crate::ensure_crypto_provider_initialized();
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let create = handler
+            .create_comment(1, "Consider extracting this")
+            .commit_id("6dcb09b5b57875f334f61aebed695e2e4193db5e")
+            .path("src/lib.rs")
+            .start_line(1u64)
+            .start_side(crate::models::pulls::Side::Right)
+            .line(2u64)
+            .side(crate::models::pulls::Side::Right);
+
+        assert_eq!(
+            serde_json::to_value(create).unwrap(),
+            serde_json::json!({
+                "body": "Consider extracting this",
+                "commit_id": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+                "path": "src/lib.rs",
+                "start_line": 1,
+                "start_side": "RIGHT",
+                "line": 2,
+                "side": "RIGHT"
+            })
+        )
+    }
 }
@@ -48,19 +48,83 @@ impl<'octo, 'b> CreatePullRequestBuilder<'octo, 'b> {
         self
     }
 
-    /// Indicates whether `maintainers` can modify the pull request.
+    /// Indicates whether `maintainers` can modify the pull request. Only has
+    /// an effect on pull requests opened from a fork.
     pub fn maintainer_can_modify(mut self, maintainer_can_modify: impl Into<Option<bool>>) -> Self {
         self.maintainer_can_modify = maintainer_can_modify.into();
         self
     }
 
+    /// The repository the changes were made in, required for pull requests
+    /// between two repositories owned by the same organization.
     pub fn head_repo(mut self, head_repo: impl Into<Option<String>>) -> Self {
         self.head_repo = head_repo.into();
         self
     }
 
-    /// Sends the request to create the pull request.
+    /// Opens the pull request from `branch` of `owner`'s fork, setting the
+    /// head to `owner:branch`.
+    pub fn from_fork(mut self, owner: impl AsRef<str>, branch: impl AsRef<str>) -> Self {
+        self.head = format!("{}:{}", owner.as_ref(), branch.as_ref());
+        self
+    }
+
+    /// Whether the head is in another repository, either through an
+    /// `owner:branch` head or an explicit `head_repo`.
+    fn is_cross_repository(&self) -> bool {
+        self.head.contains(':') || self.head_repo.is_some()
+    }
+
+    /// Catches the mistakes GitHub would only report as an opaque
+    /// `422 Validation Failed`.
+    fn validate(&self) -> Result<(), String> {
+        if self.title.trim().is_empty() {
+            return Err("pull request title must not be empty".into());
+        }
+        if self.base.is_empty() || self.base.contains(':') {
+            return Err(format!(
+                "base `{}` must be a branch name in the target repository",
+                self.base
+            ));
+        }
+        match self.head.split_once(':') {
+            Some((owner, branch))
+                if owner.is_empty() || branch.is_empty() || branch.contains(':') =>
+            {
+                return Err(format!(
+                    "head `{}` must be a branch name or `owner:branch`",
+                    self.head
+                ));
+            }
+            None if self.head.is_empty() => {
+                return Err("head branch must not be empty".into());
+            }
+            _ => {}
+        }
+        if let Some(head_repo) = &self.head_repo {
+            if head_repo.is_empty() || head_repo.contains(':') {
+                return Err(format!("head_repo `{head_repo}` is not a repository name"));
+            }
+        }
+        if !self.is_cross_repository() && self.head == self.base {
+            return Err(format!(
+                "head and base are both `{}`, use `from_fork` to open a pull request from a fork",
+                self.base
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Sends the request to create the pull request. The request is checked
+    /// for common mistakes, such as a malformed `owner:branch` head, before it
+    /// is sent.
     pub async fn send(self) -> crate::Result<crate::models::pulls::PullRequest> {
+        self.validate().map_err(|message| crate::Error::Other {
+            source: message.into(),
+            backtrace: snafu::Backtrace::capture(),
+        })?;
+
         let route = format!(
             "/repos/{owner}/{repo}/pulls",
             owner = self.handler.owner,
@@ -99,4 +163,32 @@ mod tests {
             })
         )
     }
+
+    #[tokio::test]
+    async fn validate() {
+        #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+        crate::ensure_crypto_provider_initialized();
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+
+        let fork = handler
+            .create("test-pr", "ignored", "master")
+            .from_fork("octocat", "feature")
+            .maintainer_can_modify(true);
+        assert_eq!(fork.head, "octocat:feature");
+        assert!(fork.validate().is_ok());
+
+        assert!(handler.create(" ", "feature", "master").validate().is_err());
+        assert!(handler
+            .create("t", "octocat:", "master")
+            .validate()
+            .is_err());
+        assert!(handler.create("t", "a:b:c", "master").validate().is_err());
+        assert!(handler.create("t", "master", "master").validate().is_err());
+        assert!(handler
+            .create("t", "master", "master")
+            .head_repo(String::from("octocat/rust"))
+            .validate()
+            .is_ok());
+    }
 }
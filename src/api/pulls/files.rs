@@ -0,0 +1,85 @@
+use super::*;
+
+/// A builder pattern struct for listing the files changed by a pull request.
+///
+/// created by [`PullRequestHandler::files`]
+///
+/// [`PullRequestHandler::files`]: ./struct.PullRequestHandler.html#method.files
+#[derive(serde::Serialize)]
+pub struct ListFilesBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'b> ListFilesBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b PullRequestHandler<'octo>, pr: u64) -> Self {
+        Self {
+            handler,
+            pr,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<crate::models::repos::DiffEntry>> {
+        let route = format!(
+            "/repos/{owner}/{repo}/pulls/{pr}/files",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr,
+        );
+        self.handler.http_get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<
+        impl futures_core::Stream<Item = crate::Result<crate::models::repos::DiffEntry>>,
+    > {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+        crate::ensure_crypto_provider_initialized();
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.pulls("rust-lang", "rust");
+        let files = handler.files(1).per_page(100).page(2u32);
+
+        assert_eq!(
+            serde_json::to_value(files).unwrap(),
+            serde_json::json!({
+                "per_page": 100,
+                "page": 2,
+            })
+        )
+    }
+}
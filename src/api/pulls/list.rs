@@ -96,6 +96,19 @@ impl<'octo, 'b> ListPullRequestsBuilder<'octo, 'b> {
         );
         self.handler.http_get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<
+        impl futures_core::Stream<Item = crate::Result<crate::models::pulls::PullRequest>>,
+    > {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 #[cfg(test)]
@@ -1,5 +1,12 @@
 //! Github RateLimit API
 
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use http::{HeaderMap, Uri};
+
 use crate::{models, Octocrab, Result};
 
 /// Handler for GitHub's rate_limit API.
@@ -27,4 +34,299 @@ impl<'octo> RateLimitHandler<'octo> {
     pub async fn get(&self) -> Result<models::RateLimit> {
         self.crab.get("/rate_limit", None::<&()>).await
     }
+
+    /// Returns the rate limits recorded from the headers of the responses
+    /// to this client's previous requests, without making a request.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// octocrab.search().issues_and_pull_requests("is:open").send().await?;
+    ///
+    /// if let Some(search) = octocrab.ratelimit().snapshot().get("search") {
+    ///     println!("{} searches left", search.remaining);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn snapshot(&self) -> RateLimitSnapshot {
+        self.crab.rate_limits.clone()
+    }
+}
+
+/// The rate limits GitHub reported in the `x-ratelimit-*` headers of the
+/// latest responses, per resource, e.g. `core`, `search`, `code_search` or
+/// `graphql`. Each resource has its own budget, so a bulk search job running
+/// out of its 30 searches per minute doesn't hold up other requests.
+///
+/// When the client is built with
+/// [`OctocrabBuilder::throttle_rate_limits`](crate::OctocrabBuilder::throttle_rate_limits),
+/// requests to a resource with no requests left wait for its window to reset
+/// instead of failing.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitSnapshot {
+    throttle: bool,
+    track_graphql: bool,
+    resources: Arc<RwLock<HashMap<String, models::Rate>>>,
+    last_graphql_cost: Arc<RwLock<Option<usize>>>,
+}
+
+impl RateLimitSnapshot {
+    pub(crate) fn new(throttle: bool, track_graphql: bool) -> Self {
+        Self {
+            throttle,
+            track_graphql,
+            resources: Arc::default(),
+            last_graphql_cost: Arc::default(),
+        }
+    }
+
+    /// A snapshot with the same settings but nothing recorded, for a client
+    /// using different credentials and hence different budgets.
+    pub(crate) fn fresh(&self) -> Self {
+        Self::new(self.throttle, self.track_graphql)
+    }
+
+    /// The latest rate limit recorded for the resource.
+    pub fn get(&self, resource: &str) -> Option<models::Rate> {
+        self.resources.read().unwrap().get(resource).cloned()
+    }
+
+    /// The latest rate limits recorded for all resources.
+    pub fn resources(&self) -> HashMap<String, models::Rate> {
+        self.resources.read().unwrap().clone()
+    }
+
+    /// The points the latest GraphQL query cost, recorded when the client is
+    /// built with
+    /// [`OctocrabBuilder::track_graphql_rate_limit`](crate::OctocrabBuilder::track_graphql_rate_limit).
+    pub fn last_graphql_cost(&self) -> Option<usize> {
+        *self.last_graphql_cost.read().unwrap()
+    }
+
+    pub(crate) fn record(&self, resource: &str, rate: models::Rate) {
+        self.resources
+            .write()
+            .unwrap()
+            .insert(resource.to_string(), rate);
+    }
+
+    /// Records the rate limit from the headers of a response, which name the
+    /// resource they apply to when GitHub sends them.
+    pub(crate) fn record_headers(&self, resource: &str, headers: &HeaderMap) {
+        let Some(rate) = rate_from_headers(headers) else {
+            return;
+        };
+        let resource = headers
+            .get("x-ratelimit-resource")
+            .and_then(|resource| resource.to_str().ok())
+            .unwrap_or(resource);
+        self.record(resource, rate);
+    }
+
+    /// Whether GraphQL queries get `rateLimit` added, see
+    /// [`inject_graphql_rate_limit`].
+    pub(crate) fn tracks_graphql(&self) -> bool {
+        self.track_graphql
+    }
+
+    /// Takes the `rateLimit` added by [`inject_graphql_rate_limit`] out of
+    /// the `data` of a GraphQL response and records it.
+    pub(crate) fn record_graphql(&self, data: &mut serde_json::Value) {
+        #[derive(serde::Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct GraphQLRateLimit {
+            cost: usize,
+            limit: usize,
+            used: usize,
+            remaining: usize,
+            reset_at: DateTime<Utc>,
+        }
+
+        let Some(rate_limit) = data
+            .as_object_mut()
+            .and_then(|data| data.remove(GRAPHQL_RATE_LIMIT_ALIAS))
+        else {
+            return;
+        };
+        if let Ok(rate_limit) = serde_json::from_value::<GraphQLRateLimit>(rate_limit) {
+            *self.last_graphql_cost.write().unwrap() = Some(rate_limit.cost);
+            self.record(
+                "graphql",
+                models::Rate {
+                    limit: rate_limit.limit,
+                    used: rate_limit.used,
+                    remaining: rate_limit.remaining,
+                    reset: rate_limit.reset_at.timestamp() as u64,
+                },
+            );
+        }
+    }
+
+    /// Waits until the resource has requests left, if throttling is enabled,
+    /// and counts the request against the recorded budget so concurrent
+    /// requests don't all go out on the last remaining one.
+    pub(crate) async fn throttle(&self, resource: &str) {
+        if !self.throttle {
+            return;
+        }
+        let wait = {
+            let mut resources = self.resources.write().unwrap();
+            let Some(rate) = resources.get_mut(resource) else {
+                return;
+            };
+            let now = crate::internal::async_runtime::time::unix_timestamp().unwrap_or(0);
+            if rate.reset <= now {
+                // The window has reset, the response will bring the new budget.
+                return;
+            }
+            if rate.remaining > 0 {
+                rate.remaining -= 1;
+                rate.used += 1;
+                return;
+            }
+            rate.reset - now
+        };
+        crate::internal::async_runtime::sleep(Duration::from_secs(wait)).await;
+    }
+}
+
+/// The alias of the `rateLimit` field added to GraphQL queries, which keeps
+/// it apart from a `rateLimit` the query selects itself.
+const GRAPHQL_RATE_LIMIT_ALIAS: &str = "octocrabRateLimit";
+
+/// Adds `rateLimit` to the selection of the query in a GraphQL request
+/// payload. Returns whether it was added, which it isn't for mutations,
+/// subscriptions and documents starting with a fragment.
+pub(crate) fn inject_graphql_rate_limit(payload: &mut serde_json::Value) -> bool {
+    let Some(query) = payload["query"].as_str() else {
+        return false;
+    };
+    let Some(index) = query_selection_start(query) else {
+        return false;
+    };
+    let query = format!(
+        "{} {GRAPHQL_RATE_LIMIT_ALIAS}: rateLimit {{ cost limit used remaining resetAt }}{}",
+        &query[..index],
+        &query[index..]
+    );
+    payload["query"] = query.into();
+    true
+}
+
+/// The position just after the `{` opening the selection set of the query
+/// the document starts with.
+fn query_selection_start(document: &str) -> Option<usize> {
+    let offset = document.len() - document.trim_start().len();
+    let document = &document[offset..];
+    let is_query = document.starts_with('{')
+        || document
+            .strip_prefix("query")
+            .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_'));
+    if !is_query {
+        return None;
+    }
+
+    // Skip the variable definitions, whose default values may contain braces.
+    let (mut parens, mut in_string, mut in_comment, mut escaped) = (0, false, false, false);
+    for (index, c) in document.char_indices() {
+        if in_comment {
+            in_comment = c != '\n';
+        } else if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else {
+            match c {
+                '#' => in_comment = true,
+                '"' => in_string = true,
+                '(' => parens += 1,
+                ')' => parens -= 1,
+                '{' if parens == 0 => return Some(offset + index + 1),
+                _ => {}
+            }
+        }
+    }
+    None
+}
+
+/// The rate limit in the `x-ratelimit-*` headers of a response.
+pub(crate) fn rate_from_headers(headers: &HeaderMap) -> Option<models::Rate> {
+    fn header<T: std::str::FromStr>(headers: &HeaderMap, name: &str) -> Option<T> {
+        headers.get(name)?.to_str().ok()?.parse().ok()
+    }
+
+    Some(models::Rate {
+        limit: header(headers, "x-ratelimit-limit")?,
+        used: header(headers, "x-ratelimit-used").unwrap_or(0),
+        remaining: header(headers, "x-ratelimit-remaining")?,
+        reset: header(headers, "x-ratelimit-reset")?,
+    })
+}
+
+/// The rate limit resource a request counts against, based on its path.
+pub(crate) fn resource_for(uri: &Uri) -> &'static str {
+    let path = uri.path();
+    let path = path.strip_prefix("/api/v3").unwrap_or(path);
+    if path.starts_with("/search/code") {
+        "code_search"
+    } else if path.starts_with("/search/") {
+        "search"
+    } else if path == "/graphql" || path == "/api/graphql" {
+        "graphql"
+    } else {
+        "core"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resource_for_search() {
+        let resource = |uri: &str| resource_for(&uri.parse().unwrap());
+        assert_eq!(resource("/search/issues?q=is:open"), "search");
+        assert_eq!(
+            resource("https://api.github.com/search/code?q=octocrab&page=2"),
+            "code_search"
+        );
+        assert_eq!(
+            resource("https://ghe.example.com/api/v3/search/users"),
+            "search"
+        );
+        assert_eq!(resource("/graphql"), "graphql");
+        assert_eq!(resource("/repos/octocat/search"), "core");
+    }
+
+    #[test]
+    fn inject_graphql_rate_limit_into_queries() {
+        let inject = |query: &str| {
+            let mut payload = serde_json::json!({ "query": query });
+            inject_graphql_rate_limit(&mut payload)
+                .then(|| payload["query"].as_str().unwrap().to_owned())
+        };
+        let rate_limit = format!(
+            "{GRAPHQL_RATE_LIMIT_ALIAS}: rateLimit {{ cost limit used remaining resetAt }}"
+        );
+
+        assert_eq!(
+            inject("{ viewer { login } }"),
+            Some(format!("{{ {rate_limit} viewer {{ login }} }}"))
+        );
+        assert_eq!(
+            inject("query($filter: Filter = { name: \"{\" }) { viewer { login } }"),
+            Some(format!(
+                "query($filter: Filter = {{ name: \"{{\" }}) {{ {rate_limit} viewer {{ login }} }}"
+            ))
+        );
+        assert_eq!(inject("mutation { addStar { clientMutationId } }"), None);
+        assert_eq!(inject("queryish { viewer { login } }"), None);
+        assert!(inject("{ viewer { login } }")
+            .unwrap()
+            .contains("rateLimit { cost limit"));
+    }
 }
@@ -0,0 +1,183 @@
+//! The reactions API.
+//!
+//! Every resource that can be reacted to exposes the same three endpoints
+//! under its own URL, so a [`ReactionsHandler`] is created from the handler of
+//! the resource, e.g. [`IssueHandler::reactions`] or
+//! [`ReleasesHandler::reactions`].
+//!
+//! [`IssueHandler::reactions`]: crate::issues::IssueHandler::reactions
+//! [`ReleasesHandler::reactions`]: crate::repos::ReleasesHandler::reactions
+
+use crate::models::reactions::{Reaction, ReactionContent};
+use crate::models::ReactionId;
+use crate::{Octocrab, Page, Result};
+
+/// Handler for the reactions of a single issue, comment, release or team
+/// discussion.
+pub struct ReactionsHandler<'octo> {
+    crab: &'octo Octocrab,
+    route: String,
+}
+
+impl<'octo> ReactionsHandler<'octo> {
+    /// `resource` is the route of the reacted to resource, without the
+    /// trailing `/reactions`.
+    pub(crate) fn new(crab: &'octo Octocrab, resource: String) -> Self {
+        Self {
+            crab,
+            route: format!("{resource}/reactions"),
+        }
+    }
+
+    /// Lists the reactions, optionally only those of one kind.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::reactions::ReactionContent;
+    ///
+    /// let hearts = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .reactions(1)
+    ///     .list()
+    ///     // Optional Parameters
+    ///     .content(ReactionContent::Heart)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListReactionsBuilder<'octo, '_> {
+        ListReactionsBuilder::new(self)
+    }
+
+    /// Adds a reaction. Creating a reaction that already exists returns the
+    /// existing one.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::reactions::ReactionContent;
+    ///
+    /// let reaction = octocrab::instance()
+    ///     .pulls("owner", "repo")
+    ///     .comment_reactions(42)
+    ///     .create(ReactionContent::Rocket)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(&self, content: ReactionContent) -> Result<Reaction> {
+        self.crab
+            .post(
+                &self.route,
+                Some(&serde_json::json!({ "content": content })),
+            )
+            .await
+    }
+
+    /// Deletes a reaction.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .reactions(1)
+    ///     .delete(2)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self, reaction_id: impl Into<ReactionId>) -> Result<()> {
+        let route = format!("{}/{}", self.route, reaction_id.into());
+
+        crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+}
+
+/// A builder pattern struct for listing reactions.
+///
+/// created by [`ReactionsHandler::list`]
+#[derive(serde::Serialize)]
+pub struct ListReactionsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r ReactionsHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content: Option<ReactionContent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListReactionsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r ReactionsHandler<'octo>) -> Self {
+        Self {
+            handler,
+            content: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only return reactions of this kind.
+    pub fn content(mut self, content: impl Into<ReactionContent>) -> Self {
+        self.content = Some(content.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Send the actual request.
+    pub async fn send(self) -> Result<Page<Reaction>> {
+        self.handler
+            .crab
+            .get(&self.handler.route, Some(&self))
+            .await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Reaction>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[tokio::test]
+    async fn serialize() {
+        #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+        crate::ensure_crypto_provider_initialized();
+        let octocrab = crate::Octocrab::default();
+        let handler = octocrab.issues("rust-lang", "rust").reactions(1);
+        let list = handler
+            .list()
+            .content(crate::models::reactions::ReactionContent::PlusOne)
+            .per_page(100)
+            .page(2u32);
+
+        assert_eq!(
+            serde_json::to_value(list).unwrap(),
+            serde_json::json!({
+                "content": "+1",
+                "per_page": 100,
+                "page": 2,
+            })
+        )
+    }
+}
@@ -7,25 +7,34 @@ use http::Uri;
 use http_body_util::combinators::BoxBody;
 use snafu::ResultExt;
 
+mod activity;
 mod branches;
+mod codespaces;
 mod collaborators;
 mod commits;
 mod contributors;
 mod dependabot;
+mod dependency_graph;
+mod discussions;
 pub mod events;
 mod file;
 pub mod forks;
 mod generate;
 mod merges;
+mod pages;
 mod pulls;
 pub mod release_assets;
 pub mod releases;
 mod secret_scanning_alerts;
+mod secret_scanning_bypass;
 mod secrets;
+mod security_advisories;
 mod stargazers;
+mod stats;
 mod status;
 mod tags;
 mod teams;
+mod update;
 
 use crate::error::HttpSnafu;
 use crate::models::commits::GitCommitObject;
@@ -35,24 +44,37 @@ use crate::models::interaction_limits::{
 use crate::models::{repos, RepositoryId};
 use crate::repos::collaborators::GetCollaboratorPermissionBuilder;
 use crate::repos::file::GetReadmeBuilder;
+use crate::route::Route;
 use crate::{models, params, Octocrab, Result};
+pub use activity::ListRepoActivityBuilder;
 pub use branches::ListBranchesBuilder;
+pub use codespaces::{CreateCodespaceBuilder, RepoCodespacesHandler};
 pub use collaborators::ListCollaboratorsBuilder;
 pub use commits::ListCommitsBuilder;
 pub use contributors::ListContributorsBuilder;
 pub use dependabot::RepoDependabotAlertsHandler;
+pub use dependency_graph::{CompareDependenciesBuilder, RepoDependencyGraphHandler};
+pub use discussions::{ListDiscussionsBuilder, RepoDiscussionsHandler};
 pub use file::{DeleteFileBuilder, GetContentBuilder, UpdateFileBuilder};
 pub use generate::GenerateRepositoryBuilder;
 pub use merges::MergeBranchBuilder;
+pub use pages::{CreatePagesBuilder, ListPagesBuildsBuilder, RepoPagesHandler, UpdatePagesBuilder};
 pub use pulls::ListPullsBuilder;
 pub use release_assets::ReleaseAssetsHandler;
 pub use releases::ReleasesHandler;
 pub use secret_scanning_alerts::RepoSecretScanningAlertsHandler;
+pub use secret_scanning_bypass::ListBypassRequestsBuilder;
 pub use secrets::RepoSecretsHandler;
+pub use security_advisories::{
+    CreateRepoSecurityAdvisoryBuilder, ListRepoSecurityAdvisoriesBuilder,
+    RepoSecurityAdvisoriesHandler, ReportVulnerabilityBuilder, UpdateRepoSecurityAdvisoryBuilder,
+};
 pub use stargazers::ListStarGazersBuilder;
+pub use stats::RepoStatsHandler;
 pub use status::{CreateStatusBuilder, ListStatusesBuilder};
 pub use tags::ListTagsBuilder;
 pub use teams::ListTeamsBuilder;
+pub use update::UpdateRepoBuilder;
 
 #[derive(Clone)]
 pub(crate) enum RepoRef {
@@ -83,9 +105,14 @@ impl<'octo> RepoHandler<'octo> {
     }
 
     /// Get's a repository's license.
+    ///
+    /// The returned [`Content`](models::repos::Content) holds the license file
+    /// itself (see [`Content::decoded_content`](models::repos::Content::decoded_content))
+    /// and the license GitHub detected in [`Content::license`](models::repos::Content::license).
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
     /// let license = octocrab::instance().repos("owner", "repo").license().await?;
+    /// let spdx_id = license.license.map(|license| license.spdx_id);
     /// # Ok(())
     /// # }
     /// ```
@@ -95,6 +122,27 @@ impl<'octo> RepoHandler<'octo> {
         self.crab.get(route, None::<&()>).await
     }
 
+    /// Get's a repository's license as of the given branch, tag or commit.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let license = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .license_at("v1.0.0".to_string())
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn license_at(
+        &self,
+        reference: impl Into<params::repos::Commitish>,
+    ) -> Result<models::repos::Content> {
+        let route = format!("/{}/license", self.repo);
+
+        self.crab
+            .get(route, Some(&[("ref", &reference.into().0)]))
+            .await
+    }
+
     /// Get's a repository's public key.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -154,11 +202,9 @@ impl<'octo> RepoHandler<'octo> {
         &self,
         reference: &params::repos::Reference,
     ) -> Result<models::repos::Ref> {
-        let route = format!(
-            "/{repo}/git/ref/{reference}",
-            repo = self.repo,
-            reference = reference.ref_url(),
-        );
+        let route = Route::new(format_args!("/{}", self.repo))
+            .path("git/ref")
+            .segments(reference.ref_url());
         self.crab.get(route, None::<&()>).await
     }
 
@@ -175,11 +221,9 @@ impl<'octo> RepoHandler<'octo> {
     /// # }
     /// ```
     pub async fn get_tag(&self, tag_sha: impl Into<String>) -> Result<models::repos::GitTag> {
-        let route = format!(
-            "/{repo}/git/tags/{tag_sha}",
-            repo = self.repo,
-            tag_sha = tag_sha.into(),
-        );
+        let route = Route::new(format_args!("/{}", self.repo))
+            .path("git/tags")
+            .segment(tag_sha.into());
         self.crab.get(route, None::<&()>).await
     }
 
@@ -229,11 +273,9 @@ impl<'octo> RepoHandler<'octo> {
     /// # }
     /// ```
     pub async fn delete_ref(&self, reference: &params::repos::Reference) -> Result<()> {
-        let route = format!(
-            "/{repo}/git/refs/{ref}",
-            repo = self.repo,
-            ref = reference.ref_url()
-        );
+        let route = Route::new(format_args!("/{}", self.repo))
+            .path("git/refs")
+            .segments(reference.ref_url());
         crate::map_github_error(self.crab._delete(route, None::<&()>).await?)
             .await
             .map(drop)
@@ -490,6 +532,28 @@ impl<'octo> RepoHandler<'octo> {
         GetCollaboratorPermissionBuilder::new(self, username)
     }
 
+    /// Lists the changes to the refs of a repository, such as pushes, force
+    /// pushes and deleted branches, and who made them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::activity::ActivityType;
+    /// use octocrab::params::repos::activity::TimePeriod;
+    ///
+    /// let force_pushes = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .list_activity()
+    ///     .activity_type(ActivityType::ForcePush)
+    ///     .time_period(TimePeriod::Month)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_activity(&self) -> ListRepoActivityBuilder<'_, '_> {
+        ListRepoActivityBuilder::new(self)
+    }
+
     /// List contributors from a repository.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -523,6 +587,7 @@ impl<'octo> RepoHandler<'octo> {
     ///     .repos("owner", "repo")
     ///     .list_languages()
     ///     .await?;
+    /// let total: i64 = languages.values().sum();
     /// # Ok(())
     /// # }
     /// ```
@@ -645,11 +710,10 @@ impl<'octo> RepoHandler<'octo> {
         &self,
         reference: &params::repos::Reference,
     ) -> Result<models::CombinedStatus> {
-        let route = format!(
-            "/{repo}/commits/{reference}/status",
-            repo = self.repo,
-            reference = reference.ref_url(),
-        );
+        let route = Route::new(format_args!("/{}", self.repo))
+            .path("commits")
+            .segments(reference.ref_url())
+            .path("status");
         self.crab.get(route, None::<&()>).await
     }
 
@@ -678,15 +742,12 @@ impl<'octo> RepoHandler<'octo> {
         reference: impl Into<params::repos::Commitish>,
         path: impl AsRef<str>,
     ) -> Result<http::Response<BoxBody<Bytes, crate::Error>>> {
-        let route = format!(
-            "/{repo}/contents/{path}",
-            repo = self.repo,
-            path = path.as_ref(),
-        );
-
-        let uri = self
-            .crab
-            .parameterized_uri(route, Some(&[("ref", &reference.into().0)]))?;
+        let route = Route::new(format_args!("/{}", self.repo))
+            .path("contents")
+            .segments(path)
+            .query("ref", reference.into().0);
+
+        let uri = self.crab.parameterized_uri(route, None::<&()>)?;
         let request = Builder::new()
             .uri(uri)
             .method(http::Method::GET)
@@ -696,6 +757,29 @@ impl<'octo> RepoHandler<'octo> {
             .await
     }
 
+    /// Updates the settings of this repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::SecurityAndAnalysis;
+    ///
+    /// let repo = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .update()
+    ///     .delete_branch_on_merge(true)
+    ///     .security_and_analysis(
+    ///         SecurityAndAnalysis::new()
+    ///             .secret_scanning(true)
+    ///             .secret_scanning_push_protection(true),
+    ///     )
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self) -> UpdateRepoBuilder<'_, '_> {
+        UpdateRepoBuilder::new(self)
+    }
+
     /// Deletes this repository.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -718,13 +802,11 @@ impl<'octo> RepoHandler<'octo> {
         &self,
         reference: impl Into<params::repos::Commitish>,
     ) -> Result<http::Response<BoxBody<Bytes, crate::Error>>> {
-        let route = format!(
-            "/{repo}/tarball/{reference}",
-            repo = self.repo,
-            reference = reference.into(),
-        );
+        let route = Route::new(format_args!("/{}", self.repo))
+            .path("tarball")
+            .segments(reference.into().0);
         let uri = Uri::builder()
-            .path_and_query(route)
+            .path_and_query(String::from(route))
             .build()
             .context(HttpSnafu)?;
         self.crab
@@ -734,13 +816,11 @@ impl<'octo> RepoHandler<'octo> {
 
     /// Check if a user is a repository collaborator
     pub async fn is_collaborator(&self, username: impl AsRef<str>) -> Result<bool> {
-        let route = format!(
-            "/{repo}/collaborators/{username}",
-            repo = self.repo,
-            username = username.as_ref(),
-        );
+        let route = Route::new(format_args!("/{}", self.repo))
+            .path("collaborators")
+            .segment(username);
         let uri = Uri::builder()
-            .path_and_query(route)
+            .path_and_query(String::from(route))
             .build()
             .context(HttpSnafu)?;
 
@@ -774,16 +854,51 @@ impl<'octo> RepoHandler<'octo> {
         RepoSecretsHandler::new(self)
     }
 
+    /// Handle Codespaces secrets on the repository
+    pub fn codespaces_secrets(&self) -> RepoSecretsHandler<'_> {
+        RepoSecretsHandler::for_product(self, "codespaces")
+    }
+
+    /// Handle the codespaces of the repository
+    pub fn codespaces(&self) -> RepoCodespacesHandler<'_> {
+        RepoCodespacesHandler::new(self)
+    }
+
     /// Handle dependabot alerts on the repository
     pub fn dependabot(&self) -> RepoDependabotAlertsHandler<'_> {
         RepoDependabotAlertsHandler::new(self)
     }
 
+    /// Handle the dependency graph of the repository
+    pub fn dependency_graph(&self) -> RepoDependencyGraphHandler<'_> {
+        RepoDependencyGraphHandler::new(self)
+    }
+
+    /// Handle the discussions of the repository
+    pub fn discussions(&self) -> RepoDiscussionsHandler<'_> {
+        RepoDiscussionsHandler::new(self)
+    }
+
+    /// Handle the GitHub Pages site of the repository
+    pub fn pages(&self) -> RepoPagesHandler<'_> {
+        RepoPagesHandler::new(self)
+    }
+
+    /// Handle the statistics of the repository
+    pub fn stats(&self) -> RepoStatsHandler<'_> {
+        RepoStatsHandler::new(self)
+    }
+
     /// Handle secrets scanning alerts on the repository
     pub fn secrets_scanning(&self) -> RepoSecretScanningAlertsHandler<'_> {
         RepoSecretScanningAlertsHandler::new(self)
     }
 
+    /// Handle security advisories of the repository
+    pub fn security_advisories(&self) -> RepoSecurityAdvisoriesHandler<'_> {
+        RepoSecurityAdvisoriesHandler::new(self)
+    }
+
     /// Creates a new Git commit object.
     /// See <https://docs.github.com/en/rest/git/commits?apiVersion=2022-11-28#create-a-commit>
     /// ```no_run
@@ -964,7 +1079,8 @@ impl<'octo, 'req> CreateGitCommitObjectBuilder<'octo, 'req> {
         self
     }
 
-    /// The signature of the commit.
+    /// The ASCII-armored PGP signature or SSH signature of the commit, made
+    /// over the payload from [`signing_payload`](Self::signing_payload).
     pub fn signature(mut self, signature: impl Into<String>) -> Self {
         self.signature = Some(signature.into());
         self
@@ -976,6 +1092,70 @@ impl<'octo, 'req> CreateGitCommitObjectBuilder<'octo, 'req> {
         self
     }
 
+    /// Returns the commit as Git serializes it, which is what has to be
+    /// signed for GitHub to verify the [`signature`](Self::signature).
+    ///
+    /// The author needs an email and a date, as GitHub would otherwise fill
+    /// them in and the commit wouldn't match the payload anymore. Without a
+    /// committer, GitHub uses the author, and so does the payload.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::CommitAuthor;
+    /// # fn sign(_payload: &str) -> String { String::new() }
+    ///
+    /// let octocrab = octocrab::instance();
+    /// let repos = octocrab.repos("owner", "repo");
+    /// let commit = repos
+    ///     .create_git_commit_object("message", "tree")
+    ///     .parents(vec!["parent".to_owned()])
+    ///     .author(CommitAuthor {
+    ///         name: "name".to_owned(),
+    ///         email: Some("email".to_owned()),
+    ///         date: Some(chrono::Utc::now()),
+    ///     });
+    /// let signature = sign(&commit.signing_payload()?);
+    /// let commit = commit.signature(signature).send().await?;
+    /// assert!(commit.verification.verified);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn signing_payload(&self) -> Result<String> {
+        use std::fmt::Write;
+
+        fn identity(role: &str, person: &repos::CommitAuthor) -> Result<String> {
+            match (&person.email, &person.date) {
+                (Some(email), Some(date)) => Ok(format!(
+                    "{role} {} <{email}> {} +0000\n",
+                    person.name,
+                    date.timestamp()
+                )),
+                _ => Err(crate::Error::Other {
+                    source: format!("the {role} of a signed commit needs an email and a date")
+                        .into(),
+                    backtrace: snafu::Backtrace::capture(),
+                }),
+            }
+        }
+
+        let Some(author) = &self.author else {
+            return Err(crate::Error::Other {
+                source: "a signed commit needs an author".into(),
+                backtrace: snafu::Backtrace::capture(),
+            });
+        };
+        let committer = self.committer.as_ref().unwrap_or(author);
+
+        let mut payload = format!("tree {}\n", self.tree);
+        for parent in &self.parents {
+            writeln!(payload, "parent {parent}").expect("writing to a String never fails");
+        }
+        payload.push_str(&identity("author", author)?);
+        payload.push_str(&identity("committer", committer)?);
+        payload.push('\n');
+        payload.push_str(&self.message);
+        Ok(payload)
+    }
+
     /// Sends the request
     pub async fn send(&self) -> Result<GitCommitObject> {
         let route = format!("/{}/git/commits", self.repo);
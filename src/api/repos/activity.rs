@@ -0,0 +1,112 @@
+use super::*;
+use crate::models::repos::activity::{ActivityType, RepositoryActivity};
+use crate::params::repos::activity::TimePeriod;
+
+/// A builder pattern struct for listing the activity of a repository.
+///
+/// Created by [`RepoHandler::list_activity`].
+#[derive(serde::Serialize)]
+pub struct ListRepoActivityBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<params::Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<String>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    r#ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    actor: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_period: Option<TimePeriod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    activity_type: Option<ActivityType>,
+}
+
+impl<'octo, 'r> ListRepoActivityBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            direction: None,
+            per_page: None,
+            before: None,
+            after: None,
+            r#ref: None,
+            actor: None,
+            time_period: None,
+            activity_type: None,
+        }
+    }
+
+    /// The direction to sort the results by, newest first by default.
+    pub fn direction(mut self, direction: impl Into<params::Direction>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Only list the activity before this cursor.
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    /// Only list the activity after this cursor.
+    pub fn after(mut self, after: impl Into<String>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+
+    /// Only list the activity of this ref, e.g. `main` or `refs/heads/main`.
+    /// Without the `refs/heads/` or `refs/tags/` prefix, it matches both
+    /// branches and tags.
+    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
+        self.r#ref = Some(r#ref.into());
+        self
+    }
+
+    /// Only list the activity of the user with this login.
+    pub fn actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    /// Only list the activity of the last day, week, etc.
+    pub fn time_period(mut self, time_period: impl Into<TimePeriod>) -> Self {
+        self.time_period = Some(time_period.into());
+        self
+    }
+
+    /// Only list one kind of activity, e.g. force pushes.
+    pub fn activity_type(mut self, activity_type: impl Into<ActivityType>) -> Self {
+        self.activity_type = Some(activity_type.into());
+        self
+    }
+
+    /// Sends the actual request. The pages link to each other with cursors,
+    /// which [`Octocrab::get_page`] follows.
+    pub async fn send(self) -> crate::Result<crate::Page<RepositoryActivity>> {
+        let route = format!("/{}/activity", self.handler.repo);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<RepositoryActivity>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
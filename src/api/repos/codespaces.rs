@@ -0,0 +1,176 @@
+use super::RepoHandler;
+use crate::codespaces::ListCodespacesBuilder;
+use crate::models::codespaces::{Codespace, CodespaceMachine, Devcontainer};
+
+/// A client to the codespaces of a repository.
+///
+/// Created with [`RepoHandler::codespaces`].
+pub struct RepoCodespacesHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoCodespacesHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Lists the codespaces of the repository which belong to the
+    /// authenticated user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let codespaces = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .codespaces()
+    ///     .list()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListCodespacesBuilder<'octo> {
+        let route = format!("/{}/codespaces", self.handler.repo);
+        ListCodespacesBuilder::new(self.handler.crab, route)
+    }
+
+    /// Creates a codespace for the authenticated user in the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let codespace = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .codespaces()
+    ///     .create()
+    ///     .r#ref("main")
+    ///     .machine("standardLinux32gb")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self) -> CreateCodespaceBuilder<'octo, '_> {
+        CreateCodespaceBuilder::new(self)
+    }
+
+    /// Lists the machine types available for codespaces of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let machines = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .codespaces()
+    ///     .list_machines()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_machines(&self) -> crate::Result<crate::Page<CodespaceMachine>> {
+        let route = format!("/{}/codespaces/machines", self.handler.repo);
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Lists the devcontainer configurations of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let devcontainers = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .codespaces()
+    ///     .list_devcontainers()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_devcontainers(&self) -> crate::Result<crate::Page<Devcontainer>> {
+        let route = format!("/{}/codespaces/devcontainers", self.handler.repo);
+        self.handler.crab.get(route, None::<&()>).await
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct CreateCodespaceBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoCodespacesHandler<'octo>,
+    #[serde(rename = "ref", skip_serializing_if = "Option::is_none")]
+    r#ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    geo: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    machine: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    devcontainer_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    working_directory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    idle_timeout_minutes: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    display_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    retention_period_minutes: Option<u32>,
+}
+
+impl<'octo, 'r> CreateCodespaceBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoCodespacesHandler<'octo>) -> Self {
+        Self {
+            handler,
+            r#ref: None,
+            geo: None,
+            machine: None,
+            devcontainer_path: None,
+            working_directory: None,
+            idle_timeout_minutes: None,
+            display_name: None,
+            retention_period_minutes: None,
+        }
+    }
+
+    /// The git ref to check out, defaults to the default branch.
+    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
+        self.r#ref = Some(r#ref.into());
+        self
+    }
+
+    /// The geographic area to create the codespace in, e.g. `EuropeWest`.
+    pub fn geo(mut self, geo: impl Into<String>) -> Self {
+        self.geo = Some(geo.into());
+        self
+    }
+
+    /// The machine type to run the codespace on.
+    pub fn machine(mut self, machine: impl Into<String>) -> Self {
+        self.machine = Some(machine.into());
+        self
+    }
+
+    /// The path of the devcontainer configuration to use.
+    pub fn devcontainer_path(mut self, devcontainer_path: impl Into<String>) -> Self {
+        self.devcontainer_path = Some(devcontainer_path.into());
+        self
+    }
+
+    /// The directory to open in the codespace.
+    pub fn working_directory(mut self, working_directory: impl Into<String>) -> Self {
+        self.working_directory = Some(working_directory.into());
+        self
+    }
+
+    /// Minutes of inactivity after which the codespace is stopped.
+    pub fn idle_timeout_minutes(mut self, idle_timeout_minutes: u32) -> Self {
+        self.idle_timeout_minutes = Some(idle_timeout_minutes);
+        self
+    }
+
+    /// The display name of the codespace.
+    pub fn display_name(mut self, display_name: impl Into<String>) -> Self {
+        self.display_name = Some(display_name.into());
+        self
+    }
+
+    /// Minutes after stopping after which the codespace is deleted.
+    pub fn retention_period_minutes(mut self, retention_period_minutes: u32) -> Self {
+        self.retention_period_minutes = Some(retention_period_minutes);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Codespace> {
+        let route = format!("/{}/codespaces", self.handler.handler.repo);
+        self.handler.handler.crab.post(route, Some(&self)).await
+    }
+}
@@ -1,4 +1,7 @@
+use http::StatusCode;
+
 use super::RepoHandler;
+use crate::models::repos::AutomatedSecurityFixes;
 
 /// A client to GitHub's repository dependabot API.
 ///
@@ -177,4 +180,113 @@ impl<'octo> RepoDependabotAlertsHandler<'octo> {
         let route = format!("/{}/dependabot/alerts/{}", self.handler.repo, alert_number);
         self.handler.crab.patch(route, alert_update).await
     }
+
+    /// Checks whether Dependabot alerts are enabled for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let enabled = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .dependabot()
+    ///     .is_vulnerability_alerts_enabled()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_vulnerability_alerts_enabled(&self) -> crate::Result<bool> {
+        let route = format!("/{}/vulnerability-alerts", self.handler.repo);
+        let response = self.handler.crab._get(route).await?;
+
+        match response.status() {
+            StatusCode::NO_CONTENT => Ok(true),
+            StatusCode::NOT_FOUND => Ok(false),
+            _ => crate::map_github_error(response).await.map(|_| true),
+        }
+    }
+
+    /// Enables Dependabot alerts for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .dependabot()
+    ///     .enable_vulnerability_alerts()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable_vulnerability_alerts(&self) -> crate::Result<()> {
+        let route = format!("/{}/vulnerability-alerts", self.handler.repo);
+        let response = self.handler.crab._put(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Disables Dependabot alerts, and with them Dependabot security
+    /// updates, for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .dependabot()
+    ///     .disable_vulnerability_alerts()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable_vulnerability_alerts(&self) -> crate::Result<()> {
+        let route = format!("/{}/vulnerability-alerts", self.handler.repo);
+        let response = self.handler.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Gets whether Dependabot security updates are enabled for the
+    /// repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let fixes = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .dependabot()
+    ///     .get_automated_security_fixes()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_automated_security_fixes(&self) -> crate::Result<AutomatedSecurityFixes> {
+        let route = format!("/{}/automated-security-fixes", self.handler.repo);
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Enables Dependabot security updates for the repository, which needs
+    /// Dependabot alerts.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .dependabot()
+    ///     .enable_automated_security_fixes()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable_automated_security_fixes(&self) -> crate::Result<()> {
+        let route = format!("/{}/automated-security-fixes", self.handler.repo);
+        let response = self.handler.crab._put(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Disables Dependabot security updates for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .dependabot()
+    ///     .disable_automated_security_fixes()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable_automated_security_fixes(&self) -> crate::Result<()> {
+        let route = format!("/{}/automated-security-fixes", self.handler.repo);
+        let response = self.handler.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
 }
@@ -0,0 +1,115 @@
+use super::RepoHandler;
+use crate::models::repos::dependency_graph::{DependencyChange, Sbom, SbomResponse};
+use crate::route::Route;
+
+/// A client to GitHub's dependency graph API.
+///
+/// Created with [`RepoHandler::dependency_graph`].
+pub struct RepoDependencyGraphHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoDependencyGraphHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Exports the software bill of materials (SBOM) of the repository in
+    /// SPDX format.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let sbom = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .dependency_graph()
+    ///     .sbom()
+    ///     .await?;
+    ///
+    /// for package in sbom.packages {
+    ///     println!("{:?} {:?}", package.name, package.version_info);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sbom(&self) -> crate::Result<Sbom> {
+        let route = format!("/{}/dependency-graph/sbom", self.handler.repo);
+        let response: SbomResponse = self.handler.crab.get(route, None::<&()>).await?;
+        Ok(response.sbom)
+    }
+
+    /// Exports the SBOM of the repository as raw JSON, for fields of the SPDX
+    /// document which aren't covered by [`Sbom`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let sbom = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .dependency_graph()
+    ///     .sbom_raw()
+    ///     .await?;
+    ///
+    /// println!("{}", sbom["spdxVersion"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn sbom_raw(&self) -> crate::Result<serde_json::Value> {
+        let route = format!("/{}/dependency-graph/sbom", self.handler.repo);
+        let mut response: serde_json::Value = self.handler.crab.get(route, None::<&()>).await?;
+        Ok(response["sbom"].take())
+    }
+
+    /// Compares the dependencies between two commits, as used by dependency
+    /// review. `basehead` has the form `BASE...HEAD`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::dependency_graph::DependencyChangeType;
+    ///
+    /// let changes = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .dependency_graph()
+    ///     .compare("main...feature")
+    ///     .send()
+    ///     .await?;
+    ///
+    /// let vulnerable = changes.iter().any(|change| {
+    ///     change.change_type == DependencyChangeType::Added && !change.vulnerabilities.is_empty()
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn compare(&self, basehead: impl Into<String>) -> CompareDependenciesBuilder<'octo, '_> {
+        CompareDependenciesBuilder::new(self, basehead.into())
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct CompareDependenciesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoDependencyGraphHandler<'octo>,
+    #[serde(skip)]
+    basehead: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+}
+
+impl<'octo, 'r> CompareDependenciesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoDependencyGraphHandler<'octo>, basehead: String) -> Self {
+        Self {
+            handler,
+            basehead,
+            name: None,
+        }
+    }
+
+    /// Only compare the dependencies of the manifest at this path.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Vec<DependencyChange>> {
+        let route = Route::new(format_args!("/{}", self.handler.handler.repo))
+            .path("dependency-graph/compare")
+            .segments(&self.basehead);
+        self.handler.handler.crab.get(route, Some(&self)).await
+    }
+}
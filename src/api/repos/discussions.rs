@@ -0,0 +1,395 @@
+use snafu::ResultExt;
+
+use super::{RepoHandler, RepoRef};
+use crate::error::SerdeSnafu;
+use crate::internal::sync::OnceLock;
+use crate::models::discussions::{Discussion, DiscussionCategory, DiscussionComment, Discussions};
+
+const CATEGORY_FIELDS: &str = "id name slug emoji description isAnswerable";
+
+const DISCUSSION_FIELDS: &str = "id number title body url author { login }
+category { id name slug emoji description isAnswerable }
+isAnswered answerChosenAt closed locked createdAt updatedAt";
+
+const COMMENT_FIELDS: &str = "id body url author { login } isAnswer createdAt updatedAt";
+
+/// A client to the discussions of a repository, backed by GitHub's GraphQL
+/// API.
+///
+/// Creating a discussion needs the node id of the repository, which is
+/// looked up once per handler, so keep the handler around to create several.
+///
+/// Created with [`RepoHandler::discussions`].
+pub struct RepoDiscussionsHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+    // Looked up once, for the mutations which need it
+    repository_id: OnceLock<String>,
+}
+
+impl<'octo> RepoDiscussionsHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self {
+            handler: repo,
+            repository_id: OnceLock::new(),
+        }
+    }
+
+    /// Lists the discussion categories of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let categories = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .discussions()
+    ///     .list_categories()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn list_categories(&self) -> crate::Result<Vec<DiscussionCategory>> {
+        let selection =
+            format!("discussionCategories(first: 100) {{ nodes {{ {CATEGORY_FIELDS} }} }}");
+        let mut repository = self
+            .query_repository("", &selection, serde_json::json!({}))
+            .await?;
+        serde_json::from_value(repository["discussionCategories"]["nodes"].take())
+            .context(SerdeSnafu)
+    }
+
+    /// Gets a discussion by its number.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let discussion = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .discussions()
+    ///     .get(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, number: u64) -> crate::Result<Discussion> {
+        let selection = format!("discussion(number: $number) {{ {DISCUSSION_FIELDS} }}");
+        let mut repository = self
+            .query_repository(
+                ", $number: Int!",
+                &selection,
+                serde_json::json!({ "number": number }),
+            )
+            .await?;
+        serde_json::from_value(repository["discussion"].take()).context(SerdeSnafu)
+    }
+
+    /// Lists the discussions of the repository, most recently updated first.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let discussions = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .discussions()
+    ///     .list()
+    ///     .category_id("DIC_kwDOAAABcM4CAbcd")
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListDiscussionsBuilder<'octo, '_> {
+        ListDiscussionsBuilder::new(self)
+    }
+
+    /// Starts a discussion in the given category.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let discussion = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .discussions()
+    ///     .create("DIC_kwDOAAABcM4CAbcd", "Release 1.0", "What should go in?")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create(
+        &self,
+        category_id: impl AsRef<str>,
+        title: impl AsRef<str>,
+        body: impl AsRef<str>,
+    ) -> crate::Result<Discussion> {
+        let mutation = format!(
+            "mutation($input: CreateDiscussionInput!) {{
+  createDiscussion(input: $input) {{ discussion {{ {DISCUSSION_FIELDS} }} }}
+}}"
+        );
+        let mut data = self
+            .handler
+            .crab
+            .graphql_mutation(
+                &mutation,
+                &serde_json::json!({
+                    "repositoryId": self.repository_id().await?,
+                    "categoryId": category_id.as_ref(),
+                    "title": title.as_ref(),
+                    "body": body.as_ref(),
+                }),
+            )
+            .await?;
+        serde_json::from_value(data["createDiscussion"]["discussion"].take()).context(SerdeSnafu)
+    }
+
+    /// Comments on a discussion, identified by its node id.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let comment = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .discussions()
+    ///     .add_comment("D_kwDOAAABcM4AAbcd", "Thanks for the report!")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_comment(
+        &self,
+        discussion_id: impl AsRef<str>,
+        body: impl AsRef<str>,
+    ) -> crate::Result<DiscussionComment> {
+        self.comment(discussion_id.as_ref(), None, body.as_ref())
+            .await
+    }
+
+    /// Replies to a top-level comment of a discussion.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let reply = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .discussions()
+    ///     .reply_to_comment("D_kwDOAAABcM4AAbcd", "DC_kwDOAAABcM4AAbcd", "Fixed in #43.")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn reply_to_comment(
+        &self,
+        discussion_id: impl AsRef<str>,
+        comment_id: impl AsRef<str>,
+        body: impl AsRef<str>,
+    ) -> crate::Result<DiscussionComment> {
+        self.comment(
+            discussion_id.as_ref(),
+            Some(comment_id.as_ref()),
+            body.as_ref(),
+        )
+        .await
+    }
+
+    async fn comment(
+        &self,
+        discussion_id: &str,
+        reply_to_id: Option<&str>,
+        body: &str,
+    ) -> crate::Result<DiscussionComment> {
+        let mutation = format!(
+            "mutation($input: AddDiscussionCommentInput!) {{
+  addDiscussionComment(input: $input) {{ comment {{ {COMMENT_FIELDS} }} }}
+}}"
+        );
+        let mut data = self
+            .handler
+            .crab
+            .graphql_mutation(
+                &mutation,
+                &serde_json::json!({
+                    "discussionId": discussion_id,
+                    "replyToId": reply_to_id,
+                    "body": body,
+                }),
+            )
+            .await?;
+        serde_json::from_value(data["addDiscussionComment"]["comment"].take()).context(SerdeSnafu)
+    }
+
+    /// Marks a comment as the answer of its discussion, which must be in an
+    /// answerable category.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .discussions()
+    ///     .mark_answer("DC_kwDOAAABcM4AAbcd")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn mark_answer(&self, comment_id: impl AsRef<str>) -> crate::Result<()> {
+        self.handler
+            .crab
+            .graphql_mutation(
+                "mutation($input: MarkDiscussionCommentAsAnswerInput!) {
+  markDiscussionCommentAsAnswer(input: $input) { discussion { id } }
+}",
+                &serde_json::json!({ "id": comment_id.as_ref() }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Unmarks a comment as the answer of its discussion.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .discussions()
+    ///     .unmark_answer("DC_kwDOAAABcM4AAbcd")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn unmark_answer(&self, comment_id: impl AsRef<str>) -> crate::Result<()> {
+        self.handler
+            .crab
+            .graphql_mutation(
+                "mutation($input: UnmarkDiscussionCommentAsAnswerInput!) {
+  unmarkDiscussionCommentAsAnswer(input: $input) { discussion { id } }
+}",
+                &serde_json::json!({ "id": comment_id.as_ref() }),
+            )
+            .await
+            .map(drop)
+    }
+
+    /// Runs a query for `selection` on the repository, with the variables
+    /// declared in `declarations`, and returns the selected fields.
+    ///
+    /// Repositories referred to by owner and name are looked up within the
+    /// query. The GraphQL API can't look up repositories by their REST id,
+    /// so for those the node id is looked up first.
+    async fn query_repository(
+        &self,
+        declarations: &str,
+        selection: &str,
+        mut variables: serde_json::Value,
+    ) -> crate::Result<serde_json::Value> {
+        let (query, field) = match &self.handler.repo {
+            RepoRef::ByOwnerAndName(owner, name) => {
+                variables["owner"] = owner.as_str().into();
+                variables["name"] = name.as_str().into();
+                let query = format!(
+                    "query($owner: String!, $name: String!{declarations}) {{
+  repository(owner: $owner, name: $name) {{ {selection} }}
+}}"
+                );
+                (query, "repository")
+            }
+            RepoRef::ById(_) => {
+                variables["repositoryId"] = self.repository_id().await?.into();
+                let query = format!(
+                    "query($repositoryId: ID!{declarations}) {{
+  node(id: $repositoryId) {{ ... on Repository {{ {selection} }} }}
+}}"
+                );
+                (query, "node")
+            }
+        };
+        let mut data = self.handler.crab.graphql_query(&query, &variables).await?;
+        Ok(data[field].take())
+    }
+
+    /// The GraphQL node id of the repository, looked up on first use.
+    async fn repository_id(&self) -> crate::Result<String> {
+        #[derive(serde::Deserialize)]
+        struct Repository {
+            node_id: String,
+        }
+
+        if let Some(id) = self.repository_id.get() {
+            return Ok(id.clone());
+        }
+        let id = match &self.handler.repo {
+            RepoRef::ByOwnerAndName(owner, name) => {
+                let data = self
+                    .handler
+                    .crab
+                    .graphql_query(
+                        "query($owner: String!, $name: String!) {
+  repository(owner: $owner, name: $name) { id }
+}",
+                        &serde_json::json!({ "owner": owner, "name": name }),
+                    )
+                    .await?;
+                data["repository"]["id"]
+                    .as_str()
+                    .map(String::from)
+                    .ok_or_else(|| crate::Error::Other {
+                        source: format!("repository {owner}/{name} has no id").into(),
+                        backtrace: snafu::Backtrace::capture(),
+                    })?
+            }
+            RepoRef::ById(_) => {
+                let route = format!("/{}", self.handler.repo);
+                let repository: Repository = self.handler.crab.get(route, None::<&()>).await?;
+                repository.node_id
+            }
+        };
+        Ok(self.repository_id.get_or_init(|| id).clone())
+    }
+}
+
+/// A builder pattern struct for listing the discussions of a repository.
+///
+/// Created with [`RepoDiscussionsHandler::list`].
+pub struct ListDiscussionsBuilder<'octo, 'r> {
+    handler: &'r RepoDiscussionsHandler<'octo>,
+    category_id: Option<String>,
+    per_page: u8,
+    after: Option<String>,
+}
+
+impl<'octo, 'r> ListDiscussionsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoDiscussionsHandler<'octo>) -> Self {
+        Self {
+            handler,
+            category_id: None,
+            per_page: 30,
+            after: None,
+        }
+    }
+
+    /// Only list discussions in the category with this node id.
+    pub fn category_id(mut self, category_id: impl Into<String>) -> Self {
+        self.category_id = Some(category_id.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = per_page.into();
+        self
+    }
+
+    /// The cursor after which to start, from a previous page's `end_cursor`.
+    pub fn after(mut self, after: impl Into<String>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Discussions> {
+        let selection = format!(
+            "discussions(categoryId: $categoryId, first: $first, after: $after, orderBy: {{ field: UPDATED_AT, direction: DESC }}) {{
+    totalCount
+    pageInfo {{ hasNextPage endCursor }}
+    nodes {{ {DISCUSSION_FIELDS} }}
+  }}"
+        );
+        let mut repository = self
+            .handler
+            .query_repository(
+                ", $categoryId: ID, $first: Int!, $after: String",
+                &selection,
+                serde_json::json!({
+                    "categoryId": self.category_id,
+                    "first": self.per_page,
+                    "after": self.after,
+                }),
+            )
+            .await?;
+        serde_json::from_value(repository["discussions"].take()).context(SerdeSnafu)
+    }
+}
@@ -1,6 +1,6 @@
 //! GitHub Repository Events
 use crate::{
-    etag::{EntityTag, Etagged},
+    etag::{EntityTag, Etagged, Polled},
     models::events,
     repos::RepoHandler,
     FromResponse, Page,
@@ -89,4 +89,49 @@ impl<'octo, 'handler> ListRepoEventsBuilder<'octo, 'handler> {
                 })
         }
     }
+
+    /// Sends the request as a conditional poll, returning the `ETag` and
+    /// `X-Poll-Interval` headers alongside the page. The page is `None` when
+    /// nothing changed since [`etag`](Self::etag).
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let mut etag = None;
+    /// loop {
+    ///     let polled = octocrab
+    ///         .repos("owner", "repo")
+    ///         .events()
+    ///         .etag(etag.take())
+    ///         .poll()
+    ///         .await?;
+    ///     if let Some(page) = polled.value {
+    ///         // handle the new events ...
+    ///     }
+    ///     etag = polled.etag;
+    ///     // wait `polled.poll_interval` before polling again
+    /// }
+    /// # }
+    /// ```
+    pub async fn poll(self) -> crate::Result<Polled<Page<events::Event>>> {
+        let route = format!("/{}/events", self.handler.repo);
+        let uri = self
+            .handler
+            .crab
+            .parameterized_uri(route, Some(&self.params))?;
+        crate::events::poll_events(self.handler.crab, uri, self.headers.etag).await
+    }
+
+    /// Creates a [`Poller`](crate::poller::Poller) which streams the new
+    /// events of the repository as they arrive.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub fn poller(self) -> crate::Result<crate::poller::Poller<events::Event>> {
+        let route = format!("/{}/events", self.handler.repo);
+        let uri = self
+            .handler
+            .crab
+            .parameterized_uri(route, Some(&self.params))?;
+        Ok(crate::poller::Poller::from_uri(self.handler.crab, uri))
+    }
 }
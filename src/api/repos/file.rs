@@ -34,10 +34,49 @@ impl<'octo, 'r> GetContentBuilder<'octo, 'r> {
 
     /// Sends the actual request.
     pub async fn send(self) -> Result<models::repos::ContentItems> {
-        let path = self.path.clone().unwrap_or(String::from(""));
-        let route = format!("/{}/contents/{path}", self.handler.repo, path = path,);
+        let route = Route::new(format_args!("/{}", self.handler.repo))
+            .path("contents")
+            .segments(self.path.as_deref().unwrap_or_default());
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request asking for the raw contents of the file, instead of
+    /// base64 encoded JSON.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let bytes = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_content()
+    ///     .path("Cargo.toml")
+    ///     .raw()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn raw(self) -> Result<Bytes> {
+        self.send_with_media_type(params::repos::ContentMediaType::Raw)
+            .await
+    }
+
+    /// Sends the request asking for the file rendered as HTML.
+    pub async fn html(self) -> Result<String> {
+        let bytes = self
+            .send_with_media_type(params::repos::ContentMediaType::Html)
+            .await?;
+        String::from_utf8(Vec::from(bytes)).context(crate::error::InvalidUtf8Snafu)
+    }
+
+    /// Sends the request with the given media type in the `Accept` header,
+    /// returning the body of the response as is.
+    pub async fn send_with_media_type(
+        self,
+        media_type: params::repos::ContentMediaType,
+    ) -> Result<Bytes> {
+        let route = Route::new(format_args!("/{}", self.handler.repo))
+            .path("contents")
+            .segments(self.path.as_deref().unwrap_or_default());
+        get_with_media_type(self.handler.crab, route.into(), &self, media_type).await
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -75,10 +114,69 @@ impl<'octo, 'r> GetReadmeBuilder<'octo, 'r> {
 
     /// Sends the actual request.
     pub async fn send(self) -> Result<models::repos::Content> {
-        let path = self.path.clone().unwrap_or(String::from(""));
-        let route = format!("/{}/readme/{path}", self.handler.repo, path = path,);
+        let route = Route::new(format_args!("/{}", self.handler.repo))
+            .path("readme")
+            .segments(self.path.as_deref().unwrap_or_default());
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request asking for the raw contents of the readme, instead
+    /// of base64 encoded JSON.
+    pub async fn raw(self) -> Result<Bytes> {
+        self.send_with_media_type(params::repos::ContentMediaType::Raw)
+            .await
+    }
+
+    /// Sends the request asking for the readme rendered as HTML.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let html = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .get_readme()
+    ///     .r#ref("v1.0.0")
+    ///     .html()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn html(self) -> Result<String> {
+        let bytes = self
+            .send_with_media_type(params::repos::ContentMediaType::Html)
+            .await?;
+        String::from_utf8(Vec::from(bytes)).context(crate::error::InvalidUtf8Snafu)
+    }
+
+    /// Sends the request with the given media type in the `Accept` header,
+    /// returning the body of the response as is.
+    pub async fn send_with_media_type(
+        self,
+        media_type: params::repos::ContentMediaType,
+    ) -> Result<Bytes> {
+        let route = Route::new(format_args!("/{}", self.handler.repo))
+            .path("readme")
+            .segments(self.path.as_deref().unwrap_or_default());
+        get_with_media_type(self.handler.crab, route.into(), &self, media_type).await
+    }
+}
+
+async fn get_with_media_type(
+    crab: &Octocrab,
+    route: String,
+    parameters: &impl serde::Serialize,
+    media_type: params::repos::ContentMediaType,
+) -> Result<Bytes> {
+    use http_body_util::BodyExt;
+
+    let uri = crab.parameterized_uri(route, Some(parameters))?;
+    let request = Builder::new()
+        .uri(uri)
+        .method(http::Method::GET)
+        .header(ACCEPT, crate::format_media_type(media_type.to_string()));
+    let response = crab
+        .execute(crab.build_request(request, None::<&()>)?)
+        .await?;
+    let response = crate::map_github_error(response).await?;
+    Ok(response.into_body().collect().await?.to_bytes())
 }
 
 #[derive(serde::Serialize)]
@@ -139,7 +237,9 @@ impl<'octo, 'r> UpdateFileBuilder<'octo, 'r> {
 
     /// Sends the actual request.
     pub async fn send(self) -> Result<models::repos::FileUpdate> {
-        let route = format!("/{}/contents/{path}", self.handler.repo, path = self.path,);
+        let route = Route::new(format_args!("/{}", self.handler.repo))
+            .path("contents")
+            .segments(&self.path);
         self.handler.crab.put(route, Some(&self)).await
     }
 }
@@ -198,7 +298,9 @@ impl<'octo, 'r> DeleteFileBuilder<'octo, 'r> {
 
     /// Sends the actual request.
     pub async fn send(self) -> Result<models::repos::FileDeletion> {
-        let route = format!("/{}/contents/{path}", self.handler.repo, path = self.path,);
+        let route = Route::new(format_args!("/{}", self.handler.repo))
+            .path("contents")
+            .segments(&self.path);
         self.handler.crab.delete(route, Some(&self)).await
     }
 }
@@ -0,0 +1,331 @@
+use super::RepoHandler;
+use crate::models::repos::pages::{
+    PagesBuild, PagesBuildStatus, PagesBuildType, PagesHealthCheck, PagesSite, PagesSource,
+};
+
+/// A client to GitHub's Pages API.
+///
+/// Created with [`RepoHandler::pages`].
+pub struct RepoPagesHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoPagesHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Gets information about the GitHub Pages site of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let site = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .get()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self) -> crate::Result<PagesSite> {
+        let route = format!("/{}/pages", self.handler.repo);
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Enables GitHub Pages for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::repos::pages::PagesSource;
+    ///
+    /// let site = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .create()
+    ///     .source(PagesSource::new("main", Some("/docs".to_string())))
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(&self) -> CreatePagesBuilder<'octo, '_> {
+        CreatePagesBuilder::new(self)
+    }
+
+    /// Updates the configuration of the GitHub Pages site.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .update()
+    ///     .cname("docs.example.com")
+    ///     .https_enforced(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(&self) -> UpdatePagesBuilder<'octo, '_> {
+        UpdatePagesBuilder::new(self)
+    }
+
+    /// Disables GitHub Pages for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .delete()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete(&self) -> crate::Result<()> {
+        let route = format!("/{}/pages", self.handler.repo);
+        crate::map_github_error(self.handler.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Lists the builds of the GitHub Pages site.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let builds = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .list_builds()
+    ///     .per_page(10)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_builds(&self) -> ListPagesBuildsBuilder<'octo, '_> {
+        ListPagesBuildsBuilder::new(self)
+    }
+
+    /// Requests a build of the GitHub Pages site from the latest revision on
+    /// the default branch.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let status = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .request_build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request_build(&self) -> crate::Result<PagesBuildStatus> {
+        let route = format!("/{}/pages/builds", self.handler.repo);
+        self.handler.crab.post(route, None::<&()>).await
+    }
+
+    /// Gets the latest build of the GitHub Pages site.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let build = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .get_latest_build()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_latest_build(&self) -> crate::Result<PagesBuild> {
+        let route = format!("/{}/pages/builds/latest", self.handler.repo);
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets a single build of the GitHub Pages site.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let build = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .get_build(5432)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_build(&self, build_id: u64) -> crate::Result<PagesBuild> {
+        let route = format!("/{}/pages/builds/{build_id}", self.handler.repo);
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets a DNS health check for the CNAME record configured for the
+    /// repository's GitHub Pages site.
+    ///
+    /// GitHub answers with `202 Accepted` while the check is still running, in
+    /// which case this returns `Ok(None)` and the request should be retried
+    /// later.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let health = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .pages()
+    ///     .health_check()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn health_check(&self) -> crate::Result<Option<PagesHealthCheck>> {
+        use crate::FromResponse;
+
+        let route = format!("/{}/pages/health", self.handler.repo);
+        let response = crate::map_github_error(self.handler.crab._get(route).await?).await?;
+        if response.status() == http::StatusCode::ACCEPTED {
+            return Ok(None);
+        }
+        PagesHealthCheck::from_response(response).await.map(Some)
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct CreatePagesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoPagesHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build_type: Option<PagesBuildType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<PagesSource>,
+}
+
+impl<'octo, 'r> CreatePagesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoPagesHandler<'octo>) -> Self {
+        Self {
+            handler,
+            build_type: None,
+            source: None,
+        }
+    }
+
+    /// The process in which the Page will be built.
+    pub fn build_type(mut self, build_type: impl Into<PagesBuildType>) -> Self {
+        self.build_type = Some(build_type.into());
+        self
+    }
+
+    /// The source branch and directory used to publish your Pages site.
+    pub fn source(mut self, source: impl Into<PagesSource>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<PagesSite> {
+        let route = format!("/{}/pages", self.handler.handler.repo);
+        self.handler.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct UpdatePagesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoPagesHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cname: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    https_enforced: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build_type: Option<PagesBuildType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    source: Option<PagesSource>,
+}
+
+impl<'octo, 'r> UpdatePagesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoPagesHandler<'octo>) -> Self {
+        Self {
+            handler,
+            cname: None,
+            https_enforced: None,
+            build_type: None,
+            source: None,
+        }
+    }
+
+    /// Specify a custom domain for the repository.
+    pub fn cname(mut self, cname: impl Into<String>) -> Self {
+        self.cname = Some(Some(cname.into()));
+        self
+    }
+
+    /// Removes the custom domain of the repository.
+    pub fn remove_cname(mut self) -> Self {
+        self.cname = Some(None);
+        self
+    }
+
+    /// Whether HTTPS should be enforced for the repository.
+    pub fn https_enforced(mut self, https_enforced: impl Into<bool>) -> Self {
+        self.https_enforced = Some(https_enforced.into());
+        self
+    }
+
+    /// The process by which the GitHub Pages site will be built.
+    pub fn build_type(mut self, build_type: impl Into<PagesBuildType>) -> Self {
+        self.build_type = Some(build_type.into());
+        self
+    }
+
+    /// The source branch and directory used to publish your Pages site.
+    pub fn source(mut self, source: impl Into<PagesSource>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<()> {
+        let route = format!("/{}/pages", self.handler.handler.repo);
+        let response = self.handler.handler.crab._put(route, Some(&self)).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct ListPagesBuildsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoPagesHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListPagesBuildsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoPagesHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<PagesBuild>> {
+        let route = format!("/{}/pages/builds", self.handler.handler.repo);
+        self.handler.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<PagesBuild>>> {
+        let crab = self.handler.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
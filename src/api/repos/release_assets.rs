@@ -62,8 +62,9 @@ impl<'octo, 'r> ReleaseAssetsHandler<'octo, 'r> {
     pub async fn delete(&self, id: u64) -> Result<()> {
         let route = format!("/{}/releases/assets/{id}", self.handler.repo, id = id,);
 
-        self.handler.crab._delete(route, None::<&()>).await?;
-        Ok(())
+        crate::map_github_error(self.handler.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
     }
 
     /// Streams the binary contents of an asset.
@@ -90,8 +91,68 @@ impl<'octo, 'r> ReleaseAssetsHandler<'octo, 'r> {
         id: u64,
     ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<bytes::Bytes>>> {
         use futures_util::TryStreamExt;
-        //use snafu::GenerateImplicitData;
 
+        let response = self.download_response(id).await?;
+        Ok(http_body_util::BodyStream::new(response.into_body())
+            .try_filter_map(|frame| futures_util::future::ok(frame.into_data().ok())))
+    }
+
+    /// Streams the binary contents of an asset, calling `on_progress` with the
+    /// number of bytes received so far and the total size of the asset (if
+    /// known) after every chunk.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use futures_util::StreamExt;
+    ///
+    /// let mut stream = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .release_assets()
+    ///     .stream_with_progress(42u64, |received, total| {
+    ///         if let Some(total) = total {
+    ///             println!("{received}/{total} bytes");
+    ///         }
+    ///     })
+    ///     .await?;
+    ///
+    /// while let Some(chunk) = stream.next().await {
+    ///     println!("{:?}", chunk);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn stream_with_progress<F>(
+        &self,
+        id: u64,
+        mut on_progress: F,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<bytes::Bytes>>>
+    where
+        F: FnMut(u64, Option<u64>) + Send + 'static,
+    {
+        use futures_util::TryStreamExt;
+
+        let response = self.download_response(id).await?;
+        let total = response
+            .headers()
+            .get(http::header::CONTENT_LENGTH)
+            .and_then(|length| length.to_str().ok())
+            .and_then(|length| length.parse::<u64>().ok());
+        let mut received = 0u64;
+        Ok(http_body_util::BodyStream::new(response.into_body())
+            .try_filter_map(|frame| futures_util::future::ok(frame.into_data().ok()))
+            .inspect_ok(move |chunk| {
+                received += chunk.len() as u64;
+                on_progress(received, total);
+            }))
+    }
+
+    /// Requests the binary contents of an asset, following the redirect to
+    /// the storage backend GitHub serves assets from.
+    async fn download_response(
+        &self,
+        id: u64,
+    ) -> crate::Result<http::Response<BoxBody<Bytes, crate::Error>>> {
         let route = format!("/{}/releases/assets/{id}", self.handler.repo, id = id,);
 
         let uri = Uri::builder()
@@ -105,8 +166,25 @@ impl<'octo, 'r> ReleaseAssetsHandler<'octo, 'r> {
         let request = self.handler.crab.build_request(builder, None::<&()>)?;
         let response = self.handler.crab.execute(request).await?;
         let response = self.handler.crab.follow_location_to_data(response).await?;
-        Ok(http_body_util::BodyStream::new(response.into_body())
-            .try_filter_map(|frame| futures_util::future::ok(frame.into_data().ok())))
+        crate::map_github_error(response).await
+    }
+
+    /// Downloads the binary contents of an asset into memory.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let bytes = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .release_assets()
+    ///     .download(42u64)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn download(&self, id: u64) -> crate::Result<Bytes> {
+        use http_body_util::BodyExt;
+
+        let response = self.download_response(id).await?;
+        Ok(response.into_body().collect().await?.to_bytes())
     }
 }
 
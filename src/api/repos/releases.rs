@@ -1,4 +1,5 @@
 use super::*;
+use crate::body::OctoBody;
 use crate::error::{UriParseError, UriParseSnafu};
 use crate::from_response::FromResponse;
 use crate::models::repos::Asset;
@@ -132,7 +133,9 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
     /// # }
     /// ```
     pub async fn get_by_tag(&self, tag: &str) -> crate::Result<models::repos::Release> {
-        let route = format!("/{}/releases/tags/{tag}", self.handler.repo, tag = tag,);
+        let route = Route::new(format_args!("/{}", self.handler.repo))
+            .path("releases/tags")
+            .segments(tag);
 
         self.handler.crab.get(route, None::<&()>).await
     }
@@ -196,7 +199,80 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
         asset_name: &'asset_name (impl AsRef<str> + ?Sized),
         body: Bytes,
     ) -> UploadAssetBuilder<'_, '_, '_, 'asset_name, '_> {
-        UploadAssetBuilder::new(self, id, asset_name.as_ref(), body)
+        let content_length = body.len() as u64;
+        UploadAssetBuilder::new(self, id, asset_name.as_ref(), body.into(), content_length)
+    }
+
+    /// Uploads several assets to a release in parallel, with at most
+    /// [`UploadAssetsBuilder::concurrency`] uploads in flight at once.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::repos::releases::AssetUpload;
+    ///
+    /// let results = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .upload_assets(1, vec![
+    ///         AssetUpload::new("app-linux.tar.gz", vec![0u8; 16]),
+    ///         AssetUpload::new("app-macos.zip", vec![0u8; 16]).content_type("application/zip"),
+    ///     ])
+    ///     .concurrency(2)
+    ///     .send()
+    ///     .await?;
+    ///
+    /// for result in results {
+    ///     if let Err(error) = result {
+    ///         eprintln!("upload failed: {error}");
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload_assets(
+        &self,
+        id: u64,
+        assets: impl IntoIterator<Item = AssetUpload>,
+    ) -> UploadAssetsBuilder<'_, '_, '_> {
+        UploadAssetsBuilder::new(self, id, assets.into_iter().collect())
+    }
+
+    /// Upload an [`crate::models::repos::Asset`] from a streaming body of a
+    /// known length, without having to buffer the whole file in memory first.
+    ///
+    /// Note that streamed uploads can't be replayed, so they are never
+    /// retried.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let body = http_body_util::Empty::<bytes::Bytes>::new();
+    /// # let length = 0;
+    /// let asset = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .upload_asset_stream(1, "my_asset.tar.gz", body, length)
+    ///     .content_type("application/gzip")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn upload_asset_stream<'asset_name, B>(
+        &self,
+        id: u64,
+        asset_name: &'asset_name (impl AsRef<str> + ?Sized),
+        body: B,
+        content_length: u64,
+    ) -> UploadAssetBuilder<'_, '_, '_, 'asset_name, '_>
+    where
+        B: http_body::Body<Data = Bytes> + Send + Sync + 'static,
+        B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        UploadAssetBuilder::new(
+            self,
+            id,
+            asset_name.as_ref(),
+            OctoBody::new(body),
+            content_length,
+        )
     }
 
     /// Creates a new [`ListReleaseAssetsBuilder`] that can be configured to filter
@@ -261,8 +337,63 @@ impl<'octo, 'r> ReleasesHandler<'octo, 'r> {
     pub async fn delete(&self, id: u64) -> Result<()> {
         let route = format!("/{}/releases/{id}", self.handler.repo, id = id,);
 
-        self.handler.crab._delete(route, None::<&()>).await?;
-        Ok(())
+        crate::map_github_error(self.handler.crab._delete(route, None::<&()>).await?)
+            .await
+            .map(drop)
+    }
+
+    /// Creates a [`ReactionsHandler`] to list, add and remove reactions on a
+    /// release.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::reactions::ReactionContent;
+    ///
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .reactions(1)
+    ///     .create(ReactionContent::Hooray)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ReactionsHandler`]: crate::reactions::ReactionsHandler
+    pub fn reactions(&self, id: u64) -> crate::reactions::ReactionsHandler<'octo> {
+        crate::reactions::ReactionsHandler::new(
+            self.handler.crab,
+            format!("/{}/releases/{id}", self.handler.repo),
+        )
+    }
+
+    /// Publishes a draft release, making it visible to everyone.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let release = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .publish(3)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn publish(&self, id: u64) -> Result<models::repos::Release> {
+        self.update(id).draft(false).send().await
+    }
+
+    /// Delete a release asset using its id.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .delete_asset(42)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn delete_asset(&self, asset_id: u64) -> Result<()> {
+        self.handler.release_assets().delete(asset_id).await
     }
 }
 
@@ -305,6 +436,18 @@ impl<'octo, 'r1, 'r2> ListReleasesBuilder<'octo, 'r1, 'r2> {
         let route = format!("/{}/releases", self.handler.handler.repo);
         self.handler.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<crate::models::repos::Release>>>
+    {
+        let crab = self.handler.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// A builder pattern struct for creating releases.
@@ -429,6 +572,7 @@ pub struct UpdateReleaseBuilder<'octo, 'repos, 'handler, 'tag_name, 'target_comm
 {
     #[serde(skip)]
     handler: &'handler ReleasesHandler<'octo, 'repos>,
+    #[serde(skip)]
     release_id: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
     tag_name: Option<&'tag_name str>,
@@ -666,6 +810,18 @@ impl<'octo, 'r1, 'r2> ListReleaseAssetsBuilder<'octo, 'r1, 'r2> {
         );
         self.handler.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<crate::models::repos::Asset>>>
+    {
+        let crab = self.handler.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// A builder pattern struct for updating release assets.
@@ -675,7 +831,9 @@ pub struct UploadAssetBuilder<'octo, 'repos, 'handler, 'name, 'label> {
     handler: &'handler ReleasesHandler<'octo, 'repos>,
     release_id: u64,
     name: &'name str,
-    body: Bytes,
+    body: OctoBody,
+    content_length: u64,
+    content_type: Option<String>,
     label: Option<&'label str>,
 }
 
@@ -686,13 +844,16 @@ impl<'octo, 'repos, 'handler, 'name, 'label>
         handler: &'handler ReleasesHandler<'octo, 'repos>,
         release_id: u64,
         name: &'name str,
-        body: Bytes,
+        body: OctoBody,
+        content_length: u64,
     ) -> Self {
         Self {
             handler,
             release_id,
             name,
             body,
+            content_length,
+            content_type: None,
             label: None,
         }
     }
@@ -703,33 +864,165 @@ impl<'octo, 'repos, 'handler, 'name, 'label>
         self
     }
 
+    /// The media type of the asset, e.g. `application/zip`.
+    /// Default: `application/octet-stream`
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
     /// Sends the actual request.
     pub async fn send(self) -> crate::Result<Asset> {
         // the url could be constructed without fetching the release, but if the user has no access to the release
         // then he will not have access to upload to it.
         let release = self.handler.get(self.release_id).await?;
 
-        let mut base_uri = format!(
-            "{}?name={}",
-            release.upload_url.replace("{?name,label}", ""),
-            self.name
-        );
-        if let Some(label) = self.label {
-            base_uri = format!("{base_uri}&label={label}");
+        upload_asset_to(
+            self.handler.handler.crab,
+            &release.upload_url,
+            self.name,
+            self.label,
+            self.content_type.as_deref(),
+            self.body,
+            self.content_length,
+        )
+        .await
+    }
+}
+
+/// A builder pattern struct for uploading several release assets at once.
+///
+/// created by [`ReleasesHandler::upload_assets`].
+pub struct UploadAssetsBuilder<'octo, 'repos, 'handler> {
+    handler: &'handler ReleasesHandler<'octo, 'repos>,
+    release_id: u64,
+    assets: Vec<AssetUpload>,
+    concurrency: usize,
+}
+
+impl<'octo, 'repos, 'handler> UploadAssetsBuilder<'octo, 'repos, 'handler> {
+    pub(crate) fn new(
+        handler: &'handler ReleasesHandler<'octo, 'repos>,
+        release_id: u64,
+        assets: Vec<AssetUpload>,
+    ) -> Self {
+        Self {
+            handler,
+            release_id,
+            assets,
+            concurrency: 4,
         }
+    }
+
+    /// The maximum number of assets uploaded at the same time.
+    /// Default: 4
+    pub fn concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Sends the actual requests.
+    ///
+    /// The release itself has to be accessible, otherwise the whole upload
+    /// fails. Once it is, every asset is uploaded independently and the
+    /// returned results are in the same order as the assets were given, so a
+    /// failed upload doesn't abort the remaining ones.
+    pub async fn send(self) -> crate::Result<Vec<crate::Result<Asset>>> {
+        use futures::stream::StreamExt;
+
+        let release = self.handler.get(self.release_id).await?;
+        let crab = self.handler.handler.crab;
+        let upload_url = release.upload_url.as_str();
 
-        let url: Uri = base_uri
-            .try_into()
-            .map_err(|_| UriParseError {})
-            .context(UriParseSnafu)?;
-        let request = Builder::new()
-            .method(http::Method::POST)
-            .uri(url)
-            .header(http::header::CONTENT_TYPE, "application/octet-stream")
-            .header(http::header::CONTENT_LENGTH, self.body.len())
-            .body(self.body)
-            .context(HttpSnafu)?;
-        let response = self.handler.handler.crab.execute(request).await?;
-        Asset::from_response(crate::map_github_error(response).await?).await
+        Ok(futures::stream::iter(self.assets)
+            .map(|asset| async move {
+                let content_length = asset.body.len() as u64;
+                upload_asset_to(
+                    crab,
+                    upload_url,
+                    &asset.name,
+                    asset.label.as_deref(),
+                    asset.content_type.as_deref(),
+                    asset.body.into(),
+                    content_length,
+                )
+                .await
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await)
     }
 }
+
+/// An asset to upload with [`ReleasesHandler::upload_assets`].
+#[derive(Debug, Clone)]
+pub struct AssetUpload {
+    name: String,
+    label: Option<String>,
+    content_type: Option<String>,
+    body: Bytes,
+}
+
+impl AssetUpload {
+    /// Creates a new asset named `name` with the given contents.
+    pub fn new(name: impl Into<String>, body: impl Into<Bytes>) -> Self {
+        Self {
+            name: name.into(),
+            label: None,
+            content_type: None,
+            body: body.into(),
+        }
+    }
+
+    /// The asset label
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// The media type of the asset, e.g. `application/zip`.
+    /// Default: `application/octet-stream`
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+async fn upload_asset_to(
+    crab: &Octocrab,
+    upload_url: &str,
+    name: &str,
+    label: Option<&str>,
+    content_type: Option<&str>,
+    body: OctoBody,
+    content_length: u64,
+) -> crate::Result<Asset> {
+    #[derive(serde::Serialize)]
+    struct UploadParams<'name, 'label> {
+        name: &'name str,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        label: Option<&'label str>,
+    }
+
+    let query = serde_urlencoded::to_string(UploadParams { name, label })
+        .context(crate::error::SerdeUrlEncodedSnafu)?;
+    let upload_url = crab.upload_route(&upload_url.replace("{?name,label}", ""));
+    let base_uri = format!("{upload_url}?{query}");
+
+    let url: Uri = base_uri
+        .try_into()
+        .map_err(|_| UriParseError {})
+        .context(UriParseSnafu)?;
+    let request = Builder::new()
+        .method(http::Method::POST)
+        .uri(url)
+        .header(
+            http::header::CONTENT_TYPE,
+            content_type.unwrap_or("application/octet-stream"),
+        )
+        .header(http::header::CONTENT_LENGTH, content_length)
+        .body(body)
+        .context(HttpSnafu)?;
+    let response = crab.execute(request).await?;
+    Asset::from_response(crate::map_github_error(response).await?).await
+}
@@ -1,4 +1,6 @@
-use super::RepoHandler;
+use super::{ListBypassRequestsBuilder, RepoHandler};
+use crate::models::repos::secret_scanning_bypass::{BypassRequest, PushProtectionBypass};
+use crate::params::secret_scanning::{BypassReason, BypassReview};
 
 /// A client to GitHub's repository Secret Scanning API.
 ///
@@ -232,4 +234,98 @@ impl<'octo> RepoSecretScanningAlertsHandler<'octo> {
         );
         self.handler.crab.get(route, None::<&()>).await
     }
+
+    /// Lists the push protection bypass requests of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::secret_scanning::BypassRequestStatus;
+    ///
+    /// let requests = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .secrets_scanning()
+    ///     .list_bypass_requests()
+    ///     .request_status(BypassRequestStatus::Open)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_bypass_requests(&self) -> ListBypassRequestsBuilder<'octo> {
+        let route = format!("/{}/bypass-requests/secret-scanning", self.handler.repo);
+        ListBypassRequestsBuilder::new(self.handler.crab, route)
+    }
+
+    /// Gets a single push protection bypass request of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let request = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .secrets_scanning()
+    ///     .get_bypass_request(3)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_bypass_request(&self, number: u64) -> crate::Result<BypassRequest> {
+        let route = format!(
+            "/{}/bypass-requests/secret-scanning/{}",
+            self.handler.repo, number
+        );
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Approves or denies a push protection bypass request.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::secret_scanning::BypassReview;
+    ///
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .secrets_scanning()
+    ///     .review_bypass_request(3, BypassReview::Deny, "Rotate the key instead")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn review_bypass_request(
+        &self,
+        number: u64,
+        status: BypassReview,
+        message: impl Into<String>,
+    ) -> crate::Result<()> {
+        let route = format!(
+            "/{}/bypass-requests/secret-scanning/{}",
+            self.handler.repo, number
+        );
+        let body = serde_json::json!({ "status": status, "message": message.into() });
+        let response = self.handler.crab._patch(route, Some(&body)).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Creates a push protection bypass for a secret which blocked a push,
+    /// identified by the placeholder id from the push rejection message.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::secret_scanning::BypassReason;
+    ///
+    /// let bypass = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .secrets_scanning()
+    ///     .create_push_protection_bypass(BypassReason::UsedInTests, "2k4dM3Fd")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_push_protection_bypass(
+        &self,
+        reason: BypassReason,
+        placeholder_id: impl Into<String>,
+    ) -> crate::Result<PushProtectionBypass> {
+        let route = format!(
+            "/{}/secret-scanning/push-protection-bypasses",
+            self.handler.repo
+        );
+        let body = serde_json::json!({ "reason": reason, "placeholder_id": placeholder_id.into() });
+        self.handler.crab.post(route, Some(&body)).await
+    }
 }
@@ -0,0 +1,105 @@
+use crate::models::repos::secret_scanning_bypass::BypassRequest;
+use crate::params::secret_scanning::{BypassRequestStatus, TimePeriod};
+use crate::Octocrab;
+
+/// A builder pattern struct for listing secret scanning push protection
+/// bypass requests, of either a repository or an organization.
+///
+/// Created with [`RepoSecretScanningAlertsHandler::list_bypass_requests`](super::RepoSecretScanningAlertsHandler::list_bypass_requests)
+/// or [`OrgHandler::list_secret_scanning_bypass_requests`](crate::orgs::OrgHandler::list_secret_scanning_bypass_requests).
+#[derive(serde::Serialize)]
+pub struct ListBypassRequestsBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip)]
+    route: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reviewer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    requester: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time_period: Option<TimePeriod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    request_status: Option<BypassRequestStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo> ListBypassRequestsBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, route: String) -> Self {
+        Self {
+            crab,
+            route,
+            repository_name: None,
+            reviewer: None,
+            requester: None,
+            time_period: None,
+            request_status: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Only list requests made in this repository. Only applies to
+    /// organization listings.
+    pub fn repository_name(mut self, repository_name: impl Into<String>) -> Self {
+        self.repository_name = Some(repository_name.into());
+        self
+    }
+
+    /// Only list requests reviewed by this user.
+    pub fn reviewer(mut self, reviewer: impl Into<String>) -> Self {
+        self.reviewer = Some(reviewer.into());
+        self
+    }
+
+    /// Only list requests made by this user.
+    pub fn requester(mut self, requester: impl Into<String>) -> Self {
+        self.requester = Some(requester.into());
+        self
+    }
+
+    /// Only list requests made within this time period.
+    pub fn time_period(mut self, time_period: TimePeriod) -> Self {
+        self.time_period = Some(time_period);
+        self
+    }
+
+    /// Only list requests with this status.
+    pub fn request_status(mut self, request_status: BypassRequestStatus) -> Self {
+        self.request_status = Some(request_status);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<BypassRequest>> {
+        self.crab.get(&self.route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<BypassRequest>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
@@ -2,17 +2,26 @@ use http::StatusCode;
 
 use super::RepoHandler;
 use crate::models::repos::secrets::{CreateRepositorySecret, CreateRepositorySecretResponse};
+use crate::route::Route;
 
-/// A client to GitHub's repository secrets API.
+/// A client to the repository secrets of GitHub Actions or Codespaces.
 ///
-/// Created with [`RepoHandler`].
+/// Created with [`RepoHandler::secrets`] or [`RepoHandler::codespaces_secrets`].
 pub struct RepoSecretsHandler<'octo> {
     handler: &'octo RepoHandler<'octo>,
+    product: &'static str,
 }
 
 impl<'octo> RepoSecretsHandler<'octo> {
     pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
-        Self { handler: repo }
+        Self::for_product(repo, "actions")
+    }
+
+    pub(crate) fn for_product(repo: &'octo RepoHandler<'octo>, product: &'static str) -> Self {
+        Self {
+            handler: repo,
+            product,
+        }
     }
 
     /// Lists all secrets available in a repository without revealing their encrypted values.
@@ -30,7 +39,7 @@ impl<'octo> RepoSecretsHandler<'octo> {
     pub async fn get_secrets(
         &self,
     ) -> crate::Result<crate::models::repos::secrets::RepositorySecrets> {
-        let route = format!("/{}/actions/secrets", self.handler.repo);
+        let route = format!("/{}/{}/secrets", self.handler.repo, self.product);
         self.handler.crab.get(route, None::<&()>).await
     }
 
@@ -49,7 +58,7 @@ impl<'octo> RepoSecretsHandler<'octo> {
     /// # Ok(())
     /// # }
     pub async fn get_public_key(&self) -> crate::Result<crate::models::PublicKey> {
-        let route = format!("/{}/actions/secrets/public-key", self.handler.repo);
+        let route = format!("/{}/{}/secrets/public-key", self.handler.repo, self.product);
         self.handler.crab.get(route, None::<&()>).await
     }
 
@@ -69,11 +78,11 @@ impl<'octo> RepoSecretsHandler<'octo> {
         &self,
         secret_name: impl AsRef<str>,
     ) -> crate::Result<crate::models::repos::secrets::RepositorySecret> {
-        let route = format!(
-            "/{}/actions/secrets/{secret_name}",
-            self.handler.repo,
-            secret_name = secret_name.as_ref()
-        );
+        let route = Route::new(format_args!(
+            "/{}/{}/secrets",
+            self.handler.repo, self.product
+        ))
+        .segment(secret_name);
         self.handler.crab.get(route, None::<&()>).await
     }
 
@@ -105,11 +114,11 @@ impl<'octo> RepoSecretsHandler<'octo> {
         secret_name: impl AsRef<str>,
         secret: &CreateRepositorySecret<'_>,
     ) -> crate::Result<CreateRepositorySecretResponse> {
-        let route = format!(
-            "/{}/actions/secrets/{secret_name}",
-            self.handler.repo,
-            secret_name = secret_name.as_ref()
-        );
+        let route = Route::new(format_args!(
+            "/{}/{}/secrets",
+            self.handler.repo, self.product
+        ))
+        .segment(secret_name);
 
         let resp = {
             let resp = self.handler.crab._put(route, Some(secret)).await?;
@@ -144,11 +153,11 @@ impl<'octo> RepoSecretsHandler<'octo> {
     /// # Ok(())
     /// # }
     pub async fn delete_secret(&self, secret_name: impl AsRef<str>) -> crate::Result<()> {
-        let route = format!(
-            "/{}/actions/secrets/{secret_name}",
-            self.handler.repo,
-            secret_name = secret_name.as_ref()
-        );
+        let route = Route::new(format_args!(
+            "/{}/{}/secrets",
+            self.handler.repo, self.product
+        ))
+        .segment(secret_name);
 
         let resp = self.handler.crab._delete(route, None::<&()>).await?;
         crate::map_github_error(resp).await?;
@@ -0,0 +1,621 @@
+use super::RepoHandler;
+use crate::models::security_advisories::{
+    AdvisoryCredit, AdvisoryVulnerability, RepositoryAdvisory,
+};
+use crate::models::Repository;
+use crate::params::security_advisories::{AdvisoryState, Severity, Sort};
+use crate::params::Direction;
+
+/// A client to GitHub's repository security advisories API.
+///
+/// Created with [`RepoHandler::security_advisories`].
+pub struct RepoSecurityAdvisoriesHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+}
+
+impl<'octo> RepoSecurityAdvisoriesHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self { handler: repo }
+    }
+
+    /// Lists the security advisories of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::security_advisories::AdvisoryState;
+    ///
+    /// let advisories = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .list()
+    ///     .state(AdvisoryState::Draft)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListRepoSecurityAdvisoriesBuilder<'octo, '_> {
+        ListRepoSecurityAdvisoriesBuilder::new(self)
+    }
+
+    /// Gets a security advisory of the repository by its GHSA id.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let advisory = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .get("GHSA-abcd-1234-efgh")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, ghsa_id: impl AsRef<str>) -> crate::Result<RepositoryAdvisory> {
+        let route = format!(
+            "/{}/security-advisories/{}",
+            self.handler.repo,
+            ghsa_id.as_ref()
+        );
+        self.handler.crab.get(route, None::<&()>).await
+    }
+
+    /// Creates a draft security advisory for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::security_advisories::AdvisoryVulnerability;
+    /// use octocrab::params::security_advisories::{Ecosystem, Severity};
+    ///
+    /// let advisory = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .create(
+    ///         "Remote code execution in the template engine",
+    ///         "Templates are evaluated without sandboxing.",
+    ///         vec![AdvisoryVulnerability::new(Ecosystem::Npm, "my-package")
+    ///             .vulnerable_version_range("< 1.2.3")
+    ///             .patched_versions("1.2.3")],
+    ///     )
+    ///     .severity(Severity::High)
+    ///     .start_private_fork(true)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create(
+        &self,
+        summary: impl Into<String>,
+        description: impl Into<String>,
+        vulnerabilities: impl Into<Vec<AdvisoryVulnerability>>,
+    ) -> CreateRepoSecurityAdvisoryBuilder<'octo, '_> {
+        CreateRepoSecurityAdvisoryBuilder::new(
+            self,
+            summary.into(),
+            description.into(),
+            vulnerabilities.into(),
+        )
+    }
+
+    /// Updates a security advisory of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::security_advisories::AdvisoryState;
+    ///
+    /// let advisory = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .update("GHSA-abcd-1234-efgh")
+    ///     .state(AdvisoryState::Published)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn update(
+        &self,
+        ghsa_id: impl Into<String>,
+    ) -> UpdateRepoSecurityAdvisoryBuilder<'octo, '_> {
+        UpdateRepoSecurityAdvisoryBuilder::new(self, ghsa_id.into())
+    }
+
+    /// Requests a CVE id from GitHub for a security advisory of the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .request_cve("GHSA-abcd-1234-efgh")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn request_cve(&self, ghsa_id: impl AsRef<str>) -> crate::Result<()> {
+        let route = format!(
+            "/{}/security-advisories/{}/cve",
+            self.handler.repo,
+            ghsa_id.as_ref()
+        );
+        let response = self.handler.crab._post(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Creates a temporary private fork of the repository to collaborate on
+    /// a fix for the security advisory.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let fork = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .create_fork("GHSA-abcd-1234-efgh")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_fork(&self, ghsa_id: impl AsRef<str>) -> crate::Result<Repository> {
+        let route = format!(
+            "/{}/security-advisories/{}/forks",
+            self.handler.repo,
+            ghsa_id.as_ref()
+        );
+        self.handler.crab.post(route, None::<&()>).await
+    }
+
+    /// Lists the advisories which were privately reported to the repository
+    /// and still await triage.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let reports = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .list_reported()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_reported(&self) -> ListRepoSecurityAdvisoriesBuilder<'octo, '_> {
+        ListRepoSecurityAdvisoriesBuilder::new(self).state(AdvisoryState::Triage)
+    }
+
+    /// Privately reports a vulnerability to the maintainers of the
+    /// repository. Private vulnerability reporting must be enabled.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let report = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .report(
+    ///         "Remote code execution in the template engine",
+    ///         "Templates are evaluated without sandboxing.",
+    ///     )
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn report(
+        &self,
+        summary: impl Into<String>,
+        description: impl Into<String>,
+    ) -> ReportVulnerabilityBuilder<'octo, '_> {
+        ReportVulnerabilityBuilder::new(self, summary.into(), description.into())
+    }
+
+    /// Checks whether private vulnerability reporting is enabled for the
+    /// repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let enabled = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .is_private_reporting_enabled()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn is_private_reporting_enabled(&self) -> crate::Result<bool> {
+        #[derive(serde::Deserialize)]
+        struct Response {
+            enabled: bool,
+        }
+
+        let route = format!("/{}/private-vulnerability-reporting", self.handler.repo);
+        let response: Response = self.handler.crab.get(route, None::<&()>).await?;
+        Ok(response.enabled)
+    }
+
+    /// Enables private vulnerability reporting for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .enable_private_reporting()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn enable_private_reporting(&self) -> crate::Result<()> {
+        let route = format!("/{}/private-vulnerability-reporting", self.handler.repo);
+        let response = self.handler.crab._put(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+
+    /// Disables private vulnerability reporting for the repository.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .security_advisories()
+    ///     .disable_private_reporting()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn disable_private_reporting(&self) -> crate::Result<()> {
+        let route = format!("/{}/private-vulnerability-reporting", self.handler.repo);
+        let response = self.handler.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct ListRepoSecurityAdvisoriesBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoSecurityAdvisoriesHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<AdvisoryState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sort: Option<Sort>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    direction: Option<Direction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+}
+
+impl<'octo, 'r> ListRepoSecurityAdvisoriesBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoSecurityAdvisoriesHandler<'octo>) -> Self {
+        Self {
+            handler,
+            state: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+        }
+    }
+
+    /// Only list advisories in this state.
+    pub fn state(mut self, state: AdvisoryState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// What to sort results by.
+    pub fn sort(mut self, sort: Sort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// The direction of the sort.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::Page<RepositoryAdvisory>> {
+        let route = format!("/{}/security-advisories", self.handler.handler.repo);
+        self.handler.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<RepositoryAdvisory>>> {
+        let crab = self.handler.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct CreateRepoSecurityAdvisoryBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoSecurityAdvisoriesHandler<'octo>,
+    summary: String,
+    description: String,
+    vulnerabilities: Vec<AdvisoryVulnerability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cve_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cwe_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    credits: Option<Vec<AdvisoryCredit>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<Severity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cvss_vector_string: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_private_fork: Option<bool>,
+}
+
+impl<'octo, 'r> CreateRepoSecurityAdvisoryBuilder<'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'r RepoSecurityAdvisoriesHandler<'octo>,
+        summary: String,
+        description: String,
+        vulnerabilities: Vec<AdvisoryVulnerability>,
+    ) -> Self {
+        Self {
+            handler,
+            summary,
+            description,
+            vulnerabilities,
+            cve_id: None,
+            cwe_ids: None,
+            credits: None,
+            severity: None,
+            cvss_vector_string: None,
+            start_private_fork: None,
+        }
+    }
+
+    /// The CVE id of the advisory, if one was already assigned.
+    pub fn cve_id(mut self, cve_id: impl Into<String>) -> Self {
+        self.cve_id = Some(cve_id.into());
+        self
+    }
+
+    /// The CWE ids of the advisory, e.g. `CWE-79`.
+    pub fn cwe_ids(mut self, cwe_ids: impl Into<Vec<String>>) -> Self {
+        self.cwe_ids = Some(cwe_ids.into());
+        self
+    }
+
+    /// The users credited on the advisory.
+    pub fn credits(mut self, credits: impl Into<Vec<AdvisoryCredit>>) -> Self {
+        self.credits = Some(credits.into());
+        self
+    }
+
+    /// The severity of the advisory. Can't be combined with
+    /// [`Self::cvss_vector_string`].
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// The CVSS vector to calculate the severity from. Can't be combined with
+    /// [`Self::severity`].
+    pub fn cvss_vector_string(mut self, cvss_vector_string: impl Into<String>) -> Self {
+        self.cvss_vector_string = Some(cvss_vector_string.into());
+        self
+    }
+
+    /// Whether to create a temporary private fork to collaborate on a fix.
+    pub fn start_private_fork(mut self, start_private_fork: bool) -> Self {
+        self.start_private_fork = Some(start_private_fork);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<RepositoryAdvisory> {
+        let route = format!("/{}/security-advisories", self.handler.handler.repo);
+        self.handler.handler.crab.post(route, Some(&self)).await
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct UpdateRepoSecurityAdvisoryBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoSecurityAdvisoriesHandler<'octo>,
+    #[serde(skip)]
+    ghsa_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cve_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vulnerabilities: Option<Vec<AdvisoryVulnerability>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cwe_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    credits: Option<Vec<AdvisoryCredit>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<Severity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cvss_vector_string: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    state: Option<AdvisoryState>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collaborating_users: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    collaborating_teams: Option<Vec<String>>,
+}
+
+impl<'octo, 'r> UpdateRepoSecurityAdvisoryBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoSecurityAdvisoriesHandler<'octo>, ghsa_id: String) -> Self {
+        Self {
+            handler,
+            ghsa_id,
+            summary: None,
+            description: None,
+            cve_id: None,
+            vulnerabilities: None,
+            cwe_ids: None,
+            credits: None,
+            severity: None,
+            cvss_vector_string: None,
+            state: None,
+            collaborating_users: None,
+            collaborating_teams: None,
+        }
+    }
+
+    /// A short summary of the advisory.
+    pub fn summary(mut self, summary: impl Into<String>) -> Self {
+        self.summary = Some(summary.into());
+        self
+    }
+
+    /// A detailed description of the advisory.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The CVE id of the advisory.
+    pub fn cve_id(mut self, cve_id: impl Into<String>) -> Self {
+        self.cve_id = Some(cve_id.into());
+        self
+    }
+
+    /// The vulnerable packages of the advisory.
+    pub fn vulnerabilities(
+        mut self,
+        vulnerabilities: impl Into<Vec<AdvisoryVulnerability>>,
+    ) -> Self {
+        self.vulnerabilities = Some(vulnerabilities.into());
+        self
+    }
+
+    /// The CWE ids of the advisory, e.g. `CWE-79`.
+    pub fn cwe_ids(mut self, cwe_ids: impl Into<Vec<String>>) -> Self {
+        self.cwe_ids = Some(cwe_ids.into());
+        self
+    }
+
+    /// The users credited on the advisory.
+    pub fn credits(mut self, credits: impl Into<Vec<AdvisoryCredit>>) -> Self {
+        self.credits = Some(credits.into());
+        self
+    }
+
+    /// The severity of the advisory. Can't be combined with
+    /// [`Self::cvss_vector_string`].
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// The CVSS vector to calculate the severity from. Can't be combined with
+    /// [`Self::severity`].
+    pub fn cvss_vector_string(mut self, cvss_vector_string: impl Into<String>) -> Self {
+        self.cvss_vector_string = Some(cvss_vector_string.into());
+        self
+    }
+
+    /// The state of the advisory, e.g. [`AdvisoryState::Published`] to
+    /// publish it or [`AdvisoryState::Closed`] to close it.
+    pub fn state(mut self, state: AdvisoryState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
+    /// The logins of users to collaborate on the advisory.
+    pub fn collaborating_users(mut self, users: impl Into<Vec<String>>) -> Self {
+        self.collaborating_users = Some(users.into());
+        self
+    }
+
+    /// The slugs of teams to collaborate on the advisory.
+    pub fn collaborating_teams(mut self, teams: impl Into<Vec<String>>) -> Self {
+        self.collaborating_teams = Some(teams.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<RepositoryAdvisory> {
+        let route = format!(
+            "/{}/security-advisories/{}",
+            self.handler.handler.repo, self.ghsa_id
+        );
+        self.handler.handler.crab.patch(route, Some(&self)).await
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct ReportVulnerabilityBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoSecurityAdvisoriesHandler<'octo>,
+    summary: String,
+    description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vulnerabilities: Option<Vec<AdvisoryVulnerability>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cwe_ids: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    severity: Option<Severity>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cvss_vector_string: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    start_private_fork: Option<bool>,
+}
+
+impl<'octo, 'r> ReportVulnerabilityBuilder<'octo, 'r> {
+    pub(crate) fn new(
+        handler: &'r RepoSecurityAdvisoriesHandler<'octo>,
+        summary: String,
+        description: String,
+    ) -> Self {
+        Self {
+            handler,
+            summary,
+            description,
+            vulnerabilities: None,
+            cwe_ids: None,
+            severity: None,
+            cvss_vector_string: None,
+            start_private_fork: None,
+        }
+    }
+
+    /// The vulnerable packages.
+    pub fn vulnerabilities(
+        mut self,
+        vulnerabilities: impl Into<Vec<AdvisoryVulnerability>>,
+    ) -> Self {
+        self.vulnerabilities = Some(vulnerabilities.into());
+        self
+    }
+
+    /// The CWE ids of the vulnerability, e.g. `CWE-79`.
+    pub fn cwe_ids(mut self, cwe_ids: impl Into<Vec<String>>) -> Self {
+        self.cwe_ids = Some(cwe_ids.into());
+        self
+    }
+
+    /// The severity of the vulnerability. Can't be combined with
+    /// [`Self::cvss_vector_string`].
+    pub fn severity(mut self, severity: Severity) -> Self {
+        self.severity = Some(severity);
+        self
+    }
+
+    /// The CVSS vector to calculate the severity from. Can't be combined with
+    /// [`Self::severity`].
+    pub fn cvss_vector_string(mut self, cvss_vector_string: impl Into<String>) -> Self {
+        self.cvss_vector_string = Some(cvss_vector_string.into());
+        self
+    }
+
+    /// Whether to create a temporary private fork to collaborate on a fix.
+    pub fn start_private_fork(mut self, start_private_fork: bool) -> Self {
+        self.start_private_fork = Some(start_private_fork);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<RepositoryAdvisory> {
+        let route = format!("/{}/security-advisories/reports", self.handler.handler.repo);
+        self.handler.handler.crab.post(route, Some(&self)).await
+    }
+}
@@ -0,0 +1,131 @@
+use super::RepoHandler;
+use crate::models::repos::stats::{
+    CodeFrequency, CommitActivity, ContributorStats, Participation, PunchCardHour,
+};
+use crate::ReadyBackoff;
+
+/// A client to GitHub's repository statistics API.
+///
+/// GitHub computes statistics in the background and answers `202 Accepted`
+/// until they're ready, so requests are retried according to a
+/// [`ReadyBackoff`], see [`RepoStatsHandler::backoff`].
+///
+/// Created with [`RepoHandler::stats`].
+pub struct RepoStatsHandler<'octo> {
+    handler: &'octo RepoHandler<'octo>,
+    backoff: ReadyBackoff,
+}
+
+impl<'octo> RepoStatsHandler<'octo> {
+    pub(crate) fn new(repo: &'octo RepoHandler<'octo>) -> Self {
+        Self {
+            handler: repo,
+            backoff: ReadyBackoff::default(),
+        }
+    }
+
+    /// Sets how long to wait for statistics which aren't computed yet.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::time::Duration;
+    /// use octocrab::ReadyBackoff;
+    ///
+    /// let activity = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .backoff(ReadyBackoff::new(Duration::from_secs(10)))
+    ///     .commit_activity()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn backoff(mut self, backoff: ReadyBackoff) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    /// Gets the number of commits, additions and deletions of each
+    /// contributor per week.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let contributors = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .contributors()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn contributors(&self) -> crate::Result<Vec<ContributorStats>> {
+        self.get("contributors").await
+    }
+
+    /// Gets the number of commits per day of each week of the last year.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let activity = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .commit_activity()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn commit_activity(&self) -> crate::Result<Vec<CommitActivity>> {
+        self.get("commit_activity").await
+    }
+
+    /// Gets the number of additions and deletions per week.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let frequency = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .code_frequency()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn code_frequency(&self) -> crate::Result<Vec<CodeFrequency>> {
+        self.get("code_frequency").await
+    }
+
+    /// Gets the number of commits per week of the last year, of everyone and
+    /// of the owner.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let participation = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .participation()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn participation(&self) -> crate::Result<Participation> {
+        self.get("participation").await
+    }
+
+    /// Gets the number of commits per hour of each day of the week.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let punch_card = octocrab::instance()
+    ///     .repos("owner", "repo")
+    ///     .stats()
+    ///     .punch_card()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn punch_card(&self) -> crate::Result<Vec<PunchCardHour>> {
+        self.get("punch_card").await
+    }
+
+    async fn get<R: serde::de::DeserializeOwned>(&self, statistic: &str) -> crate::Result<R> {
+        let route = format!("/{}/stats/{statistic}", self.handler.repo);
+        self.handler
+            .crab
+            .get_when_ready(route, None::<&()>, self.backoff)
+            .await
+    }
+}
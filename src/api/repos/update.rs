@@ -0,0 +1,171 @@
+use super::RepoHandler;
+use crate::models::repos::SecurityAndAnalysis;
+
+/// A builder pattern struct for updating the settings of a repository.
+///
+/// Created by [`RepoHandler::update`].
+#[derive(serde::Serialize)]
+pub struct UpdateRepoBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r RepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_template: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_squash_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_merge_commit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_rebase_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_auto_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_branch_on_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    security_and_analysis: Option<SecurityAndAnalysis>,
+}
+
+impl<'octo, 'r> UpdateRepoBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r RepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            name: None,
+            description: None,
+            homepage: None,
+            private: None,
+            has_issues: None,
+            has_projects: None,
+            has_wiki: None,
+            is_template: None,
+            default_branch: None,
+            allow_squash_merge: None,
+            allow_merge_commit: None,
+            allow_rebase_merge: None,
+            allow_auto_merge: None,
+            delete_branch_on_merge: None,
+            archived: None,
+            security_and_analysis: None,
+        }
+    }
+
+    /// The new name of the repository.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// A short description of the repository.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// A URL with more information about the repository.
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    /// Whether the repository is private.
+    pub fn private(mut self, private: bool) -> Self {
+        self.private = Some(private);
+        self
+    }
+
+    /// Whether issues are enabled.
+    pub fn has_issues(mut self, has_issues: bool) -> Self {
+        self.has_issues = Some(has_issues);
+        self
+    }
+
+    /// Whether projects are enabled.
+    pub fn has_projects(mut self, has_projects: bool) -> Self {
+        self.has_projects = Some(has_projects);
+        self
+    }
+
+    /// Whether the wiki is enabled.
+    pub fn has_wiki(mut self, has_wiki: bool) -> Self {
+        self.has_wiki = Some(has_wiki);
+        self
+    }
+
+    /// Whether the repository can be used as a template.
+    pub fn is_template(mut self, is_template: bool) -> Self {
+        self.is_template = Some(is_template);
+        self
+    }
+
+    /// The name of the default branch.
+    pub fn default_branch(mut self, default_branch: impl Into<String>) -> Self {
+        self.default_branch = Some(default_branch.into());
+        self
+    }
+
+    /// Whether pull requests can be squash-merged.
+    pub fn allow_squash_merge(mut self, allow: bool) -> Self {
+        self.allow_squash_merge = Some(allow);
+        self
+    }
+
+    /// Whether pull requests can be merged with a merge commit.
+    pub fn allow_merge_commit(mut self, allow: bool) -> Self {
+        self.allow_merge_commit = Some(allow);
+        self
+    }
+
+    /// Whether pull requests can be rebase-merged.
+    pub fn allow_rebase_merge(mut self, allow: bool) -> Self {
+        self.allow_rebase_merge = Some(allow);
+        self
+    }
+
+    /// Whether pull requests can be merged automatically once they're ready.
+    pub fn allow_auto_merge(mut self, allow: bool) -> Self {
+        self.allow_auto_merge = Some(allow);
+        self
+    }
+
+    /// Whether head branches are deleted once their pull request is merged.
+    pub fn delete_branch_on_merge(mut self, delete: bool) -> Self {
+        self.delete_branch_on_merge = Some(delete);
+        self
+    }
+
+    /// Whether the repository is archived, which can't be undone through
+    /// the API.
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+
+    /// Enables or disables security and analysis features, leaving those
+    /// which aren't set as they are.
+    pub fn security_and_analysis(mut self, settings: SecurityAndAnalysis) -> Self {
+        self.security_and_analysis = Some(settings);
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<crate::models::Repository> {
+        let route = format!("/{}", self.handler.repo);
+        self.handler.crab.patch(route, Some(&self)).await
+    }
+}
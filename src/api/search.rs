@@ -1,5 +1,8 @@
 //! Using GitHub's search.
 
+use snafu::ResultExt;
+
+use crate::error::SerdeSnafu;
 use crate::{models, Octocrab};
 
 /// Handler for the search API.
@@ -60,7 +63,7 @@ impl<'octo> SearchHandler<'octo> {
     /// let page = octocrab::instance()
     ///     .search()
     ///     .users("bors type:user")
-    ///     .sort("followers")
+    ///     .sort(octocrab::params::search::UserSort::Followers)
     ///     .order("desc")
     ///     .send()
     ///     .await?;
@@ -94,6 +97,85 @@ impl<'octo> SearchHandler<'octo> {
         QueryHandler::new(self.crab, "issues", query.as_ref())
     }
 
+    /// Searches for issues and pull requests through GraphQL, fetching only
+    /// the given fields of each result. This avoids the large REST payloads
+    /// and counts against the GraphQL rate limit instead of the 30 searches
+    /// per minute of the REST search.
+    ///
+    /// The fields are selected on both `Issue` and `PullRequest`, so they
+    /// should exist on both, and each result is deserialized into `T`.
+    /// ```no_run
+    ///# async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::search::GraphQLSearchResults;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Found {
+    ///     number: u64,
+    ///     title: String,
+    /// }
+    ///
+    /// let results: GraphQLSearchResults<Found> = octocrab::instance()
+    ///     .search()
+    ///     .graphql_issues_and_pull_requests("repo:rust-lang/rust is:open", "number title")
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn graphql_issues_and_pull_requests<T>(
+        self,
+        query: impl Into<String>,
+        fields: impl Into<String>,
+    ) -> GraphQLSearchBuilder<'octo, T> {
+        GraphQLSearchBuilder::new(self.crab, query.into(), fields.into())
+    }
+
+    /// Searches for the labels of a repository whose name or description
+    /// match the search query.
+    /// ```no_run
+    ///# async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::RepositoryId;
+    /// use octocrab::params::search::LabelSort;
+    ///
+    /// let page = octocrab::instance()
+    ///     .search()
+    ///     .labels(RepositoryId(64778136), "bug defect enhancement")
+    ///     .sort(LabelSort::Created)
+    ///     .order("asc")
+    ///     .send()
+    ///     .await?;
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn labels<'query>(
+        self,
+        repository_id: models::RepositoryId,
+        query: &'query (impl AsRef<str> + ?Sized),
+    ) -> QueryHandler<'octo, 'query, models::Label> {
+        let mut handler = QueryHandler::new(self.crab, "labels", query.as_ref());
+        handler.repository_id = Some(repository_id);
+        handler
+    }
+
+    /// Searches for all topics matching the search query.
+    /// ```no_run
+    ///# async fn run() -> octocrab::Result<()> {
+    /// let page = octocrab::instance()
+    ///     .search()
+    ///     .topics("ruby is:featured")
+    ///     .send()
+    ///     .await?;
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub fn topics<'query>(
+        self,
+        query: &'query (impl AsRef<str> + ?Sized),
+    ) -> QueryHandler<'octo, 'query, models::search::Topic> {
+        QueryHandler::new(self.crab, "topics", query.as_ref())
+    }
+
     /// Searches for all code matching the search query.
     /// ```no_run
     ///# async fn run() -> octocrab::Result<()> {
@@ -126,6 +208,8 @@ pub struct QueryHandler<'octo, 'query, T> {
     route: &'static str,
     #[serde(rename = "q")]
     query: &'query str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    repository_id: Option<models::RepositoryId>,
     per_page: Option<u8>,
     page: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -142,6 +226,7 @@ impl<'octo, 'query, T> QueryHandler<'octo, 'query, T> {
             page: None,
             per_page: None,
             query,
+            repository_id: None,
             return_type: std::marker::PhantomData,
             route,
             sort: None,
@@ -182,4 +267,87 @@ impl<T: serde::de::DeserializeOwned> QueryHandler<'_, '_, T> {
             .get(&format!("/search/{}", self.route), Some(&self))
             .await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<T>>>
+    where
+        T: 'static,
+    {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
+
+/// A builder pattern struct for searching issues and pull requests through
+/// GraphQL.
+///
+/// Created with [`SearchHandler::graphql_issues_and_pull_requests`].
+pub struct GraphQLSearchBuilder<'octo, T> {
+    return_type: std::marker::PhantomData<T>,
+    crab: &'octo Octocrab,
+    query: String,
+    fields: String,
+    per_page: u8,
+    after: Option<String>,
+}
+
+impl<'octo, T> GraphQLSearchBuilder<'octo, T> {
+    pub(crate) fn new(crab: &'octo Octocrab, query: String, fields: String) -> Self {
+        Self {
+            return_type: std::marker::PhantomData,
+            crab,
+            query,
+            fields,
+            per_page: 30,
+            after: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = per_page.into();
+        self
+    }
+
+    /// The cursor after which to start, from a previous page's `end_cursor`.
+    pub fn after(mut self, after: impl Into<String>) -> Self {
+        self.after = Some(after.into());
+        self
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> GraphQLSearchBuilder<'_, T> {
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<models::search::GraphQLSearchResults<T>> {
+        let fields = &self.fields;
+        let query = format!(
+            "query($query: String!, $first: Int!, $after: String) {{
+  search(query: $query, type: ISSUE, first: $first, after: $after) {{
+    issueCount
+    pageInfo {{ hasNextPage endCursor }}
+    nodes {{
+      ... on Issue {{ {fields} }}
+      ... on PullRequest {{ {fields} }}
+    }}
+  }}
+}}"
+        );
+        let mut data = self
+            .crab
+            .graphql_query(
+                &query,
+                &serde_json::json!({
+                    "query": self.query,
+                    "first": self.per_page,
+                    "after": self.after,
+                }),
+            )
+            .await?;
+        serde_json::from_value(data["search"].take()).context(SerdeSnafu)
+    }
 }
@@ -6,12 +6,20 @@ mod edit;
 mod invitations;
 mod list;
 mod members;
+mod memberships;
+mod sync;
 mod team_repos;
 
 pub use self::{
-    children::ListChildTeamsBuilder, create::CreateTeamBuilder, edit::EditTeamBuilder,
-    invitations::ListTeamInvitationsBuilder, list::ListTeamsBuilder,
-    members::ListTeamMembersBuilder, team_repos::TeamRepoHandler,
+    children::ListChildTeamsBuilder,
+    create::CreateTeamBuilder,
+    edit::EditTeamBuilder,
+    invitations::ListTeamInvitationsBuilder,
+    list::ListTeamsBuilder,
+    members::ListTeamMembersBuilder,
+    memberships::TeamMembershipHandler,
+    sync::{ListIdpGroupsBuilder, TeamSyncHandler},
+    team_repos::{ListTeamReposBuilder, TeamRepoHandler},
 };
 use http::Uri;
 use snafu::ResultExt;
@@ -178,6 +186,36 @@ impl<'octo> TeamHandler<'octo> {
         ListTeamMembersBuilder::new(self, team_slug.into())
     }
 
+    /// Creates a new `TeamMembershipHandler` for the specified team,
+    /// that allows you to add, remove and look up the roles of its members.
+    pub fn memberships(&self, team_slug: impl Into<String>) -> TeamMembershipHandler<'_> {
+        TeamMembershipHandler::new(self.crab, self.owner.clone(), team_slug.into())
+    }
+
+    /// Creates a new `TeamSyncHandler` for the specified team, that allows
+    /// you to map it to groups of the organization's identity provider.
+    pub fn team_sync(&self, team_slug: impl Into<String>) -> TeamSyncHandler<'_> {
+        TeamSyncHandler::new(self.crab, self.owner.clone(), team_slug.into())
+    }
+
+    /// Lists the identity provider groups of the organization, which teams
+    /// can be mapped to with [`TeamHandler::team_sync`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let groups = octocrab::instance()
+    ///     .teams("owner")
+    ///     .list_idp_groups()
+    ///     .query("admins")
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_idp_groups(&self) -> ListIdpGroupsBuilder<'_, '_> {
+        ListIdpGroupsBuilder::new(self)
+    }
+
     /// List the pending invitations for a team in an organization.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -195,4 +233,65 @@ impl<'octo> TeamHandler<'octo> {
     pub fn invitations(&self, team_slug: impl Into<String>) -> ListTeamInvitationsBuilder<'_, '_> {
         ListTeamInvitationsBuilder::new(self, team_slug.into())
     }
+
+    /// Creates a [`ReactionsHandler`] to list, add and remove reactions on a
+    /// team discussion.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let reactions = octocrab::instance()
+    ///     .teams("owner")
+    ///     .discussion_reactions("team-name-here", 1)
+    ///     .list()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ReactionsHandler`]: crate::reactions::ReactionsHandler
+    pub fn discussion_reactions(
+        &self,
+        team_slug: impl AsRef<str>,
+        discussion_number: u64,
+    ) -> crate::reactions::ReactionsHandler<'octo> {
+        crate::reactions::ReactionsHandler::new(
+            self.crab,
+            format!(
+                "/orgs/{org}/teams/{team}/discussions/{discussion_number}",
+                org = self.owner,
+                team = team_slug.as_ref(),
+            ),
+        )
+    }
+
+    /// Creates a [`ReactionsHandler`] to list, add and remove reactions on a
+    /// comment of a team discussion.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let reactions = octocrab::instance()
+    ///     .teams("owner")
+    ///     .discussion_comment_reactions("team-name-here", 1, 2)
+    ///     .list()
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`ReactionsHandler`]: crate::reactions::ReactionsHandler
+    pub fn discussion_comment_reactions(
+        &self,
+        team_slug: impl AsRef<str>,
+        discussion_number: u64,
+        comment_number: u64,
+    ) -> crate::reactions::ReactionsHandler<'octo> {
+        crate::reactions::ReactionsHandler::new(
+            self.crab,
+            format!(
+                "/orgs/{org}/teams/{team}/discussions/{discussion_number}/comments/{comment_number}",
+                org = self.owner,
+                team = team_slug.as_ref(),
+            ),
+        )
+    }
 }
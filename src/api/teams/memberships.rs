@@ -0,0 +1,92 @@
+use crate::{models, params, Octocrab, Result};
+
+/// Handler for managing the memberships of a team through GitHub's teams
+/// API.
+///
+/// Created with [`TeamHandler::memberships`]
+///
+/// [`TeamHandler::memberships`]: ./struct.TeamHandler.html#method.memberships
+pub struct TeamMembershipHandler<'octo> {
+    crab: &'octo Octocrab,
+    org: String,
+    team: String,
+}
+
+impl<'octo> TeamMembershipHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, org: String, team: String) -> Self {
+        Self { crab, org, team }
+    }
+
+    fn route(&self, username: &str) -> String {
+        format!(
+            "/orgs/{org}/teams/{team}/memberships/{username}",
+            org = self.org,
+            team = self.team,
+        )
+    }
+
+    /// Gets a user's membership of the team, including their role.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let membership = octocrab::instance()
+    ///     .teams("owner")
+    ///     .memberships("team")
+    ///     .get("ferris")
+    ///     .await?;
+    /// println!("{:?}", membership.role);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get(&self, username: impl AsRef<str>) -> Result<models::teams::TeamMembership> {
+        self.crab
+            .get(self.route(username.as_ref()), None::<&()>)
+            .await
+    }
+
+    /// Adds a user to the team, or changes their role if they're already a
+    /// member. Users that aren't in the organization yet are invited, and
+    /// their membership stays pending until they accept. The role defaults
+    /// to `member`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params;
+    ///
+    /// octocrab::instance()
+    ///     .teams("owner")
+    ///     .memberships("team")
+    ///     .add_or_update("ferris", params::teams::Role::Maintainer)
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn add_or_update(
+        &self,
+        username: impl AsRef<str>,
+        role: impl Into<Option<params::teams::Role>>,
+    ) -> Result<models::teams::TeamMembership> {
+        let body = role.into().map(|role| serde_json::json!({ "role": role }));
+
+        self.crab
+            .put(self.route(username.as_ref()), body.as_ref())
+            .await
+    }
+
+    /// Removes a user from the team.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance()
+    ///     .teams("owner")
+    ///     .memberships("team")
+    ///     .remove("ferris")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn remove(&self, username: impl AsRef<str>) -> Result<()> {
+        let response = self
+            .crab
+            ._delete(self.route(username.as_ref()), None::<&()>)
+            .await?;
+        crate::map_github_error(response).await.map(drop)
+    }
+}
@@ -0,0 +1,130 @@
+use super::*;
+use crate::models::teams::{GroupMapping, IdpGroup};
+use crate::Page;
+
+/// Handler for the team synchronization of a team, which keeps its members
+/// in sync with groups of the organization's identity provider. Team
+/// synchronization is only available on GitHub Enterprise Cloud.
+///
+/// Created with [`TeamHandler::team_sync`]
+pub struct TeamSyncHandler<'octo> {
+    crab: &'octo Octocrab,
+    org: String,
+    team: String,
+}
+
+impl<'octo> TeamSyncHandler<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, org: String, team: String) -> Self {
+        Self { crab, org, team }
+    }
+
+    fn route(&self) -> String {
+        format!(
+            "/orgs/{org}/teams/{team}/team-sync/group-mappings",
+            org = self.org,
+            team = self.team,
+        )
+    }
+
+    /// Lists the identity provider groups the team is synchronized with.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let mapping = octocrab::instance()
+    ///     .teams("owner")
+    ///     .team_sync("team")
+    ///     .group_mappings()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn group_mappings(&self) -> Result<GroupMapping> {
+        self.crab.get(self.route(), None::<&()>).await
+    }
+
+    /// Replaces the identity provider groups the team is synchronized with.
+    /// An empty list removes all of them.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::teams::IdpGroup;
+    ///
+    /// let mapping = octocrab::instance()
+    ///     .teams("owner")
+    ///     .team_sync("team")
+    ///     .update_group_mappings(vec![IdpGroup::new(
+    ///         "123",
+    ///         "Octocat admins",
+    ///         "The people who configure your octoworld.",
+    ///     )])
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_group_mappings(&self, groups: Vec<IdpGroup>) -> Result<GroupMapping> {
+        let body = serde_json::json!({ "groups": groups });
+
+        self.crab.patch(self.route(), Some(&body)).await
+    }
+}
+
+/// A builder pattern struct for listing the identity provider groups of an
+/// organization.
+///
+/// Created by [`TeamHandler::list_idp_groups`].
+#[derive(serde::Serialize)]
+pub struct ListIdpGroupsBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r TeamHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    q: Option<String>,
+}
+
+impl<'octo, 'r> ListIdpGroupsBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r TeamHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+            q: None,
+        }
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// The cursor of the page of results to fetch, from the link to the
+    /// next page.
+    pub fn page(mut self, page: impl Into<String>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Only list the groups whose name contains `query`.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.q = Some(query.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<IdpGroup>> {
+        let route = format!("/orgs/{owner}/team-sync/groups", owner = self.handler.owner);
+        self.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<IdpGroup>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
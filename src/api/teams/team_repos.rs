@@ -1,6 +1,6 @@
 use crate::error::HttpSnafu;
 use crate::params;
-use crate::{models, FromResponse, Octocrab, Result};
+use crate::{models, FromResponse, Octocrab, Page, Result};
 use http::header::ACCEPT;
 use http::request::Builder;
 use http::{StatusCode, Uri};
@@ -28,6 +28,23 @@ impl<'octo> TeamRepoHandler<'octo> {
         Self { crab, org, team }
     }
 
+    /// Lists the repositories the team has access to.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repos = octocrab::instance()
+    ///     .teams("owner")
+    ///     .repos("team")
+    ///     .list()
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list(&self) -> ListTeamReposBuilder<'_, '_> {
+        ListTeamReposBuilder::new(self)
+    }
+
     /// Checks if a team manages a repository, returning the repository if it does.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
@@ -132,12 +149,66 @@ impl<'octo> TeamRepoHandler<'octo> {
             owner = repo_owner.into(),
             repo = repo_name.into(),
         );
-        self.crab
+        let response = self
+            .crab
             ._delete(
                 self.crab.parameterized_uri(route, None::<&()>)?,
                 None::<&()>,
             )
             .await?;
-        Ok(())
+        crate::map_github_error(response).await.map(drop)
+    }
+}
+
+#[derive(serde::Serialize)]
+pub struct ListTeamReposBuilder<'octo, 'r> {
+    #[serde(skip)]
+    handler: &'r TeamRepoHandler<'octo>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    page: Option<u32>,
+}
+
+impl<'octo, 'r> ListTeamReposBuilder<'octo, 'r> {
+    pub(crate) fn new(handler: &'r TeamRepoHandler<'octo>) -> Self {
+        Self {
+            handler,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Results per page.
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Page number of the results to fetch.
+    pub fn page(mut self, page: impl Into<u32>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> Result<Page<models::Repository>> {
+        let route = format!(
+            "/orgs/{org}/teams/{team}/repos",
+            org = self.handler.org,
+            team = self.handler.team,
+        );
+        self.handler.crab.get(route, Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::Repository>>> {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
     }
 }
@@ -1,10 +1,9 @@
 //! The users API.
 
-use std::backtrace::Backtrace;
-
 use http::StatusCode;
 
 pub use self::follow::{ListUserFollowerBuilder, ListUserFollowingBuilder};
+pub use self::list_users::ListUsersBuilder;
 use self::user_repos::ListUserReposBuilder;
 use crate::api::users::user_blocks::BlockedUsersBuilder;
 use crate::api::users::user_emails::UserEmailsOpsBuilder;
@@ -14,9 +13,10 @@ use crate::api::users::user_social_accounts::UserSocialAccountsOpsBuilder;
 use crate::api::users::user_ssh_signing_keys::UserSshSigningKeysOpsBuilder;
 use crate::models::UserId;
 use crate::params::users::emails::EmailVisibilityState;
-use crate::{error, GitHubError, Octocrab};
+use crate::Octocrab;
 
 mod follow;
+mod list_users;
 mod user_blocks;
 mod user_emails;
 mod user_git_ssh_keys;
@@ -54,6 +54,15 @@ impl<'octo> UserHandler<'octo> {
     }
 
     /// Get this users profile info
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::instance();
+    /// let by_name = octocrab.users("octocat").profile().await?;
+    /// let by_id = octocrab.users_by_id(583231u64).profile().await?;
+    /// assert_eq!(by_name.id, by_id.id);
+    /// # Ok(())
+    /// # }
+    /// ```
     pub async fn profile(&self) -> crate::Result<crate::models::UserProfile> {
         // build the route to get info on this user
         let route = format!("/{}", self.user);
@@ -61,6 +70,62 @@ impl<'octo> UserHandler<'octo> {
         self.crab.get(route, None::<&()>).await
     }
 
+    /// Gets the contextual information GitHub shows in this user's hovercard
+    /// when it's displayed on the given subject, e.g. that they opened an
+    /// issue or are a member of an organization. The subject id is the
+    /// numeric id of the resource, or the number of an issue or pull request.
+    ///
+    /// Only works on handlers created from a username, and requires
+    /// authentication.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::users::HovercardSubjectType;
+    ///
+    /// let hovercard = octocrab::instance()
+    ///     .users("octocat")
+    ///     .hovercard(HovercardSubjectType::Repository, 1296269)
+    ///     .await?;
+    /// for context in hovercard.contexts {
+    ///     println!("{}", context.message);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn hovercard(
+        &self,
+        subject_type: crate::params::users::HovercardSubjectType,
+        subject_id: impl ToString,
+    ) -> crate::Result<crate::models::Hovercard> {
+        #[derive(serde::Serialize)]
+        struct Params {
+            subject_type: crate::params::users::HovercardSubjectType,
+            subject_id: String,
+        }
+
+        let route = format!("/{}/hovercard", self.user);
+        let params = Params {
+            subject_type,
+            subject_id: subject_id.to_string(),
+        };
+        self.crab.get(route, Some(&params)).await
+    }
+
+    /// Handle the billing of this user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .users("octocat")
+    ///     .billing()
+    ///     .actions()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn billing(&self) -> crate::billing::BillingHandler<'octo> {
+        let route = format!("/{}/settings/billing", self.user);
+        crate::billing::BillingHandler::new(self.crab, route.clone(), route)
+    }
+
     /// List this users that follow this user
     pub fn followers(&self) -> ListUserFollowerBuilder<'_, '_> {
         ListUserFollowerBuilder::new(self)
@@ -75,6 +140,62 @@ impl<'octo> UserHandler<'octo> {
         ListUserReposBuilder::new(self)
     }
 
+    /// Lists the events performed by this user. Includes private events when
+    /// authenticated as this user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let events = octocrab::instance()
+    ///     .users("octocat")
+    ///     .events()
+    ///     .per_page(50)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn events(&self) -> crate::events::EventsBuilder<'octo> {
+        crate::events::EventsBuilder::new(self.crab, format!("/{}/events", self.user))
+    }
+
+    /// Lists the public events performed by this user.
+    pub fn public_events(&self) -> crate::events::EventsBuilder<'octo> {
+        crate::events::EventsBuilder::new(self.crab, format!("/{}/events/public", self.user))
+    }
+
+    /// Lists the events received by this user, from the repositories and
+    /// users they watch and follow. Includes private events when
+    /// authenticated as this user.
+    pub fn received_events(&self) -> crate::events::EventsBuilder<'octo> {
+        crate::events::EventsBuilder::new(self.crab, format!("/{}/received_events", self.user))
+    }
+
+    /// Lists the public events received by this user.
+    pub fn received_public_events(&self) -> crate::events::EventsBuilder<'octo> {
+        crate::events::EventsBuilder::new(
+            self.crab,
+            format!("/{}/received_events/public", self.user),
+        )
+    }
+
+    /// Lists the events of an organization as seen on this user's
+    /// organization dashboard. Requires authentication as this user.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let events = octocrab::instance()
+    ///     .users("octocat")
+    ///     .org_events("github")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn org_events(&self, org: impl AsRef<str>) -> crate::events::EventsBuilder<'octo> {
+        crate::events::EventsBuilder::new(
+            self.crab,
+            format!("/{}/events/orgs/{}", self.user, org.as_ref()),
+        )
+    }
+
     /// API for listing blocked users
     /// you must pass authentication information with your requests
     pub fn blocks(&self) -> BlockedUsersBuilder<'_, '_> {
@@ -99,7 +220,12 @@ impl<'octo> UserHandler<'octo> {
     pub async fn is_blocked(&self, username: &str) -> crate::Result<bool> {
         let route = format!("/user/blocks/{username}");
         let response = self.crab._get(route).await?;
-        Ok(response.status() == 204)
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(false);
+        }
+        crate::map_github_error(response)
+            .await
+            .map(|response| response.status() == StatusCode::NO_CONTENT)
     }
 
     ///## Blocks the given user
@@ -107,7 +233,7 @@ impl<'octo> UserHandler<'octo> {
     ///[GitHub App user access tokens](https://docs.github.com/en/apps/creating-github-apps/authenticating-with-a-github-app/generating-a-user-access-token-for-a-github-app)
     ///[Fine-grained personal access tokens](https://docs.github.com/en/authentication/keeping-your-account-and-data-secure/managing-your-personal-access-tokens#creating-a-fine-grained-personal-access-token)
     ///
-    ///The token must have the following permission set: `blocking:read`
+    ///The token must have the following permission set: `blocking:write`
     ///
     ///```no_run
     ///  async fn run() -> octocrab::Result<()> {
@@ -118,20 +244,8 @@ impl<'octo> UserHandler<'octo> {
     ///  }
     pub async fn block_user(&self, username: &str) -> crate::Result<()> {
         let route = format!("/user/blocks/{username}");
-        /* '204 not found' is returned if user blocked */
-        let result: crate::Result<()> = self.crab.put(route, None::<&()>).await;
-        match result {
-            Ok(_) => Err(error::Error::GitHub {
-                source: Box::new(GitHubError {
-                    status_code: StatusCode::OK,
-                    documentation_url: None,
-                    errors: None,
-                    message: "".to_string(),
-                }),
-                backtrace: Backtrace::capture(),
-            }),
-            Err(_v) => Ok(()),
-        }
+        let response = self.crab._put(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
     }
 
     ///## Unblocks the given user
@@ -139,7 +253,7 @@ impl<'octo> UserHandler<'octo> {
     ///[GitHub App user access tokens](https://docs.github.com/en/apps/creating-github-apps/authenticating-with-a-github-app/generating-a-user-access-token-for-a-github-app)
     ///[Fine-grained personal access tokens](https://docs.github.com/en/authentication/keeping-your-account-and-data-secure/managing-your-personal-access-tokens#creating-a-fine-grained-personal-access-token)
     ///
-    ///The token must have the following permission set: `blocking:read`
+    ///The token must have the following permission set: `blocking:write`
     ///
     ///```no_run
     ///  async fn run() -> octocrab::Result<()> {
@@ -150,8 +264,8 @@ impl<'octo> UserHandler<'octo> {
     ///  }
     pub async fn unblock_user(&self, username: &str) -> crate::Result<()> {
         let route = format!("/user/blocks/{username}");
-
-        self.crab.delete(route, None::<&()>).await
+        let response = self.crab._delete(route, None::<&()>).await?;
+        crate::map_github_error(response).await.map(drop)
     }
 
     ///## Set primary email visibility for the authenticated user
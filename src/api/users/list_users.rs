@@ -0,0 +1,56 @@
+use crate::models::{SimpleUser, UserId};
+use crate::{Octocrab, Page};
+
+/// A builder pattern struct for listing all users, in the order they signed
+/// up, including organizations.
+///
+/// created by [`Octocrab::list_users`]
+#[derive(serde::Serialize)]
+pub struct ListUsersBuilder<'octo> {
+    #[serde(skip)]
+    crab: &'octo Octocrab,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    since: Option<UserId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    per_page: Option<u8>,
+}
+
+impl<'octo> ListUsersBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab) -> Self {
+        Self {
+            crab,
+            since: None,
+            per_page: None,
+        }
+    }
+
+    /// Only list users with an ID greater than this one. Pagination of this
+    /// endpoint is driven by `since` alone; the `next` link of each page
+    /// carries the last ID seen.
+    pub fn since(mut self, since: impl Into<UserId>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    /// Results per page (max 100).
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// Sends the actual request.
+    pub async fn send(self) -> crate::Result<Page<SimpleUser>> {
+        self.crab.get("/users", Some(&self)).await
+    }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<SimpleUser>>> {
+        let crab = self.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
+}
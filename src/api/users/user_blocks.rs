@@ -52,6 +52,6 @@ impl<'octo, 'b> BlockedUsersBuilder<'octo, 'b> {
     ///  }
     pub async fn list(&self) -> crate::Result<crate::Page<models::SimpleUser>> {
         let route = "/user/blocks".to_string();
-        self.handler.crab.get(route, None::<&()>).await
+        self.handler.crab.get(route, Some(self)).await
     }
 }
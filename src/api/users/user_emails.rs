@@ -111,10 +111,6 @@ impl<'octo, 'b> UserEmailsOpsBuilder<'octo, 'b> {
             "emails": serde_json::Value::from(emails),
         });
         let response = self.handler.crab._post(route, Some(&params)).await?;
-        if response.status() != http::StatusCode::CREATED {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
         <Vec<UserEmailInfo>>::from_response(crate::map_github_error(response).await?).await
     }
 
@@ -142,10 +138,6 @@ impl<'octo, 'b> UserEmailsOpsBuilder<'octo, 'b> {
             "emails": serde_json::Value::from(emails),
         });
         let response = self.handler.crab._delete(route, Some(&params)).await?;
-        if response.status() != http::StatusCode::NO_CONTENT {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
-        Ok(())
+        crate::map_github_error(response).await.map(drop)
     }
 }
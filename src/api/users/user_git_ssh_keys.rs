@@ -87,10 +87,6 @@ impl<'octo, 'b> UserGitSshKeysOpsBuilder<'octo, 'b> {
             "key": key,
         });
         let response = self.handler.crab._post(route, Some(&params)).await?;
-        if response.status() != http::StatusCode::CREATED {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
         <GitSshKey>::from_response(crate::map_github_error(response).await?).await
     }
 
@@ -117,11 +113,7 @@ impl<'octo, 'b> UserGitSshKeysOpsBuilder<'octo, 'b> {
         let route = format!("/user/keys/{git_ssh_key_id}");
 
         let response = self.handler.crab._delete(route, None::<&()>).await?;
-        if response.status() != http::StatusCode::NO_CONTENT {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
-        Ok(())
+        crate::map_github_error(response).await.map(drop)
     }
 
     ///## Get a public SSH key for the authenticated user
@@ -109,10 +109,6 @@ impl<'octo, 'b> UserGpgKeysOpsBuilder<'octo, 'b> {
             "armored_public_key": armored_public_key,
         });
         let response = self.handler.crab._post(route, Some(&params)).await?;
-        if response.status() != http::StatusCode::CREATED {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
         <GpgKey>::from_response(crate::map_github_error(response).await?).await
     }
 
@@ -137,10 +133,6 @@ impl<'octo, 'b> UserGpgKeysOpsBuilder<'octo, 'b> {
         let route = format!("/user/gpg_keys/{gpg_key_id}");
 
         let response = self.handler.crab._delete(route, None::<&()>).await?;
-        if response.status() != http::StatusCode::NO_CONTENT {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
-        Ok(())
+        crate::map_github_error(response).await.map(drop)
     }
 }
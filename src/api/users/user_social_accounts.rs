@@ -84,10 +84,6 @@ impl<'octo, 'b> UserSocialAccountsOpsBuilder<'octo, 'b> {
             "account_urls": account_urls,
         });
         let response = self.handler.crab._post(route, Some(&params)).await?;
-        if response.status() != http::StatusCode::CREATED {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
         <Vec<SocialAccount>>::from_response(crate::map_github_error(response).await?).await
     }
 
@@ -119,10 +115,6 @@ impl<'octo, 'b> UserSocialAccountsOpsBuilder<'octo, 'b> {
         });
 
         let response = self.handler.crab._delete(route, Some(&params)).await?;
-        if response.status() != http::StatusCode::NO_CONTENT {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
-        Ok(())
+        crate::map_github_error(response).await.map(drop)
     }
 }
@@ -113,10 +113,6 @@ impl<'octo, 'b> UserSshSigningKeysOpsBuilder<'octo, 'b> {
             "key": key,
         });
         let response = self.handler.crab._post(route, Some(&params)).await?;
-        if response.status() != http::StatusCode::CREATED {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
         <SshSigningKey>::from_response(crate::map_github_error(response).await?).await
     }
 
@@ -143,10 +139,6 @@ impl<'octo, 'b> UserSshSigningKeysOpsBuilder<'octo, 'b> {
         let route = format!("/user/ssh_signing_keys/{ssh_signing_key_id}");
 
         let response = self.handler.crab._delete(route, None::<&()>).await?;
-        if response.status() != http::StatusCode::NO_CONTENT {
-            return Err(crate::map_github_error(response).await.unwrap_err());
-        }
-
-        Ok(())
+        crate::map_github_error(response).await.map(drop)
     }
 }
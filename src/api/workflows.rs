@@ -119,6 +119,59 @@ impl<'octo> WorkflowsHandler<'octo> {
     pub fn list_jobs(&self, run_id: RunId) -> ListJobsBuilder<'_, '_> {
         ListJobsBuilder::new(self, run_id)
     }
+
+    /// Gets the billable time a workflow used in the current billing cycle,
+    /// per runner operating system. workflow_file_or_id can be either file
+    /// name or numeric expression.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .workflows("owner", "repo")
+    ///     .get_workflow_usage("ci.yml")
+    ///     .await?;
+    /// for (os, timing) in usage.billable.iter() {
+    ///     println!("{os}: {}ms", timing.total_ms);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_workflow_usage(
+        &self,
+        workflow_file_or_id: impl Into<String>,
+    ) -> Result<models::workflows::WorkflowUsage> {
+        let route = format!(
+            "/repos/{owner}/{repo}/actions/workflows/{workflow}/timing",
+            owner = self.owner,
+            repo = self.repo,
+            workflow = workflow_file_or_id.into(),
+        );
+
+        self.crab.get(route, None::<&()>).await
+    }
+
+    /// Gets the billable time of each job of a run, per runner operating
+    /// system, and how long the run took.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let usage = octocrab::instance()
+    ///     .workflows("owner", "repo")
+    ///     .get_run_usage(1234u64.into())
+    ///     .await?;
+    /// if let Some(ubuntu) = usage.billable.ubuntu {
+    ///     println!("{} jobs took {}ms", ubuntu.jobs, ubuntu.total_ms);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_run_usage(&self, run_id: RunId) -> Result<models::workflows::RunUsage> {
+        let route = format!(
+            "/repos/{owner}/{repo}/actions/runs/{run_id}/timing",
+            owner = self.owner,
+            repo = self.repo,
+        );
+
+        self.crab.get(route, None::<&()>).await
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -161,6 +214,18 @@ impl<'octo, 'b> ListWorkflowsBuilder<'octo, 'b> {
         );
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::workflows::WorkFlow>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 /// The type of list workflow runs request.
@@ -285,6 +350,18 @@ impl<'octo, 'b> ListRunsBuilder<'octo, 'b> {
         };
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::workflows::Run>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 #[derive(serde::Serialize)]
@@ -340,6 +417,18 @@ impl<'octo, 'b> ListJobsBuilder<'octo, 'b> {
         );
         self.handler.crab.get(route, Some(&self)).await
     }
+
+    /// Sends the request and streams the results of all pages, fetching
+    /// each following page once the stream reaches it.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn into_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<models::workflows::Job>>>
+    {
+        let crab = self.handler.crab.clone();
+        Ok(self.send().await?.into_owned_stream(crab))
+    }
 }
 
 #[cfg(test)]
@@ -37,7 +37,12 @@ where
     }
 }
 
-// Define octocrab Body
+/// The body of a request.
+///
+/// Bodies built from bytes, such as a [`String`] or [`Bytes`], keep a copy
+/// of themselves so the request can be retried. Bodies streamed with
+/// [`OctoBody::new`], [`OctoBody::from_stream`] or [`OctoBody::from_reader`]
+/// can only be read once, so requests sending them are never retried.
 #[derive(Debug)]
 pub struct OctoBody {
     body: Arc<RwLock<BoxBody>>,
@@ -56,8 +61,59 @@ impl OctoBody {
     }
     /// Create an empty body.
     pub fn empty() -> Self {
-        Self::new(http_body_util::Empty::new())
+        // Buffered, so that requests without a body can be retried
+        Self::create(http_body_util::Empty::new(), Some(Bytes::new()))
     }
+
+    /// Create a body streaming the chunks of `stream`, e.g. a file being
+    /// read, without loading it into memory. If `content_length` is given
+    /// the body is sent with a `Content-Length` header, which some endpoints
+    /// such as release asset uploads require.
+    ///
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use bytes::Bytes;
+    /// use octocrab::OctoBody;
+    ///
+    /// let chunks = vec![Ok::<_, std::io::Error>(Bytes::from("some_data"))];
+    /// let body = OctoBody::from_stream(futures::stream::iter(chunks), Some(9));
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub fn from_stream<S, E>(stream: S, content_length: Option<u64>) -> Self
+    where
+        S: futures_core::Stream<Item = Result<Bytes, E>> + Send + Sync + 'static,
+        E: Into<BoxError>,
+    {
+        use futures_util::TryStreamExt;
+
+        let body = http_body_util::StreamBody::new(stream.map_ok(Frame::data));
+        match content_length {
+            Some(length) => Self::new(KnownLength { body, length }),
+            None => Self::new(body),
+        }
+    }
+
+    /// Create a body reading `reader` in chunks, e.g. a [`tokio::fs::File`],
+    /// without loading it into memory. `content_length` is the number of
+    /// bytes `reader` will produce.
+    #[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+    #[cfg_attr(docsrs, doc(cfg(feature = "tokio")))]
+    pub fn from_reader<R>(reader: R, content_length: u64) -> Self
+    where
+        R: tokio::io::AsyncRead + Send + Sync + 'static,
+    {
+        Self::new(KnownLength {
+            body: ReaderBody {
+                reader,
+                buf: vec![0; READ_CHUNK_SIZE].into_boxed_slice(),
+            },
+            length: content_length,
+        })
+    }
+
     /// Try to perform a deep clone of this body
     pub fn try_clone(&self) -> Option<Self> {
         self.buffered.as_ref().map(|buffered| {
@@ -79,6 +135,78 @@ impl OctoBody {
     }
 }
 
+/// A body of a known length, so that it's sent with a `Content-Length`
+/// header rather than chunked.
+#[cfg(any(
+    feature = "stream",
+    all(feature = "tokio", not(target_arch = "wasm32"))
+))]
+#[pin_project::pin_project]
+struct KnownLength<B> {
+    #[pin]
+    body: B,
+    length: u64,
+}
+
+#[cfg(any(
+    feature = "stream",
+    all(feature = "tokio", not(target_arch = "wasm32"))
+))]
+impl<B: http_body::Body> http_body::Body for KnownLength<B> {
+    type Data = B::Data;
+    type Error = B::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        self.project().body.poll_frame(cx)
+    }
+
+    fn size_hint(&self) -> http_body::SizeHint {
+        http_body::SizeHint::with_exact(self.length)
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.body.is_end_stream()
+    }
+}
+
+/// How much of a reader is read into each frame of a [`ReaderBody`].
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+
+/// A body reading an [`tokio::io::AsyncRead`] until it's exhausted.
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+#[pin_project::pin_project]
+struct ReaderBody<R> {
+    #[pin]
+    reader: R,
+    buf: Box<[u8]>,
+}
+
+#[cfg(all(feature = "tokio", not(target_arch = "wasm32")))]
+impl<R: tokio::io::AsyncRead> http_body::Body for ReaderBody<R> {
+    type Data = Bytes;
+    type Error = std::io::Error;
+
+    fn poll_frame(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let this = self.project();
+        let mut buf = tokio::io::ReadBuf::new(this.buf);
+        match this.reader.poll_read(cx, &mut buf) {
+            Poll::Pending => Poll::Pending,
+            Poll::Ready(Err(e)) => Poll::Ready(Some(Err(e))),
+            Poll::Ready(Ok(())) if buf.filled().is_empty() => Poll::Ready(None),
+            Poll::Ready(Ok(())) => {
+                Poll::Ready(Some(Ok(Frame::data(Bytes::copy_from_slice(buf.filled())))))
+            }
+        }
+    }
+}
+
 impl Default for OctoBody {
     fn default() -> Self {
         Self::empty()
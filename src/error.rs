@@ -28,6 +28,72 @@ pub enum Error {
         source: Box<GitHubError>,
         backtrace: Backtrace,
     },
+    /// GitHub refused a request with a 403 or 404 because the OAuth token
+    /// lacks the scopes the endpoint accepts.
+    #[snafu(display(
+        "The token's OAuth scopes ({}) don't include any of the scopes this request needs ({}): {}\n\nFound at {}",
+        have.join(", "),
+        need.join(", "),
+        source,
+        backtrace
+    ))]
+    InsufficientScopes {
+        /// The scopes of the token, from `X-OAuth-Scopes`.
+        have: Vec<String>,
+        /// The scopes of which the endpoint needs one, from
+        /// `X-Accepted-OAuth-Scopes`.
+        need: Vec<String>,
+        source: Box<GitHubError>,
+        backtrace: Backtrace,
+    },
+    /// The request is to an endpoint which the GitHub Enterprise Server the
+    /// client talks to doesn't have, see [`crate::ServerVersion`].
+    #[snafu(display(
+        "{} isn't available on GitHub Enterprise Server {}{}\n\nFound at {}",
+        endpoint,
+        version,
+        required.map(|required| format!(", it needs {required} or later")).unwrap_or_default(),
+        backtrace
+    ))]
+    UnsupportedOnThisServer {
+        /// The path of the request.
+        endpoint: String,
+        /// The version of the server.
+        version: crate::ServerVersion,
+        /// The first version with the endpoint, or `None` if it's only
+        /// available on GitHub.com.
+        required: Option<crate::ServerVersion>,
+        backtrace: Backtrace,
+    },
+    /// An error response whose body isn't a GitHub error, e.g. an HTML page
+    /// from a proxy in front of GitHub.
+    #[snafu(display(
+        "Unexpected error response {}{}: {}\n\nFound at {}",
+        status_code,
+        if *truncated { " (truncated)" } else { "" },
+        String::from_utf8_lossy(&body[..body.len().min(512)]),
+        backtrace
+    ))]
+    UnexpectedErrorBody {
+        status_code: http::StatusCode,
+        /// The start of the body, up to
+        /// [`OctocrabBuilder::error_body_limit`](crate::OctocrabBuilder::error_body_limit).
+        body: bytes::Bytes,
+        /// Whether the body was longer than the limit.
+        truncated: bool,
+        backtrace: Backtrace,
+    },
+    /// [`crate::initialise`] was called after the static instance was
+    /// already initialised, which keeps the first instance.
+    #[snafu(display(
+        "The static octocrab instance is already initialised\n\nFound at {}",
+        backtrace
+    ))]
+    AlreadyInitialised { backtrace: Backtrace },
+    /// [`crate::try_instance`] was called before the static instance was
+    /// initialised with [`crate::initialise`].
+    #[snafu(display("The static octocrab instance isn't initialised, call `octocrab::initialise` first\n\nFound at {}", backtrace))]
+    NotInitialised { backtrace: Backtrace },
     UriParse {
         source: UriParseError,
         backtrace: Backtrace,
@@ -130,3 +196,162 @@ impl fmt::Display for GitHubError {
 }
 
 impl std::error::Error for GitHubError {}
+
+impl GitHubError {
+    /// The entries of [`GitHubError::errors`], which GitHub sends with a
+    /// `422 Unprocessable Entity` to explain why the request was rejected.
+    /// Entries which are only a message have the code
+    /// [`ValidationErrorCode::Custom`].
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::{Error, ValidationErrorCode};
+    ///
+    /// let result = octocrab::instance()
+    ///     .issues("owner", "repo")
+    ///     .create_label("bug", "d73a4a", "Something isn't working")
+    ///     .await;
+    ///
+    /// match result {
+    ///     Err(Error::GitHub { source, .. })
+    ///         if source
+    ///             .validation_errors()
+    ///             .iter()
+    ///             .any(|error| error.code == ValidationErrorCode::AlreadyExists) =>
+    ///     {
+    ///         println!("the label already exists");
+    ///     }
+    ///     result => {
+    ///         result?;
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validation_errors(&self) -> Vec<ValidationError> {
+        self.errors
+            .iter()
+            .flatten()
+            .map(|error| match error {
+                serde_json::Value::String(message) => ValidationError {
+                    resource: None,
+                    field: None,
+                    code: ValidationErrorCode::Custom,
+                    message: Some(message.clone()),
+                },
+                error => {
+                    serde_json::from_value(error.clone()).unwrap_or_else(|_| ValidationError {
+                        resource: None,
+                        field: None,
+                        code: ValidationErrorCode::Custom,
+                        message: Some(error.to_string()),
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+/// The scopes of the token and the scopes the endpoint accepts, from the
+/// headers of a response, if the token has none of the accepted scopes.
+/// Tokens without scopes, such as fine-grained tokens and installation
+/// tokens, don't send `X-OAuth-Scopes`.
+pub(crate) fn missing_scopes(headers: &http::HeaderMap) -> Option<(Vec<String>, Vec<String>)> {
+    fn scopes(headers: &http::HeaderMap, name: &str) -> Option<Vec<String>> {
+        let scopes = headers.get(name)?.to_str().ok()?;
+        Some(
+            scopes
+                .split(',')
+                .map(str::trim)
+                .filter(|scope| !scope.is_empty())
+                .map(str::to_owned)
+                .collect(),
+        )
+    }
+
+    let have = scopes(headers, "x-oauth-scopes")?;
+    let need = scopes(headers, "x-accepted-oauth-scopes")?;
+    let satisfied = need
+        .iter()
+        .any(|need| have.iter().any(|have| scope_covers(have, need)));
+    (!need.is_empty() && !satisfied).then_some((have, need))
+}
+
+/// Whether a token with the scope `have` is granted the scope `need`, e.g.
+/// `repo` grants `public_repo` and `repo:status`, and `admin:org` grants
+/// `read:org`.
+fn scope_covers(have: &str, need: &str) -> bool {
+    fn level(scope: &str) -> (u8, &str) {
+        if let Some(resource) = scope.strip_prefix("admin:") {
+            (3, resource)
+        } else if let Some(resource) = scope.strip_prefix("write:") {
+            (2, resource)
+        } else if let Some(resource) = scope.strip_prefix("read:") {
+            (1, resource)
+        } else {
+            (3, scope)
+        }
+    }
+
+    if have == need
+        || need
+            .strip_prefix(have)
+            .is_some_and(|sub| sub.starts_with(':'))
+    {
+        return true;
+    }
+    match (have, need) {
+        ("repo", "public_repo" | "repo_deployment" | "security_events") => return true,
+        ("user", "read:user" | "user:email" | "user:follow") => return true,
+        _ => {}
+    }
+    let (have_level, have_resource) = level(have);
+    let (need_level, need_resource) = level(need);
+    have_resource == need_resource && have_level >= need_level
+}
+
+/// An entry of the `errors` of a [`GitHubError`], explaining what was wrong
+/// with a request.
+///
+/// See <https://docs.github.com/en/rest/using-the-rest-api/troubleshooting-the-rest-api#validation-failed>
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
+pub struct ValidationError {
+    /// The kind of resource the error is about, e.g. `Issue` or `Reference`.
+    pub resource: Option<String>,
+    /// The field of the request the error is about.
+    pub field: Option<String>,
+    /// What was wrong.
+    #[serde(default = "ValidationErrorCode::custom")]
+    pub code: ValidationErrorCode,
+    /// A description of the error, set for [`ValidationErrorCode::Custom`]
+    /// and sometimes for the other codes.
+    pub message: Option<String>,
+}
+
+/// What was wrong with a field of a request, see [`ValidationError`].
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ValidationErrorCode {
+    /// The resource doesn't exist.
+    Missing,
+    /// A required field wasn't set.
+    MissingField,
+    /// The format of the field is invalid.
+    Invalid,
+    /// Another resource already has the same value, e.g. a label with the
+    /// same name.
+    AlreadyExists,
+    /// The parameters are valid but can't be processed.
+    Unprocessable,
+    /// See the `message` of the error.
+    Custom,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+impl ValidationErrorCode {
+    fn custom() -> Self {
+        Self::Custom
+    }
+}
@@ -1,4 +1,4 @@
-//! Types for handling etags.
+//! Types for handling etags and other conditional requests.
 use std::{
     fmt::{self, Display},
     str::FromStr,
@@ -20,6 +20,47 @@ pub struct Etagged<T> {
     pub value: Option<T>,
 }
 
+/// Represents resources that are polled with `If-Modified-Since`, such as
+/// notifications, or with `If-None-Match`, such as events.
+#[derive(Debug, PartialEq)]
+pub struct Polled<T> {
+    /// The raw `Last-Modified` header of the response. Pass it back as
+    /// `If-Modified-Since` on the next poll to only receive new data.
+    pub last_modified: Option<String>,
+    /// The etag of the response. Pass it back as `If-None-Match` on the next
+    /// poll to only receive new data.
+    pub etag: Option<EntityTag>,
+    /// How long GitHub asks clients to wait before polling again, taken from
+    /// the `X-Poll-Interval` header.
+    pub poll_interval: Option<std::time::Duration>,
+    /// The polled value.
+    ///
+    /// This is `None` if nothing changed since `If-Modified-Since` or
+    /// `If-None-Match`.
+    pub value: Option<T>,
+}
+
+impl<T> Polled<T> {
+    pub(crate) fn extract_from_response<B>(response: &http::Response<B>, value: Option<T>) -> Self {
+        let headers = response.headers();
+        let last_modified = headers
+            .get(http::header::LAST_MODIFIED)
+            .and_then(|it| it.to_str().ok())
+            .map(str::to_owned);
+        let poll_interval = headers
+            .get("X-Poll-Interval")
+            .and_then(|it| it.to_str().ok())
+            .and_then(|it| it.parse().ok())
+            .map(std::time::Duration::from_secs);
+        Polled {
+            last_modified,
+            etag: EntityTag::extract_from_response(response),
+            poll_interval,
+            value,
+        }
+    }
+}
+
 /*
  * NOTE: The following code was copied from the "hyperx" crate (https://github.com/dekellum/hyperx/), which is no longer a dependency of this project.
  */
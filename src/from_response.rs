@@ -6,6 +6,12 @@ use snafu::ResultExt;
 /// A trait for mapping from a `http::Response` to an another type.
 #[async_trait::async_trait]
 pub trait FromResponse: Sized {
+    /// Whether the response is a page of a listing, which the client's
+    /// [`default_per_page`](crate::OctocrabBuilder::default_per_page)
+    /// applies to.
+    #[doc(hidden)]
+    const PAGINATED: bool = false;
+
     async fn from_response<B>(response: http::Response<B>) -> crate::Result<Self>
     where
         B: Body<Data = Bytes, Error = crate::Error> + Send;
@@ -23,3 +29,63 @@ impl<T: serde::de::DeserializeOwned> FromResponse for T {
         return serde_path_to_error::deserialize(de).context(crate::error::JsonSnafu);
     }
 }
+
+/// A response whose body is kept as it was received instead of being
+/// deserialized, for proxies and archival tools which pass responses on
+/// without needing the models.
+///
+/// Use it as the response type of the generic request methods, e.g.
+/// [`Octocrab::get`](crate::Octocrab::get). Error responses are still
+/// turned into [`Error::GitHub`](crate::Error::GitHub).
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// use octocrab::RawResponse;
+///
+/// let response: RawResponse = octocrab::instance()
+///     .get("/repos/XAMPPRocky/octocrab/issues", None::<&()>)
+///     .await?;
+///
+/// println!("{:?}", response.headers.get("link"));
+/// std::fs::write("issues.json", &response.body).unwrap();
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct RawResponse {
+    /// The status of the response.
+    pub status: http::StatusCode,
+    /// The headers of the response, e.g. its `link` and `etag`.
+    pub headers: http::HeaderMap,
+    /// The body of the response.
+    pub body: Bytes,
+}
+
+impl RawResponse {
+    /// Deserializes the body, e.g. into a model once it's needed after all.
+    pub fn json<T: serde::de::DeserializeOwned>(&self) -> crate::Result<T> {
+        let de = &mut serde_json::Deserializer::from_slice(&self.body);
+        serde_path_to_error::deserialize(de).context(crate::error::JsonSnafu)
+    }
+
+    /// Checks that the body is valid JSON and returns it without parsing it
+    /// any further.
+    pub fn raw_json(&self) -> crate::Result<Box<serde_json::value::RawValue>> {
+        self.json()
+    }
+}
+
+#[async_trait::async_trait]
+impl FromResponse for RawResponse {
+    async fn from_response<B>(response: http::Response<B>) -> crate::Result<Self>
+    where
+        B: Body<Data = Bytes, Error = crate::Error> + Send,
+    {
+        let (parts, body) = response.into_parts();
+        Ok(Self {
+            status: parts.status,
+            headers: parts.headers,
+            body: body.collect().await?.to_bytes(),
+        })
+    }
+}
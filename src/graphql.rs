@@ -0,0 +1,297 @@
+//! Types for building GraphQL requests.
+//!
+//! With the `graphql-client` feature, queries derived with
+//! [`graphql_client`](https://docs.rs/graphql_client) can be sent with
+//! [`Octocrab::graphql_typed`](crate::Octocrab::graphql_typed), and
+//! [`GITHUB_SCHEMA`] provides GitHub's schema to generate them from.
+
+use serde::Serialize;
+
+#[cfg(feature = "stream")]
+use {
+    crate::{error::SerdeSnafu, models::projects_v2::PageInfo, Octocrab},
+    futures_core::Stream,
+    futures_util::stream::try_unfold,
+    serde::de::DeserializeOwned,
+    snafu::ResultExt,
+};
+
+/// GitHub's public GraphQL schema, for generating compile-time checked
+/// queries without fetching it. `graphql_client` reads the schema from a
+/// file, so write it into your crate first, e.g. from a build script with
+/// `octocrab` as a build dependency:
+/// ```no_run
+/// std::fs::write("github_schema.graphql", octocrab::graphql::GITHUB_SCHEMA).unwrap();
+/// ```
+#[cfg(feature = "graphql-client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "graphql-client")))]
+pub const GITHUB_SCHEMA: &str = include_str!("../schema/github_schema.graphql");
+
+/// A GraphQL request with its variables and operation name, sent with
+/// [`Octocrab::graphql_send`](crate::Octocrab::graphql_send).
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// use octocrab::graphql::GraphQLQuery;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Data {
+///     repository: Repository,
+/// }
+///
+/// #[derive(serde::Deserialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Repository {
+///     stargazer_count: u64,
+/// }
+///
+/// let query = GraphQLQuery::new(
+///     "query GetRepo($owner: String!, $name: String!) {
+///         repository(owner: $owner, name: $name) { stargazerCount }
+///     }",
+/// )
+/// .var("owner", "XAMPPRocky")
+/// .var("name", "octocrab")
+/// .operation("GetRepo");
+///
+/// let data: Data = octocrab::instance().graphql_send(&query).await?;
+/// println!("{} stars", data.repository.stargazer_count);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GraphQLQuery {
+    query: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    operation_name: Option<String>,
+    variables: serde_json::Map<String, serde_json::Value>,
+    #[serde(skip)]
+    error: Option<String>,
+}
+
+impl GraphQLQuery {
+    /// Creates a request for a query or mutation document.
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            operation_name: None,
+            variables: serde_json::Map::new(),
+            error: None,
+        }
+    }
+
+    /// Sets the variable `name` to `value`. A value that fails to serialize
+    /// is reported when the request is sent.
+    pub fn var(mut self, name: impl Into<String>, value: impl Serialize) -> Self {
+        let name = name.into();
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.variables.insert(name, value);
+            }
+            Err(error) => {
+                self.error
+                    .get_or_insert(format!("failed to serialize variable `{name}`: {error}"));
+            }
+        }
+        self
+    }
+
+    /// The operation to run, when the document contains several.
+    pub fn operation(mut self, operation_name: impl Into<String>) -> Self {
+        self.operation_name = Some(operation_name.into());
+        self
+    }
+
+    pub(crate) fn error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+
+    /// Streams the nodes of the connection in the response, issuing the
+    /// query again with the `endCursor` of each page as the variable
+    /// `cursor` until `hasNextPage` is false.
+    ///
+    /// The query must select `pageInfo { hasNextPage endCursor }` and either
+    /// `nodes` or `edges { node }` of exactly one connection, which may be
+    /// nested anywhere in `data`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use futures_util::TryStreamExt;
+    /// use octocrab::graphql::GraphQLQuery;
+    ///
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct Issue {
+    ///     number: u64,
+    ///     title: String,
+    /// }
+    ///
+    /// let crab = octocrab::instance();
+    /// let issues: Vec<Issue> = GraphQLQuery::new(
+    ///     "query($owner: String!, $name: String!, $after: String) {
+    ///         repository(owner: $owner, name: $name) {
+    ///             issues(first: 100, after: $after) {
+    ///                 pageInfo { hasNextPage endCursor }
+    ///                 nodes { number title }
+    ///             }
+    ///         }
+    ///     }",
+    /// )
+    /// .var("owner", "XAMPPRocky")
+    /// .var("name", "octocrab")
+    /// .into_stream(&crab, "after")
+    /// .try_collect()
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub fn into_stream<T>(
+        self,
+        crab: &Octocrab,
+        cursor: impl Into<String>,
+    ) -> impl Stream<Item = crate::Result<T>> + '_
+    where
+        T: DeserializeOwned + 'static,
+    {
+        connection_stream(self, crab, cursor.into())
+    }
+}
+
+/// Several queries sent as one request with
+/// [`Octocrab::graphql_batch`](crate::Octocrab::graphql_batch), which saves
+/// a request per query when fetching the same data for many objects, e.g.
+/// the default branches of many repositories.
+///
+/// Each query is a top-level field with its selection, e.g.
+/// `repository(owner: "owner", name: "repo") { id }`, which is aliased so
+/// that several queries can use the same field. GitHub limits the cost of a
+/// request, so batches of more than about a hundred queries may fail.
+#[derive(Debug, Clone, Default)]
+pub struct GraphQLBatch {
+    queries: Vec<String>,
+}
+
+impl GraphQLBatch {
+    /// Creates an empty batch.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a query to the batch. Use [`quote`] to pass strings as
+    /// arguments.
+    pub fn query(mut self, query: impl Into<String>) -> Self {
+        self.queries.push(query.into());
+        self
+    }
+
+    /// The number of queries in the batch.
+    pub fn len(&self) -> usize {
+        self.queries.len()
+    }
+
+    /// Whether the batch has no queries.
+    pub fn is_empty(&self) -> bool {
+        self.queries.is_empty()
+    }
+
+    pub(crate) fn document(&self) -> String {
+        let fields: String = self
+            .queries
+            .iter()
+            .enumerate()
+            .map(|(index, query)| format!("  {}: {query}\n", Self::alias(index)))
+            .collect();
+        format!("query {{\n{fields}}}")
+    }
+
+    pub(crate) fn alias(index: usize) -> String {
+        format!("q{index}")
+    }
+
+    pub(crate) fn index_of(alias: &str) -> Option<usize> {
+        alias.strip_prefix('q')?.parse().ok()
+    }
+}
+
+/// Quotes a string as a GraphQL string literal, escaping it as needed, to
+/// use it as an argument in a query.
+/// ```
+/// assert_eq!(octocrab::graphql::quote(r#"say "hi""#), r#""say \"hi\"""#);
+/// ```
+pub fn quote(value: &str) -> String {
+    // JSON strings are valid GraphQL strings.
+    serde_json::Value::from(value).to_string()
+}
+
+#[cfg(feature = "stream")]
+fn connection_stream<T>(
+    query: GraphQLQuery,
+    crab: &Octocrab,
+    cursor: String,
+) -> impl Stream<Item = crate::Result<T>> + '_
+where
+    T: DeserializeOwned + 'static,
+{
+    let state = ConnectionIterator {
+        crab,
+        query: Some(query),
+        cursor,
+        current: Vec::new().into_iter(),
+    };
+    try_unfold(state, |mut state| async move {
+        loop {
+            if let Some(node) = state.current.next() {
+                return Ok(Some((node, state)));
+            }
+            let Some(query) = state.query.take() else {
+                return Ok(None);
+            };
+            let mut data: serde_json::Value = state.crab.graphql_send(&query).await?;
+            let (page_info, nodes) = take_connection(&mut data)?;
+            state.current = serde_json::from_value::<Vec<T>>(nodes)
+                .context(SerdeSnafu)?
+                .into_iter();
+            if let (true, Some(end_cursor)) = (page_info.has_next_page, page_info.end_cursor) {
+                state.query = Some(query.var(&state.cursor, end_cursor));
+            }
+        }
+    })
+}
+
+#[cfg(feature = "stream")]
+struct ConnectionIterator<'octo, T> {
+    crab: &'octo Octocrab,
+    query: Option<GraphQLQuery>,
+    cursor: String,
+    current: std::vec::IntoIter<T>,
+}
+
+/// Takes the page info and the nodes of the connection in `data`.
+#[cfg(feature = "stream")]
+fn take_connection(data: &mut serde_json::Value) -> crate::Result<(PageInfo, serde_json::Value)> {
+    fn find(value: &mut serde_json::Value) -> Option<&mut serde_json::Value> {
+        if value.get("pageInfo").is_some() {
+            return Some(value);
+        }
+        match value {
+            serde_json::Value::Object(fields) => fields.values_mut().find_map(find),
+            _ => None,
+        }
+    }
+
+    let Some(connection) = find(data) else {
+        return Err(<serde_json::Error as serde::de::Error>::custom(
+            "no connection with `pageInfo` in the response",
+        ))
+        .context(SerdeSnafu);
+    };
+    let page_info = serde_json::from_value(connection["pageInfo"].take()).context(SerdeSnafu)?;
+    let nodes = match connection.get_mut("nodes") {
+        Some(nodes) => nodes.take(),
+        None => connection["edges"]
+            .as_array_mut()
+            .map(|edges| edges.iter_mut().map(|edge| edge["node"].take()).collect())
+            .unwrap_or_default(),
+    };
+    Ok((page_info, nodes))
+}
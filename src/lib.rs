@@ -11,18 +11,26 @@
 //!
 //! - [`actions`] GitHub Actions
 //! - [`activity`] GitHub Activity
+//! - [`advisories`] The global security advisory database
 //! - [`apps`] GitHub Apps
+//! - [`billing`] Billing of organizations, users and enterprises
 //! - [`checks`] GitHub Checks
 //! - [`code_scannings`] Code Scanning
+//! - [`codes_of_conduct`] Codes of Conduct
+//! - [`codespaces`] Codespaces
 //! - [`commits`] GitHub Commits
 //! - [`current`] Information about the current user.
+//! - [`enterprises`] GitHub Enterprises
 //! - [`events`] GitHub Events
 //! - [`gists`] Gists
 //! - [`gitignore`] Gitignore templates
-//! - [`Octocrab::graphql`] GraphQL.
+//! - [`inference`] GitHub Models, with the `models-api` feature
+//! - [`Octocrab::graphql`] GraphQL, see also [`graphql::GraphQLQuery`].
 //! - [`issues`] Issues and related items, e.g. comments, labels, etc.
 //! - [`licenses`] License Metadata.
 //! - [`markdown`] Rendering Markdown with GitHub
+//! - [`meta`] Information about GitHub, e.g. IP ranges, emojis and zen
+//! - [`migrations`] Migrations, exporting repositories into an archive
 //! - [`orgs`] GitHub Organisations
 //! - [`projects`] GitHub Projects
 //! - [`pulls`] Pull Requests
@@ -170,7 +178,7 @@
 //! // instance of the client.
 //! # use octocrab::Octocrab;
 //! tokio_test::block_on(async {
-//! octocrab::initialise(Octocrab::default());
+//! octocrab::initialise(Octocrab::default()).unwrap();
 //! // Gets a instance of `Octocrab` from the static API. If you call this
 //! // without first calling `octocrab::initialise` a default client will be
 //! // initialised and returned instead.
@@ -189,6 +197,9 @@
 //! **Note**: Webhook support in `octocrab` is still beta, not all known webhook events are
 //! strongly typed.
 //!
+//! Check that a request comes from GitHub with [`webhooks::verify_signature`]
+//! before handling it.
+//!
 //! ```no_run
 //! # use http::request::Request;
 //! # use tracing::{warn, info};
@@ -214,26 +225,41 @@ mod api;
 mod body;
 mod error;
 mod from_response;
+mod metadata;
 mod page;
+mod ready;
+mod route;
+mod server_version;
 
 pub mod internal;
 
 pub mod auth;
 use auth::{AppAuth, Auth};
 pub mod etag;
+pub mod graphql;
 pub mod models;
 pub mod params;
+#[cfg(feature = "stream")]
+#[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+pub mod poller;
+pub mod request;
 pub mod service;
+#[cfg(all(feature = "test-utils", not(target_arch = "wasm32")))]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod test_utils;
+#[cfg(feature = "webhooks")]
+#[cfg_attr(docsrs, doc(cfg(feature = "webhooks")))]
+pub mod webhooks;
 
 use api::repos::RepoRef;
 use api::users::UserRef;
-use body::OctoBody;
 use chrono::{DateTime, Utc};
 use http::{HeaderMap, HeaderValue, Method, Uri};
 use http_body_util::combinators::BoxBody;
 use http_body_util::BodyExt;
 use service::middleware::auth_header::AuthHeaderLayer;
 use service::middleware::cache::{CacheStorage, HttpCacheLayer};
+use service::middleware::cassette::{Cassette, CassetteLayer};
 use std::convert::{Infallible, TryInto};
 use std::fmt;
 use std::future::Future;
@@ -270,7 +296,6 @@ use tower_http::{classify::ServerErrorsFailureClass, map_response_body::MapRespo
 #[cfg(feature = "tracing")]
 use {tower_http::trace::TraceLayer, tracing::Span};
 
-use crate::api::codes_of_conduct;
 use crate::error::{
     HttpSnafu, HyperSnafu, InvalidUtf8Snafu, SerdeSnafu, SerdeUrlEncodedSnafu, ServiceSnafu,
     UriParseError, UriParseSnafu, UriSnafu,
@@ -280,26 +305,38 @@ use crate::service::middleware::base_uri::BaseUriLayer;
 use crate::service::middleware::extra_headers::ExtraHeadersLayer;
 
 #[cfg(feature = "retry")]
-use crate::service::middleware::retry::RetryConfig;
+use crate::service::middleware::retry::{RetryConfig, RetryPolicy};
 
 use models::{AppId, InstallationId, InstallationToken, RepositoryId, UserId};
 
 pub use self::{
     api::{
-        actions, activity, apps, checks, classroom, code_scannings, commits, current, events,
-        gists, gitignore, hooks, issues, licenses, markdown, orgs, projects, pulls, ratelimit,
-        repos, search, teams, users, workflows,
+        actions, activity, advisories, apps, billing, checks, classroom, code_scannings,
+        codes_of_conduct, codespaces, commits, current, enterprises, events, gists, gitignore,
+        hooks, issues, licenses, markdown, meta, migrations, orgs, projects, pulls, ratelimit,
+        reactions, repos, search, teams, users, workflows,
     },
-    error::{Error, GitHubError},
-    from_response::FromResponse,
-    page::Page,
+    body::OctoBody,
+    error::{Error, GitHubError, ValidationError, ValidationErrorCode},
+    from_response::{FromResponse, RawResponse},
+    metadata::{ResponseMetadata, WithMetadata},
+    page::{AllPagesOptions, Page, PaginationState, Progress, Rel},
+    ready::ReadyBackoff,
+    server_version::ServerVersion,
 };
 
+#[cfg(feature = "models-api")]
+#[cfg_attr(docsrs, doc(cfg(feature = "models-api")))]
+pub use self::api::inference;
+
 /// A convenience type with a default error type of [`Error`].
 pub type Result<T, E = error::Error> = std::result::Result<T, E>;
 
 const GITHUB_BASE_URI: &str = "https://api.github.com";
 const GITHUB_BASE_UPLOAD_URI: &str = "https://uploads.github.com";
+#[cfg(feature = "models-api")]
+const GITHUB_MODELS_URI: &str = "https://models.github.ai";
+const DEFAULT_ERROR_BODY_LIMIT: usize = 64 * 1024;
 
 // This `include!` gives us pub const _SET_HEADERS_MAP: [(&str, &str)]
 // generated from Cargo.toml `[package.metadata.github-api].request-headers` array, like
@@ -310,9 +347,14 @@ const GITHUB_BASE_UPLOAD_URI: &str = "https://uploads.github.com";
 // ```
 include!(concat!(env!("OUT_DIR"), "/headers_metadata.rs"));
 
+/// The instance set by [`initialise`], if any.
 #[cfg(feature = "default-client")]
-static STATIC_INSTANCE: Lazy<arc_swap::ArcSwap<Octocrab>> =
-    Lazy::new(|| arc_swap::ArcSwap::from_pointee(Octocrab::default()));
+static STATIC_INSTANCE: Lazy<arc_swap::ArcSwapOption<Octocrab>> =
+    Lazy::new(arc_swap::ArcSwapOption::empty);
+
+/// The instance [`instance`] returns until one is initialised.
+#[cfg(feature = "default-client")]
+static DEFAULT_INSTANCE: Lazy<Arc<Octocrab>> = Lazy::new(|| Arc::new(Octocrab::default()));
 
 /// Formats a GitHub preview from it's name into the full value for the
 /// `Accept` header.
@@ -340,6 +382,21 @@ pub fn format_media_type(media_type: impl AsRef<str>) -> String {
     format!("application/vnd.github.v3.{media_type}{json_suffix}")
 }
 
+/// The URI of another API on the host of a GitHub Enterprise Server, whose
+/// REST API is at `/api/v3`, e.g. `/api/graphql`. `None` for other hosts.
+fn enterprise_server_uri(base_uri: &Uri, path: &str) -> Option<Uri> {
+    let prefix = base_uri
+        .path()
+        .trim_end_matches('/')
+        .strip_suffix("/api/v3")?;
+    Uri::builder()
+        .scheme(base_uri.scheme()?.clone())
+        .authority(base_uri.authority()?.clone())
+        .path_and_query(format!("{prefix}{path}"))
+        .build()
+        .ok()
+}
+
 #[derive(Debug, Deserialize)]
 struct GitHubErrorBody {
     pub documentation_url: Option<String>,
@@ -347,6 +404,32 @@ struct GitHubErrorBody {
     pub message: String,
 }
 
+/// How much of the body of an error response to read, see
+/// [`OctocrabBuilder::error_body_limit`]. Responses carry it in their
+/// extensions, so that [`map_github_error`] can find it.
+#[derive(Debug, Clone, Copy)]
+struct ErrorBodyLimit(usize);
+
+/// Reads up to `limit` bytes of `body`, returning them and whether the rest
+/// of the body was dropped.
+async fn collect_limited(
+    mut body: BoxBody<Bytes, crate::Error>,
+    limit: usize,
+) -> Result<(Bytes, bool)> {
+    let mut collected = bytes::BytesMut::new();
+    while let Some(frame) = body.frame().await {
+        let Ok(data) = frame?.into_data() else {
+            continue;
+        };
+        if collected.len() + data.len() > limit {
+            collected.extend_from_slice(&data[..limit - collected.len()]);
+            return Ok((collected.freeze(), true));
+        }
+        collected.extend_from_slice(&data);
+    }
+    Ok((collected.freeze(), false))
+}
+
 /// Maps a GitHub error response into and `Err()` variant if the status is
 /// not a success.
 pub async fn map_github_error(
@@ -356,38 +439,76 @@ pub async fn map_github_error(
         Ok(response)
     } else {
         let (parts, body) = response.into_parts();
-        let GitHubErrorBody {
+        let limit = parts
+            .extensions
+            .get::<ErrorBodyLimit>()
+            .map_or(DEFAULT_ERROR_BODY_LIMIT, |limit| limit.0);
+        let (body, truncated) = collect_limited(body, limit).await?;
+        let parsed = (!truncated)
+            .then(|| serde_json::from_slice::<GitHubErrorBody>(&body).ok())
+            .flatten();
+        let Some(GitHubErrorBody {
             documentation_url,
             errors,
             message,
-        } = serde_json::from_slice(body.collect().await?.to_bytes().as_ref())
-            .context(error::SerdeSnafu)?;
+        }) = parsed
+        else {
+            return Err(error::Error::UnexpectedErrorBody {
+                status_code: parts.status,
+                body,
+                truncated,
+                backtrace: Backtrace::capture(),
+            });
+        };
 
+        let source = Box::new(GitHubError {
+            status_code: parts.status,
+            documentation_url,
+            errors,
+            message,
+        });
+        if matches!(parts.status, StatusCode::FORBIDDEN | StatusCode::NOT_FOUND) {
+            if let Some((have, need)) = error::missing_scopes(&parts.headers) {
+                return Err(error::Error::InsufficientScopes {
+                    have,
+                    need,
+                    source,
+                    backtrace: Backtrace::capture(),
+                });
+            }
+        }
         Err(error::Error::GitHub {
-            source: Box::new(GitHubError {
-                status_code: parts.status,
-                documentation_url,
-                errors,
-                message,
-            }),
+            source,
             backtrace: Backtrace::capture(),
         })
     }
 }
 
-/// Initialises the static instance using the configuration set by
-/// `builder`.
+/// Initialises the static instance with `crab` and returns it.
+///
+/// The static instance can only be initialised once, so that every part of
+/// a program sees the same client. Later calls fail with
+/// [`Error::AlreadyInitialised`] and keep the first instance, so calling it
+/// again, e.g. from several tests, is harmless as long as the error is
+/// ignored. Use [`instance`] to get the instance that was kept.
 /// ```
 /// # #[tokio::main]
 /// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
-/// let octocrab = octocrab::initialise(octocrab::Octocrab::default());
+/// let octocrab = octocrab::initialise(octocrab::Octocrab::default())?;
+///
+/// assert!(octocrab::initialise(octocrab::Octocrab::default()).is_err());
 /// # Ok(())
 /// # }
 /// ```
 #[cfg(feature = "default-client")]
 #[cfg_attr(docsrs, doc(cfg(feature = "default-client")))]
-pub fn initialise(crab: Octocrab) -> Arc<Octocrab> {
-    STATIC_INSTANCE.swap(Arc::from(crab))
+pub fn initialise(crab: Octocrab) -> Result<Arc<Octocrab>> {
+    let crab = Arc::new(crab);
+    let previous = STATIC_INSTANCE.compare_and_swap(&None::<Arc<Octocrab>>, Some(crab.clone()));
+    match &*previous {
+        Some(_) => error::AlreadyInitialisedSnafu.fail(),
+        None => Ok(crab),
+    }
 }
 
 /// Returns a new instance of [`Octocrab`]. If it hasn't been previously
@@ -401,7 +522,38 @@ pub fn initialise(crab: Octocrab) -> Arc<Octocrab> {
 #[cfg(feature = "default-client")]
 #[cfg_attr(docsrs, doc(cfg(feature = "default-client")))]
 pub fn instance() -> Arc<Octocrab> {
-    STATIC_INSTANCE.load().clone()
+    try_instance().unwrap_or_else(|_| DEFAULT_INSTANCE.clone())
+}
+
+/// Returns the instance set by [`initialise`], or fails with
+/// [`Error::NotInitialised`] if there is none, for programs which shouldn't
+/// fall back to an unauthenticated client.
+/// ```
+/// #[tokio::main]
+/// async fn main() -> () {
+/// assert!(octocrab::try_instance().is_err());
+/// }
+/// ```
+#[cfg(feature = "default-client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "default-client")))]
+pub fn try_instance() -> Result<Arc<Octocrab>> {
+    STATIC_INSTANCE
+        .load_full()
+        .context(error::NotInitialisedSnafu)
+}
+
+/// Clears the static instance, so that tests can [`initialise`] it with a
+/// client of their own, e.g. one pointing at a mock server.
+///
+/// Tests run in parallel by default, so tests which use the static instance
+/// should run one at a time.
+#[cfg(all(feature = "default-client", feature = "test-utils"))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(feature = "default-client", feature = "test-utils")))
+)]
+pub fn reset() {
+    STATIC_INSTANCE.store(None);
 }
 
 type Executor = Box<dyn Fn(Pin<Box<dyn Future<Output = ()>>>)>;
@@ -588,6 +740,17 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         self
     }
 
+    /// Also retry requests which aren't idempotent, i.e. `POST` and `PATCH`,
+    /// which could then e.g. create a comment twice when GitHub created it
+    /// but the response got lost. Off by default. See
+    /// [`RetryPolicy`](crate::service::middleware::retry::RetryPolicy).
+    #[cfg(feature = "retry")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "retry")))]
+    pub fn retry_non_idempotent(mut self, retry: bool) -> Self {
+        self.config.retry_non_idempotent = retry;
+        self
+    }
+
     /// Set the connect timeout.
     #[cfg(feature = "timeout")]
     #[cfg_attr(docsrs, doc(cfg(feature = "timeout")))]
@@ -668,7 +831,10 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         Ok(self)
     }
 
-    /// Set the base upload url for `Octocrab`.
+    /// Set the base upload url for `Octocrab`, which release assets are
+    /// uploaded to. Defaults to `/api/uploads` on the host of a GitHub
+    /// Enterprise Server [`base_uri`](Self::base_uri) ending in `/api/v3`,
+    /// and to `https://uploads.github.com` otherwise.
     pub fn upload_uri(mut self, upload_uri: impl TryInto<Uri>) -> Result<Self> {
         self.config.upload_uri = Some(
             upload_uri
@@ -679,6 +845,88 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         Ok(self)
     }
 
+    /// Set the url of the GraphQL API for `Octocrab`. Defaults to
+    /// `/api/graphql` on the host of a GitHub Enterprise Server
+    /// [`base_uri`](Self::base_uri) ending in `/api/v3`, and to `/graphql`
+    /// under the base url otherwise.
+    pub fn graphql_uri(mut self, graphql_uri: impl TryInto<Uri>) -> Result<Self> {
+        self.config.graphql_uri = Some(
+            graphql_uri
+                .try_into()
+                .map_err(|_| UriParseError {})
+                .context(UriParseSnafu)?,
+        );
+        Ok(self)
+    }
+
+    /// Set the url of the GitHub Models API for `Octocrab`, see
+    /// [`Octocrab::models_api`]. Defaults to `https://models.github.ai`.
+    #[cfg(feature = "models-api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "models-api")))]
+    pub fn models_uri(mut self, models_uri: impl TryInto<Uri>) -> Result<Self> {
+        self.config.models_uri = Some(
+            models_uri
+                .try_into()
+                .map_err(|_| UriParseError {})
+                .context(UriParseSnafu)?,
+        );
+        Ok(self)
+    }
+
+    /// Wait for the rate limit window of a resource to reset before sending
+    /// a request once its budget is used up, instead of letting GitHub
+    /// reject the request. Each resource is throttled separately, so e.g. a
+    /// bulk search job waiting on the search budget of 30 requests per
+    /// minute doesn't hold up other requests.
+    ///
+    /// The budgets are taken from the `x-ratelimit-*` headers of previous
+    /// responses, see [`ratelimit::RateLimitHandler::snapshot`].
+    pub fn throttle_rate_limits(mut self, throttle: bool) -> Self {
+        self.config.throttle_rate_limits = throttle;
+        self
+    }
+
+    /// Add `rateLimit` to the GraphQL queries sent with
+    /// [`Octocrab::graphql`] and record the point budget it reports, so it
+    /// shows up in [`ratelimit::RateLimitHandler::snapshot`] under `graphql`
+    /// and [`OctocrabBuilder::throttle_rate_limits`] takes it into account,
+    /// along with the points each query cost, see
+    /// [`RateLimitSnapshot::last_graphql_cost`](ratelimit::RateLimitSnapshot::last_graphql_cost).
+    /// The field is removed from the response before it's deserialized.
+    ///
+    /// Only queries are tracked, as mutations can't select `rateLimit`.
+    pub fn track_graphql_rate_limit(mut self, track: bool) -> Self {
+        self.config.track_graphql_rate_limit = track;
+        self
+    }
+
+    /// The number of results per page (max 100) to request from list
+    /// endpoints when a request doesn't set `per_page` itself. GitHub
+    /// otherwise returns 30 results per page, which takes more requests to
+    /// go through long listings.
+    ///
+    /// # Panics
+    /// If `per_page` is more than 100.
+    pub fn default_per_page(mut self, per_page: u8) -> Self {
+        assert!(
+            per_page <= 100,
+            "GitHub returns at most 100 results per page"
+        );
+        self.config.default_per_page = Some(per_page);
+        self
+    }
+
+    /// Sets how many bytes of the body of an error response are read, 64 KiB
+    /// by default. The body is only read once a response turns out to be an
+    /// error, and whatever is past the limit is dropped, so that a proxy
+    /// answering with a huge HTML page can't exhaust memory. Error bodies
+    /// which are cut off or aren't GitHub errors are returned as
+    /// [`Error::UnexpectedErrorBody`].
+    pub fn error_body_limit(mut self, limit: usize) -> Self {
+        self.config.error_body_limit = limit;
+        self
+    }
+
     pub fn cache<C>(mut self, cache: C) -> Self
     where
         C: CacheStorage + 'static,
@@ -687,13 +935,55 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
         self
     }
 
+    /// Record the responses to the requests into a [`Cassette`], or replay
+    /// the responses recorded into it without sending the requests, e.g. to
+    /// test code using octocrab against real responses without reaching
+    /// GitHub.
+    pub fn cassette(mut self, cassette: Cassette) -> Self {
+        self.config.cassette = Some(Arc::new(cassette));
+        self
+    }
+
+    /// Adds a [`Layer`] of middleware, e.g. for logging, to the service stack
+    /// of the client. Layers wrap the HTTP client and its retries, so they
+    /// see each request once with its full URI and authentication, and the
+    /// response before octocrab handles it. Like with
+    /// [`tower::ServiceBuilder`], the first layer added is the outermost.
+    /// Requests replayed from a [`cassette`](Self::cassette) don't reach
+    /// them.
+    pub fn layer<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<StackService> + Send + Sync + 'static,
+        L::Service:
+            Service<Request<OctoBody>, Response = Response<BoxBody<Bytes, Error>>> + Send + 'static,
+        <L::Service as Service<Request<OctoBody>>>::Future: Send + 'static,
+        <L::Service as Service<Request<OctoBody>>>::Error: Into<BoxError>,
+    {
+        self.config.layers.push(Box::new(move |service| {
+            BoxService::new(layer.layer(service).map_err(Into::into))
+        }));
+        self
+    }
+
+    /// The version of the GitHub Enterprise Server set with
+    /// [`OctocrabBuilder::base_uri`]. Requests to endpoints that version
+    /// doesn't have then fail with [`Error::UnsupportedOnThisServer`]
+    /// instead of a 404. See also [`Octocrab::detect_server_version`].
+    pub fn enterprise_server_version(mut self, version: ServerVersion) -> Self {
+        self.config.server_version = Some(version);
+        self
+    }
+
     #[cfg(feature = "retry")]
     #[cfg_attr(docsrs, doc(cfg(feature = "retry")))]
     pub fn set_connector_retry_service(
         &self,
         connector: crate::internal::http_client::HttpClient,
-    ) -> Retry<RetryConfig, crate::internal::http_client::HttpClient> {
-        let retry_layer = RetryLayer::new(self.config.retry_config.clone());
+    ) -> Retry<RetryPolicy, crate::internal::http_client::HttpClient> {
+        let retry_layer = RetryLayer::new(RetryPolicy::new(
+            self.config.retry_config.clone(),
+            self.config.retry_non_idempotent,
+        ));
 
         retry_layer.layer(connector)
     }
@@ -849,22 +1139,83 @@ impl OctocrabBuilder<NoSvc, DefaultOctocrabBuilderConfig, NoAuth, NotLayerReady>
             .config
             .upload_uri
             .clone()
-            .unwrap_or_else(|| Uri::from_str(GITHUB_BASE_UPLOAD_URI).unwrap());
+            .or_else(|| enterprise_server_uri(&base_uri, "/api/uploads"));
+        let graphql_uri = self
+            .config
+            .graphql_uri
+            .clone()
+            .or_else(|| enterprise_server_uri(&base_uri, "/api/graphql"));
+        #[cfg(feature = "models-api")]
+        let models_uri = self
+            .config
+            .models_uri
+            .clone()
+            .unwrap_or_else(|| Uri::from_static(GITHUB_MODELS_URI));
+
+        let client = self.config.layers.iter().rev().fold(
+            BoxService::new(client.map_err(Into::into)),
+            |client, layer| layer(client),
+        );
+
+        let client = CassetteLayer::new(self.config.cassette.clone()).layer(client);
 
         let client = BaseUriLayer::new(base_uri.clone()).layer(client);
 
-        let client = AuthHeaderLayer::new(auth_header, base_uri, upload_uri).layer(client);
+        let mut auth_header_layer = AuthHeaderLayer::new(
+            auth_header,
+            base_uri,
+            upload_uri
+                .clone()
+                .unwrap_or_else(|| Uri::from_str(GITHUB_BASE_UPLOAD_URI).unwrap()),
+        );
+        if let Some(graphql_uri) = &graphql_uri {
+            auth_header_layer = auth_header_layer.with_graphql_uri(graphql_uri.clone());
+        }
+        #[cfg(feature = "models-api")]
+        {
+            auth_header_layer = auth_header_layer.with_models_uri(models_uri.clone());
+        }
+        let client = auth_header_layer.layer(client);
 
         let client = HttpCacheLayer::new(self.config.cache_storage.clone()).layer(client);
 
+        let rate_limits = ratelimit::RateLimitSnapshot::new(
+            self.config.throttle_rate_limits,
+            self.config.track_graphql_rate_limit,
+        );
+        let server_version = Arc::new(RwLock::new(self.config.server_version));
+
         if let Some(executor) = self.executor {
-            return Ok(Octocrab::new_with_executor(client, auth_state, executor));
+            return Ok(Octocrab {
+                rate_limits,
+                default_per_page: self.config.default_per_page,
+                error_body_limit: self.config.error_body_limit,
+                server_version,
+                upload_uri,
+                graphql_uri,
+                #[cfg(feature = "models-api")]
+                models_uri,
+                ..Octocrab::new_with_executor(client, auth_state, executor)
+            });
         }
 
-        Ok(Octocrab::new(client, auth_state))
+        Ok(Octocrab {
+            rate_limits,
+            default_per_page: self.config.default_per_page,
+            error_body_limit: self.config.error_body_limit,
+            server_version,
+            upload_uri,
+            graphql_uri,
+            #[cfg(feature = "models-api")]
+            models_uri,
+            ..Octocrab::new(client, auth_state)
+        })
     }
 }
 
+/// The service stack of a client, which [`OctocrabBuilder::layer`] wraps.
+type StackService = BoxService<Request<OctoBody>, Response<BoxBody<Bytes, Error>>, BoxError>;
+
 pub struct DefaultOctocrabBuilderConfig {
     auth: Auth,
     previews: Vec<&'static str>,
@@ -877,9 +1228,21 @@ pub struct DefaultOctocrabBuilderConfig {
     write_timeout: Option<Duration>,
     base_uri: Option<Uri>,
     upload_uri: Option<Uri>,
+    graphql_uri: Option<Uri>,
+    #[cfg(feature = "models-api")]
+    models_uri: Option<Uri>,
     #[cfg(feature = "retry")]
     retry_config: RetryConfig,
+    #[cfg(feature = "retry")]
+    retry_non_idempotent: bool,
     cache_storage: Option<Arc<dyn CacheStorage>>,
+    cassette: Option<Arc<Cassette>>,
+    layers: Vec<Box<dyn Fn(StackService) -> StackService + Send + Sync>>,
+    server_version: Option<ServerVersion>,
+    throttle_rate_limits: bool,
+    track_graphql_rate_limit: bool,
+    default_per_page: Option<u8>,
+    error_body_limit: usize,
 }
 
 impl Default for DefaultOctocrabBuilderConfig {
@@ -896,9 +1259,21 @@ impl Default for DefaultOctocrabBuilderConfig {
             write_timeout: None,
             base_uri: None,
             upload_uri: None,
+            graphql_uri: None,
+            #[cfg(feature = "models-api")]
+            models_uri: None,
             #[cfg(feature = "retry")]
             retry_config: RetryConfig::Simple(3),
+            #[cfg(feature = "retry")]
+            retry_non_idempotent: false,
             cache_storage: None,
+            cassette: None,
+            layers: Vec::new(),
+            server_version: None,
+            throttle_rate_limits: false,
+            track_graphql_rate_limit: false,
+            default_per_page: None,
+            error_body_limit: DEFAULT_ERROR_BODY_LIMIT,
         }
     }
 }
@@ -1037,6 +1412,16 @@ pub type OctocrabService = Buffer<
 pub struct Octocrab {
     client: OctocrabService,
     auth_state: AuthState,
+    rate_limits: ratelimit::RateLimitSnapshot,
+    default_per_page: Option<u8>,
+    error_body_limit: usize,
+    metadata_sink: Option<metadata::MetadataSink>,
+    server_version: Arc<RwLock<Option<ServerVersion>>>,
+    upload_uri: Option<Uri>,
+    graphql_uri: Option<Uri>,
+    #[cfg(feature = "models-api")]
+    models_uri: Uri,
+    accept: Option<HeaderValue>,
 }
 
 impl fmt::Debug for Octocrab {
@@ -1081,6 +1466,16 @@ impl Octocrab {
         Self {
             client: service,
             auth_state,
+            rate_limits: ratelimit::RateLimitSnapshot::default(),
+            default_per_page: None,
+            error_body_limit: DEFAULT_ERROR_BODY_LIMIT,
+            metadata_sink: None,
+            server_version: Arc::default(),
+            upload_uri: None,
+            graphql_uri: None,
+            #[cfg(feature = "models-api")]
+            models_uri: Uri::from_static(GITHUB_MODELS_URI),
+            accept: None,
         }
     }
 
@@ -1102,6 +1497,16 @@ impl Octocrab {
         Self {
             client: service,
             auth_state,
+            rate_limits: ratelimit::RateLimitSnapshot::default(),
+            default_per_page: None,
+            error_body_limit: DEFAULT_ERROR_BODY_LIMIT,
+            metadata_sink: None,
+            server_version: Arc::default(),
+            upload_uri: None,
+            graphql_uri: None,
+            #[cfg(feature = "models-api")]
+            models_uri: Uri::from_static(GITHUB_MODELS_URI),
+            accept: None,
         }
     }
 
@@ -1127,6 +1532,16 @@ impl Octocrab {
                 installation: id,
                 token: CachedToken::default(),
             },
+            rate_limits: self.rate_limits.fresh(),
+            default_per_page: self.default_per_page,
+            error_body_limit: self.error_body_limit,
+            metadata_sink: None,
+            server_version: self.server_version.clone(),
+            upload_uri: self.upload_uri.clone(),
+            graphql_uri: self.graphql_uri.clone(),
+            #[cfg(feature = "models-api")]
+            models_uri: self.models_uri.clone(),
+            accept: None,
         })
     }
 
@@ -1188,8 +1603,112 @@ impl Octocrab {
             auth_state: AuthState::AccessToken {
                 token: token.into(),
             },
+            rate_limits: self.rate_limits.fresh(),
+            default_per_page: self.default_per_page,
+            error_body_limit: self.error_body_limit,
+            metadata_sink: None,
+            server_version: self.server_version.clone(),
+            upload_uri: self.upload_uri.clone(),
+            graphql_uri: self.graphql_uri.clone(),
+            #[cfg(feature = "models-api")]
+            models_uri: self.models_uri.clone(),
+            accept: None,
         })
     }
+
+    /// Runs `request` with a client which keeps the [`ResponseMetadata`]
+    /// of its responses, and returns the request's output with the metadata
+    /// of the last response. This gives access to the headers of the typed
+    /// methods, which otherwise only return the deserialized body.
+    ///
+    /// Fails with [`Error::Other`] if `request` didn't make a request.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let repo = octocrab::instance()
+    ///     .with_metadata(|crab| async move { crab.repos("XAMPPRocky", "octocrab").get().await })
+    ///     .await?;
+    ///
+    /// println!("{:?} stars", repo.stargazers_count);
+    /// println!("request id: {:?}", repo.metadata.request_id());
+    /// println!("rate limit: {:?}", repo.metadata.rate_limit());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn with_metadata<F, Fut, T>(&self, request: F) -> Result<WithMetadata<T>>
+    where
+        F: FnOnce(Octocrab) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let sink = metadata::MetadataSink::default();
+        let crab = Octocrab {
+            metadata_sink: Some(sink.clone()),
+            ..self.clone()
+        };
+        let value = request(crab).await?;
+        let metadata = sink.lock().unwrap().take();
+        match metadata {
+            Some(metadata) => Ok(WithMetadata { metadata, value }),
+            None => Err(Error::Other {
+                source: "no request was made to take the metadata from".into(),
+                backtrace: Backtrace::capture(),
+            }),
+        }
+    }
+
+    /// Returns a client which requests `media_type` in the `Accept` header
+    /// of its requests, instead of the media type the called method asks
+    /// for. Chain calls to request several media types, e.g. a preview
+    /// together with text matches. The client shares everything else with
+    /// this one, so it is cheap to create for a single call.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::params::MediaType;
+    ///
+    /// let issue = octocrab::instance()
+    ///     .accept(MediaType::Html)
+    ///     .issues("XAMPPRocky", "octocrab")
+    ///     .get(1)
+    ///     .await?;
+    ///
+    /// println!("{:?}", issue.body_html);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn accept(&self, media_type: params::MediaType) -> Octocrab {
+        let accept = match &self.accept {
+            Some(accept) => format!("{}, {media_type}", accept.to_str().unwrap_or_default()),
+            None => media_type.to_string(),
+        };
+        Octocrab {
+            accept: HeaderValue::from_str(&accept).ok(),
+            ..self.clone()
+        }
+    }
+
+    /// Gets the version of the GitHub Enterprise Server the client talks to
+    /// from `GET /meta`, and gates the following requests of the client and
+    /// its clones on it like
+    /// [`OctocrabBuilder::enterprise_server_version`]. Returns `None` on
+    /// GitHub.com, which has all endpoints.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let octocrab = octocrab::Octocrab::builder()
+    ///     .base_uri("https://ghe.example.com/api/v3")?
+    ///     .build()?;
+    /// if let Some(version) = octocrab.detect_server_version().await? {
+    ///     println!("GitHub Enterprise Server {version}");
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn detect_server_version(&self) -> Result<Option<ServerVersion>> {
+        let meta = self.meta().get().await?;
+        let version = meta
+            .installed_version
+            .and_then(|version| version.parse().ok());
+        *self.server_version.write().unwrap() = version;
+        Ok(version)
+    }
 }
 
 /// # GitHub API Methods
@@ -1200,6 +1719,12 @@ impl Octocrab {
         actions::ActionsHandler::new(self)
     }
 
+    /// Creates an [`advisories::AdvisoriesHandler`] for accessing GitHub's
+    /// global security advisory database.
+    pub fn advisories(&self) -> advisories::AdvisoriesHandler<'_> {
+        advisories::AdvisoriesHandler::new(self)
+    }
+
     /// Creates a [`current::CurrentAuthHandler`] that allows you to access
     /// information about the current authenticated user.
     pub fn current(&self) -> current::CurrentAuthHandler<'_> {
@@ -1276,6 +1801,12 @@ impl Octocrab {
         markdown::MarkdownHandler::new(self)
     }
 
+    /// Creates an [`enterprises::EnterpriseHandler`] for the specified
+    /// enterprise, that allows you to access GitHub's enterprise API.
+    pub fn enterprises(&self, enterprise: impl Into<String>) -> enterprises::EnterpriseHandler<'_> {
+        enterprises::EnterpriseHandler::new(self, enterprise.into())
+    }
+
     /// Creates an [`orgs::OrgHandler`] for the specified organization,
     /// that allows you to access GitHub's organization API.
     pub fn orgs(&self, owner: impl Into<String>) -> orgs::OrgHandler<'_> {
@@ -1336,6 +1867,23 @@ impl Octocrab {
         users::UserHandler::new(self, UserRef::ById(user.into()))
     }
 
+    /// Creates a [`users::ListUsersBuilder`] that lists every user and
+    /// organization on GitHub in the order they signed up.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let users = octocrab::instance()
+    ///     .list_users()
+    ///     .since(1000u64)
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn list_users(&self) -> users::ListUsersBuilder<'_> {
+        users::ListUsersBuilder::new(self)
+    }
+
     /// Creates a [`workflows::WorkflowsHandler`] for the specified repository that allows
     /// you to access GitHub's workflows API.
     pub fn workflows(
@@ -1349,7 +1897,7 @@ impl Octocrab {
     /// Creates an [`events::EventsBuilder`] that allows you to access
     /// GitHub's events API.
     pub fn events(&self) -> events::EventsBuilder<'_> {
-        events::EventsBuilder::new(self)
+        events::EventsBuilder::new(self, String::from("/events"))
     }
 
     /// Creates a [`gists::GistsHandler`] that allows you to access
@@ -1367,6 +1915,11 @@ impl Octocrab {
         checks::ChecksHandler::new(self, owner.into(), repo.into())
     }
 
+    /// Creates a [`meta::MetaHandler`] that returns information about GitHub.
+    pub fn meta(&self) -> meta::MetaHandler<'_> {
+        meta::MetaHandler::new(self)
+    }
+
     /// Creates a [`ratelimit::RateLimitHandler`] that returns the API rate limit.
     pub fn ratelimit(&self) -> ratelimit::RateLimitHandler<'_> {
         ratelimit::RateLimitHandler::new(self)
@@ -1387,10 +1940,24 @@ impl Octocrab {
         classroom::ClassroomHandler::new(self)
     }
 
-    /// Creates a [`codes_of_conduct::CodesOfConductHandler`] providing the GitHub Codes of Codes of Conduct API
+    /// Creates a [`codespaces::CodespacesHandler`] for managing the
+    /// codespaces of the authenticated user.
+    pub fn codespaces(&self) -> codespaces::CodespacesHandler<'_> {
+        codespaces::CodespacesHandler::new(self)
+    }
+
+    /// Creates a [`codes_of_conduct::CodesOfConductHandler`] providing the GitHub Codes of Conduct API
     pub fn codes_of_conduct(&self) -> codes_of_conduct::CodesOfConductHandler<'_> {
         codes_of_conduct::CodesOfConductHandler::new(self)
     }
+
+    /// Creates an [`inference::ModelsHandler`] for listing the models of
+    /// GitHub Models and running inference with them.
+    #[cfg(feature = "models-api")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "models-api")))]
+    pub fn models_api(&self) -> inference::ModelsHandler<'_> {
+        inference::ModelsHandler::new(self)
+    }
 }
 
 /// # GraphQL API.
@@ -1409,9 +1976,238 @@ impl Octocrab {
         &self,
         payload: &(impl serde::Serialize + ?Sized),
     ) -> crate::Result<R> {
-        self.post("/graphql", Some(&serde_json::json!(payload)))
+        let mut payload = serde_json::json!(payload);
+        if !self.rate_limits.tracks_graphql() || !ratelimit::inject_graphql_rate_limit(&mut payload)
+        {
+            return self.post(self.graphql_route(), Some(&payload)).await;
+        }
+
+        let response = self
+            ._post(
+                self.parameterized_uri(self.graphql_route(), None::<&()>)?,
+                Some(&payload),
+            )
+            .await?;
+        let (parts, body) = crate::map_github_error(response).await?.into_parts();
+        let mut body: serde_json::Value =
+            serde_json::from_slice(&body.collect().await?.to_bytes()).context(SerdeSnafu)?;
+        if let Some(data) = body.get_mut("data") {
+            self.rate_limits.record_graphql(data);
+        }
+        let body = serde_json::to_vec(&body).context(SerdeSnafu)?;
+        R::from_response(http::Response::from_parts(
+            parts,
+            http_body_util::Full::new(Bytes::from(body))
+                .map_err(|infallible| -> Error { match infallible {} }),
+        ))
+        .await
+    }
+
+    /// Runs a GraphQL mutation with `input` as its `$input` variable and
+    /// returns the response's `data`. GraphQL errors, which GitHub reports
+    /// with a `200 OK`, are turned into [`Error::GitHub`].
+    pub(crate) async fn graphql_mutation(
+        &self,
+        mutation: &str,
+        input: &impl Serialize,
+    ) -> Result<serde_json::Value> {
+        self.graphql_query(mutation, &serde_json::json!({ "input": input }))
             .await
     }
+
+    /// Runs a GraphQL query with `variables` and returns the response's
+    /// `data`, turning GraphQL errors into [`Error::GitHub`] like
+    /// [`Octocrab::graphql_mutation`].
+    pub(crate) async fn graphql_query(
+        &self,
+        query: &str,
+        variables: &impl Serialize,
+    ) -> Result<serde_json::Value> {
+        let response: serde_json::Value = self
+            .graphql(&serde_json::json!({
+                "query": query,
+                "variables": variables,
+            }))
+            .await?;
+        graphql_data(response)
+    }
+
+    /// Sends a [`graphql::GraphQLQuery`] and deserializes the response's
+    /// `data` into `T`. GraphQL errors, which GitHub reports with a `200 OK`,
+    /// are returned as [`Error::GitHub`] with all of them in
+    /// [`GitHubError::errors`].
+    /// ```no_run
+    ///# async fn run() -> octocrab::Result<()> {
+    /// use octocrab::graphql::GraphQLQuery;
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Viewer {
+    ///     login: String,
+    /// }
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Data {
+    ///     viewer: Viewer,
+    /// }
+    ///
+    /// let data: Data = octocrab::instance()
+    ///     .graphql_send(&GraphQLQuery::new("{ viewer { login } }"))
+    ///     .await?;
+    /// println!("{}", data.viewer.login);
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub async fn graphql_send<T: serde::de::DeserializeOwned>(
+        &self,
+        query: &graphql::GraphQLQuery,
+    ) -> Result<T> {
+        if let Some(error) = query.error() {
+            return Err(<serde_json::Error as serde::ser::Error>::custom(error))
+                .context(SerdeSnafu);
+        }
+        let response: serde_json::Value = self.graphql(query).await?;
+        serde_json::from_value(graphql_data(response)?).context(SerdeSnafu)
+    }
+
+    /// Sends a query derived with [`graphql_client`](https://docs.rs/graphql_client)
+    /// and returns its response data. GraphQL errors are returned as
+    /// [`Error::GitHub`] like with [`Octocrab::graphql_send`].
+    /// ```ignore
+    /// use graphql_client::GraphQLQuery;
+    ///
+    /// #[derive(GraphQLQuery)]
+    /// #[graphql(
+    ///     schema_path = "github_schema.graphql",
+    ///     query_path = "viewer_query.graphql",
+    /// )]
+    /// struct ViewerQuery;
+    ///
+    /// # async fn run() -> octocrab::Result<()> {
+    /// let data = octocrab::instance()
+    ///     .graphql_typed::<ViewerQuery>(viewer_query::Variables {})
+    ///     .await?;
+    /// println!("{}", data.viewer.login);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "graphql-client")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "graphql-client")))]
+    pub async fn graphql_typed<Q: graphql_client::GraphQLQuery>(
+        &self,
+        variables: Q::Variables,
+    ) -> Result<Q::ResponseData> {
+        let response: serde_json::Value = self.graphql(&Q::build_query(variables)).await?;
+        serde_json::from_value(graphql_data(response)?).context(SerdeSnafu)
+    }
+
+    /// Sends all queries of a [`graphql::GraphQLBatch`] in one request and
+    /// returns their results in the order they were added. A query that
+    /// failed, e.g. because the repository it looks up doesn't exist, only
+    /// fails its own result.
+    /// ```no_run
+    ///# async fn run() -> octocrab::Result<()> {
+    /// use octocrab::graphql::{quote, GraphQLBatch};
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// #[serde(rename_all = "camelCase")]
+    /// struct Repository {
+    ///     default_branch_ref: Option<Branch>,
+    /// }
+    ///
+    /// #[derive(serde::Deserialize)]
+    /// struct Branch {
+    ///     name: String,
+    /// }
+    ///
+    /// let repos = [("rust-lang", "rust"), ("XAMPPRocky", "octocrab")];
+    /// let batch = repos.iter().fold(GraphQLBatch::new(), |batch, (owner, name)| {
+    ///     batch.query(format!(
+    ///         "repository(owner: {}, name: {}) {{ defaultBranchRef {{ name }} }}",
+    ///         quote(owner),
+    ///         quote(name),
+    ///     ))
+    /// });
+    ///
+    /// let results = octocrab::instance()
+    ///     .graphql_batch::<Repository>(&batch)
+    ///     .await?;
+    /// for ((owner, name), result) in repos.iter().zip(results) {
+    ///     if let Some(branch) = result?.default_branch_ref {
+    ///         println!("{owner}/{name}: {}", branch.name);
+    ///     }
+    /// }
+    ///# Ok(())
+    ///# }
+    /// ```
+    pub async fn graphql_batch<T: serde::de::DeserializeOwned>(
+        &self,
+        batch: &graphql::GraphQLBatch,
+    ) -> Result<Vec<Result<T>>> {
+        if batch.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut response: serde_json::Value = self
+            .graphql(&serde_json::json!({ "query": batch.document() }))
+            .await?;
+        let errors = match response["errors"].take() {
+            serde_json::Value::Array(errors) => errors,
+            _ => Vec::new(),
+        };
+        // Errors which don't belong to one of the queries, e.g. a syntax
+        // error, fail the whole batch.
+        let alias_of = |error: &serde_json::Value| {
+            error["path"][0]
+                .as_str()
+                .and_then(graphql::GraphQLBatch::index_of)
+                .filter(|&index| index < batch.len())
+        };
+        if errors.iter().any(|error| alias_of(error).is_none()) {
+            return Err(graphql_error(errors));
+        }
+
+        let mut results: Vec<Result<T>> = (0..batch.len())
+            .map(|index| {
+                let data = response["data"][graphql::GraphQLBatch::alias(index)].take();
+                serde_json::from_value(data).context(SerdeSnafu)
+            })
+            .collect();
+        let mut failed = std::collections::BTreeMap::<usize, Vec<serde_json::Value>>::new();
+        for error in errors {
+            failed
+                .entry(alias_of(&error).unwrap())
+                .or_default()
+                .push(error);
+        }
+        for (index, errors) in failed {
+            results[index] = Err(graphql_error(errors));
+        }
+        Ok(results)
+    }
+}
+
+/// Takes the `data` of a GraphQL response, or turns its `errors` into
+/// [`Error::GitHub`].
+fn graphql_data(mut response: serde_json::Value) -> Result<serde_json::Value> {
+    match response.get("errors").and_then(|errors| errors.as_array()) {
+        Some(errors) if !errors.is_empty() => Err(graphql_error(errors.clone())),
+        _ => Ok(response["data"].take()),
+    }
+}
+
+/// Turns the `errors` of a GraphQL response into [`Error::GitHub`].
+fn graphql_error(errors: Vec<serde_json::Value>) -> Error {
+    Error::GitHub {
+        source: Box::new(GitHubError {
+            documentation_url: None,
+            message: errors[0]["message"]
+                .as_str()
+                .unwrap_or("GraphQL request failed")
+                .to_owned(),
+            errors: Some(errors),
+            status_code: http::StatusCode::OK,
+        }),
+        backtrace: snafu::Backtrace::capture(),
+    }
 }
 
 /// # HTTP Methods
@@ -1465,6 +2261,58 @@ impl Octocrab {
         self.get_with_headers(route, parameters, None).await
     }
 
+    /// Send a `GET` request to `route` like [`Octocrab::get`], for resources
+    /// which GitHub computes in the background. While GitHub answers
+    /// `202 Accepted` the request is retried according to `backoff`, giving
+    /// up with an error once its timeout has elapsed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::ReadyBackoff;
+    ///
+    /// let activity: serde_json::Value = octocrab::instance()
+    ///     .get_when_ready(
+    ///         "/repos/rust-lang/rust/stats/commit_activity",
+    ///         None::<&()>,
+    ///         ReadyBackoff::default(),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_when_ready<R, A, P>(
+        &self,
+        route: A,
+        parameters: Option<&P>,
+        backoff: ReadyBackoff,
+    ) -> Result<R>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+        R: FromResponse,
+    {
+        use crate::internal::async_runtime::{sleep, time::instant_now, TimeoutError};
+
+        let uri = self.get_uri::<R, _, _>(route, parameters)?;
+        let start = instant_now();
+        let mut delay = backoff.initial_delay;
+        loop {
+            let response = crate::map_github_error(self._get(uri.clone()).await?).await?;
+            if response.status() != http::StatusCode::ACCEPTED {
+                return R::from_response(response).await;
+            }
+
+            let remaining = backoff.timeout.saturating_sub(start.elapsed());
+            if remaining.is_zero() {
+                return Err(crate::Error::Other {
+                    source: Box::new(TimeoutError),
+                    backtrace: snafu::Backtrace::capture(),
+                });
+            }
+            sleep(delay.min(remaining)).await;
+            delay = (delay * 2).min(backoff.max_delay);
+        }
+    }
+
     /// Send a `GET` request with no additional post-processing.
     pub async fn _get(
         &self,
@@ -1473,6 +2321,38 @@ impl Octocrab {
         self._get_with_headers(uri, None).await
     }
 
+    /// The route of GraphQL requests, see [`OctocrabBuilder::graphql_uri`].
+    fn graphql_route(&self) -> String {
+        match &self.graphql_uri {
+            Some(graphql_uri) => graphql_uri.to_string(),
+            None => String::from("/graphql"),
+        }
+    }
+
+    /// The url of a route of the GitHub Models API, see
+    /// [`OctocrabBuilder::models_uri`].
+    #[cfg(feature = "models-api")]
+    pub(crate) fn models_route(&self, route: &str) -> String {
+        format!(
+            "{}{}",
+            self.models_uri.to_string().trim_end_matches('/'),
+            route
+        )
+    }
+
+    /// Points the `upload_url` of a release at the configured upload url,
+    /// see [`OctocrabBuilder::upload_uri`].
+    pub(crate) fn upload_route(&self, upload_url: &str) -> String {
+        let (Some(upload_uri), Some(index)) = (&self.upload_uri, upload_url.find("/repos/")) else {
+            return upload_url.to_owned();
+        };
+        format!(
+            "{}{}",
+            upload_uri.to_string().trim_end_matches('/'),
+            &upload_url[index..]
+        )
+    }
+
     /// Convenience method to accept any &str, and attempt to convert it to a Uri.
     /// the method also attempts to serialize any parameters into a query string, and append it to the uri.
     fn parameterized_uri<A, P>(&self, uri: A, parameters: Option<&P>) -> Result<Uri>
@@ -1499,12 +2379,51 @@ impl Octocrab {
         uri
     }
 
+    /// The URI of a `GET` request, which asks for the default number of
+    /// results per page if `R` is a page of a listing.
+    fn get_uri<R, A, P>(&self, uri: A, parameters: Option<&P>) -> Result<Uri>
+    where
+        R: FromResponse,
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+    {
+        if R::PAGINATED {
+            self.list_uri(uri, parameters)
+        } else {
+            self.parameterized_uri(uri, parameters)
+        }
+    }
+
+    /// The URI of the first page of a listing, which asks for the default
+    /// number of results per page, if one was set, unless the request sets
+    /// its own. The links to the following pages, which are absolute, are
+    /// kept as GitHub sent them.
+    fn list_uri<A, P>(&self, uri: A, parameters: Option<&P>) -> Result<Uri>
+    where
+        A: AsRef<str>,
+        P: Serialize + ?Sized,
+    {
+        let uri = self.parameterized_uri(uri, parameters)?;
+        let Some(per_page) = self.default_per_page else {
+            return Ok(uri);
+        };
+        if uri.scheme().is_some() {
+            return Ok(uri);
+        }
+        let sets_per_page = url::form_urlencoded::parse(uri.query().unwrap_or("").as_bytes())
+            .any(|(key, _)| key == "per_page");
+        if sets_per_page {
+            return Ok(uri);
+        }
+        self.parameterized_uri(uri.to_string(), Some(&[("per_page", per_page)]))
+    }
+
     pub async fn body_to_string(
         &self,
         res: http::Response<BoxBody<Bytes, crate::Error>>,
     ) -> Result<String> {
         let body_bytes = res.into_body().collect().await?.to_bytes();
-        String::from_utf8(body_bytes.to_vec()).context(InvalidUtf8Snafu)
+        String::from_utf8(Vec::from(body_bytes)).context(InvalidUtf8Snafu)
     }
 
     /// Send a `GET` request to `route` with optional query parameters and headers, returning
@@ -1521,7 +2440,7 @@ impl Octocrab {
         R: FromResponse,
     {
         let response = self
-            ._get_with_headers(self.parameterized_uri(route, parameters)?, headers)
+            ._get_with_headers(self.get_uri::<R, _, _>(route, parameters)?, headers)
             .await?;
         R::from_response(crate::map_github_error(response).await?).await
     }
@@ -1635,6 +2554,12 @@ impl Octocrab {
         }
     }
 
+    /// Creates a request to any `route` with `method`, for endpoints which
+    /// octocrab doesn't have methods for yet. See [`request`] for an example.
+    pub fn request(&self, method: Method, route: impl Into<String>) -> request::RequestBuilder<'_> {
+        request::RequestBuilder::new(self, method, route)
+    }
+
     /// Send a `DELETE` request to `route` with optional query body,
     /// returning the body of the response.
     pub async fn delete<R, A, B>(&self, route: A, body: Option<&B>) -> Result<R>
@@ -1820,9 +2745,28 @@ impl Octocrab {
             }
         }
 
+        if let Some(accept) = &self.accept {
+            parts.headers.insert(http::header::ACCEPT, accept.clone());
+        }
+
+        if let Some(version) = *self.server_version.read().unwrap() {
+            server_version::ensure_available(&parts.uri, version)?;
+        }
+
+        let resource = ratelimit::resource_for(&parts.uri);
+        self.rate_limits.throttle(resource).await;
+
         let request = http::Request::from_parts(parts, body);
 
-        let response = self.send(request).await?;
+        let mut response = self.send(request).await?;
+        self.rate_limits
+            .record_headers(resource, response.headers());
+        response
+            .extensions_mut()
+            .insert(ErrorBodyLimit(self.error_body_limit));
+        if let Some(sink) = &self.metadata_sink {
+            *sink.lock().unwrap() = Some(ResponseMetadata::from_response(&response));
+        }
 
         let status = response.status();
         if StatusCode::UNAUTHORIZED == status {
@@ -1871,7 +2815,7 @@ impl Octocrab {
         let response = self.execute(request).await?;
 
         let bytes = response.into_body().collect().await?.to_bytes();
-        Ok(bytes.to_vec())
+        Ok(Vec::from(bytes))
     }
 
     /// Download a zip file from the given URL into a `Vec<u8>`.
@@ -1893,6 +2837,38 @@ impl Octocrab {
         }
     }
 
+    /// Gets the page with the given relation to `page`, e.g. the previous
+    /// or the last one, if the response for `page` linked to it.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::{models::Repository, Rel};
+    ///
+    /// let octocrab = octocrab::instance();
+    /// let page = octocrab.orgs("owner").list_repos().per_page(10).send().await?;
+    /// println!("page 1 of {:?}", page.number_of_pages());
+    ///
+    /// let last: Option<octocrab::Page<Repository>> =
+    ///     octocrab.get_page_rel(&page, Rel::Last).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_page_rel<R: serde::de::DeserializeOwned>(
+        &self,
+        page: &Page<R>,
+        rel: Rel,
+    ) -> crate::Result<Option<Page<R>>> {
+        self.get_page(&page.link(rel).cloned()).await
+    }
+
+    /// Gets the next page of a listing saved as a [`PaginationState`], or
+    /// `None` once the last page was reached.
+    pub async fn resume_page<R: serde::de::DeserializeOwned>(
+        &self,
+        state: &PaginationState,
+    ) -> crate::Result<Option<Page<R>>> {
+        self.get_page(&state.next).await
+    }
+
     /// A convenience method to get all the results starting at a given
     /// page.
     pub async fn all_pages<R: serde::de::DeserializeOwned>(
@@ -1906,25 +2882,85 @@ impl Octocrab {
         }
         Ok(ret)
     }
+
+    /// Like [`Octocrab::all_pages`], but can cap the number of items
+    /// collected and report the progress after every page, e.g. to stop
+    /// early or show a progress bar.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use std::ops::ControlFlow;
+    /// use octocrab::AllPagesOptions;
+    ///
+    /// let octocrab = octocrab::instance();
+    /// let first = octocrab.issues("rust-lang", "rust").list().per_page(100).send().await?;
+    /// let issues = octocrab
+    ///     .all_pages_with(
+    ///         first,
+    ///         AllPagesOptions::new()
+    ///             .max_items(5_000)
+    ///             .on_progress(|progress| {
+    ///                 println!("page {} of {:?}", progress.pages, progress.total_pages);
+    ///                 ControlFlow::Continue(())
+    ///             }),
+    ///     )
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn all_pages_with<R: serde::de::DeserializeOwned>(
+        &self,
+        mut page: Page<R>,
+        mut options: AllPagesOptions<'_>,
+    ) -> crate::Result<Vec<R>> {
+        let max_items = options.max_items.unwrap_or(usize::MAX);
+        let total_pages = page.number_of_pages();
+        let mut ret = Vec::new();
+        let mut pages = 0;
+        loop {
+            let mut items = page.take_items();
+            items.truncate(max_items - ret.len());
+            ret.append(&mut items);
+            pages += 1;
+            let progress = Progress {
+                items: ret.len(),
+                pages,
+                total_pages,
+            };
+            let stop = options
+                .on_progress
+                .as_mut()
+                .is_some_and(|on_progress| on_progress(&progress).is_break());
+            if stop || ret.len() >= max_items {
+                return Ok(ret);
+            }
+            match self.get_page(&page.next).await? {
+                Some(next_page) => page = next_page,
+                None => return Ok(ret),
+            }
+        }
+    }
 }
 
-// Global CryptoProvider initialization for rustls tests
-// This will be initialized when first accessed during tests
-#[cfg(all(test, feature = "rustls", not(target_arch = "wasm32")))]
-pub(crate) fn ensure_crypto_provider_initialized() {
+/// Installs the default rustls crypto provider once per process, which
+/// clients need when several providers are enabled. Public for the
+/// integration tests and `test_utils`, which re-exports it.
+#[doc(hidden)]
+#[cfg(all(
+    feature = "rustls",
+    any(feature = "rustls-ring", feature = "rustls-aws-lc-rs"),
+    not(target_arch = "wasm32")
+))]
+pub fn ensure_crypto_provider_initialized() {
     use std::sync::OnceLock;
 
     static INIT: OnceLock<()> = OnceLock::new();
     INIT.get_or_init(|| {
         #[cfg(feature = "rustls-ring")]
         let provider = rustls::crypto::ring::default_provider();
-        #[cfg(all(feature = "rustls-aws-lc-rs", not(feature = "rustls-ring")))]
+        #[cfg(not(feature = "rustls-ring"))]
         let provider = rustls::crypto::aws_lc_rs::default_provider();
-        #[cfg(all(not(feature = "rustls-ring"), not(feature = "rustls-aws-lc-rs")))]
-        compile_error!("Either rustls-ring or rustls-aws-lc-rs feature must be enabled");
-        provider
-            .install_default()
-            .expect("Failed to install CryptoProvider");
+        // Another part of the process may have installed one already.
+        let _ = provider.install_default();
     });
 }
 
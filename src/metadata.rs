@@ -0,0 +1,99 @@
+use std::sync::{Arc, Mutex};
+
+use bytes::Bytes;
+use http_body::Body;
+
+use crate::etag::EntityTag;
+use crate::{models, FromResponse};
+
+/// The status and headers of a response, which the typed methods otherwise
+/// discard once the body is deserialized.
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct ResponseMetadata {
+    /// The status of the response.
+    pub status: http::StatusCode,
+    /// All headers of the response.
+    pub headers: http::HeaderMap,
+}
+
+impl ResponseMetadata {
+    pub(crate) fn from_response<B>(response: &http::Response<B>) -> Self {
+        Self {
+            status: response.status(),
+            headers: response.headers().clone(),
+        }
+    }
+
+    /// The etag of the response, to make conditional requests with.
+    pub fn etag(&self) -> Option<EntityTag> {
+        self.headers
+            .get(http::header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .and_then(|etag| etag.parse().ok())
+    }
+
+    /// The id GitHub assigned to the request, from the
+    /// `x-github-request-id` header, which GitHub support asks for.
+    pub fn request_id(&self) -> Option<&str> {
+        self.headers
+            .get("x-github-request-id")
+            .and_then(|id| id.to_str().ok())
+    }
+
+    /// The rate limit of the resource the request counted against, from
+    /// the `x-ratelimit-*` headers.
+    pub fn rate_limit(&self) -> Option<models::Rate> {
+        crate::ratelimit::rate_from_headers(&self.headers)
+    }
+}
+
+/// A value deserialized from a response, together with the response's
+/// [`ResponseMetadata`].
+///
+/// Returned by [`Octocrab::with_metadata`](crate::Octocrab::with_metadata),
+/// and can be used as the response type of the generic request methods,
+/// e.g. `WithMetadata<models::Repository>` with
+/// [`Octocrab::get`](crate::Octocrab::get).
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct WithMetadata<T> {
+    /// The metadata of the response.
+    pub metadata: ResponseMetadata,
+    /// The deserialized body of the response.
+    pub value: T,
+}
+
+impl<T> WithMetadata<T> {
+    /// Returns the deserialized value, dropping the metadata.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T> std::ops::Deref for WithMetadata<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.value
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: FromResponse + Send> FromResponse for WithMetadata<T> {
+    const PAGINATED: bool = T::PAGINATED;
+
+    async fn from_response<B>(response: http::Response<B>) -> crate::Result<Self>
+    where
+        B: Body<Data = Bytes, Error = crate::Error> + Send,
+    {
+        let metadata = ResponseMetadata::from_response(&response);
+        let value = T::from_response(response).await?;
+        Ok(Self { metadata, value })
+    }
+}
+
+/// Where a client created by
+/// [`Octocrab::with_metadata`](crate::Octocrab::with_metadata) records the
+/// metadata of its latest response.
+pub(crate) type MetadataSink = Arc<Mutex<Option<ResponseMetadata>>>;
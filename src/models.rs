@@ -14,23 +14,38 @@ pub use apps::App;
 pub mod actions;
 pub mod activity;
 pub mod apps;
+pub mod billing;
 pub mod checks;
 pub mod classroom;
 pub mod code_scannings;
 pub mod codes_of_conduct;
+pub mod codespaces;
 pub mod commits;
+pub mod discussions;
+pub mod enterprises;
 pub mod events;
 pub mod gists;
 pub mod hooks;
+#[cfg(feature = "models-api")]
+pub mod inference;
 pub mod interaction_limits;
 pub mod issues;
+pub mod marketplace;
+pub mod meta;
+pub mod migrations;
 pub mod orgs;
 pub mod orgs_copilot;
+pub mod projects_v2;
 pub mod pulls;
 pub mod reactions;
 pub mod repos;
+pub mod search;
+pub mod security_advisories;
 pub mod teams;
 pub mod timelines;
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+pub mod timestamps;
 pub mod webhook_events;
 pub mod workflows;
 
@@ -71,6 +86,17 @@ macro_rules! id_type {
                 Self(value)
             }
         }
+        impl From<$name> for BaseIdType {
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+        impl std::str::FromStr for $name {
+            type Err = std::num::ParseIntError;
+            fn from_str(id: &str) -> Result<Self, Self::Err> {
+                id.parse().map(Self)
+            }
+        }
         impl AsRef<BaseIdType> for $name {
             fn as_ref(&self) -> &BaseIdType {
                 &self.0
@@ -120,6 +146,7 @@ id_type!(
     HookId,
     HookDeliveryId,
     LabelId,
+    MigrationId,
     MilestoneId,
     NotificationId,
     OrgId,
@@ -131,6 +158,7 @@ id_type!(
     ReleaseId,
     RepositoryId,
     ReviewId,
+    RoleId,
     RunId,
     RunnerId,
     RunnerGroupId,
@@ -148,6 +176,74 @@ id_type!(
     ClassroomId
 );
 
+/// The global node ID of an object, which GitHub's GraphQL API identifies
+/// objects by and the REST API returns as `node_id`.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NodeId(pub String);
+
+impl NodeId {
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for NodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl Deref for NodeId {
+    type Target = str;
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NodeId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<String> for NodeId {
+    fn from(id: String) -> Self {
+        Self(id)
+    }
+}
+
+impl From<&str> for NodeId {
+    fn from(id: &str) -> Self {
+        Self(id.to_owned())
+    }
+}
+
+impl From<NodeId> for String {
+    fn from(id: NodeId) -> Self {
+        id.0
+    }
+}
+
+impl std::str::FromStr for NodeId {
+    type Err = std::convert::Infallible;
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        Ok(id.into())
+    }
+}
+
+impl PartialEq<str> for NodeId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for NodeId {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
 macro_rules! convert_into {
     ($($from:ident -> $to:ident),+) => {$(
         impl From<$from> for $to {
@@ -166,6 +262,7 @@ convert_into!(OrgId -> ActorId,
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Contents {
     #[serde(rename = "type")]
     pub contents_type: String,
@@ -310,6 +407,9 @@ pub enum Event {
     Unsubscribed,
     /// An organization owner blocked a user from the organization.
     UserBlocked,
+    /// An event type this version of octocrab doesn't know yet.
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -318,15 +418,18 @@ pub enum Event {
 pub enum IssueState {
     Open,
     Closed,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssueEvent {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<IssueEventId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_id: Option<String>,
+    pub node_id: Option<NodeId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
     pub actor: Author,
@@ -353,6 +456,7 @@ pub struct IssueEvent {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ProjectCard {
     pub id: CardId,
     pub url: Url,
@@ -368,13 +472,14 @@ pub struct ProjectCard {
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Project {
     pub owner_url: Url,
     pub url: Url,
     pub html_url: Url,
     pub columns_url: Url,
     pub id: ProjectId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<String>,
@@ -396,12 +501,13 @@ pub enum ProjectCardContentType {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ProjectColumn {
     pub url: Url,
     pub project_url: Url,
     pub cards_url: Url,
     pub id: ProjectColumnId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub name: String,
     pub created_at: DateTime<Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -410,6 +516,7 @@ pub struct ProjectColumn {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssuePullRequest {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
@@ -423,10 +530,11 @@ pub struct IssuePullRequest {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Author {
     pub login: String,
     pub id: UserId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub avatar_url: Url,
     pub gravatar_id: String,
     pub url: Url,
@@ -466,10 +574,11 @@ where
 /// The full profile for a user
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct UserProfile {
     pub login: String,
     pub id: UserId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub avatar_url: Url,
     pub gravatar_id: String,
     pub url: Url,
@@ -507,6 +616,7 @@ pub struct UserProfile {
 /// e.g. name, email, company, location, bio, blog, twitter, hireable
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct UpdateUserProfile {
     pub name: Option<String>,
     pub email: Option<String>,
@@ -521,6 +631,7 @@ pub struct UpdateUserProfile {
 /// The simple profile for a GitHub user
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SimpleUser {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub name: Option<String>,
@@ -528,7 +639,7 @@ pub struct SimpleUser {
     pub email: Option<String>,
     pub login: String,
     pub id: UserId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub avatar_url: Url,
     pub gravatar_id: String,
     pub url: Url,
@@ -550,10 +661,11 @@ pub struct SimpleUser {
 /// A user that is following another user
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Follower {
     pub login: String,
     pub id: UserId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub avatar_url: Url,
     pub gravatar_id: String,
     pub url: Url,
@@ -575,10 +687,11 @@ pub struct Follower {
 /// A user that is being followed by another user
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Followee {
     pub login: String,
     pub id: UserId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub avatar_url: Url,
     pub gravatar_id: String,
     pub url: Url,
@@ -631,6 +744,7 @@ pub struct Contributor {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct StarGazer {
     pub starred_at: Option<DateTime<Utc>>,
     pub user: Option<Author>,
@@ -638,9 +752,10 @@ pub struct StarGazer {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Label {
     pub id: LabelId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub url: Url,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -651,13 +766,14 @@ pub struct Label {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Milestone {
     pub url: Url,
     pub html_url: Url,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub labels_url: Option<Url>,
     pub id: MilestoneId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub number: i64,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub state: Option<String>,
@@ -698,10 +814,14 @@ pub enum SquashMergeCommitMessage {
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(
+    all(feature = "strict-models", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Repository {
     pub id: RepositoryId,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_id: Option<String>,
+    pub node_id: Option<NodeId>,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub full_name: Option<String>,
@@ -884,9 +1004,17 @@ pub struct Repository {
     pub parent: Option<Box<Repository>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub source: Option<Box<Repository>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub security_and_analysis: Option<repos::SecurityAndAnalysis>,
+    /// Fields GitHub sent that aren't part of this model yet.
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepositoryFile {
     pub name: Option<String>,
     pub key: Option<String>,
@@ -895,6 +1023,7 @@ pub struct RepositoryFile {
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepositoryMetrics {
     pub health_percentage: u64,
     pub description: Option<String>,
@@ -904,12 +1033,28 @@ pub struct RepositoryMetrics {
     pub content_reports_enabled: Option<bool>,
 }
 
+impl RepositoryMetrics {
+    /// The code of conduct GitHub detected in the repository, with its `key`
+    /// and `name` when it's one of GitHub's known codes of conduct. The full
+    /// text can be fetched with
+    /// [`CodesOfConductHandler::get_code_of_conduct`](crate::codes_of_conduct::CodesOfConductHandler::get_code_of_conduct).
+    pub fn code_of_conduct(&self) -> Option<&RepositoryFile> {
+        self.files.get("code_of_conduct")?.as_ref()
+    }
+
+    /// The code of conduct file of the repository, if it has one.
+    pub fn code_of_conduct_file(&self) -> Option<&RepositoryFile> {
+        self.files.get("code_of_conduct_file")?.as_ref()
+    }
+}
+
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct License {
     pub key: String,
     pub name: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub spdx_id: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<Url>,
@@ -925,6 +1070,7 @@ pub struct License {
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Code {
     pub name: String,
     pub path: String,
@@ -937,6 +1083,7 @@ pub struct Code {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Permissions {
     #[serde(default)]
     pub admin: bool,
@@ -950,6 +1097,7 @@ pub struct Permissions {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CheckRuns {
     pub total_count: i32,
     pub check_runs: Vec<CheckRun>,
@@ -957,13 +1105,14 @@ pub struct CheckRuns {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CheckRun {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<CheckRunId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub head_sha: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_id: Option<String>,
+    pub node_id: Option<NodeId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_id: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -993,6 +1142,7 @@ pub enum CheckStatus {
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CombinedStatus {
     pub state: StatusState,
     pub sha: String,
@@ -1008,11 +1158,12 @@ pub struct CombinedStatus {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Status {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<StatusId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_id: Option<String>,
+    pub node_id: Option<NodeId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub avatar_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1044,6 +1195,7 @@ pub enum StatusState {
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationRepositories {
     pub total_count: i64,
     pub repositories: Vec<Repository>,
@@ -1052,6 +1204,7 @@ pub struct InstallationRepositories {
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Installation {
     pub id: InstallationId,
     pub account: Author,
@@ -1095,6 +1248,7 @@ pub struct Installation {
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationPermissions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub actions: Option<String>,
@@ -1115,6 +1269,7 @@ pub struct InstallationPermissions {
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationToken {
     pub token: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -1127,18 +1282,31 @@ pub struct InstallationToken {
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PublicKey {
     pub key_id: String,
     pub key: String,
 }
 
+/// The rate limits of the authenticated user, as returned by
+/// [`RateLimitHandler::get`](crate::ratelimit::RateLimitHandler::get).
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RateLimit {
+    /// The rate limit of each category of API requests.
     pub resources: Resources,
+    /// The rate limit of the core API.
+    ///
+    /// GitHub has deprecated this object in favour of `resources.core`, which
+    /// should be used instead.
     pub rate: Rate,
 }
 
+/// The rate limit of each category of API requests. The categories other
+/// than `core` and `search` are only returned when they apply to the
+/// authenticated user.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Resources {
     pub core: Rate,
     pub search: Rate,
@@ -1147,18 +1315,38 @@ pub struct Resources {
     pub scim: Option<Rate>,
     pub source_import: Option<Rate>,
     pub code_scanning_upload: Option<Rate>,
+    pub code_scanning_autofix: Option<Rate>,
     pub actions_runner_registration: Option<Rate>,
+    pub dependency_snapshots: Option<Rate>,
+    pub dependency_sbom: Option<Rate>,
+    pub code_search: Option<Rate>,
+    pub audit_log: Option<Rate>,
+    pub audit_log_streaming: Option<Rate>,
 }
 
+/// The rate limit of a category of API requests.
 #[derive(Default, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Rate {
+    /// The maximum number of requests allowed per window.
     pub limit: usize,
+    /// The number of requests made in the current window.
     pub used: usize,
+    /// The number of requests left in the current window.
     pub remaining: usize,
+    /// When the current window resets, in UTC epoch seconds.
     pub reset: u64,
 }
 
+impl Rate {
+    /// When the current window resets.
+    pub fn reset_at(&self) -> Option<DateTime<Utc>> {
+        DateTime::from_timestamp(self.reset as i64, 0)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct UserEmailInfo {
     pub email: String,
     pub primary: bool,
@@ -1167,12 +1355,14 @@ pub struct UserEmailInfo {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct VerifiedEmailInfo {
     pub email: String,
     pub verified: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SubKeyInfo {
     pub id: u64,
     pub primary_key_id: u64,
@@ -1193,6 +1383,7 @@ pub struct SubKeyInfo {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GpgKey {
     pub id: u64,
     pub name: String,
@@ -1218,6 +1409,7 @@ pub struct GpgKey {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GitSshKey {
     pub key: String,
     pub id: u64,
@@ -1229,15 +1421,66 @@ pub struct GitSshKey {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SocialAccount {
     pub provider: String,
     pub url: String,
 }
 
+/// The contextual information GitHub shows when hovering over a user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Hovercard {
+    pub contexts: Vec<HovercardContext>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct HovercardContext {
+    /// A human readable line such as "Owns this repository".
+    pub message: String,
+    /// The name of the [Octicon](https://primer.style/octicons) shown next
+    /// to the message.
+    pub octicon: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SshSigningKey {
     pub key: String,
     pub id: u64,
     pub title: String,
     pub created_at: DateTime<Utc>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn id_round_trips_through_u64() {
+        let id = IssueId::from(1347u64);
+        assert_eq!(u64::from(id), 1347);
+    }
+
+    #[test]
+    fn id_round_trips_through_str() {
+        let id: RepositoryId = "1296269".parse().unwrap();
+        assert_eq!(id, RepositoryId(1296269));
+        assert_eq!(id.to_string().parse::<RepositoryId>().unwrap(), id);
+        assert!("not-a-number".parse::<RepositoryId>().is_err());
+    }
+
+    #[test]
+    fn node_id_round_trips_through_str() {
+        let id: NodeId = "MDEwOlJlcG9zaXRvcnkxMjk2MjY5".parse().unwrap();
+        assert_eq!(id, "MDEwOlJlcG9zaXRvcnkxMjk2MjY5");
+        assert_eq!(id.to_string().parse::<NodeId>().unwrap(), id);
+        assert_eq!(
+            serde_json::from_value::<NodeId>(serde_json::to_value(&id).unwrap()).unwrap(),
+            id
+        );
+    }
+}
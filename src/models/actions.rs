@@ -2,6 +2,7 @@ use super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SelfHostedRunner {
     pub id: RunnerId,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -15,6 +16,7 @@ pub struct SelfHostedRunner {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SelfHostedRunnerLabel {
     pub id: RunnerLabelId,
     pub name: String,
@@ -32,6 +34,7 @@ pub enum SelfHostedRunnerLabelType {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SelfHostedRunnerJitConfig {
     pub runner: SelfHostedRunner,
     pub encoded_jit_config: String,
@@ -39,6 +42,7 @@ pub struct SelfHostedRunnerJitConfig {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SelfHostedRunnerToken {
     pub token: String,
     pub expires_at: DateTime<Utc>,
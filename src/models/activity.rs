@@ -2,6 +2,7 @@ use super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Notification {
     pub id: NotificationId,
     pub repository: Repository,
@@ -32,10 +33,13 @@ pub enum Reason {
     Subscribed,
     #[serde(rename = "team_mention")]
     TeamMention,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Subject {
     pub title: String,
     pub url: Option<Url>,
@@ -44,6 +48,7 @@ pub struct Subject {
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ThreadSubscription {
     pub subscribed: bool,
     pub ignored: bool,
@@ -52,3 +57,66 @@ pub struct ThreadSubscription {
     pub url: Url,
     pub thread_url: Url,
 }
+
+/// The subscription of the authenticated user to the notifications of a
+/// repository, i.e. whether they watch or ignore it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct RepositorySubscription {
+    pub subscribed: bool,
+    pub ignored: bool,
+    pub reason: Option<String>,
+    pub created_at: chrono::DateTime<chrono::Utc>,
+    pub url: Url,
+    pub repository_url: Url,
+}
+
+/// The Atom feeds available to the authenticated user, as returned by
+/// `GET /feeds`. Some of the URLs are URI templates, such as
+/// `https://github.com/{user}`, which is why they're kept as strings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Feed {
+    pub timeline_url: String,
+    pub user_url: String,
+    pub current_user_public_url: Option<String>,
+    pub current_user_url: Option<String>,
+    pub current_user_actor_url: Option<String>,
+    pub current_user_organization_url: Option<String>,
+    #[serde(default)]
+    pub current_user_organization_urls: Vec<String>,
+    pub security_advisories_url: Option<String>,
+    pub repository_discussions_url: Option<String>,
+    pub repository_discussions_category_url: Option<String>,
+    #[serde(rename = "_links")]
+    pub links: FeedLinks,
+}
+
+/// The typed links to each of the feeds of a [`Feed`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct FeedLinks {
+    pub timeline: FeedLink,
+    pub user: FeedLink,
+    pub security_advisories: Option<FeedLink>,
+    pub current_user: Option<FeedLink>,
+    pub current_user_public: Option<FeedLink>,
+    pub current_user_actor: Option<FeedLink>,
+    pub current_user_organization: Option<FeedLink>,
+    #[serde(default)]
+    pub current_user_organizations: Vec<FeedLink>,
+    pub repository_discussions: Option<FeedLink>,
+    pub repository_discussions_category: Option<FeedLink>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct FeedLink {
+    pub href: String,
+    /// The media type of the feed, e.g. `application/atom+xml`.
+    pub r#type: String,
+}
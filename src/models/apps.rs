@@ -3,11 +3,12 @@ use super::*;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct App {
     pub id: AppId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub slug: Option<String>,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub owner: Author,
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -34,6 +35,7 @@ pub struct App {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Permissions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub actions: Option<String>,
@@ -122,3 +124,48 @@ pub struct Permissions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub workflows: Option<String>,
 }
+
+/// An authorization of an OAuth app or GitHub App by a user, along with its
+/// access token.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct OAuthAuthorization {
+    pub id: u64,
+    pub url: Url,
+    pub scopes: Option<Vec<String>>,
+    pub token: String,
+    pub token_last_eight: Option<String>,
+    pub hashed_token: Option<String>,
+    pub app: OAuthAuthorizationApp,
+    pub note: Option<String>,
+    pub note_url: Option<Url>,
+    pub fingerprint: Option<String>,
+    pub user: Option<Author>,
+    /// The installation the token is scoped to, for tokens created with
+    /// [`OAuthApplicationHandler::scope_token`](crate::apps::OAuthApplicationHandler::scope_token).
+    pub installation: Option<OAuthScopedInstallation>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub expires_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct OAuthAuthorizationApp {
+    pub client_id: String,
+    pub name: String,
+    pub url: Url,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct OAuthScopedInstallation {
+    pub permissions: Permissions,
+    pub repository_selection: String,
+    pub single_file_name: Option<String>,
+    pub repositories_url: Url,
+    pub account: Author,
+}
@@ -0,0 +1,63 @@
+use super::*;
+
+/// The GitHub Actions minutes used in the current billing cycle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ActionsBillingUsage {
+    pub total_minutes_used: u64,
+    pub total_paid_minutes_used: u64,
+    pub included_minutes: u64,
+    /// The minutes used per runner type, e.g. `UBUNTU`, `MACOS` or
+    /// `ubuntu_4_core`.
+    pub minutes_used_breakdown: std::collections::HashMap<String, u64>,
+}
+
+/// The GitHub Packages bandwidth used in the current billing cycle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PackagesBillingUsage {
+    pub total_gigabytes_bandwidth_used: u64,
+    pub total_paid_gigabytes_bandwidth_used: u64,
+    pub included_gigabytes_bandwidth: u64,
+}
+
+/// The estimated storage used by GitHub Actions and GitHub Packages in the
+/// current billing cycle.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SharedStorageBillingUsage {
+    pub days_left_in_billing_cycle: u64,
+    pub estimated_paid_storage_for_month: f64,
+    pub estimated_storage_for_month: f64,
+}
+
+/// A report of billable usage from the enhanced billing platform.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct BillingUsageReport {
+    pub usage_items: Vec<BillingUsageItem>,
+}
+
+/// The usage of a single SKU, e.g. Actions minutes on Linux, over a period.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct BillingUsageItem {
+    pub date: DateTime<Utc>,
+    pub product: String,
+    pub sku: String,
+    pub quantity: f64,
+    pub unit_type: String,
+    pub price_per_unit: f64,
+    pub gross_amount: f64,
+    pub discount_amount: f64,
+    pub net_amount: f64,
+    pub organization_name: Option<String>,
+    pub repository_name: Option<String>,
+}
@@ -5,6 +5,7 @@ use crate::models::pulls::PullRequest;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CheckRunOutput {
     pub title: Option<String>,
     pub summary: Option<String>,
@@ -15,9 +16,10 @@ pub struct CheckRunOutput {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CheckRun {
     pub id: CheckRunId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub details_url: Option<String>,
     pub head_sha: String,
     pub url: String,
@@ -32,6 +34,7 @@ pub struct CheckRun {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ListCheckRuns {
     pub total_count: u64,
     pub check_runs: Vec<CheckRun>,
@@ -39,9 +42,10 @@ pub struct ListCheckRuns {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CheckSuite {
     pub id: CheckSuiteId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub head_branch: Option<String>,
     pub head_sha: String,
     pub status: Option<String>,
@@ -62,6 +66,7 @@ pub struct CheckSuite {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ListCheckSuites {
     pub total_count: u32,
     pub check_suites: Vec<CheckSuite>,
@@ -69,17 +74,20 @@ pub struct ListCheckSuites {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CheckSuitePreferences {
     pub preferences: CheckSuiteUpdatePreferences,
     pub repository: Repository,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CheckSuiteUpdatePreferences {
     pub auto_trigger_checks: Vec<AutoTriggerCheck>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct AutoTriggerCheck {
     /// Enables or disables automatic creation of CheckSuite events upon pushes to the repository.
     pub app_id: AppId,
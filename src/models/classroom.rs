@@ -1,4 +1,4 @@
-use crate::models::{AssignmentId, ClassroomId, OrgId, RepositoryId, UserId};
+use crate::models::{AssignmentId, ClassroomId, NodeId, OrgId, RepositoryId, UserId};
 use chrono::DateTime;
 use serde::{Deserialize, Serialize};
 use url::Url;
@@ -6,6 +6,7 @@ use url::Url;
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 /// A GitHub Classroom assignment
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Assignment {
     pub id: AssignmentId,
     pub public_repo: bool,
@@ -40,17 +41,19 @@ pub enum AssignmentType {
 /// GitHub repository view for Classroom
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SimpleCodeRepository {
     pub id: RepositoryId,
     pub full_name: String,
     pub html_url: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub private: bool,
     pub default_branch: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Classroom {
     pub id: ClassroomId,
     pub name: String,
@@ -61,10 +64,11 @@ pub struct Classroom {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SimpleOrganization {
     pub id: OrgId,
     pub login: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub html_url: String,
     pub name: String,
     pub avatar_url: String,
@@ -73,6 +77,7 @@ pub struct SimpleOrganization {
 /// A GitHub Classroom Accepted assignment
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct AcceptedAssignment {
     pub id: AssignmentId,
     pub submitted: bool,
@@ -86,6 +91,7 @@ pub struct AcceptedAssignment {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SimpleClassroomUser {
     pub id: UserId,
     pub login: String,
@@ -95,6 +101,7 @@ pub struct SimpleClassroomUser {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SimpleAssignment {
     pub id: AssignmentId,
     pub public_repo: bool,
@@ -120,6 +127,7 @@ pub struct SimpleAssignment {
 /// A GitHub Classroom simple classroom
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SimpleClassroom {
     pub id: ClassroomId,
     pub name: String,
@@ -130,6 +138,7 @@ pub struct SimpleClassroom {
 /// Classroom Assignment Grade
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct AssignmentGrade {
     pub assignment_name: String,
     pub assignment_url: String,
@@ -0,0 +1,130 @@
+use super::*;
+
+/// A cloud development environment hosted by GitHub.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Codespace {
+    pub id: u64,
+    pub name: String,
+    pub display_name: Option<String>,
+    pub environment_id: Option<String>,
+    pub owner: SimpleUser,
+    pub billable_owner: SimpleUser,
+    pub repository: Repository,
+    pub machine: Option<CodespaceMachine>,
+    pub devcontainer_path: Option<String>,
+    pub prebuild: Option<bool>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_used_at: DateTime<Utc>,
+    pub state: CodespaceState,
+    pub url: Url,
+    pub git_status: CodespaceGitStatus,
+    pub location: String,
+    pub idle_timeout_minutes: Option<u32>,
+    pub web_url: Url,
+    pub machines_url: Url,
+    pub start_url: Url,
+    pub stop_url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pulls_url: Option<Url>,
+    #[serde(default)]
+    pub recent_folders: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention_period_minutes: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retention_expires_at: Option<DateTime<Utc>>,
+}
+
+/// The lifecycle state of a codespace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum CodespaceState {
+    Unknown,
+    Created,
+    Queued,
+    Provisioning,
+    Available,
+    Awaiting,
+    Unavailable,
+    Deleted,
+    Moved,
+    Shutdown,
+    Archived,
+    Starting,
+    ShuttingDown,
+    Failed,
+    Exporting,
+    Updating,
+    Rebuilding,
+}
+
+/// The state of the git checkout inside a codespace.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CodespaceGitStatus {
+    pub ahead: Option<u32>,
+    pub behind: Option<u32>,
+    pub has_unpushed_changes: Option<bool>,
+    pub has_uncommitted_changes: Option<bool>,
+    #[serde(rename = "ref")]
+    pub r#ref: Option<String>,
+}
+
+/// A machine type a codespace can run on.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CodespaceMachine {
+    pub name: String,
+    pub display_name: String,
+    pub operating_system: String,
+    pub storage_in_bytes: u64,
+    pub memory_in_bytes: u64,
+    pub cpus: u32,
+    pub prebuild_availability: Option<String>,
+}
+
+/// A devcontainer configuration of a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Devcontainer {
+    pub path: String,
+    pub name: Option<String>,
+    pub display_name: Option<String>,
+}
+
+/// A Codespaces secret of the authenticated user.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CodespacesUserSecret {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub visibility: String,
+    pub selected_repositories_url: Url,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CodespacesUserSecrets {
+    pub total_count: i32,
+    pub secrets: Vec<CodespacesUserSecret>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateCodespacesUserSecret<'a> {
+    /// Value for your secret,
+    /// encrypted with LibSodium using the public key retrieved from the Get public key for the authenticated user endpoint.
+    pub encrypted_value: &'a str,
+    /// ID of the key you used to encrypt the secret.
+    pub key_id: &'a str,
+    /// The repositories which can access the secret.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_repository_ids: Option<&'a [RepositoryId]>,
+}
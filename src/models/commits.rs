@@ -4,12 +4,13 @@ use super::{reactions::ReactionContent, *};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Comment {
     // TODO check actuality comparing with github json schema and pulls::ReviewComment
     pub html_url: Url,
     pub url: Url,
     pub id: CommentId,
-    pub node_id: String,
+    pub node_id: NodeId,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub body: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -39,9 +40,21 @@ pub struct CommentReactions {
     pub reactions: Option<HashMap<ReactionContent, u64>>,
 }
 
+impl CommentReactions {
+    /// How many reactions of the given kind there are.
+    pub fn count(&self, content: ReactionContent) -> u64 {
+        self.reactions
+            .as_ref()
+            .and_then(|reactions| reactions.get(&content))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
 /// Commit Comparison
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommitComparison {
     pub ahead_by: i64,
     /// Commit
@@ -62,6 +75,7 @@ pub struct CommitComparison {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommitElement {
     pub author: Option<GitUser>,
     pub comment_count: i64,
@@ -75,6 +89,7 @@ pub struct CommitElement {
 /// Metaproperties for Git author/committer information.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GitUser {
     pub date: Option<String>,
     pub email: Option<String>,
@@ -83,6 +98,7 @@ pub struct GitUser {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Tree {
     pub sha: String,
     pub url: String,
@@ -90,6 +106,7 @@ pub struct Tree {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Verification {
     pub payload: Option<String>,
     pub reason: String,
@@ -106,6 +123,7 @@ pub type CommitFile = repos::DiffEntry;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommitParent {
     pub html_url: Option<String>,
     pub sha: String,
@@ -114,6 +132,7 @@ pub struct CommitParent {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommitStats {
     pub additions: Option<i64>,
     pub deletions: Option<i64>,
@@ -123,6 +142,7 @@ pub struct CommitStats {
 /// Commit
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Commit {
     pub author: Option<Author>,
     pub comments_url: String,
@@ -130,7 +150,7 @@ pub struct Commit {
     pub committer: Option<Author>,
     pub files: Option<Vec<repos::DiffEntry>>,
     pub html_url: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub parents: Vec<CommitParent>,
     pub sha: String,
     pub stats: Option<CommitStats>,
@@ -149,9 +169,10 @@ pub enum GithubCommitStatus {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GitCommitObject {
     pub sha: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub url: String,
     pub author: models::repos::CommitAuthor,
     pub committer: repos::CommitAuthor,
@@ -0,0 +1,79 @@
+//! Discussions, which are only available through GitHub's GraphQL API.
+
+use super::projects_v2::PageInfo;
+use super::*;
+
+/// A discussion in a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Discussion {
+    /// The GraphQL node id, used to refer to the discussion in mutations.
+    pub id: NodeId,
+    pub number: u64,
+    pub title: String,
+    pub body: String,
+    pub url: Url,
+    /// `None` if the author's account was deleted.
+    pub author: Option<DiscussionAuthor>,
+    pub category: DiscussionCategory,
+    /// Whether an answer was chosen, `None` if the category isn't answerable.
+    pub is_answered: Option<bool>,
+    pub answer_chosen_at: Option<DateTime<Utc>>,
+    pub closed: bool,
+    pub locked: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// A category discussions are filed under, e.g. `Q&A` or `Ideas`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DiscussionCategory {
+    pub id: NodeId,
+    pub name: String,
+    pub slug: String,
+    pub emoji: String,
+    pub description: Option<String>,
+    /// Whether comments in discussions of this category can be marked as
+    /// the answer.
+    pub is_answerable: bool,
+}
+
+/// A comment on a discussion, or a reply to such a comment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DiscussionComment {
+    pub id: NodeId,
+    pub body: String,
+    pub url: Url,
+    pub author: Option<DiscussionAuthor>,
+    pub is_answer: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The author of a discussion or a comment.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DiscussionAuthor {
+    pub login: String,
+}
+
+/// A page of discussions, fetched with a GraphQL cursor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Discussions {
+    pub total_count: u64,
+    #[serde(rename = "nodes")]
+    pub discussions: Vec<Discussion>,
+    pub page_info: PageInfo,
+}
@@ -0,0 +1,81 @@
+use super::*;
+
+/// The licenses an enterprise purchased and who consumes them, across
+/// GitHub.com, GitHub Enterprise Server and Visual Studio subscriptions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ConsumedLicenses {
+    pub total_seats_consumed: u64,
+    pub total_seats_purchased: u64,
+    /// The users of the requested page.
+    #[serde(default)]
+    pub users: Vec<LicensedUser>,
+}
+
+/// A user consuming a license of an enterprise, with the accounts they're
+/// known by on GitHub.com and the enterprise's servers.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct LicensedUser {
+    pub github_com_login: Option<String>,
+    pub github_com_name: Option<String>,
+    #[serde(default)]
+    pub enterprise_server_user_ids: Vec<String>,
+    pub github_com_user: bool,
+    pub enterprise_server_user: Option<bool>,
+    pub visual_studio_subscription_user: bool,
+    /// The kind of license, e.g. `enterprise`.
+    pub license_type: String,
+    pub github_com_profile: Option<String>,
+    #[serde(default)]
+    pub github_com_member_roles: Vec<String>,
+    #[serde(default)]
+    pub github_com_enterprise_roles: Vec<String>,
+    #[serde(default)]
+    pub github_com_verified_domain_emails: Vec<String>,
+    pub github_com_saml_name_id: Option<String>,
+    #[serde(default)]
+    pub github_com_orgs_with_pending_invites: Vec<String>,
+    pub github_com_two_factor_auth: Option<bool>,
+    #[serde(default)]
+    pub enterprise_server_emails: Vec<String>,
+    pub visual_studio_license_status: Option<String>,
+    pub visual_studio_subscription_email: Option<String>,
+    /// The number of accounts the user has across GitHub.com and the
+    /// enterprise's servers.
+    pub total_user_accounts: u64,
+}
+
+/// When the GitHub Enterprise Server instances of an enterprise last synced
+/// their license usage with GitHub.com.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct LicenseSyncStatus {
+    #[serde(default)]
+    pub server_instances: Vec<ServerInstance>,
+}
+
+/// A GitHub Enterprise Server instance of an enterprise.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ServerInstance {
+    pub server_id: String,
+    pub hostname: String,
+    pub last_sync: Option<LicenseSync>,
+}
+
+/// The outcome of a license sync.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct LicenseSync {
+    pub date: Option<DateTime<Utc>>,
+    /// E.g. `success` or `failed`.
+    pub status: Option<String>,
+    /// Why the sync failed, empty if it didn't.
+    pub error: Option<String>,
+}
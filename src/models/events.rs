@@ -95,6 +95,7 @@ event_type! {
 /// The repository an [`Event`] belongs to.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Repository {
     pub id: RepositoryId,
     pub name: String,
@@ -104,6 +105,7 @@ pub struct Repository {
 /// The organization an [`Event`] belongs to.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Org {
     pub id: OrgId,
     pub login: String,
@@ -115,6 +117,7 @@ pub struct Org {
 /// The actor that created this [`Event`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Actor {
     pub id: ActorId,
     pub login: String,
@@ -130,6 +133,7 @@ impl<'de> Deserialize<'de> for Event {
         D: serde::Deserializer<'de>,
     {
         #[derive(Deserialize)]
+        #[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
         struct Intermediate {
             id: String,
             #[serde(rename = "type")]
@@ -245,7 +249,7 @@ mod test {
             crate::models::events::payload::EventInstallation::Minimal(Box::new(
                 crate::models::events::payload::EventInstallationId {
                     id: 18995746.into(),
-                    node_id: "MDIzOkludGVncmF0aW9uSW5zdGFsbGF0aW9uMTg5OTU3NDY=".to_string()
+                    node_id: "MDIzOkludGVncmF0aW9uSW5zdGFsbGF0aW9uMTg5OTU3NDY=".into()
                 }
             ))
         )
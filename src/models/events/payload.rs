@@ -42,8 +42,8 @@ use serde::{Deserialize, Serialize};
 use url::Url;
 
 use crate::models::{
-    orgs::Organization, repos::CommitAuthor, Author, Installation, InstallationId, Repository,
-    RepositoryId,
+    orgs::Organization, repos::CommitAuthor, Author, Installation, InstallationId, NodeId,
+    Repository, RepositoryId,
 };
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -56,9 +56,10 @@ pub enum EventInstallation {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct EventInstallationId {
     pub id: InstallationId,
-    pub node_id: String,
+    pub node_id: NodeId,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -105,6 +106,7 @@ pub enum EventPayload {
 /// A git commit in specific payload types.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Commit {
     pub sha: String,
     pub author: CommitAuthor,
@@ -116,9 +118,10 @@ pub struct Commit {
 /// A repository in installation related webhook events.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationEventRepository {
     pub id: RepositoryId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub name: String,
     pub full_name: String,
     pub private: bool,
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::CommitCommentEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommitCommentEventPayload {
     /// The comment this event corresponds to.
     pub comment: Comment,
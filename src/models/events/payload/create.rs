@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::CreateEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CreateEventPayload {
     // a null ref will occur on the initial create event
     pub r#ref: Option<String>,
@@ -4,6 +4,7 @@ use url::Url;
 /// The payload in a [`super::EventPayload::GollumEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GollumEventPayload {
     /// The pages that were updated.
     pub pages: Vec<GollumEventPage>,
@@ -12,6 +13,7 @@ pub struct GollumEventPayload {
 /// A page in a [`GollumEventPayload`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GollumEventPage {
     /// The name of the page.
     pub page_name: String,
@@ -10,6 +10,7 @@ use crate::models::Author;
 /// The payload in a webhook installation_repositories event type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationRepositoriesEventPayload {
     /// The action this event represents.
     pub action: InstallationRepositoriesEventAction,
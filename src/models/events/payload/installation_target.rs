@@ -10,6 +10,7 @@ use crate::models::orgs::Organization;
 /// Somebody renamed the user or organization account that a GitHub App is installed on.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationTargetEventPayload {
     pub account: Organization,
     pub changes: InstallationTargetChanges,
@@ -18,6 +19,7 @@ pub struct InstallationTargetEventPayload {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationTargetChanges {
     pub login: InstallationTargetLoginChanges,
     pub slug: InstallationTargetSlugChanges,
@@ -25,12 +27,14 @@ pub struct InstallationTargetChanges {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationTargetLoginChanges {
     pub from: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationTargetSlugChanges {
     pub from: String,
 }
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::IssueCommentEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssueCommentEventPayload {
     /// The action this event represents.
     pub action: IssueCommentEventAction,
@@ -28,6 +29,7 @@ pub enum IssueCommentEventChanges {
 /// available in an event of type [`IssueCommentEventAction::Edited`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssueCommentEventChangesFrom {
     pub from: String,
 }
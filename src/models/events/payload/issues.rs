@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::IssuesEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssuesEventPayload {
     /// The action this event represents.
     pub action: IssuesEventAction,
@@ -36,6 +37,7 @@ pub enum IssuesEventChanges {
 /// available in an event of type [`IssuesEventAction::Edited`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssuesEventChangesFrom {
     pub from: String,
 }
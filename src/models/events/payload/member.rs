@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::MemberEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct MemberEventPayload {
     /// The action this event represents.
     pub action: MemberEventAction,
@@ -26,6 +27,7 @@ pub enum MemberEventChanges {
 /// The new permission given to the collaborator.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct MemberEventChangesTo {
     /// The optional previous permission.
     pub from: Option<String>,
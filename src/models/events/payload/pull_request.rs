@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::PullRequestEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestEventPayload {
     /// The action this event represents.
     pub action: PullRequestEventAction,
@@ -46,6 +47,7 @@ pub enum PullRequestEventAction {
 /// The change which occurred in an event of type [`PullRequestEventAction::Edited`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestChanges {
     pub title: Option<PullRequestEventChangesFrom>,
     pub body: Option<PullRequestEventChangesFrom>,
@@ -56,6 +58,7 @@ pub struct PullRequestChanges {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestEventChangesFrom {
     pub from: String,
 }
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::PullRequestReviewEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestReviewEventPayload {
     /// The action this event represents.
     pub action: PullRequestReviewEventAction,
@@ -34,6 +35,7 @@ pub enum PullRequestReviewChanges {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestReviewChangesFrom {
     pub from: String,
 }
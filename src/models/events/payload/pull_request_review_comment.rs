@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::PullRequestReviewCommentEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestReviewCommentEventPayload {
     /// The action this event represents.
     pub action: PullRequestReviewCommentEventAction,
@@ -38,6 +39,7 @@ pub enum PullRequestReviewCommentChanges {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "lowercase")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestReviewCommentChangesFrom {
     pub from: String,
 }
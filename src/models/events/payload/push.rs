@@ -5,6 +5,7 @@ use crate::models::PushId;
 /// The payload in a [`super::EventPayload::PushEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PushEventPayload {
     pub push_id: PushId,
     pub r#ref: String,
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::ReleaseEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ReleaseEventPayload {
     /// The action this event represents.
     pub action: ReleaseEventAction,
@@ -26,6 +27,7 @@ pub enum ReleaseEventChanges {
 /// available in an event of type [`ReleaseEventAction::Edited`].
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ReleaseEventChangesFrom {
     pub from: String,
 }
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 /// The payload in a [`super::EventPayload::WatchEvent`] type.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct WatchEventPayload {
     /// The action that was performed.
     pub action: WatchEventAction,
@@ -3,6 +3,7 @@ use std::collections::BTreeMap;
 
 #[non_exhaustive]
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Gist {
     pub comments: u64,
     pub comments_url: Url,
@@ -15,7 +16,7 @@ pub struct Gist {
     pub git_push_url: Url,
     pub html_url: Url,
     pub id: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub public: bool,
     pub updated_at: DateTime<Utc>,
     pub url: Url,
@@ -23,6 +24,7 @@ pub struct Gist {
 
 #[non_exhaustive]
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GistFile {
     pub content: Option<String>,
     pub filename: String,
@@ -35,6 +37,7 @@ pub struct GistFile {
 
 #[non_exhaustive]
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GistCommit {
     pub user: Option<Author>,
     pub version: String,
@@ -45,6 +48,7 @@ pub struct GistCommit {
 
 #[non_exhaustive]
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GistChangeStatus {
     pub total: Option<u64>,
     pub additions: Option<u64>,
@@ -53,9 +57,10 @@ pub struct GistChangeStatus {
 
 #[non_exhaustive]
 #[derive(Debug, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GistRevision {
     pub id: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub public: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -2,6 +2,7 @@ use super::{webhook_events::WebhookEventType, *};
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Hook {
     pub r#type: String,
     pub active: bool,
@@ -35,6 +36,7 @@ pub struct Hook {
 
 #[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Config {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub content_type: Option<ContentType>,
@@ -47,6 +49,7 @@ pub struct Config {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct LastResponse {
     pub code: Option<i64>,
     pub status: Option<String>,
@@ -66,6 +69,7 @@ pub enum ContentType {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Delivery {
     pub id: HookDeliveryId,
     pub guid: String,
@@ -0,0 +1,122 @@
+//! Models of the GitHub Models catalog and inference API.
+use super::*;
+
+/// A model of the GitHub Models catalog.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CatalogModel {
+    /// The id to run inference with, e.g. `openai/gpt-4.1`.
+    pub id: String,
+    pub name: String,
+    pub publisher: String,
+    pub registry: Option<String>,
+    pub summary: Option<String>,
+    pub html_url: Option<Url>,
+    pub version: Option<String>,
+    /// E.g. `streaming` or `tool-calling`.
+    #[serde(default)]
+    pub capabilities: Vec<String>,
+    pub limits: Option<ModelLimits>,
+    /// The rate limit tier of the model, e.g. `low` or `high`.
+    pub rate_limit_tier: Option<String>,
+    #[serde(default)]
+    pub supported_input_modalities: Vec<String>,
+    #[serde(default)]
+    pub supported_output_modalities: Vec<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// The token limits of a model.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ModelLimits {
+    pub max_input_tokens: Option<u64>,
+    pub max_output_tokens: Option<u64>,
+}
+
+/// Who a message of a chat is from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ChatRole {
+    System,
+    Developer,
+    User,
+    Assistant,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// A message of a chat, either sent to a model or answered by it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    /// The text of the message, which answers calling tools don't have.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+impl ChatMessage {
+    /// A message with the given role and text.
+    pub fn new(role: ChatRole, content: impl Into<String>) -> Self {
+        Self {
+            role,
+            content: Some(content.into()),
+        }
+    }
+
+    /// Instructions for the model.
+    pub fn system(content: impl Into<String>) -> Self {
+        Self::new(ChatRole::System, content)
+    }
+
+    /// A message of the user.
+    pub fn user(content: impl Into<String>) -> Self {
+        Self::new(ChatRole::User, content)
+    }
+
+    /// A previous answer of the model.
+    pub fn assistant(content: impl Into<String>) -> Self {
+        Self::new(ChatRole::Assistant, content)
+    }
+}
+
+/// The answer of a model to a chat.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ChatCompletion {
+    pub id: String,
+    pub object: Option<String>,
+    /// When the answer was created, as a Unix timestamp.
+    pub created: Option<i64>,
+    pub model: String,
+    pub choices: Vec<ChatChoice>,
+    pub usage: Option<ChatUsage>,
+}
+
+/// One of the answers of a [`ChatCompletion`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ChatChoice {
+    pub index: u32,
+    pub message: ChatMessage,
+    /// Why the model stopped, e.g. `stop` or `length`.
+    pub finish_reason: Option<String>,
+}
+
+/// The tokens a [`ChatCompletion`] used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ChatUsage {
+    pub prompt_tokens: u64,
+    pub completion_tokens: u64,
+    pub total_tokens: u64,
+}
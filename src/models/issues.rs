@@ -2,9 +2,13 @@ use super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(
+    all(feature = "strict-models", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Issue {
     pub id: IssueId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub url: Url,
     pub repository_url: Url,
     pub labels_url: Url,
@@ -41,13 +45,50 @@ pub struct Issue {
     pub closed_by: Option<Author>,
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub updated_at: chrono::DateTime<chrono::Utc>,
+    /// Progress of this issue's sub-issues.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sub_issues_summary: Option<SubIssuesSummary>,
+    /// The API URL of this issue's parent, if it is a sub-issue.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent_issue_url: Option<Url>,
+    /// Per-reaction counts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<crate::models::commits::CommentReactions>,
+    /// Fields GitHub sent that aren't part of this model yet.
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
+}
+
+/// How many of an issue's sub-issues have been completed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SubIssuesSummary {
+    pub total: u64,
+    pub completed: u64,
+    pub percent_completed: u64,
+}
+
+impl Issue {
+    /// The reason the conversation was locked, if it is locked with one of
+    /// the reasons GitHub documents.
+    pub fn lock_reason(&self) -> Option<crate::params::LockReason> {
+        let reason = self.active_lock_reason.as_deref()?;
+        serde_json::from_value(serde_json::Value::from(reason)).ok()
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(
+    all(feature = "strict-models", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Comment {
     pub id: CommentId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub url: Url,
     pub html_url: Url,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -64,6 +105,14 @@ pub struct Comment {
     pub created_at: chrono::DateTime<chrono::Utc>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Per-reaction counts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<crate::models::commits::CommentReactions>,
+    /// Fields GitHub sent that aren't part of this model yet.
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
@@ -74,13 +123,37 @@ pub enum IssueStateReason {
     NotPlanned,
     Reopened,
     Duplicate,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestLink {
     pub url: Url,
     pub html_url: Url,
     pub diff_url: Url,
     pub patch_url: Url,
 }
+
+#[cfg(all(test, feature = "extra-fields"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keep_unknown_fields() {
+        let mut json: serde_json::Value = serde_json::from_str(include_str!(
+            "../../tests/resources/issues_opened_webhook_event.json"
+        ))
+        .unwrap();
+        json["issue"]["brand_new_field"] = serde_json::json!({ "enabled": true });
+
+        let issue: Issue = serde_json::from_value(json["issue"].take()).unwrap();
+        assert_eq!(
+            issue.extra["brand_new_field"],
+            serde_json::json!({ "enabled": true })
+        );
+        assert!(!issue.extra.contains_key("title"));
+    }
+}
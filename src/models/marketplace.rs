@@ -0,0 +1,78 @@
+use super::*;
+
+/// A pricing plan of a GitHub Marketplace listing.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct MarketplacePlan {
+    pub id: u64,
+    pub number: u64,
+    pub name: String,
+    pub description: String,
+    pub url: Url,
+    pub accounts_url: Url,
+    pub monthly_price_in_cents: u64,
+    pub yearly_price_in_cents: u64,
+    pub price_model: MarketplacePriceModel,
+    pub has_free_trial: bool,
+    /// The name of the unit for `PER_UNIT` plans, e.g. `seat`.
+    pub unit_name: Option<String>,
+    pub state: String,
+    #[serde(default)]
+    pub bullets: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum MarketplacePriceModel {
+    Free,
+    FlatRate,
+    PerUnit,
+}
+
+/// A user or organization account along with its subscription to a
+/// Marketplace plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct MarketplaceAccount {
+    pub id: u64,
+    pub login: String,
+    /// `User` or `Organization`.
+    pub r#type: String,
+    pub url: Url,
+    pub email: Option<String>,
+    pub organization_billing_email: Option<String>,
+    pub marketplace_purchase: MarketplacePurchase,
+    /// A plan change which takes effect at the end of the billing cycle.
+    pub marketplace_pending_change: Option<MarketplacePendingChange>,
+}
+
+/// The subscription of an account to a Marketplace plan.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct MarketplacePurchase {
+    /// `monthly` or `yearly`.
+    pub billing_cycle: Option<String>,
+    pub next_billing_date: Option<DateTime<Utc>>,
+    pub unit_count: Option<u64>,
+    pub on_free_trial: Option<bool>,
+    pub free_trial_ends_on: Option<DateTime<Utc>>,
+    pub is_installed: Option<bool>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub plan: Option<MarketplacePlan>,
+}
+
+/// A pending change to the subscription of an account.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct MarketplacePendingChange {
+    pub id: Option<u64>,
+    pub effective_date: Option<DateTime<Utc>>,
+    pub unit_count: Option<u64>,
+    pub is_installed: Option<bool>,
+    pub plan: Option<MarketplacePlan>,
+}
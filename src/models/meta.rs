@@ -0,0 +1,68 @@
+use super::*;
+
+/// Information about GitHub.com, such as the IP ranges GitHub's services
+/// connect from, as returned by `GET /meta`. IP ranges are in CIDR notation,
+/// and can be IPv4 or IPv6.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Meta {
+    pub verifiable_password_authentication: bool,
+    pub ssh_key_fingerprints: Option<SshKeyFingerprints>,
+    pub ssh_keys: Option<Vec<String>>,
+    /// The ranges webhooks are delivered from.
+    pub hooks: Option<Vec<String>>,
+    pub web: Option<Vec<String>>,
+    pub api: Option<Vec<String>>,
+    pub git: Option<Vec<String>>,
+    pub github_enterprise_importer: Option<Vec<String>>,
+    pub packages: Option<Vec<String>>,
+    pub pages: Option<Vec<String>>,
+    pub importer: Option<Vec<String>>,
+    /// The ranges of GitHub-hosted Actions runners.
+    pub actions: Option<Vec<String>>,
+    pub actions_macos: Option<Vec<String>>,
+    pub codespaces: Option<Vec<String>>,
+    pub dependabot: Option<Vec<String>>,
+    pub copilot: Option<Vec<String>>,
+    pub domains: Option<MetaDomains>,
+    /// The version of GitHub Enterprise Server, not set on GitHub.com.
+    pub installed_version: Option<String>,
+}
+
+/// The fingerprints of GitHub's SSH host keys.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SshKeyFingerprints {
+    #[serde(rename = "SHA256_RSA")]
+    pub sha256_rsa: Option<String>,
+    #[serde(rename = "SHA256_DSA")]
+    pub sha256_dsa: Option<String>,
+    #[serde(rename = "SHA256_ECDSA")]
+    pub sha256_ecdsa: Option<String>,
+    #[serde(rename = "SHA256_ED25519")]
+    pub sha256_ed25519: Option<String>,
+}
+
+/// The domains used by GitHub's services.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct MetaDomains {
+    pub website: Option<Vec<String>>,
+    pub codespaces: Option<Vec<String>>,
+    pub copilot: Option<Vec<String>>,
+    pub packages: Option<Vec<String>>,
+    pub actions: Option<Vec<String>>,
+    pub artifact_attestations: Option<ArtifactAttestationsDomains>,
+}
+
+/// The domains used for artifact attestations.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ArtifactAttestationsDomains {
+    pub trust_domain: Option<String>,
+    pub services: Option<Vec<String>>,
+}
@@ -0,0 +1,41 @@
+use super::*;
+
+/// A migration of repositories, which exports them into an archive.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Migration {
+    pub id: MigrationId,
+    pub node_id: NodeId,
+    pub guid: String,
+    pub owner: Option<Author>,
+    pub state: MigrationState,
+    pub lock_repositories: bool,
+    pub exclude_metadata: bool,
+    pub exclude_git_data: bool,
+    pub exclude_attachments: bool,
+    pub exclude_releases: bool,
+    pub exclude_owner_projects: bool,
+    pub org_metadata_only: bool,
+    pub repositories: Vec<Repository>,
+    /// Which kinds of data were excluded, e.g. `repositories`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub exclude: Option<Vec<String>>,
+    pub url: Url,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub archive_url: Option<Url>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The state of a migration. The archive can be downloaded once it's
+/// `Exported`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum MigrationState {
+    Pending,
+    Exporting,
+    Exported,
+    Failed,
+}
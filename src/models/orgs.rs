@@ -1,12 +1,18 @@
 use super::*;
+pub mod roles;
 pub mod secrets;
+pub mod variables;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(
+    all(feature = "strict-models", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Organization {
     pub login: String,
     pub id: OrgId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub url: Url,
     pub repos_url: Url,
     pub events_url: Url,
@@ -75,9 +81,15 @@ pub struct Organization {
     pub members_can_create_private_repositories: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub members_can_create_internal_repositories: Option<bool>,
+    /// Fields GitHub sent that aren't part of this model yet.
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct MembershipInvitation {
     pub url: Url,
     pub state: String,
@@ -89,6 +101,7 @@ pub struct MembershipInvitation {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Plan {
     pub name: String,
     pub space: i64,
@@ -9,6 +9,7 @@ pub enum Visibility {
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct OrganizationSecret {
     pub name: String,
     pub created_at: DateTime<Utc>,
@@ -20,6 +21,7 @@ pub struct OrganizationSecret {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct OrganizationSecrets {
     pub total_count: i32,
     pub secrets: Vec<OrganizationSecret>,
@@ -46,3 +48,13 @@ pub enum CreateOrganizationSecretResponse {
     Created,
     Updated,
 }
+
+/// The repositories that can access a secret or variable whose visibility is
+/// [`Visibility::Selected`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SelectedRepositories {
+    pub total_count: i32,
+    pub repositories: Vec<Repository>,
+}
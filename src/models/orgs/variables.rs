@@ -0,0 +1,56 @@
+use super::super::*;
+use super::secrets::Visibility;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct OrganizationVariable {
+    pub name: String,
+    pub value: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub visibility: Visibility,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_repositories_url: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct OrganizationVariables {
+    pub total_count: i32,
+    pub variables: Vec<OrganizationVariable>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CreateOrganizationVariable<'a> {
+    /// The name of the variable.
+    pub name: &'a str,
+    /// The value of the variable, stored in plain text.
+    pub value: &'a str,
+    /// Which type of organization repositories have access to the variable.
+    pub visibility: Visibility,
+    /// An array of repository ids that can access the variable.
+    /// You can only provide a list of repository ids when the visibility is set to selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_repository_ids: Option<&'a [u32]>,
+}
+
+/// The fields to change on an organization variable. Fields left as `None`
+/// are kept as they are.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct UpdateOrganizationVariable<'a> {
+    /// The new name of the variable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'a str>,
+    /// The new value of the variable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<&'a str>,
+    /// Which type of organization repositories have access to the variable.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<Visibility>,
+    /// An array of repository ids that can access the variable.
+    /// You can only provide a list of repository ids when the visibility is set to selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_repository_ids: Option<&'a [u32]>,
+}
@@ -17,6 +17,7 @@ use super::super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CopilotBilling {
     pub seat_breakdown: CopilotSeatBreakdown,
     pub seat_management_setting: String,
@@ -34,6 +35,7 @@ pub struct CopilotBilling {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CopilotSeatBreakdown {
     pub total: u32,
     pub added_this_cycle: u32,
@@ -45,6 +47,7 @@ pub struct CopilotSeatBreakdown {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CopilotBillingSeats {
     pub total_seats: u32,
     pub seats: Vec<CopilotSeat>,
@@ -52,6 +55,7 @@ pub struct CopilotBillingSeats {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CopilotSeat {
     pub created_at: DateTime<Utc>,
     pub pending_cancellation_date: Option<String>,
@@ -63,6 +67,7 @@ pub struct CopilotSeat {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SeatsCreated {
     /// The total number of seats created for the specified user(s).
     pub seats_created: u32,
@@ -70,6 +75,7 @@ pub struct SeatsCreated {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SeatsCancelled {
     /// The total number of seats set to "pending cancellation" for members of the specified team(s).
     pub seats_cancelled: u32,
@@ -14,6 +14,7 @@ use super::super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CopilotMetrics {
     pub date: NaiveDate,
     pub total_active_users: u32,
@@ -23,6 +24,7 @@ pub struct CopilotMetrics {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CopilotIdeCodeCompletions {
     pub total_engaged_users: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,6 +35,7 @@ pub struct CopilotIdeCodeCompletions {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Language {
     pub name: String,
     pub total_engaged_users: u32,
@@ -40,6 +43,7 @@ pub struct Language {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Editor {
     pub name: String,
     pub total_engaged_users: u32,
@@ -48,6 +52,7 @@ pub struct Editor {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Model {
     pub name: String,
     pub is_custom_model: bool,
@@ -60,6 +65,7 @@ pub struct Model {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct EditorLanguage {
     pub name: String,
     pub total_engaged_users: u32,
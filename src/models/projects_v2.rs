@@ -0,0 +1,212 @@
+//! Projects (v2), which are only available through GitHub's GraphQL API.
+
+use super::*;
+
+/// A project (v2) of a user or an organization.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ProjectV2 {
+    /// The GraphQL node id, used to refer to the project in mutations.
+    pub id: NodeId,
+    pub number: u64,
+    pub title: String,
+    pub short_description: Option<String>,
+    pub readme: Option<String>,
+    pub public: bool,
+    pub closed: bool,
+    pub url: Url,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(deserialize_with = "nodes")]
+    pub fields: Vec<ProjectV2Field>,
+}
+
+/// A field of a project, e.g. `Status` or `Iteration`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ProjectV2Field {
+    pub id: NodeId,
+    pub name: String,
+    /// The type of the field, e.g. `TEXT`, `SINGLE_SELECT` or `ITERATION`.
+    pub data_type: String,
+    /// The options of a single select field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<Vec<ProjectV2SingleSelectOption>>,
+    /// The iterations of an iteration field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub configuration: Option<ProjectV2IterationConfiguration>,
+}
+
+/// An option of a single select field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ProjectV2SingleSelectOption {
+    pub id: String,
+    pub name: String,
+}
+
+/// The iterations of an iteration field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ProjectV2IterationConfiguration {
+    pub iterations: Vec<ProjectV2Iteration>,
+    #[serde(default)]
+    pub completed_iterations: Vec<ProjectV2Iteration>,
+}
+
+/// A single iteration of an iteration field.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ProjectV2Iteration {
+    pub id: String,
+    pub title: String,
+    pub start_date: chrono::NaiveDate,
+    /// The duration of the iteration in days.
+    pub duration: u32,
+}
+
+/// A page of project items, fetched with a GraphQL cursor.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ProjectV2Items {
+    #[serde(rename = "nodes")]
+    pub items: Vec<ProjectV2Item>,
+    pub page_info: PageInfo,
+}
+
+/// The pagination state of a GraphQL connection.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PageInfo {
+    pub has_next_page: bool,
+    /// Pass this to `after` to fetch the next page.
+    pub end_cursor: Option<String>,
+}
+
+/// An item of a project, which is an issue, a pull request or a draft issue.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ProjectV2Item {
+    pub id: NodeId,
+    pub r#type: ProjectV2ItemType,
+    pub is_archived: bool,
+    pub content: Option<ProjectV2ItemContent>,
+    #[serde(deserialize_with = "nodes")]
+    pub field_values: Vec<ProjectV2ItemFieldValue>,
+}
+
+/// The type of a project item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[non_exhaustive]
+pub enum ProjectV2ItemType {
+    Issue,
+    PullRequest,
+    DraftIssue,
+    Redacted,
+}
+
+/// What a project item refers to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum ProjectV2ItemContent {
+    Issue {
+        id: String,
+        number: u64,
+        title: String,
+        url: Url,
+    },
+    PullRequest {
+        id: String,
+        number: u64,
+        title: String,
+        url: Url,
+    },
+    DraftIssue {
+        id: String,
+        title: String,
+        body: String,
+    },
+}
+
+/// The value of a field on a project item.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "__typename")]
+#[non_exhaustive]
+pub enum ProjectV2ItemFieldValue {
+    #[serde(rename = "ProjectV2ItemFieldTextValue")]
+    Text {
+        field: ProjectV2FieldRef,
+        text: Option<String>,
+    },
+    #[serde(rename = "ProjectV2ItemFieldNumberValue")]
+    Number {
+        field: ProjectV2FieldRef,
+        number: Option<f64>,
+    },
+    #[serde(rename = "ProjectV2ItemFieldDateValue")]
+    Date {
+        field: ProjectV2FieldRef,
+        date: Option<chrono::NaiveDate>,
+    },
+    #[serde(rename = "ProjectV2ItemFieldSingleSelectValue")]
+    SingleSelect {
+        field: ProjectV2FieldRef,
+        name: Option<String>,
+        #[serde(rename = "optionId")]
+        option_id: Option<String>,
+    },
+    #[serde(rename = "ProjectV2ItemFieldIterationValue")]
+    Iteration {
+        field: ProjectV2FieldRef,
+        title: String,
+        #[serde(rename = "iterationId")]
+        iteration_id: String,
+        #[serde(rename = "startDate")]
+        start_date: chrono::NaiveDate,
+        duration: u32,
+    },
+    /// Values of built-in fields, such as labels or assignees, which aren't
+    /// fetched.
+    #[serde(other)]
+    Other,
+}
+
+/// The field a value belongs to.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ProjectV2FieldRef {
+    pub id: NodeId,
+    pub name: String,
+}
+
+fn nodes<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+    struct Connection<T> {
+        nodes: Vec<T>,
+    }
+
+    Connection::deserialize(deserializer).map(|connection| connection.nodes)
+}
@@ -3,11 +3,15 @@ use crate::models::commits::CommentReactions;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(
+    all(feature = "strict-models", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct PullRequest {
     pub url: String,
     pub id: PullRequestId,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_id: Option<String>,
+    pub node_id: Option<NodeId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html_url: Option<Url>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -102,10 +106,16 @@ pub struct PullRequest {
     pub review_comments: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comments: Option<u64>,
+    /// Fields GitHub sent that aren't part of this model yet.
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Head {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
@@ -120,6 +130,7 @@ pub struct Head {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Base {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub label: Option<String>,
@@ -134,6 +145,7 @@ pub struct Base {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Links {
     #[serde(rename = "self")]
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -159,62 +171,72 @@ pub struct Links {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SelfLink {
     pub href: Url,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct HtmlLink {
     pub href: Url,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssueLink {
     pub href: Url,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommentsLink {
     pub href: Url,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ReviewCommentsLink {
     pub href: Url,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ReviewCommentLink {
     pub href: Url,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommitsLink {
     pub href: Url,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct StatusesLink {
     pub href: Url,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestLink {
     pub href: Url,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Review {
     pub id: ReviewId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub html_url: Url,
     pub user: Option<Author>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -238,7 +260,7 @@ pub struct Review {
     pub author_association: Option<AuthorAssociation>,
 }
 
-#[derive(Debug, Copy, Clone, PartialEq, Serialize)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(rename_all(serialize = "SCREAMING_SNAKE_CASE"))]
 #[non_exhaustive]
 pub enum ReviewState {
@@ -248,6 +270,8 @@ pub enum ReviewState {
     ChangesRequested,
     Commented,
     Dismissed,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Serialize)]
@@ -261,11 +285,15 @@ pub enum ReviewAction {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(
+    all(feature = "strict-models", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Comment {
     pub url: Url,
     pub pull_request_review_id: Option<ReviewId>,
     pub id: CommentId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub diff_hunk: String,
     pub path: String,
     pub position: Option<u64>,
@@ -293,16 +321,22 @@ pub struct Comment {
     pub line: Option<u64>,
     pub original_line: Option<u64>,
     pub side: Option<String>,
+    /// Fields GitHub sent that aren't part of this model yet.
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 ///Legacy Review Comment
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ReviewComment {
     pub url: Url,
     pub pull_request_review_id: Option<ReviewId>,
     pub id: CommentId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub diff_hunk: String,
     pub path: String,
     pub position: Option<u64>,
@@ -346,9 +380,87 @@ pub enum Side {
 /// A Thread in a pull request review
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Thread {
     pub comments: Vec<Comment>,
-    pub node_id: String,
+    pub node_id: NodeId,
+}
+
+/// A conversation on a pull request diff: a top level review comment and all
+/// the replies to it, in the order they were created.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct CommentThread {
+    pub root: Comment,
+    pub replies: Vec<Comment>,
+}
+
+impl CommentThread {
+    /// Groups review comments into conversation threads using their
+    /// `in_reply_to_id`. Threads are ordered by when their top level comment
+    /// was created. Replies whose parent isn't part of `comments` (e.g. because
+    /// it is on another page) start a thread of their own.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::models::pulls::CommentThread;
+    ///
+    /// let octocrab = octocrab::instance();
+    /// let page = octocrab.pulls("owner", "repo").list_comments(Some(5)).send().await?;
+    /// let comments = octocrab.all_pages(page).await?;
+    /// for thread in CommentThread::from_comments(comments) {
+    ///     println!("{}: {} replies", thread.root.path, thread.replies.len());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_comments(comments: impl IntoIterator<Item = Comment>) -> Vec<CommentThread> {
+        let comments: Vec<Comment> = comments.into_iter().collect();
+        let parents: HashMap<CommentId, Option<CommentId>> = comments
+            .iter()
+            .map(|comment| (comment.id, comment.in_reply_to_id))
+            .collect();
+        let root_of = |mut id: CommentId| {
+            // Guard against cycles, a thread can't be longer than all comments.
+            for _ in 0..parents.len() {
+                match parents.get(&id) {
+                    Some(Some(parent)) if parents.contains_key(parent) => id = *parent,
+                    _ => break,
+                }
+            }
+            id
+        };
+
+        let mut roots = Vec::new();
+        let mut replies: HashMap<CommentId, Vec<Comment>> = HashMap::new();
+        for comment in comments {
+            let root = root_of(comment.id);
+            if root == comment.id {
+                roots.push(comment);
+            } else {
+                replies.entry(root).or_default().push(comment);
+            }
+        }
+
+        roots.sort_by_key(|comment| comment.created_at);
+        roots
+            .into_iter()
+            .map(|root| {
+                let mut replies = replies.remove(&root.id).unwrap_or_default();
+                replies.sort_by_key(|comment| comment.created_at);
+                CommentThread { root, replies }
+            })
+            .collect()
+    }
+
+    /// The path of the file the thread is about.
+    pub fn path(&self) -> &str {
+        &self.root.path
+    }
+
+    /// All the comments in the thread, starting with the top level one.
+    pub fn comments(&self) -> impl Iterator<Item = &Comment> {
+        std::iter::once(&self.root).chain(self.replies.iter())
+    }
 }
 
 // This is rather annoying, but Github uses both SCREAMING_SNAKE_CASE and snake_case
@@ -380,7 +492,7 @@ impl<'de> Deserialize<'de> for ReviewState {
                     "CHANGES_REQUESTED" | "changes_requested" => ReviewState::ChangesRequested,
                     "COMMENTED" | "commented" => ReviewState::Commented,
                     "DISMISSED" | "dismissed" => ReviewState::Dismissed,
-                    unknown => return Err(E::custom(format!("unknown variant `{unknown}`, expected one of `open`, `approved`, `pending`, `changes_requested`, `commented`, `dismissed`"))),
+                    unknown => ReviewState::Unknown(unknown.to_owned()),
                 })
             }
         }
@@ -458,6 +570,7 @@ pub enum PullRequestAction {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Merge {
     pub sha: Option<String>,
     pub message: Option<String>,
@@ -500,7 +613,7 @@ mod test {
         use super::ReviewState;
 
         let states: Vec<ReviewState> = serde_json::from_str(
-            r#"["APPROVED","pending","CHANGES_REQUESTED","commented", "dismissed"]"#,
+            r#"["APPROVED","pending","CHANGES_REQUESTED","commented", "dismissed", "SUPERSEDED"]"#,
         )
         .unwrap();
 
@@ -512,7 +625,12 @@ mod test {
                 ReviewState::ChangesRequested,
                 ReviewState::Commented,
                 ReviewState::Dismissed,
+                ReviewState::Unknown("SUPERSEDED".to_string()),
             ]
         );
+        assert_eq!(
+            serde_json::to_string(&states[5]).unwrap(),
+            r#""SUPERSEDED""#
+        );
     }
 }
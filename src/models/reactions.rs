@@ -1,6 +1,6 @@
 use super::*;
 
-#[derive(Debug, Clone, Hash, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum ReactionContent {
     #[serde(rename = "heart")]
@@ -23,9 +23,10 @@ pub enum ReactionContent {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Reaction {
     pub id: ReactionId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub user: Author,
     pub content: ReactionContent,
     pub created_at: chrono::DateTime<chrono::Utc>,
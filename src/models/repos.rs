@@ -7,17 +7,24 @@ use hyper::Response;
 use snafu::ResultExt;
 use url::Url;
 
+pub mod activity;
 pub mod dependabot;
+pub mod dependency_graph;
+pub mod diff;
+pub mod pages;
 pub mod secret_scanning_alert;
+pub mod secret_scanning_bypass;
 pub mod secrets;
+pub mod stats;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Ref {
     #[serde(rename = "ref")]
     pub ref_field: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub url: Url,
     pub object: Object,
 }
@@ -33,10 +40,11 @@ pub enum Object {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepoCommit {
     pub url: String,
     pub sha: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub html_url: String,
     pub comments_url: String,
     pub commit: RepoCommitPage,
@@ -55,6 +63,7 @@ pub struct RepoCommit {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepoCommitPage {
     pub url: Url,
     pub author: Option<CommitAuthor>,
@@ -69,6 +78,7 @@ pub struct RepoCommitPage {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Verification {
     pub verified: bool,
     pub reason: String,
@@ -78,6 +88,7 @@ pub struct Verification {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct DiffEntry {
     // unlike the schema online, this can be null if only metadata changed
     pub sha: Option<String>,
@@ -100,6 +111,15 @@ pub struct DiffEntry {
     pub previous_filename: Option<String>,
 }
 
+impl DiffEntry {
+    /// Parses [`DiffEntry::patch`] into hunks and numbered lines. Returns
+    /// `None` when GitHub omitted the patch, e.g. for binary or very large
+    /// files.
+    pub fn parsed_patch(&self) -> Option<Result<diff::Patch, diff::ParsePatchError>> {
+        self.patch.as_deref().map(diff::Patch::parse)
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
@@ -115,6 +135,7 @@ pub enum DiffEntryStatus {
 
 #[non_exhaustive]
 #[derive(Debug, Serialize, Deserialize, PartialEq, Clone)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepoChangeStatus {
     pub total: Option<u64>,
     pub additions: Option<u64>,
@@ -123,13 +144,14 @@ pub struct RepoChangeStatus {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Commit {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub sha: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_id: Option<String>,
+    pub node_id: Option<NodeId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html_url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -157,6 +179,7 @@ pub struct GitUserTime {
 /// The author of a commit, identified by its name and email.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommitAuthor {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -168,6 +191,7 @@ pub struct CommitAuthor {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct FileUpdate {
     pub content: Content,
     pub commit: Commit,
@@ -176,6 +200,7 @@ pub struct FileUpdate {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct FileDeletion {
     pub content: Option<Content>,
     pub commit: Commit,
@@ -184,6 +209,7 @@ pub struct FileDeletion {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Content {
     pub name: String,
     pub path: String,
@@ -269,6 +295,7 @@ impl crate::FromResponse for ContentItems {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ContentLinks {
     pub git: Option<Url>,
     pub html: Option<Url>,
@@ -279,6 +306,7 @@ pub struct ContentLinks {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Branch {
     pub name: String,
     pub commit: CommitObject,
@@ -288,17 +316,19 @@ pub struct Branch {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Tag {
     pub name: String,
     pub commit: CommitObject,
     pub zipball_url: Url,
     pub tarball_url: Url,
-    pub node_id: String,
+    pub node_id: NodeId,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommitObject {
     pub sha: String,
     pub url: Url,
@@ -307,6 +337,10 @@ pub struct CommitObject {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(
+    all(feature = "strict-models", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Release {
     pub url: Url,
     pub html_url: Url,
@@ -315,7 +349,7 @@ pub struct Release {
     pub tarball_url: Option<Url>,
     pub zipball_url: Option<Url>,
     pub id: ReleaseId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub tag_name: String,
     pub target_commitish: String,
     pub name: Option<String>,
@@ -328,11 +362,20 @@ pub struct Release {
     pub published_at: Option<DateTime<Utc>>,
     pub author: Option<crate::models::Author>,
     pub assets: Vec<Asset>,
+    /// Per-reaction counts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<crate::models::commits::CommentReactions>,
+    /// Fields GitHub sent that aren't part of this model yet.
+    #[cfg(feature = "extra-fields")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "extra-fields")))]
+    #[serde(flatten)]
+    pub extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ReleaseNotes {
     pub name: String,
     pub body: String,
@@ -341,11 +384,12 @@ pub struct ReleaseNotes {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Asset {
     pub url: Url,
     pub browser_download_url: Url,
     pub id: AssetId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub name: String,
     pub label: Option<String>,
     pub state: String,
@@ -361,12 +405,13 @@ pub struct Asset {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Uploader {
     pub name: Option<String>,
     pub email: Option<String>,
     pub login: String,
     pub id: UploaderId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub avatar_url: Url,
     pub gravatar_id: Option<String>,
     pub url: Url,
@@ -388,8 +433,9 @@ pub struct Uploader {
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 /// Metadata for a Git tag
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct GitTag {
-    pub node_id: String,
+    pub node_id: NodeId,
     /// Name of the tag. Example: v0.0.1
     pub tag: String,
     pub sha: String,
@@ -399,16 +445,18 @@ pub struct GitTag {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct MergeCommit {
     pub url: Url,
     pub sha: String,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub html_url: String,
     pub comments_url: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepoPermission {
     /// Provides the legacy base roles of admin, write, read, and none, where the
     /// maintain role is mapped to write and the triage role is mapped to read.
@@ -421,6 +469,143 @@ pub struct RepoPermission {
 /// A HashMap of languages and the number of bytes of code written in that language.
 pub type Languages = std::collections::HashMap<String, i64>;
 
+/// The security and analysis features of a repository, each of which is
+/// only sent when it's available to the repository. Also used to toggle
+/// them with [`UpdateRepoBuilder::security_and_analysis`](crate::repos::UpdateRepoBuilder::security_and_analysis).
+/// ```
+/// use octocrab::models::repos::SecurityAndAnalysis;
+///
+/// let settings = SecurityAndAnalysis::new()
+///     .secret_scanning(true)
+///     .secret_scanning_push_protection(true);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SecurityAndAnalysis {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub advanced_security: Option<SecurityFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code_security: Option<SecurityFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dependabot_security_updates: Option<SecurityFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_scanning: Option<SecurityFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_scanning_push_protection: Option<SecurityFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_scanning_non_provider_patterns: Option<SecurityFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_scanning_ai_detection: Option<SecurityFeature>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret_scanning_validity_checks: Option<SecurityFeature>,
+}
+
+impl SecurityAndAnalysis {
+    /// Settings which leave every feature as it is.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Enables or disables GitHub Advanced Security.
+    pub fn advanced_security(mut self, enabled: bool) -> Self {
+        self.advanced_security = Some(enabled.into());
+        self
+    }
+
+    /// Enables or disables GitHub Code Security.
+    pub fn code_security(mut self, enabled: bool) -> Self {
+        self.code_security = Some(enabled.into());
+        self
+    }
+
+    /// Enables or disables Dependabot security updates, which needs
+    /// Dependabot alerts, see
+    /// [`RepoDependabotAlertsHandler::enable_vulnerability_alerts`](crate::repos::RepoDependabotAlertsHandler::enable_vulnerability_alerts).
+    pub fn dependabot_security_updates(mut self, enabled: bool) -> Self {
+        self.dependabot_security_updates = Some(enabled.into());
+        self
+    }
+
+    /// Enables or disables secret scanning.
+    pub fn secret_scanning(mut self, enabled: bool) -> Self {
+        self.secret_scanning = Some(enabled.into());
+        self
+    }
+
+    /// Enables or disables push protection, which needs secret scanning.
+    pub fn secret_scanning_push_protection(mut self, enabled: bool) -> Self {
+        self.secret_scanning_push_protection = Some(enabled.into());
+        self
+    }
+
+    /// Enables or disables scanning for secrets which don't belong to a
+    /// known provider.
+    pub fn secret_scanning_non_provider_patterns(mut self, enabled: bool) -> Self {
+        self.secret_scanning_non_provider_patterns = Some(enabled.into());
+        self
+    }
+
+    /// Enables or disables detecting passwords with AI.
+    pub fn secret_scanning_ai_detection(mut self, enabled: bool) -> Self {
+        self.secret_scanning_ai_detection = Some(enabled.into());
+        self
+    }
+
+    /// Enables or disables checking whether detected secrets are still
+    /// valid.
+    pub fn secret_scanning_validity_checks(mut self, enabled: bool) -> Self {
+        self.secret_scanning_validity_checks = Some(enabled.into());
+        self
+    }
+}
+
+/// Whether a security feature of a repository is enabled.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SecurityFeature {
+    pub status: SecurityFeatureStatus,
+}
+
+impl SecurityFeature {
+    pub fn is_enabled(&self) -> bool {
+        self.status == SecurityFeatureStatus::Enabled
+    }
+}
+
+impl From<bool> for SecurityFeature {
+    fn from(enabled: bool) -> Self {
+        let status = if enabled {
+            SecurityFeatureStatus::Enabled
+        } else {
+            SecurityFeatureStatus::Disabled
+        };
+        Self { status }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SecurityFeatureStatus {
+    Enabled,
+    Disabled,
+    #[serde(untagged)]
+    Unknown(String),
+}
+
+/// Whether Dependabot security updates are enabled for a repository.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct AutomatedSecurityFixes {
+    pub enabled: bool,
+    /// Whether the updates were paused, e.g. because the repository is
+    /// inactive.
+    pub paused: bool,
+}
+
 mod maybe_empty {
     use serde::{Deserialize, Deserializer};
 
@@ -459,6 +644,7 @@ mod maybe_empty {
         use serde_json::json;
 
         #[derive(Deserialize, Debug)]
+        #[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
         struct Struct {
             #[serde(deserialize_with = "deserialize")]
             value: Option<u32>,
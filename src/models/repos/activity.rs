@@ -0,0 +1,36 @@
+use super::super::*;
+
+/// A change to a ref of a repository, e.g. a push or the deletion of a
+/// branch, from the activity of the repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct RepositoryActivity {
+    pub id: u64,
+    pub node_id: NodeId,
+    /// The SHA the ref pointed to before the change.
+    pub before: String,
+    /// The SHA the ref points to after the change.
+    pub after: String,
+    /// The full name of the ref, e.g. `refs/heads/main`.
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    pub timestamp: DateTime<Utc>,
+    pub activity_type: ActivityType,
+    pub actor: Option<Author>,
+}
+
+/// The kind of change to a ref of a repository.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ActivityType {
+    Push,
+    ForcePush,
+    BranchCreation,
+    BranchDeletion,
+    PrMerge,
+    MergeQueueMerge,
+    #[serde(untagged)]
+    Unknown(String),
+}
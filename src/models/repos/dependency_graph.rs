@@ -0,0 +1,153 @@
+use super::super::*;
+
+/// The response envelope of the dependency graph SBOM export.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SbomResponse {
+    pub sbom: Sbom,
+}
+
+/// A software bill of materials for a repository, in SPDX format.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Sbom {
+    #[serde(rename = "SPDXID")]
+    pub spdx_id: String,
+    #[serde(rename = "spdxVersion")]
+    pub spdx_version: String,
+    #[serde(rename = "creationInfo")]
+    pub creation_info: SbomCreationInfo,
+    pub name: String,
+    #[serde(rename = "dataLicense")]
+    pub data_license: String,
+    #[serde(rename = "documentNamespace")]
+    pub document_namespace: String,
+    #[serde(
+        rename = "documentDescribes",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub document_describes: Vec<String>,
+    #[serde(default)]
+    pub packages: Vec<SbomPackage>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub relationships: Vec<SbomRelationship>,
+}
+
+/// Who created the SBOM and when.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SbomCreationInfo {
+    pub created: DateTime<Utc>,
+    #[serde(default)]
+    pub creators: Vec<String>,
+}
+
+/// A single package listed in the SBOM.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SbomPackage {
+    #[serde(rename = "SPDXID", skip_serializing_if = "Option::is_none")]
+    pub spdx_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "versionInfo", skip_serializing_if = "Option::is_none")]
+    pub version_info: Option<String>,
+    #[serde(rename = "downloadLocation", skip_serializing_if = "Option::is_none")]
+    pub download_location: Option<String>,
+    #[serde(rename = "filesAnalyzed", skip_serializing_if = "Option::is_none")]
+    pub files_analyzed: Option<bool>,
+    #[serde(rename = "licenseConcluded", skip_serializing_if = "Option::is_none")]
+    pub license_concluded: Option<String>,
+    #[serde(rename = "licenseDeclared", skip_serializing_if = "Option::is_none")]
+    pub license_declared: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub supplier: Option<String>,
+    #[serde(rename = "copyrightText", skip_serializing_if = "Option::is_none")]
+    pub copyright_text: Option<String>,
+    #[serde(
+        rename = "externalRefs",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub external_refs: Vec<SbomExternalRef>,
+}
+
+/// An external reference of a package, such as its package URL.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SbomExternalRef {
+    #[serde(rename = "referenceCategory")]
+    pub reference_category: String,
+    #[serde(rename = "referenceLocator")]
+    pub reference_locator: String,
+    #[serde(rename = "referenceType")]
+    pub reference_type: String,
+}
+
+/// A relationship between two elements of the SBOM.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SbomRelationship {
+    #[serde(rename = "relationshipType")]
+    pub relationship_type: String,
+    #[serde(rename = "spdxElementId")]
+    pub spdx_element_id: String,
+    #[serde(rename = "relatedSpdxElement")]
+    pub related_spdx_element: String,
+}
+
+/// A dependency which was added or removed between two revisions.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DependencyChange {
+    pub change_type: DependencyChangeType,
+    pub manifest: String,
+    pub ecosystem: String,
+    pub name: String,
+    pub version: String,
+    pub package_url: Option<String>,
+    pub license: Option<String>,
+    pub source_repository_url: Option<String>,
+    #[serde(default)]
+    pub vulnerabilities: Vec<DependencyVulnerability>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<DependencyScope>,
+}
+
+/// Whether a dependency was added or removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DependencyChangeType {
+    Added,
+    Removed,
+}
+
+/// Where a dependency is used.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DependencyScope {
+    Unknown,
+    Runtime,
+    Development,
+}
+
+/// A known vulnerability affecting a changed dependency.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DependencyVulnerability {
+    pub severity: String,
+    pub advisory_ghsa_id: String,
+    pub advisory_summary: String,
+    pub advisory_url: Url,
+}
@@ -0,0 +1,183 @@
+//! Parsing of the unified diffs GitHub returns in [`DiffEntry::patch`].
+//!
+//! [`DiffEntry::patch`]: super::DiffEntry::patch
+
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed unified diff for a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Patch {
+    pub hunks: Vec<Hunk>,
+}
+
+/// A contiguous block of changes, introduced by a `@@ -a,b +c,d @@` header.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Hunk {
+    /// First line of the hunk in the old file.
+    pub old_start: u64,
+    /// Number of lines of the old file covered by the hunk.
+    pub old_lines: u64,
+    /// First line of the hunk in the new file.
+    pub new_start: u64,
+    /// Number of lines of the new file covered by the hunk.
+    pub new_lines: u64,
+    /// The text following the closing `@@`, usually the enclosing function.
+    pub section: Option<String>,
+    pub lines: Vec<DiffLine>,
+}
+
+/// A single line of a hunk.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    /// The line without its leading `+`, `-` or space.
+    pub content: String,
+    /// Line number in the old file, `None` for additions.
+    pub old_line: Option<u64>,
+    /// Line number in the new file, `None` for deletions.
+    pub new_line: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum DiffLineKind {
+    Context,
+    Addition,
+    Deletion,
+}
+
+/// The error returned when a patch isn't a valid unified diff.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePatchError {
+    line: usize,
+    message: &'static str,
+}
+
+impl ParsePatchError {
+    /// The 1-based line of the patch that failed to parse.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+}
+
+impl fmt::Display for ParsePatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid patch at line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParsePatchError {}
+
+impl Patch {
+    /// Parses a unified diff as found in [`DiffEntry::patch`].
+    ///
+    /// ```
+    /// use octocrab::models::repos::diff::{DiffLineKind, Patch};
+    ///
+    /// let patch = Patch::parse("@@ -1,2 +1,2 @@ fn main()\n-old\n+new\n context").unwrap();
+    /// let hunk = &patch.hunks[0];
+    /// assert_eq!(hunk.section.as_deref(), Some("fn main()"));
+    /// assert_eq!(hunk.lines[1].kind, DiffLineKind::Addition);
+    /// assert_eq!(hunk.lines[1].new_line, Some(1));
+    /// ```
+    ///
+    /// [`DiffEntry::patch`]: super::DiffEntry::patch
+    pub fn parse(patch: &str) -> Result<Self, ParsePatchError> {
+        let mut hunks: Vec<Hunk> = Vec::new();
+        let mut old_line = 0;
+        let mut new_line = 0;
+
+        for (index, line) in patch.lines().enumerate() {
+            let error = |message| ParsePatchError {
+                line: index + 1,
+                message,
+            };
+
+            if line.starts_with("@@") {
+                let hunk = parse_hunk_header(line).ok_or_else(|| error("malformed hunk header"))?;
+                old_line = hunk.old_start;
+                new_line = hunk.new_start;
+                hunks.push(hunk);
+                continue;
+            }
+
+            let hunk = hunks
+                .last_mut()
+                .ok_or_else(|| error("expected a hunk header"))?;
+            let (kind, content) = match line.chars().next() {
+                Some('+') => (DiffLineKind::Addition, &line[1..]),
+                Some('-') => (DiffLineKind::Deletion, &line[1..]),
+                Some(' ') => (DiffLineKind::Context, &line[1..]),
+                // Blank context lines sometimes lose their leading space.
+                None => (DiffLineKind::Context, ""),
+                // "\ No newline at end of file"
+                Some('\\') => continue,
+                Some(_) => return Err(error("unexpected line prefix")),
+            };
+
+            let (old, new) = match kind {
+                DiffLineKind::Addition => (None, Some(new_line)),
+                DiffLineKind::Deletion => (Some(old_line), None),
+                DiffLineKind::Context => (Some(old_line), Some(new_line)),
+            };
+            if old.is_some() {
+                old_line += 1;
+            }
+            if new.is_some() {
+                new_line += 1;
+            }
+
+            hunk.lines.push(DiffLine {
+                kind,
+                content: content.to_owned(),
+                old_line: old,
+                new_line: new,
+            });
+        }
+
+        Ok(Self { hunks })
+    }
+
+    /// All lines across every hunk.
+    pub fn lines(&self) -> impl Iterator<Item = &DiffLine> {
+        self.hunks.iter().flat_map(|hunk| hunk.lines.iter())
+    }
+}
+
+impl FromStr for Patch {
+    type Err = ParsePatchError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Parses `@@ -old_start[,old_lines] +new_start[,new_lines] @@[ section]`.
+fn parse_hunk_header(line: &str) -> Option<Hunk> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (ranges, section) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let (old_start, old_lines) = parse_range(old)?;
+    let (new_start, new_lines) = parse_range(new)?;
+    let section = section.trim();
+
+    Some(Hunk {
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        section: (!section.is_empty()).then(|| section.to_owned()),
+        lines: Vec::new(),
+    })
+}
+
+fn parse_range(range: &str) -> Option<(u64, u64)> {
+    match range.split_once(',') {
+        Some((start, lines)) => Some((start.parse().ok()?, lines.parse().ok()?)),
+        None => Some((range.parse().ok()?, 1)),
+    }
+}
@@ -0,0 +1,147 @@
+use super::super::*;
+
+/// The configuration of a GitHub Pages site.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PagesSite {
+    pub url: Url,
+    pub status: Option<PagesStatus>,
+    pub cname: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected_domain_state: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pending_domain_unverified_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub custom_404: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<Url>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_type: Option<PagesBuildType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<PagesSource>,
+    pub public: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub https_certificate: Option<PagesHttpsCertificate>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub https_enforced: Option<bool>,
+}
+
+/// The status of the most recent build of a Pages site.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PagesStatus {
+    Built,
+    Building,
+    Errored,
+}
+
+/// How a Pages site is built.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum PagesBuildType {
+    /// Built by GitHub from a branch and path.
+    Legacy,
+    /// Built and deployed by a GitHub Actions workflow.
+    Workflow,
+}
+
+/// The branch and directory a Pages site is published from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PagesSource {
+    pub branch: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+}
+
+impl PagesSource {
+    /// Creates a new source publishing from `branch`, optionally restricted to `path`
+    /// (either `/` or `/docs`).
+    pub fn new(branch: impl Into<String>, path: Option<String>) -> Self {
+        Self {
+            branch: branch.into(),
+            path,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PagesHttpsCertificate {
+    pub state: String,
+    pub description: String,
+    pub domains: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<chrono::NaiveDate>,
+}
+
+/// A single build of a Pages site.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PagesBuild {
+    pub url: Url,
+    pub status: String,
+    pub error: PagesBuildError,
+    pub pusher: Option<Author>,
+    pub commit: String,
+    pub duration: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PagesBuildError {
+    pub message: Option<String>,
+}
+
+/// The response to a build request, the build itself is queued.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PagesBuildStatus {
+    pub url: Url,
+    pub status: String,
+}
+
+/// The DNS health check for a Pages site and its alternate domain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PagesHealthCheck {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub domain: Option<PagesDomainHealth>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alt_domain: Option<PagesDomainHealth>,
+}
+
+/// Health of a single domain. GitHub reports a large set of checks here, only
+/// the most commonly used are typed, the remaining ones are kept in `other`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PagesDomainHealth {
+    pub host: Option<String>,
+    pub uri: Option<String>,
+    pub nameservers: Option<String>,
+    pub dns_resolves: Option<bool>,
+    pub is_valid_domain: Option<bool>,
+    pub is_apex_domain: Option<bool>,
+    pub is_pointed_to_github_pages_ip: Option<bool>,
+    pub is_cname_to_github_user_domain: Option<bool>,
+    pub is_served_by_pages: Option<bool>,
+    pub is_valid: Option<bool>,
+    pub reason: Option<String>,
+    pub responds_to_https: Option<bool>,
+    pub enforces_https: Option<bool>,
+    pub https_error: Option<String>,
+    pub caa_error: Option<String>,
+    #[serde(flatten)]
+    pub other: HashMap<String, serde_json::Value>,
+}
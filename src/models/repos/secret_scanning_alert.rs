@@ -1,6 +1,7 @@
 use super::super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct SecretScanningAlert {
     pub number: i64,
     pub created_at: DateTime<Utc>,
@@ -0,0 +1,98 @@
+use super::super::*;
+use crate::params::secret_scanning::BypassReason;
+
+/// A request to bypass secret scanning push protection, awaiting review by
+/// a designated reviewer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct BypassRequest {
+    pub id: u64,
+    pub number: u64,
+    pub repository: Option<BypassRequestRepository>,
+    pub organization: Option<BypassRequestOrganization>,
+    pub requester: Option<BypassActor>,
+    pub request_type: Option<String>,
+    pub data: Option<Vec<BypassRequestData>>,
+    pub resource_identifier: Option<String>,
+    pub status: BypassRequestState,
+    pub requester_comment: Option<String>,
+    pub expires_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub responses: Option<Vec<BypassResponse>>,
+    pub url: Url,
+    pub html_url: Url,
+}
+
+/// The state of a push protection bypass request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BypassRequestState {
+    Pending,
+    Denied,
+    Approved,
+    Cancelled,
+    Completed,
+    Expired,
+    Deleted,
+    Open,
+}
+
+/// The repository a bypass request was made in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct BypassRequestRepository {
+    pub id: RepositoryId,
+    pub name: String,
+    pub full_name: String,
+}
+
+/// The organization a bypass request was made in.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct BypassRequestOrganization {
+    pub id: OrgId,
+    pub name: String,
+}
+
+/// A user requesting or reviewing a bypass.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct BypassActor {
+    pub actor_id: UserId,
+    pub actor_name: String,
+}
+
+/// A secret a bypass was requested for.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct BypassRequestData {
+    pub secret_type: Option<String>,
+    pub bypass_reason: Option<String>,
+}
+
+/// A review of a bypass request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct BypassResponse {
+    pub id: u64,
+    pub reviewer: Option<BypassActor>,
+    pub status: String,
+    pub created_at: DateTime<Utc>,
+}
+
+/// A push protection bypass created for a blocked secret.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct PushProtectionBypass {
+    pub reason: BypassReason,
+    pub expire_at: Option<DateTime<Utc>>,
+    pub token_type: Option<String>,
+}
@@ -1,6 +1,7 @@
 use super::super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepositorySecret {
     pub name: String,
     pub created_at: DateTime<Utc>,
@@ -9,6 +10,7 @@ pub struct RepositorySecret {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepositorySecrets {
     pub total_count: i32,
     pub secrets: Vec<RepositorySecret>,
@@ -0,0 +1,106 @@
+use super::super::*;
+
+/// The contributions of a contributor to the default branch of a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ContributorStats {
+    pub author: Option<Author>,
+    /// The number of commits of the contributor.
+    pub total: u64,
+    pub weeks: Vec<ContributorWeek>,
+}
+
+/// The contributions of a contributor during a week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ContributorWeek {
+    /// The start of the week, as a Unix timestamp.
+    #[serde(rename = "w")]
+    pub week: i64,
+    /// The number of additions.
+    #[serde(rename = "a")]
+    pub additions: u64,
+    /// The number of deletions.
+    #[serde(rename = "d")]
+    pub deletions: u64,
+    /// The number of commits.
+    #[serde(rename = "c")]
+    pub commits: u64,
+}
+
+/// The commits to a repository during a week.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CommitActivity {
+    /// The number of commits on each day of the week, starting on Sunday.
+    pub days: Vec<u64>,
+    pub total: u64,
+    /// The start of the week, as a Unix timestamp.
+    pub week: i64,
+}
+
+/// The additions and deletions to a repository during a week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "(i64, i64, i64)", into = "(i64, i64, i64)")]
+#[non_exhaustive]
+pub struct CodeFrequency {
+    /// The start of the week, as a Unix timestamp.
+    pub week: i64,
+    pub additions: i64,
+    /// The number of deletions, which GitHub sends as a negative number.
+    pub deletions: i64,
+}
+
+impl From<(i64, i64, i64)> for CodeFrequency {
+    fn from((week, additions, deletions): (i64, i64, i64)) -> Self {
+        Self {
+            week,
+            additions,
+            deletions,
+        }
+    }
+}
+
+impl From<CodeFrequency> for (i64, i64, i64) {
+    fn from(frequency: CodeFrequency) -> Self {
+        (frequency.week, frequency.additions, frequency.deletions)
+    }
+}
+
+/// The weekly commit counts of the last year, oldest first.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Participation {
+    /// The commits of everyone.
+    pub all: Vec<u64>,
+    /// The commits of the owner of the repository.
+    pub owner: Vec<u64>,
+}
+
+/// The commits to a repository during an hour of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(from = "(u8, u8, u64)", into = "(u8, u8, u64)")]
+#[non_exhaustive]
+pub struct PunchCardHour {
+    /// The day of the week, from 0 for Sunday to 6 for Saturday.
+    pub day: u8,
+    /// The hour of the day, from 0 to 23.
+    pub hour: u8,
+    pub commits: u64,
+}
+
+impl From<(u8, u8, u64)> for PunchCardHour {
+    fn from((day, hour, commits): (u8, u8, u64)) -> Self {
+        Self { day, hour, commits }
+    }
+}
+
+impl From<PunchCardHour> for (u8, u8, u64) {
+    fn from(hour: PunchCardHour) -> Self {
+        (hour.day, hour.hour, hour.commits)
+    }
+}
@@ -0,0 +1,54 @@
+use super::*;
+
+/// A topic found by [`SearchHandler::topics`](crate::search::SearchHandler::topics).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Topic {
+    pub name: String,
+    pub display_name: Option<String>,
+    pub short_description: Option<String>,
+    pub description: Option<String>,
+    pub created_by: Option<String>,
+    pub released: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub featured: bool,
+    pub curated: bool,
+    pub score: f64,
+    pub repository_count: Option<u64>,
+    pub logo_url: Option<Url>,
+    pub related: Option<Vec<TopicRelationWrapper>>,
+    pub aliases: Option<Vec<TopicRelationWrapper>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct TopicRelationWrapper {
+    pub topic_relation: TopicRelation,
+}
+
+/// A topic related to, or an alias of, another topic.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct TopicRelation {
+    pub id: Option<u64>,
+    pub name: Option<String>,
+    pub topic_id: Option<u64>,
+    pub relation_type: Option<String>,
+}
+
+/// A page of results of a GraphQL search, with the fields requested from
+/// [`SearchHandler::graphql_issues_and_pull_requests`](crate::search::SearchHandler::graphql_issues_and_pull_requests)
+/// deserialized into `T`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct GraphQLSearchResults<T> {
+    pub issue_count: u64,
+    pub page_info: super::projects_v2::PageInfo,
+    pub nodes: Vec<T>,
+}
@@ -0,0 +1,197 @@
+use super::*;
+use crate::params::security_advisories::{
+    AdvisoryState, AdvisoryType, CreditType, Ecosystem, Severity,
+};
+
+/// A security advisory drafted or published by a repository.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct RepositoryAdvisory {
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub url: Url,
+    pub html_url: Url,
+    pub summary: String,
+    pub description: Option<String>,
+    pub severity: Option<Severity>,
+    pub author: Option<SimpleUser>,
+    pub publisher: Option<SimpleUser>,
+    #[serde(default)]
+    pub identifiers: Vec<AdvisoryIdentifier>,
+    pub state: AdvisoryState,
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    pub published_at: Option<DateTime<Utc>>,
+    pub closed_at: Option<DateTime<Utc>>,
+    pub withdrawn_at: Option<DateTime<Utc>>,
+    /// Set if the advisory was privately reported by someone outside the
+    /// repository.
+    pub submission: Option<AdvisorySubmission>,
+    pub vulnerabilities: Option<Vec<AdvisoryVulnerability>>,
+    pub cvss: Option<AdvisoryCvss>,
+    pub cwes: Option<Vec<AdvisoryCwe>>,
+    pub cwe_ids: Option<Vec<String>>,
+    pub credits: Option<Vec<AdvisoryCredit>>,
+    pub collaborating_users: Option<Vec<SimpleUser>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_fork: Option<Repository>,
+}
+
+/// The private vulnerability report an advisory was created from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct AdvisorySubmission {
+    /// Whether the report was accepted by the maintainers.
+    pub accepted: bool,
+}
+
+/// An advisory in GitHub's global advisory database.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct GlobalAdvisory {
+    pub ghsa_id: String,
+    pub cve_id: Option<String>,
+    pub url: Url,
+    pub html_url: Url,
+    pub repository_advisory_url: Option<Url>,
+    pub summary: String,
+    pub description: Option<String>,
+    pub r#type: AdvisoryType,
+    pub severity: Severity,
+    pub source_code_location: Option<String>,
+    pub identifiers: Option<Vec<AdvisoryIdentifier>>,
+    pub references: Option<Vec<String>>,
+    pub published_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub github_reviewed_at: Option<DateTime<Utc>>,
+    pub nvd_published_at: Option<DateTime<Utc>>,
+    pub withdrawn_at: Option<DateTime<Utc>>,
+    pub vulnerabilities: Option<Vec<GlobalAdvisoryVulnerability>>,
+    pub cvss: Option<AdvisoryCvss>,
+    pub cwes: Option<Vec<AdvisoryCwe>>,
+    pub credits: Option<Vec<GlobalAdvisoryCredit>>,
+}
+
+/// An identifier of an advisory, such as its GHSA or CVE id.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct AdvisoryIdentifier {
+    pub r#type: String,
+    pub value: String,
+}
+
+/// The package affected by a vulnerability.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct AdvisoryPackage {
+    pub ecosystem: Ecosystem,
+    pub name: Option<String>,
+}
+
+/// A vulnerable package range of a repository advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct AdvisoryVulnerability {
+    pub package: Option<AdvisoryPackage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vulnerable_version_range: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patched_versions: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vulnerable_functions: Option<Vec<String>>,
+}
+
+impl AdvisoryVulnerability {
+    /// Creates a vulnerability affecting the package `name` in `ecosystem`.
+    pub fn new(ecosystem: Ecosystem, name: impl Into<String>) -> Self {
+        Self {
+            package: Some(AdvisoryPackage {
+                ecosystem,
+                name: Some(name.into()),
+            }),
+            vulnerable_version_range: None,
+            patched_versions: None,
+            vulnerable_functions: None,
+        }
+    }
+
+    /// The range of vulnerable versions, e.g. `< 1.2.3`.
+    pub fn vulnerable_version_range(mut self, range: impl Into<String>) -> Self {
+        self.vulnerable_version_range = Some(range.into());
+        self
+    }
+
+    /// The versions which fix the vulnerability, e.g. `1.2.3`.
+    pub fn patched_versions(mut self, versions: impl Into<String>) -> Self {
+        self.patched_versions = Some(versions.into());
+        self
+    }
+
+    /// The functions of the package which are vulnerable.
+    pub fn vulnerable_functions(mut self, functions: impl Into<Vec<String>>) -> Self {
+        self.vulnerable_functions = Some(functions.into());
+        self
+    }
+}
+
+/// A vulnerable package range of a global advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct GlobalAdvisoryVulnerability {
+    pub package: Option<AdvisoryPackage>,
+    pub vulnerable_version_range: Option<String>,
+    pub first_patched_version: Option<String>,
+    pub vulnerable_functions: Option<Vec<String>>,
+}
+
+/// The CVSS score of an advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct AdvisoryCvss {
+    pub vector_string: Option<String>,
+    pub score: Option<f64>,
+}
+
+/// A weakness (CWE) associated with an advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct AdvisoryCwe {
+    pub cwe_id: String,
+    pub name: String,
+}
+
+/// A user credited on a repository advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct AdvisoryCredit {
+    pub login: String,
+    pub r#type: CreditType,
+}
+
+impl AdvisoryCredit {
+    pub fn new(login: impl Into<String>, r#type: CreditType) -> Self {
+        Self {
+            login: login.into(),
+            r#type,
+        }
+    }
+}
+
+/// A user credited on a global advisory.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct GlobalAdvisoryCredit {
+    pub user: SimpleUser,
+    pub r#type: CreditType,
+}
@@ -4,9 +4,10 @@ use chrono;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Team {
     pub id: TeamId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub url: Url,
     pub html_url: Url,
     pub name: String,
@@ -30,17 +31,19 @@ pub struct Team {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RequestedReviewers {
     pub users: Vec<Author>,
     pub teams: Vec<Team>,
 }
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RequestedTeam {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<TeamId>,
     #[serde(skip_serializing_if = "Option::is_none")]
-    pub node_id: Option<String>,
+    pub node_id: Option<NodeId>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub url: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -59,6 +62,7 @@ pub struct RequestedTeam {
 
 #[derive(PartialEq, Clone, Debug, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct TeamInvitation {
     pub id: TeamInvitationId,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -73,7 +77,7 @@ pub struct TeamInvitation {
     pub failed_reason: Option<String>,
     pub inviter: Author,
     pub team_count: u32,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub invitation_teams_url: String,
 }
 
@@ -85,3 +89,66 @@ pub enum TeamPrivacy {
     Closed,
     Secret,
 }
+
+/// A user's membership of a team.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct TeamMembership {
+    pub url: Url,
+    pub role: crate::params::teams::Role,
+    pub state: TeamMembershipState,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum TeamMembershipState {
+    Active,
+    /// The user has been invited to the organization and will join the team
+    /// once they accept.
+    Pending,
+}
+
+/// A group of an identity provider, which can be mapped to a team for team
+/// synchronization.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct IdpGroup {
+    pub group_id: String,
+    pub group_name: String,
+    pub group_description: String,
+    /// Whether the members of the team were synced with the group yet.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub synced_at: Option<String>,
+}
+
+impl IdpGroup {
+    /// A group to map a team to, as listed by
+    /// [`TeamHandler::list_idp_groups`](crate::teams::TeamHandler::list_idp_groups).
+    pub fn new(
+        group_id: impl Into<String>,
+        group_name: impl Into<String>,
+        group_description: impl Into<String>,
+    ) -> Self {
+        Self {
+            group_id: group_id.into(),
+            group_name: group_name.into(),
+            group_description: group_description.into(),
+            status: None,
+            synced_at: None,
+        }
+    }
+}
+
+/// The identity provider groups a team is synchronized with.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct GroupMapping {
+    #[serde(default)]
+    pub groups: Vec<IdpGroup>,
+}
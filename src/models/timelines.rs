@@ -2,13 +2,14 @@ use super::*;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct TimelineEvent {
     /// Identifies the actual type of event that occurred.
     pub event: Event,
     /// The unique identifier of the event.
     pub id: Option<TimelineEventId>,
     /// The Global Node ID of the event.
-    pub node_id: Option<String>,
+    pub node_id: Option<NodeId>,
     /// The REST API URL for fetching the event.
     pub url: Option<Url>,
     /// The person who generated the event.
@@ -87,6 +88,7 @@ pub struct TimelineEvent {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct DismissedReview {
     pub state: pulls::ReviewState,
     pub review_id: ReviewId,
@@ -96,6 +98,7 @@ pub struct DismissedReview {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Source {
     pub issue: issues::Issue,
     pub r#type: String,
@@ -103,6 +106,7 @@ pub struct Source {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Rename {
     pub from: String,
     pub to: String,
@@ -110,6 +114,7 @@ pub struct Rename {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Label {
     pub name: String,
     pub color: String,
@@ -117,6 +122,7 @@ pub struct Label {
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Milestone {
     pub title: String,
 }
@@ -124,6 +130,7 @@ pub struct Milestone {
 /// The author of a commit, identified by its name and email.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CommitAuthor {
     pub name: String,
     pub email: String,
@@ -0,0 +1,39 @@
+//! Conversions between the [`chrono`] timestamps of the models and
+//! [`time::OffsetDateTime`], for code that works with the `time` crate.
+//!
+//! ```
+//! use octocrab::models::timestamps::{from_offset_date_time, OffsetDateTimeExt};
+//!
+//! let created_at: chrono::DateTime<chrono::Utc> = "2024-07-25T11:50:32Z".parse().unwrap();
+//! let created_at = created_at.to_offset_date_time().unwrap();
+//! assert_eq!(created_at.year(), 2024);
+//!
+//! // e.g. to pass to a builder's `since`
+//! let since = from_offset_date_time(created_at).unwrap();
+//! assert_eq!(since.to_rfc3339(), "2024-07-25T11:50:32+00:00");
+//! ```
+
+use chrono::{DateTime, TimeZone, Utc};
+use time::{error::ComponentRange, OffsetDateTime};
+
+/// Converts the timestamps of the models into [`OffsetDateTime`]s.
+pub trait OffsetDateTimeExt {
+    /// The same instant as an [`OffsetDateTime`] in UTC. Fails for instants
+    /// outside the years -9999 to 9999 that `time` supports by default.
+    fn to_offset_date_time(&self) -> Result<OffsetDateTime, ComponentRange>;
+}
+
+impl<Tz: TimeZone> OffsetDateTimeExt for DateTime<Tz> {
+    fn to_offset_date_time(&self) -> Result<OffsetDateTime, ComponentRange> {
+        OffsetDateTime::from_unix_timestamp(self.timestamp())?
+            .replace_nanosecond(self.timestamp_subsec_nanos())
+    }
+}
+
+/// Converts an [`OffsetDateTime`] into the timestamp type the builders take.
+/// Returns `None` for instants outside the roughly ±262,000 years that
+/// `chrono` supports, which `time` reaches with its `large-dates` feature.
+pub fn from_offset_date_time(timestamp: OffsetDateTime) -> Option<DateTime<Utc>> {
+    Utc.timestamp_opt(timestamp.unix_timestamp(), timestamp.nanosecond())
+        .single()
+}
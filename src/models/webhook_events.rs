@@ -67,7 +67,9 @@
 
 pub mod payload;
 
-use super::{orgs::Organization, Author, Installation, InstallationId, Repository, RepositoryId};
+use super::{
+    orgs::Organization, Author, Installation, InstallationId, NodeId, Repository, RepositoryId,
+};
 use serde::{Deserialize, Serialize};
 
 pub use payload::WebhookEventPayload;
@@ -87,6 +89,10 @@ pub struct WebhookEvent {
     pub kind: WebhookEventType,
     #[serde(flatten)]
     pub specific: WebhookEventPayload,
+    /// The whole payload as received, including fields the typed payloads
+    /// don't model (yet), e.g. to log or forward them.
+    #[serde(skip)]
+    pub raw: serde_json::Value,
 }
 
 impl WebhookEvent {
@@ -115,13 +121,14 @@ impl WebhookEvent {
             specific: serde_json::Value,
         }
 
+        let raw = serde_json::from_slice::<serde_json::Value>(body.as_ref())?;
         let Intermediate {
             sender,
             repository,
             organization,
             installation,
             specific,
-        } = serde_json::from_slice::<Intermediate>(body.as_ref())?;
+        } = serde_json::from_value::<Intermediate>(raw.clone())?;
 
         let specific = kind.parse_specific_payload(specific)?;
 
@@ -132,6 +139,7 @@ impl WebhookEvent {
             installation,
             kind,
             specific,
+            raw,
         })
     }
 }
@@ -147,6 +155,12 @@ impl WebhookEvent {
 #[non_exhaustive]
 #[serde(rename_all = "snake_case")]
 pub enum WebhookEventType {
+    /// This event occurs when there is a change to branch protection configurations for a
+    /// repository. For more information, see "About protected branches."
+    ///
+    /// To subscribe to this event, a GitHub App must have at least read-level access for the
+    /// "Administration" repository permission.
+    BranchProtectionConfiguration,
     /// This event occurs when there is activity relating to branch protection rules. For more
     /// information, see "About protected branches."
     ///
@@ -215,6 +229,21 @@ pub enum WebhookEventType {
     ///
     /// **Note:** This event will not occur when more than three tags are created at once.
     Create,
+    /// This event occurs when there is activity relating to a custom property. For more
+    /// information, see "Managing custom properties for repositories in your organization."
+    ///
+    /// For activity relating to the values of custom properties of a repository, see the
+    /// [`CustomPropertyValues`](WebhookEventType::CustomPropertyValues) event.
+    ///
+    /// To subscribe to this event, a GitHub App must have at least read-level access for the
+    /// "Custom properties" organization permission.
+    CustomProperty,
+    /// This event occurs when the values of the custom properties of a repository change. For
+    /// more information, see "Managing custom properties for repositories in your organization."
+    ///
+    /// To subscribe to this event, a GitHub App must have at least read-level access for the
+    /// "Custom properties" organization permission.
+    CustomPropertyValues,
     /// This event occurs when a Git branch or tag is deleted.
     ///
     /// To subscribe to this event, a GitHub App must have at least read-level access for the
@@ -252,6 +281,12 @@ pub enum WebhookEventType {
     /// To subscribe to this event, a GitHub App must have at least read-level access for the
     /// "Deployments" repository permission.
     DeploymentProtectionRule,
+    /// This event occurs when there is activity relating to deployment reviews. For more
+    /// information, see "Reviewing deployments."
+    ///
+    /// To subscribe to this event, a GitHub App must have at least read-level access for the
+    /// "Actions" repository permission.
+    DeploymentReview,
     /// This event occurs when there is activity relating to deployment statuses. For more
     /// information, see "About deployments."
     ///
@@ -503,6 +538,14 @@ pub enum WebhookEventType {
     /// **Note:** Webhook events for projects are currently in beta and subject to change. To share
     /// feedback about projects webhooks with GitHub, see the Projects webhook feedback discussion.
     ProjectsV2Item,
+    /// This event occurs when there is activity relating to a status update on an
+    /// organization-level project. For more information, see "About Projects."
+    ///
+    /// To subscribe to this event, a GitHub App must have at least read-level access for the
+    /// "Projects" organization permission.
+    ///
+    /// **Note:** Webhook events for projects are currently in beta and subject to change.
+    ProjectsV2StatusUpdate,
     /// This event occurs when repository visibility changes from private to public. For more
     /// information, see "Setting repository visibility."
     ///
@@ -603,6 +646,12 @@ pub enum WebhookEventType {
     /// "Importing a repository with GitHub Importer." For more information about the API to manage
     /// imports, see the REST API documentation.
     RepositoryImport,
+    /// This event occurs when there is activity relating to repository rulesets. For more
+    /// information, see "About rulesets."
+    ///
+    /// To subscribe to this event, a GitHub App must have at least read-level access for the
+    /// "Administration" repository or organization permission.
+    RepositoryRuleset,
     /// This event occurs when there is activity relating to a security vulnerability alert in a
     /// repository.
     ///
@@ -635,6 +684,12 @@ pub enum WebhookEventType {
     /// To subscribe to this event, a GitHub App must have at least read-level access for the
     /// "Secret scanning alerts" repository permission.
     SecretScanningAlertLocation,
+    /// This event occurs when secret scanning completes certain scans on a repository. For more
+    /// information about secret scanning, see "About secret scanning."
+    ///
+    /// To subscribe to this event, a GitHub App must have at least read-level access for the
+    /// "Secret scanning alerts" repository permission.
+    SecretScanningScan,
     /// This event occurs when there is activity relating to a security advisory that was reviewed
     /// by GitHub. A GitHub-reviewed security advisory provides information about security-related
     /// vulnerabilities in software on GitHub. For more information about security advisories, see
@@ -668,6 +723,15 @@ pub enum WebhookEventType {
     /// To subscribe to this event, a GitHub App must have at least read-level access for the
     /// "Commit statuses" repository permission.
     Status,
+    /// This event occurs when there is activity relating to sub-issues. For more information,
+    /// see "Adding sub-issues."
+    ///
+    /// For activity relating to the issues themselves, see the
+    /// [`Issues`](WebhookEventType::Issues) event.
+    ///
+    /// To subscribe to this event, a GitHub App must have at least read-level access for the
+    /// "Issues" repository permission.
+    SubIssues,
     /// This event occurs when a team is added to a repository. For more information, see "Managing
     /// teams and people with access to your repository."
     ///
@@ -731,6 +795,11 @@ impl WebhookEventType {
         data: serde_json::Value,
     ) -> Result<WebhookEventPayload, serde_json::Error> {
         match self {
+            WebhookEventType::BranchProtectionConfiguration => {
+                Ok(WebhookEventPayload::BranchProtectionConfiguration(
+                    Box::new(serde_json::from_value(data)?),
+                ))
+            }
             WebhookEventType::BranchProtectionRule => Ok(
                 WebhookEventPayload::BranchProtectionRule(Box::new(serde_json::from_value(data)?)),
             ),
@@ -749,6 +818,12 @@ impl WebhookEventType {
             WebhookEventType::Create => Ok(WebhookEventPayload::Create(Box::new(
                 serde_json::from_value(data)?,
             ))),
+            WebhookEventType::CustomProperty => Ok(WebhookEventPayload::CustomProperty(Box::new(
+                serde_json::from_value(data)?,
+            ))),
+            WebhookEventType::CustomPropertyValues => Ok(
+                WebhookEventPayload::CustomPropertyValues(Box::new(serde_json::from_value(data)?)),
+            ),
             WebhookEventType::Delete => Ok(WebhookEventPayload::Delete(Box::new(
                 serde_json::from_value(data)?,
             ))),
@@ -766,6 +841,9 @@ impl WebhookEventType {
                     serde_json::from_value(data)?,
                 )))
             }
+            WebhookEventType::DeploymentReview => Ok(WebhookEventPayload::DeploymentReview(
+                Box::new(serde_json::from_value(data)?),
+            )),
             WebhookEventType::DeploymentStatus => Ok(WebhookEventPayload::DeploymentStatus(
                 Box::new(serde_json::from_value(data)?),
             )),
@@ -859,6 +937,11 @@ impl WebhookEventType {
             WebhookEventType::ProjectsV2Item => Ok(WebhookEventPayload::ProjectsV2Item(Box::new(
                 serde_json::from_value(data)?,
             ))),
+            WebhookEventType::ProjectsV2StatusUpdate => {
+                Ok(WebhookEventPayload::ProjectsV2StatusUpdate(Box::new(
+                    serde_json::from_value(data)?,
+                )))
+            }
             WebhookEventType::Public => Ok(WebhookEventPayload::Public(Box::new(
                 serde_json::from_value(data)?,
             ))),
@@ -899,6 +982,9 @@ impl WebhookEventType {
             WebhookEventType::RepositoryImport => Ok(WebhookEventPayload::RepositoryImport(
                 Box::new(serde_json::from_value(data)?),
             )),
+            WebhookEventType::RepositoryRuleset => Ok(WebhookEventPayload::RepositoryRuleset(
+                Box::new(serde_json::from_value(data)?),
+            )),
             WebhookEventType::RepositoryVulnerabilityAlert => {
                 Ok(WebhookEventPayload::RepositoryVulnerabilityAlert(Box::new(
                     serde_json::from_value(data)?,
@@ -915,6 +1001,9 @@ impl WebhookEventType {
                     serde_json::from_value(data)?,
                 )))
             }
+            WebhookEventType::SecretScanningScan => Ok(WebhookEventPayload::SecretScanningScan(
+                Box::new(serde_json::from_value(data)?),
+            )),
             WebhookEventType::SecurityAdvisory => Ok(WebhookEventPayload::SecurityAdvisory(
                 Box::new(serde_json::from_value(data)?),
             )),
@@ -930,6 +1019,9 @@ impl WebhookEventType {
             WebhookEventType::Status => Ok(WebhookEventPayload::Status(Box::new(
                 serde_json::from_value(data)?,
             ))),
+            WebhookEventType::SubIssues => Ok(WebhookEventPayload::SubIssues(Box::new(
+                serde_json::from_value(data)?,
+            ))),
             WebhookEventType::TeamAdd => Ok(WebhookEventPayload::TeamAdd(Box::new(
                 serde_json::from_value(data)?,
             ))),
@@ -973,17 +1065,19 @@ impl EventInstallation {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct EventInstallationId {
     pub id: InstallationId,
-    pub node_id: String,
+    pub node_id: NodeId,
 }
 
 /// A repository in installation related webhook events.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationEventRepository {
     pub id: RepositoryId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub name: String,
     pub full_name: String,
     pub private: bool,
@@ -993,7 +1087,7 @@ pub struct InstallationEventRepository {
 mod tests {
     use chrono::TimeZone;
 
-    use crate::models::AuthorAssociation;
+    use crate::models::{reactions::ReactionContent, AuthorAssociation};
 
     use super::payload::*;
     use super::*;
@@ -1019,6 +1113,68 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_custom_property_values_updated() {
+        let json =
+            include_str!("../../tests/resources/custom_property_values_updated_webhook_event.json");
+        let event = WebhookEvent::try_from_header_and_body("custom_property_values", json).unwrap();
+        let WebhookEventPayload::CustomPropertyValues(values_event) = event.specific else {
+            panic!(" event is of the wrong type {:?}", event)
+        };
+        assert_eq!(
+            values_event.action,
+            CustomPropertyValuesWebhookEventAction::Updated
+        );
+        assert_eq!(values_event.new_property_values[0].property_name, "team");
+        assert_eq!(
+            values_event.old_property_values[0].value,
+            serde_json::Value::Null
+        );
+    }
+
+    #[test]
+    fn deserialize_discussion_answered() {
+        let json = include_str!("../../tests/resources/discussion_answered_webhook_event.json");
+        let event = WebhookEvent::try_from_header_and_body("discussion", json).unwrap();
+        let WebhookEventPayload::Discussion(discussion_event) = event.specific else {
+            panic!(" event is of the wrong type {:?}", event)
+        };
+        assert_eq!(
+            discussion_event.action,
+            DiscussionWebhookEventAction::Answered
+        );
+        assert_eq!(discussion_event.discussion.number, 2);
+        assert!(discussion_event.discussion.category.is_answerable);
+        assert_eq!(
+            discussion_event.discussion.state,
+            DiscussionWebhookEventState::Open
+        );
+        let reactions = discussion_event.discussion.reactions.unwrap();
+        assert_eq!(reactions.total_count, 2);
+        assert_eq!(reactions.count(ReactionContent::Heart), 1);
+        assert_eq!(discussion_event.answer.unwrap().id, 6432101);
+    }
+
+    #[test]
+    fn deserialize_discussion_comment_created() {
+        let json =
+            include_str!("../../tests/resources/discussion_comment_created_webhook_event.json");
+        let event = WebhookEvent::try_from_header_and_body("discussion_comment", json).unwrap();
+        let WebhookEventPayload::DiscussionComment(discussion_comment_event) = event.specific
+        else {
+            panic!(" event is of the wrong type {:?}", event)
+        };
+        assert_eq!(
+            discussion_comment_event.action,
+            DiscussionCommentWebhookEventAction::Created
+        );
+        assert_eq!(discussion_comment_event.comment.parent_id, Some(6432101));
+        assert_eq!(
+            discussion_comment_event.comment.author_association,
+            AuthorAssociation::Owner
+        );
+    }
+
     #[test]
     fn deserialize_installation_created() {
         let json = include_str!("../../tests/resources/installation_created_webhook_event.json");
@@ -1231,6 +1387,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_sub_issues_sub_issue_added() {
+        let json =
+            include_str!("../../tests/resources/sub_issues_sub_issue_added_webhook_event.json");
+        let event = WebhookEvent::try_from_header_and_body("sub_issues", json).unwrap();
+        let WebhookEventPayload::SubIssues(sub_issues_event) = event.specific else {
+            panic!(" event is of the wrong type {:?}", event)
+        };
+        assert_eq!(
+            sub_issues_event.action,
+            SubIssuesWebhookEventAction::SubIssueAdded
+        );
+        assert_eq!(sub_issues_event.parent_issue.number, 1);
+        assert_eq!(sub_issues_event.sub_issue.number, 2);
+    }
+
+    #[test]
+    fn deserialize_unknown_event() {
+        let event =
+            WebhookEvent::try_from_header_and_body("not_an_event", r#"{"action": "created"}"#)
+                .unwrap();
+        assert_eq!(
+            event.kind,
+            WebhookEventType::Unknown("not_an_event".to_owned())
+        );
+        let WebhookEventPayload::Unknown(payload) = event.specific else {
+            panic!(" event is of the wrong type {:?}", event)
+        };
+        assert_eq!(payload["action"], "created");
+    }
+
+    #[test]
+    fn keep_raw_payload() {
+        let json = include_str!("../../tests/resources/push_webhook_event.json");
+        let mut raw: serde_json::Value = serde_json::from_str(json).unwrap();
+        raw["field_from_the_future"] = serde_json::json!({ "answer": 42 });
+        let event =
+            WebhookEvent::try_from_header_and_body("push", &serde_json::to_vec(&raw).unwrap())
+                .unwrap();
+        assert_eq!(event.kind, WebhookEventType::Push);
+        assert_eq!(event.raw["field_from_the_future"]["answer"], 42);
+        assert_eq!(event.raw, raw);
+    }
+
     #[test]
     fn deserialize_push() {
         let json = include_str!("../../tests/resources/push_webhook_event.json");
@@ -1,14 +1,18 @@
+mod branch_protection_configuration;
 mod branch_protection_rule;
 mod check_run;
 mod check_suite;
 mod code_scanning_alert;
 mod commit_comment;
 mod create;
+mod custom_property;
+mod custom_property_values;
 mod delete;
 mod dependabot_alert;
 mod deploy_key;
 mod deployment;
 mod deployment_protection_rule;
+mod deployment_review;
 mod deployment_status;
 mod discussion;
 mod discussion_comment;
@@ -38,6 +42,7 @@ mod project_card;
 mod project_column;
 mod projects_v2;
 mod projects_v2_item;
+mod projects_v2_status_update;
 mod public;
 mod pull_request;
 mod pull_request_review;
@@ -50,15 +55,18 @@ mod repository;
 mod repository_advisory;
 mod repository_dispatch;
 mod repository_import;
+mod repository_ruleset;
 mod repository_vulnerability_alert;
 mod schedule;
 mod secret_scanning_alert;
 mod secret_scanning_alert_location;
+mod secret_scanning_scan;
 mod security_advisory;
 mod security_and_analysis;
 mod sponsorship;
 mod star;
 mod status;
+mod sub_issues;
 mod team;
 mod team_add;
 mod watch;
@@ -67,21 +75,23 @@ mod workflow_job;
 mod workflow_run;
 
 pub use self::{
-    branch_protection_rule::*, check_run::*, check_suite::*, code_scanning_alert::*,
-    commit_comment::*, create::*, delete::*, dependabot_alert::*, deploy_key::*, deployment::*,
-    deployment_protection_rule::*, deployment_status::*, discussion::*, discussion_comment::*,
-    fork::*, github_app_authorization::*, gollum::*, installation::*, installation_repositories::*,
-    installation_target::*, issue_comment::*, issues::*, label::*, marketplace_purchase::*,
-    member::*, membership::*, merge_group::*, meta::*, milestone::*, org_block::*, organization::*,
-    package::*, page_build::*, personal_access_token_request::*, ping::*, project::*,
-    project_card::*, project_column::*, projects_v2::*, projects_v2_item::*, public::*,
-    pull_request::*, pull_request_review::*, pull_request_review_comment::*,
-    pull_request_review_thread::*, push::*, registry_package::*, release::*, repository::*,
-    repository_advisory::*, repository_dispatch::*, repository_import::*,
-    repository_vulnerability_alert::*, schedule::*, secret_scanning_alert::*,
-    secret_scanning_alert_location::*, security_advisory::*, security_and_analysis::*,
-    sponsorship::*, star::*, status::*, team::*, team_add::*, watch::*, workflow_dispatch::*,
-    workflow_job::*, workflow_run::*,
+    branch_protection_configuration::*, branch_protection_rule::*, check_run::*, check_suite::*,
+    code_scanning_alert::*, commit_comment::*, create::*, custom_property::*,
+    custom_property_values::*, delete::*, dependabot_alert::*, deploy_key::*, deployment::*,
+    deployment_protection_rule::*, deployment_review::*, deployment_status::*, discussion::*,
+    discussion_comment::*, fork::*, github_app_authorization::*, gollum::*, installation::*,
+    installation_repositories::*, installation_target::*, issue_comment::*, issues::*, label::*,
+    marketplace_purchase::*, member::*, membership::*, merge_group::*, meta::*, milestone::*,
+    org_block::*, organization::*, package::*, page_build::*, personal_access_token_request::*,
+    ping::*, project::*, project_card::*, project_column::*, projects_v2::*, projects_v2_item::*,
+    projects_v2_status_update::*, public::*, pull_request::*, pull_request_review::*,
+    pull_request_review_comment::*, pull_request_review_thread::*, push::*, registry_package::*,
+    release::*, repository::*, repository_advisory::*, repository_dispatch::*,
+    repository_import::*, repository_ruleset::*, repository_vulnerability_alert::*, schedule::*,
+    secret_scanning_alert::*, secret_scanning_alert_location::*, secret_scanning_scan::*,
+    security_advisory::*, security_and_analysis::*, sponsorship::*, star::*, status::*,
+    sub_issues::*, team::*, team_add::*, watch::*, workflow_dispatch::*, workflow_job::*,
+    workflow_run::*,
 };
 
 use serde::{Deserialize, Serialize};
@@ -90,17 +100,21 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
 pub enum WebhookEventPayload {
+    BranchProtectionConfiguration(Box<BranchProtectionConfigurationWebhookEventPayload>),
     BranchProtectionRule(Box<BranchProtectionRuleWebhookEventPayload>),
     CheckRun(Box<CheckRunWebhookEventPayload>),
     CheckSuite(Box<CheckSuiteWebhookEventPayload>),
     CodeScanningAlert(Box<CodeScanningAlertWebhookEventPayload>),
     CommitComment(Box<CommitCommentWebhookEventPayload>),
+    CustomProperty(Box<CustomPropertyWebhookEventPayload>),
+    CustomPropertyValues(Box<CustomPropertyValuesWebhookEventPayload>),
     Create(Box<CreateWebhookEventPayload>),
     Delete(Box<DeleteWebhookEventPayload>),
     DependabotAlert(Box<DependabotAlertWebhookEventPayload>),
     DeployKey(Box<DeployKeyWebhookEventPayload>),
     Deployment(Box<DeploymentWebhookEventPayload>),
     DeploymentProtectionRule(Box<DeploymentProtectionRuleWebhookEventPayload>),
+    DeploymentReview(Box<DeploymentReviewWebhookEventPayload>),
     DeploymentStatus(Box<DeploymentStatusWebhookEventPayload>),
     Discussion(Box<DiscussionWebhookEventPayload>),
     DiscussionComment(Box<DiscussionCommentWebhookEventPayload>),
@@ -130,6 +144,7 @@ pub enum WebhookEventPayload {
     ProjectColumn(Box<ProjectColumnWebhookEventPayload>),
     ProjectsV2(Box<ProjectsV2WebhookEventPayload>),
     ProjectsV2Item(Box<ProjectsV2ItemWebhookEventPayload>),
+    ProjectsV2StatusUpdate(Box<ProjectsV2StatusUpdateWebhookEventPayload>),
     Public(Box<PublicWebhookEventPayload>),
     PullRequest(Box<PullRequestWebhookEventPayload>),
     PullRequestReview(Box<PullRequestReviewWebhookEventPayload>),
@@ -142,15 +157,18 @@ pub enum WebhookEventPayload {
     Repository(Box<RepositoryWebhookEventPayload>),
     RepositoryDispatch(Box<RepositoryDispatchWebhookEventPayload>),
     RepositoryImport(Box<RepositoryImportWebhookEventPayload>),
+    RepositoryRuleset(Box<RepositoryRulesetWebhookEventPayload>),
     RepositoryVulnerabilityAlert(Box<RepositoryVulnerabilityAlertWebhookEventPayload>),
     Schedule(Box<ScheduleWebhookEventPayload>),
     SecretScanningAlert(Box<SecretScanningAlertWebhookEventPayload>),
     SecretScanningAlertLocation(Box<SecretScanningAlertLocationWebhookEventPayload>),
+    SecretScanningScan(Box<SecretScanningScanWebhookEventPayload>),
     SecurityAdvisory(Box<SecurityAdvisoryWebhookEventPayload>),
     SecurityAndAnalysis(Box<SecurityAndAnalysisWebhookEventPayload>),
     Sponsorship(Box<SponsorshipWebhookEventPayload>),
     Star(Box<StarWebhookEventPayload>),
     Status(Box<StatusWebhookEventPayload>),
+    SubIssues(Box<SubIssuesWebhookEventPayload>),
     TeamAdd(Box<TeamAddWebhookEventPayload>),
     Team(Box<TeamWebhookEventPayload>),
     Watch(Box<WatchWebhookEventPayload>),
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct BranchProtectionConfigurationWebhookEventPayload {
+    pub action: BranchProtectionConfigurationWebhookEventAction,
+    pub enterprise: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum BranchProtectionConfigurationWebhookEventAction {
+    Disabled,
+    Enabled,
+}
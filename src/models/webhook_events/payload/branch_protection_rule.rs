@@ -7,6 +7,7 @@ use super::OldValue;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct BranchProtectionRuleWebhookEventPayload {
     pub action: BranchProtectionRuleWebhookEventAction,
     pub changes: Option<BranchProtectionRuleWebhookEventChanges>,
@@ -25,6 +26,7 @@ pub enum BranchProtectionRuleWebhookEventAction {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct BranchProtectionRuleWebhookEventChanges {
     pub admin_enforced: Option<OldValue<Option<bool>>>,
     pub authorized_actor_names: Option<OldValue<Vec<String>>>,
@@ -37,6 +39,7 @@ pub struct BranchProtectionRuleWebhookEventChanges {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct BranchProtectionRule {
     pub admin_enfored: bool,
     pub allow_deletions_enforcement_level: BranchProtectionRuleLevel,
@@ -4,6 +4,7 @@ use crate::models::{code_scannings::CodeScanningAlert, orgs::Organization, Autho
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CodeScanningAlertWebhookEventPayload {
     /// The action that was performed.
     pub action: CodeScanningAlertWebhookEventAction,
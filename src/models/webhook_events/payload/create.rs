@@ -4,6 +4,7 @@ use super::{PusherType, RefType};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct CreateWebhookEventPayload {
     pub description: Option<String>,
     pub enterprise: Option<serde_json::Value>,
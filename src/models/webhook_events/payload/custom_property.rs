@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CustomPropertyWebhookEventPayload {
+    pub action: CustomPropertyWebhookEventAction,
+    pub definition: CustomPropertyWebhookEventDefinition,
+    pub enterprise: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CustomPropertyWebhookEventAction {
+    Created,
+    Deleted,
+    PromoteToEnterprise,
+    Updated,
+}
+
+/// The definition of a custom property. Only `property_name` is sent when
+/// the property is deleted.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CustomPropertyWebhookEventDefinition {
+    pub property_name: String,
+    pub value_type: Option<String>,
+    pub required: Option<bool>,
+    pub default_value: Option<serde_json::Value>,
+    pub description: Option<String>,
+    pub allowed_values: Option<Vec<String>>,
+    pub values_editable_by: Option<String>,
+}
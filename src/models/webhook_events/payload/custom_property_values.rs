@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CustomPropertyValuesWebhookEventPayload {
+    pub action: CustomPropertyValuesWebhookEventAction,
+    pub enterprise: Option<serde_json::Value>,
+    pub new_property_values: Vec<CustomPropertyValue>,
+    pub old_property_values: Vec<CustomPropertyValue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum CustomPropertyValuesWebhookEventAction {
+    Updated,
+}
+
+/// The value of a custom property of a repository, which is a string, a
+/// list of strings or `null`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct CustomPropertyValue {
+    pub property_name: String,
+    pub value: serde_json::Value,
+}
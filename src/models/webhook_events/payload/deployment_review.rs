@@ -0,0 +1,29 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::Author;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DeploymentReviewWebhookEventPayload {
+    pub action: DeploymentReviewWebhookEventAction,
+    pub approver: Option<Author>,
+    pub comment: Option<String>,
+    pub enterprise: Option<serde_json::Value>,
+    pub environment: Option<String>,
+    pub requestor: Option<Author>,
+    pub reviewers: Option<Vec<serde_json::Value>>,
+    pub since: Option<String>,
+    pub workflow_job_run: Option<serde_json::Value>,
+    pub workflow_job_runs: Option<Vec<serde_json::Value>>,
+    pub workflow_run: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DeploymentReviewWebhookEventAction {
+    Approved,
+    Rejected,
+    Requested,
+}
@@ -1,14 +1,23 @@
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::models::{
+    commits::CommentReactions, Author, AuthorAssociation, Label, NodeId, Repository, RepositoryId,
+};
+
+use super::OldValue;
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct DiscussionWebhookEventPayload {
     pub action: DiscussionWebhookEventAction,
-    pub answer: Option<serde_json::Value>,
-    pub discussion: serde_json::Value,
+    /// The comment chosen as the answer, for `answered` events.
+    pub answer: Option<DiscussionWebhookEventComment>,
+    pub discussion: DiscussionWebhookEventDiscussion,
     pub enterprise: Option<serde_json::Value>,
-    pub changes: Option<serde_json::Value>,
-    pub label: Option<serde_json::Value>,
+    pub changes: Option<DiscussionWebhookEventChanges>,
+    pub label: Option<Label>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -31,3 +40,95 @@ pub enum DiscussionWebhookEventAction {
     Unlocked,
     Unpinned,
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DiscussionWebhookEventChanges {
+    pub title: Option<OldValue<String>>,
+    pub body: Option<OldValue<String>>,
+    pub category: Option<OldValue<DiscussionWebhookEventCategory>>,
+    /// The discussion in the repository it was transferred to.
+    pub new_discussion: Option<DiscussionWebhookEventDiscussion>,
+    pub new_repository: Option<Repository>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DiscussionWebhookEventDiscussion {
+    pub id: u64,
+    pub node_id: NodeId,
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    pub user: Author,
+    pub state: DiscussionWebhookEventState,
+    pub state_reason: Option<String>,
+    pub locked: bool,
+    pub active_lock_reason: Option<String>,
+    pub comments: u64,
+    pub category: DiscussionWebhookEventCategory,
+    pub answer_html_url: Option<String>,
+    pub answer_chosen_at: Option<DateTime<Utc>>,
+    pub answer_chosen_by: Option<Author>,
+    pub author_association: AuthorAssociation,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    pub html_url: String,
+    pub repository_url: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Per-reaction counts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<CommentReactions>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum DiscussionWebhookEventState {
+    Open,
+    Closed,
+    Locked,
+    Converting,
+    Transferring,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DiscussionWebhookEventCategory {
+    pub id: u64,
+    pub node_id: NodeId,
+    pub repository_id: RepositoryId,
+    pub name: String,
+    pub slug: String,
+    pub emoji: String,
+    pub description: Option<String>,
+    pub is_answerable: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DiscussionWebhookEventComment {
+    pub id: u64,
+    pub node_id: NodeId,
+    pub discussion_id: u64,
+    /// The id of the comment this is a reply to, if any.
+    pub parent_id: Option<u64>,
+    pub child_comment_count: u64,
+    pub body: String,
+    pub user: Author,
+    pub author_association: AuthorAssociation,
+    pub html_url: String,
+    pub repository_url: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Per-reaction counts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reactions: Option<CommentReactions>,
+}
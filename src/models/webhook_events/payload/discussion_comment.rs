@@ -1,12 +1,15 @@
 use serde::{Deserialize, Serialize};
 
+use super::{DiscussionWebhookEventComment, DiscussionWebhookEventDiscussion, OldValue};
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct DiscussionCommentWebhookEventPayload {
     pub action: DiscussionCommentWebhookEventAction,
-    pub changes: Option<serde_json::Value>,
-    pub comment: serde_json::Value,
-    pub discussion: serde_json::Value,
+    pub changes: Option<DiscussionCommentWebhookEventChanges>,
+    pub comment: DiscussionWebhookEventComment,
+    pub discussion: DiscussionWebhookEventDiscussion,
     pub enterprise: Option<serde_json::Value>,
 }
 
@@ -18,3 +21,10 @@ pub enum DiscussionCommentWebhookEventAction {
     Deleted,
     Edited,
 }
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct DiscussionCommentWebhookEventChanges {
+    pub body: OldValue<String>,
+}
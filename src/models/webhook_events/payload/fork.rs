@@ -4,6 +4,7 @@ use crate::models::Repository;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ForkWebhookEventPayload {
     // TODO: Make sure that it's a crate::models::Repository
     pub forkee: Repository,
@@ -4,6 +4,7 @@ use crate::models::webhook_events::InstallationEventRepository;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct InstallationRepositoriesWebhookEventPayload {
     pub action: InstallationRepositoriesWebhookEventAction,
     pub enterprise: Option<serde_json::Value>,
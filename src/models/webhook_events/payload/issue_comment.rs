@@ -6,6 +6,7 @@ use super::OldValue;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssueCommentWebhookEventPayload {
     pub action: IssueCommentWebhookEventAction,
     pub changes: Option<IssueCommentWebhookEventChanges>,
@@ -25,6 +26,7 @@ pub enum IssueCommentWebhookEventAction {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssueCommentWebhookEventChanges {
     pub body: OldValue<String>,
 }
@@ -6,6 +6,7 @@ use super::OldValue;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssuesWebhookEventPayload {
     pub action: IssuesWebhookEventAction,
     pub assignee: Option<Author>,
@@ -40,6 +41,7 @@ pub enum IssuesWebhookEventAction {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct IssuesWebhookEventChanges {
     pub body: Option<OldValue<String>>,
     pub title: Option<OldValue<String>>,
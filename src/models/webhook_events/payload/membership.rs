@@ -4,6 +4,7 @@ use super::MembershipScope;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct MembershipWebhookEventPayload {
     pub action: MembershipWebhookEventAction,
     pub enterprise: Option<serde_json::Value>,
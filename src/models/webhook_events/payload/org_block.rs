@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct OrgBlockWebhookEventPayload {
     pub action: OrgBlockWebhookEventAction,
     pub blocked_user: serde_json::Value,
@@ -4,6 +4,7 @@ use crate::models::{hooks::Hook, HookId};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PingWebhookEventPayload {
     pub hook: Option<Hook>,
     pub hook_id: Option<HookId>,
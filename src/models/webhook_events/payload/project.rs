@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct ProjectWebhookEventPayload {
     pub action: ProjectWebhookEventAction,
     pub enterprise: Option<serde_json::Value>,
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct ProjectsV2StatusUpdateWebhookEventPayload {
+    pub action: ProjectsV2StatusUpdateWebhookEventAction,
+    pub projects_v2_status_update: serde_json::Value,
+    pub changes: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum ProjectsV2StatusUpdateWebhookEventAction {
+    Created,
+    Deleted,
+    Edited,
+}
@@ -4,6 +4,7 @@ use crate::models::{pulls::PullRequest, teams::RequestedTeam, Author, Label, Mil
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestWebhookEventPayload {
     pub action: PullRequestWebhookEventAction,
     pub assignee: Option<Author>,
@@ -6,6 +6,7 @@ use super::OldValue;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestReviewWebhookEventPayload {
     pub action: PullRequestReviewWebhookEventAction,
     pub pull_request: PullRequest,
@@ -24,6 +25,7 @@ pub enum PullRequestReviewWebhookEventAction {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestReviewWebhookEventChanges {
     pub body: Option<OldValue<String>>,
 }
@@ -6,6 +6,7 @@ use super::OldValue;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestReviewCommentWebhookEventPayload {
     pub action: PullRequestReviewCommentWebhookEventAction,
     pub enterprise: Option<serde_json::Value>,
@@ -25,6 +26,7 @@ pub enum PullRequestReviewCommentWebhookEventAction {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestReviewCommentWebhookEventChanges {
     pub body: Option<OldValue<String>>,
 }
@@ -4,6 +4,7 @@ use crate::models::pulls::{PullRequest, Thread};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PullRequestReviewThreadWebhookEventPayload {
     pub action: PullRequestReviewThreadWebhookEventAction,
     pub pull_request: PullRequest,
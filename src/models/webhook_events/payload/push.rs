@@ -6,6 +6,7 @@ use crate::models::repos::GitUserTime;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PushWebhookEventPayload {
     pub enterprise: Option<serde_json::Value>,
     pub after: String,
@@ -23,6 +24,7 @@ pub struct PushWebhookEventPayload {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct PushWebhookEventCommit {
     #[serde(default)]
     pub added: Vec<String>,
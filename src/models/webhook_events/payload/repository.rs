@@ -6,6 +6,7 @@ use super::OldValue;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepositoryWebhookEventPayload {
     pub action: RepositoryWebhookEventAction,
     pub enterprise: Option<serde_json::Value>,
@@ -29,6 +30,7 @@ pub enum RepositoryWebhookEventAction {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepositoryWebhookEventChanges {
     pub default_branch: Option<OldValue<String>>,
     pub description: Option<OldValue<Option<String>>>,
@@ -40,12 +42,14 @@ pub struct RepositoryWebhookEventChanges {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepositoryWebhookEventChangesOwner {
     pub user: Author,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct RepositoryWebhookEventChangesRepository {
     pub name: Option<OldValue<String>>,
 }
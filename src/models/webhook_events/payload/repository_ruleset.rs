@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct RepositoryRulesetWebhookEventPayload {
+    pub action: RepositoryRulesetWebhookEventAction,
+    pub enterprise: Option<serde_json::Value>,
+    pub repository_ruleset: serde_json::Value,
+    pub changes: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum RepositoryRulesetWebhookEventAction {
+    Created,
+    Deleted,
+    Edited,
+}
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SecretScanningScanWebhookEventPayload {
+    pub action: SecretScanningScanWebhookEventAction,
+    pub enterprise: Option<serde_json::Value>,
+    /// `backfill`, `custom-pattern-backfill` or `pattern-version-backfill`.
+    pub r#type: String,
+    /// `git`, `issues`, `pull-requests`, `discussions` or `wiki`.
+    pub source: String,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub secret_types: Option<Vec<String>>,
+    pub custom_pattern_name: Option<String>,
+    pub custom_pattern_scope: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SecretScanningScanWebhookEventAction {
+    Completed,
+}
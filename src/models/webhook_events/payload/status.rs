@@ -5,6 +5,7 @@ use crate::models::StatusId;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct StatusWebhookEventPayload {
     pub avatar_url: Option<Url>,
     pub branches: Vec<serde_json::Value>,
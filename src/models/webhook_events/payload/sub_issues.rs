@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::{issues::Issue, IssueId, Repository};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct SubIssuesWebhookEventPayload {
+    pub action: SubIssuesWebhookEventAction,
+    pub enterprise: Option<serde_json::Value>,
+    pub parent_issue_id: IssueId,
+    pub parent_issue: Issue,
+    pub parent_issue_repo: Option<Repository>,
+    pub sub_issue_id: IssueId,
+    pub sub_issue: Issue,
+    pub sub_issue_repo: Option<Repository>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[non_exhaustive]
+pub enum SubIssuesWebhookEventAction {
+    ParentIssueAdded,
+    ParentIssueRemoved,
+    SubIssueAdded,
+    SubIssueRemoved,
+}
@@ -2,6 +2,7 @@ use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct WorkflowJobWebhookEventPayload {
     pub action: WorkflowJobWebhookEventAction,
     pub enterprise: Option<serde_json::Value>,
@@ -3,9 +3,10 @@ use chrono::{DateTime, Utc};
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct WorkFlow {
     pub id: WorkflowId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub name: String,
     pub path: String,
     pub state: String,
@@ -18,10 +19,11 @@ pub struct WorkFlow {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Run {
     pub id: RunId,
     pub workflow_id: WorkflowId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub name: String,
     pub head_branch: String,
     pub head_sha: String,
@@ -54,6 +56,7 @@ pub struct Run {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct HeadCommit {
     pub id: String,
     pub tree_id: String,
@@ -65,6 +68,7 @@ pub struct HeadCommit {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Job {
     pub id: JobId,
     pub run_id: RunId,
@@ -73,7 +77,7 @@ pub struct Job {
     pub run_url: Url,
     pub run_attempt: u32,
 
-    pub node_id: String,
+    pub node_id: NodeId,
     pub head_sha: String,
     pub url: Url,
     pub html_url: Url,
@@ -109,6 +113,8 @@ pub enum Conclusion {
     Skipped,
     Success,
     TimedOut,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -120,10 +126,13 @@ pub enum Status {
     InProgress,
     Completed,
     Failed,
+    #[serde(untagged)]
+    Unknown(String),
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct Step {
     pub name: String,
     pub status: Status,
@@ -138,9 +147,10 @@ pub struct Step {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct WorkflowListArtifact {
     pub id: crate::models::ArtifactId,
-    pub node_id: String,
+    pub node_id: NodeId,
     pub name: String,
     pub size_in_bytes: usize,
     pub url: Url,
@@ -153,7 +163,85 @@ pub struct WorkflowListArtifact {
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 #[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
 pub struct WorkflowDispatch {
     pub r#ref: String,
     pub inputs: serde_json::Value,
 }
+
+/// The billable time of a workflow in the current billing cycle, as
+/// returned by [`WorkflowsHandler::get_workflow_usage`](crate::workflows::WorkflowsHandler::get_workflow_usage).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct WorkflowUsage {
+    pub billable: Billable<WorkflowTiming>,
+}
+
+/// The billable time of a workflow on one kind of runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct WorkflowTiming {
+    pub total_ms: u64,
+}
+
+/// The billable time and duration of a workflow run, as returned by
+/// [`WorkflowsHandler::get_run_usage`](crate::workflows::WorkflowsHandler::get_run_usage).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct RunUsage {
+    pub billable: Billable<RunTiming>,
+    /// How long the run took, billable or not.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_duration_ms: Option<u64>,
+}
+
+/// The billable time of the jobs of a run on one kind of runner.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct RunTiming {
+    pub total_ms: u64,
+    /// The number of jobs.
+    pub jobs: u64,
+    #[serde(default)]
+    pub job_runs: Vec<JobRunTiming>,
+}
+
+/// How long a job of a run took.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct JobRunTiming {
+    pub job_id: JobId,
+    pub duration_ms: u64,
+}
+
+/// Billable time broken down by the operating system of the runners. Runners
+/// which weren't used are `None`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[cfg_attr(feature = "strict-models", serde(deny_unknown_fields))]
+pub struct Billable<T> {
+    #[serde(rename = "UBUNTU", skip_serializing_if = "Option::is_none")]
+    pub ubuntu: Option<T>,
+    #[serde(rename = "MACOS", skip_serializing_if = "Option::is_none")]
+    pub macos: Option<T>,
+    #[serde(rename = "WINDOWS", skip_serializing_if = "Option::is_none")]
+    pub windows: Option<T>,
+}
+
+impl<T> Billable<T> {
+    /// Iterates over the runners which were used, with the name GitHub gives
+    /// their operating system.
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &T)> {
+        IntoIterator::into_iter([
+            ("UBUNTU", self.ubuntu.as_ref()),
+            ("MACOS", self.macos.as_ref()),
+            ("WINDOWS", self.windows.as_ref()),
+        ])
+        .filter_map(|(os, timing)| Some((os, timing?)))
+    }
+}
@@ -2,7 +2,8 @@ use bytes::Bytes;
 use http::Uri;
 use http_body::Body;
 use http_body_util::BodyExt;
-use serde::{Serialize, Serializer};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::ops::ControlFlow;
 use std::slice::Iter;
 use std::str::FromStr;
 
@@ -15,6 +16,7 @@ cfg_if::cfg_if! {
         use futures_core::Stream;
         use futures_util::stream::try_unfold;
         use serde::de::DeserializeOwned;
+        use std::borrow::Borrow;
 
         use crate::Octocrab;
     }
@@ -37,7 +39,11 @@ cfg_if::cfg_if! {
 #[derive(Clone, Debug, Serialize)]
 pub struct Page<T> {
     pub items: Vec<T>,
+    /// Whether a search timed out before finding all matches, in which case
+    /// `items` may be missing some. Only set for search results.
     pub incomplete_results: Option<bool>,
+    /// The total number of matches of a search, or of the items of some
+    /// other listings that report it.
     pub total_count: Option<u64>,
     #[serde(serialize_with = "serialize_url")]
     pub next: Option<Uri>,
@@ -56,9 +62,112 @@ where
     Option::<String>::serialize(&uri.as_ref().map(Uri::to_string), serializer)
 }
 
+fn deserialize_url<'de, D>(deserializer: D) -> Result<Option<Uri>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|uri| Uri::from_str(&uri).map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Where a long listing got to, which can be saved, e.g. to disk or a KV
+/// store, to resume the listing after a crash or a timeout without fetching
+/// the pages before it again.
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// use octocrab::{models::issues::Issue, PaginationState};
+///
+/// let octocrab = octocrab::instance();
+/// let mut state = match std::fs::read("state.json") {
+///     Ok(saved) => serde_json::from_slice(&saved).unwrap(),
+///     Err(_) => {
+///         let page = octocrab.issues("owner", "repo").list().send().await?;
+///         // process the first page...
+///         PaginationState::from_page(&page)
+///     }
+/// };
+/// while let Some(page) = octocrab.resume_page::<Issue>(&state).await? {
+///     // process the page...
+///     state.advance(&page);
+///     std::fs::write("state.json", serde_json::to_vec(&state).unwrap()).unwrap();
+/// }
+/// println!("done after {} issues", state.items_seen);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct PaginationState {
+    /// The link to the next page, which holds the page number or cursor.
+    /// `None` once the last page was reached.
+    #[serde(serialize_with = "serialize_url", deserialize_with = "deserialize_url")]
+    pub next: Option<Uri>,
+    /// The number of items on the pages seen so far.
+    pub items_seen: u64,
+}
+
+impl PaginationState {
+    /// The state after the first page of a listing.
+    pub fn from_page<T>(page: &Page<T>) -> Self {
+        let mut state = Self::default();
+        state.advance(page);
+        state
+    }
+
+    /// Moves the state past `page`, the page its `next` link led to.
+    pub fn advance<T>(&mut self, page: &Page<T>) {
+        self.next = page.next.clone();
+        self.items_seen += page.items.len() as u64;
+    }
+
+    /// Whether the last page was reached.
+    pub fn is_complete(&self) -> bool {
+        self.next.is_none()
+    }
+}
+
+/// The relation of a page to the current one in the `Link` header of a
+/// paginated response, to navigate with
+/// [`Octocrab::get_page_rel`](crate::Octocrab::get_page_rel).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Rel {
+    First,
+    Prev,
+    Next,
+    Last,
+}
+
+/// The `page` query parameter of a page link.
+fn page_number(uri: &Uri) -> Option<u32> {
+    form_urlencoded::parse(uri.query().unwrap_or("").as_bytes())
+        .find(|(key, _)| key == "page")
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+/// The page link with its `page` query parameter set to `page`.
+#[cfg(feature = "stream")]
+fn with_page_number(uri: &Uri, page: u32) -> Option<Uri> {
+    let query = uri
+        .query()?
+        .split('&')
+        .map(|pair| {
+            if pair.starts_with("page=") {
+                format!("page={page}")
+            } else {
+                pair.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    let mut parts = uri.clone().into_parts();
+    parts.path_and_query = Some(format!("{}?{query}", uri.path()).parse().ok()?);
+    Uri::from_parts(parts).ok()
+}
+
 #[cfg(feature = "stream")]
-struct PageIterator<'octo, T> {
-    crab: &'octo Octocrab,
+struct PageIterator<C, T> {
+    crab: C,
     next: Option<Uri>,
     current: std::vec::IntoIter<T>,
 }
@@ -69,20 +178,35 @@ impl<T> Page<T> {
         std::mem::take(&mut self.items)
     }
 
-    /// If `last` is present, return the number of pages for this navigation.
+    /// The link to the page with the given relation to this one, if the
+    /// response had one.
+    pub fn link(&self, rel: Rel) -> Option<&Uri> {
+        match rel {
+            Rel::First => self.first.as_ref(),
+            Rel::Prev => self.prev.as_ref(),
+            Rel::Next => self.next.as_ref(),
+            Rel::Last => self.last.as_ref(),
+        }
+    }
+
+    /// The number of pages for this navigation. GitHub leaves out the `last`
+    /// link on the last page, whose number then follows from `prev`.
     pub fn number_of_pages(&self) -> Option<u32> {
-        self.last.as_ref().and_then(|uri| {
-            let query = form_urlencoded::parse(uri.query().unwrap_or("").as_bytes());
-            query
-                .filter_map(|(k, v)| {
-                    if k == "page" {
-                        Some(v).and_then(|v| v.parse().ok())
-                    } else {
-                        None
-                    }
-                })
-                .next()
-        })
+        match (&self.last, &self.next, &self.prev) {
+            (Some(last), _, _) => page_number(last),
+            (None, None, Some(prev)) => page_number(prev).map(|prev| prev + 1),
+            _ => None,
+        }
+    }
+
+    /// The number of this page, counting from 1, when it has a link to a
+    /// neighbouring page.
+    pub fn current_page(&self) -> Option<u32> {
+        match (&self.prev, &self.next) {
+            (Some(prev), _) => page_number(prev).map(|prev| prev + 1),
+            (None, Some(_)) => Some(1),
+            (None, None) => None,
+        }
     }
 
     /// Convert Page into a stream of results
@@ -111,40 +235,173 @@ impl<T> Page<T> {
     /// # Ok(())
     /// # }
     /// ```
+    ///
+    /// List builders can also send their request and return the stream
+    /// directly with their own `into_stream`, e.g.
+    /// `crab.orgs("owner").list_repos().into_stream().await?`.
     #[cfg(feature = "stream")]
     #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
     pub fn into_stream(self, crab: &Octocrab) -> impl Stream<Item = crate::Result<T>> + '_
     where
         T: DeserializeOwned + 'static,
     {
-        let state = PageIterator {
-            crab,
-            next: self.next,
-            current: self.items.into_iter(),
+        stream_pages(self, crab)
+    }
+
+    /// Like [`Page::into_stream`], but owns its client, so list builders can
+    /// return the stream without borrowing their handler.
+    #[cfg(feature = "stream")]
+    pub(crate) fn into_owned_stream(self, crab: Octocrab) -> impl Stream<Item = crate::Result<T>>
+    where
+        T: DeserializeOwned + 'static,
+    {
+        stream_pages(self, crab)
+    }
+
+    /// Like [`Page::into_stream`], but fetches up to `pages_ahead` of the
+    /// following pages concurrently while the items are consumed, which
+    /// makes going through long listings much faster. Items are still
+    /// returned in order.
+    ///
+    /// This needs the page numbers, so it falls back to fetching one page
+    /// after another for listings which paginate with cursors or don't
+    /// link to their last page.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let crab = octocrab::instance();
+    /// let issues: Vec<_> = crab
+    ///     .issues("rust-lang", "rust")
+    ///     .list()
+    ///     .per_page(100)
+    ///     .send()
+    ///     .await?
+    ///     .into_stream_prefetching(&crab, 4)
+    ///     .try_collect()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub fn into_stream_prefetching(
+        self,
+        crab: &Octocrab,
+        pages_ahead: usize,
+    ) -> impl Stream<Item = crate::Result<T>> + '_
+    where
+        T: DeserializeOwned + 'static,
+    {
+        use futures_util::future::Either;
+        use futures_util::stream::{self, StreamExt, TryStreamExt};
+
+        let Some(uris) = self.following_page_uris() else {
+            return Either::Left(stream_pages(self, crab));
         };
-        try_unfold(state, |mut state| async move {
-            if let Some(val) = state.current.next() {
-                return Ok(Some((val, state)));
-            }
-            let page = state.crab.get_page::<T>(&state.next).await?;
-            Ok(page.and_then(|page| {
-                let mut current = page.items.into_iter();
-                // If we get an empty page we'll return early here with out
-                // checking next.
-                // It doesn't really make much sense to have an empty page in
-                // the middle so we assume this isn't going to happen
-                let val = current.next()?;
-                let state = PageIterator {
-                    crab: state.crab,
-                    next: page.next,
-                    current,
-                };
-                Some((val, state))
-            }))
-        })
+        let pages = stream::iter(uris)
+            .map(move |uri| async move {
+                crab.get::<Page<T>, _, _>(uri.to_string(), None::<&()>)
+                    .await
+            })
+            .buffered(pages_ahead.max(1))
+            .map_ok(|page| stream::iter(page.items.into_iter().map(Ok)))
+            .try_flatten();
+        Either::Right(stream::iter(self.items.into_iter().map(Ok)).chain(pages))
+    }
+
+    /// The links to all pages from `next` to `last`, if they are numbered.
+    #[cfg(feature = "stream")]
+    fn following_page_uris(&self) -> Option<Vec<Uri>> {
+        let next = self.next.as_ref()?;
+        let (first, last) = (page_number(next)?, page_number(self.last.as_ref()?)?);
+        (first..=last)
+            .map(|page| with_page_number(next, page))
+            .collect()
+    }
+}
+
+#[cfg(feature = "stream")]
+fn stream_pages<C, T>(page: Page<T>, crab: C) -> impl Stream<Item = crate::Result<T>>
+where
+    C: Borrow<Octocrab>,
+    T: DeserializeOwned + 'static,
+{
+    let state = PageIterator {
+        crab,
+        next: page.next,
+        current: page.items.into_iter(),
+    };
+    try_unfold(state, |mut state| async move {
+        if let Some(val) = state.current.next() {
+            return Ok(Some((val, state)));
+        }
+        let crab: &Octocrab = state.crab.borrow();
+        let page = crab.get_page::<T>(&state.next).await?;
+        Ok(page.and_then(|page| {
+            let mut current = page.items.into_iter();
+            // If we get an empty page we'll return early here with out
+            // checking next.
+            // It doesn't really make much sense to have an empty page in
+            // the middle so we assume this isn't going to happen
+            let val = current.next()?;
+            let state = PageIterator {
+                crab: state.crab,
+                next: page.next,
+                current,
+            };
+            Some((val, state))
+        }))
+    })
+}
+
+type ProgressCallback<'a> = Box<dyn FnMut(&Progress) -> ControlFlow<()> + Send + 'a>;
+
+/// Options for collecting the items of all pages with
+/// [`Octocrab::all_pages_with`](crate::Octocrab::all_pages_with).
+#[derive(Default)]
+pub struct AllPagesOptions<'a> {
+    pub(crate) max_items: Option<usize>,
+    pub(crate) on_progress: Option<ProgressCallback<'a>>,
+}
+
+impl<'a> AllPagesOptions<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop once this many items are collected, dropping the rest of the
+    /// last page fetched.
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Called after each page, including the first one. Returning
+    /// [`ControlFlow::Break`] stops fetching and returns the items collected
+    /// so far.
+    pub fn on_progress<F>(mut self, on_progress: F) -> Self
+    where
+        F: FnMut(&Progress) -> ControlFlow<()> + Send + 'a,
+    {
+        self.on_progress = Some(Box::new(on_progress));
+        self
     }
 }
 
+/// How far [`Octocrab::all_pages_with`](crate::Octocrab::all_pages_with)
+/// got through the pages.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Progress {
+    /// The number of items collected so far.
+    pub items: usize,
+    /// The number of pages fetched so far.
+    pub pages: u32,
+    /// The total number of pages, if the first page linked to the last one.
+    pub total_pages: Option<u32>,
+}
+
 impl<T> Default for Page<T> {
     fn default() -> Self {
         Self {
@@ -179,6 +436,8 @@ impl<'iter, T> IntoIterator for &'iter Page<T> {
 
 #[async_trait::async_trait]
 impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
+    const PAGINATED: bool = true;
+
     async fn from_response<B>(response: http::Response<B>) -> crate::Result<Self>
     where
         B: Body<Data = Bytes, Error = crate::Error> + Send,
@@ -214,6 +473,10 @@ impl<T: serde::de::DeserializeOwned> crate::FromResponse for Page<T> {
                 "repositories",
                 "installations",
                 "runners",
+                "codespaces",
+                "machines",
+                "devcontainers",
+                "groups",
             ]
             .into_iter()
             .find(|v| json.get(v).is_some())
@@ -296,7 +559,7 @@ fn get_links(headers: &http::header::HeaderMap) -> crate::Result<HeaderLinks> {
 
 #[cfg(test)]
 mod test {
-    use super::{get_links, HeaderLinks, Page};
+    use super::{get_links, HeaderLinks, Page, Rel};
     use http::Uri;
     use std::str::FromStr;
 
@@ -406,4 +669,75 @@ mod test {
         assert!(serialized
             .contains("\"last\":\"https://api.github.com/repositories/1234/releases?page=3\""));
     }
+
+    #[test]
+    fn page_numbers_from_links() {
+        let uri = |page: u32| {
+            Some(Uri::from_str(&format!("https://api.github.com/user/repos?page={page}")).unwrap())
+        };
+        let middle = Page::<()> {
+            first: uri(1),
+            prev: uri(2),
+            next: uri(4),
+            last: uri(5),
+            ..Page::default()
+        };
+        assert_eq!(middle.link(Rel::Prev), uri(2).as_ref());
+        assert_eq!(middle.current_page(), Some(3));
+        assert_eq!(middle.number_of_pages(), Some(5));
+
+        let last = Page::<()> {
+            first: uri(1),
+            prev: uri(4),
+            ..Page::default()
+        };
+        assert_eq!(last.link(Rel::Last), None);
+        assert_eq!(last.current_page(), Some(5));
+        assert_eq!(last.number_of_pages(), Some(5));
+
+        let only = Page::<()>::default();
+        assert_eq!(only.current_page(), None);
+        assert_eq!(only.number_of_pages(), None);
+    }
+
+    #[cfg(feature = "stream")]
+    #[test]
+    fn with_page_number_keeps_other_parameters() {
+        let uri =
+            Uri::from_str("https://api.github.com/repos/o/r/issues?state=all&page=2&per_page=100")
+                .unwrap();
+        assert_eq!(
+            super::with_page_number(&uri, 7).unwrap(),
+            "https://api.github.com/repos/o/r/issues?state=all&page=7&per_page=100"
+        );
+    }
+
+    #[test]
+    fn pagination_state_roundtrip() {
+        let page = Page {
+            items: vec![1, 2, 3],
+            next: Some(
+                Uri::from_str("https://api.github.com/app/hook/deliveries?cursor=v1_42").unwrap(),
+            ),
+            ..Page::default()
+        };
+        let state = super::PaginationState::from_page(&page);
+        let json = serde_json::to_value(&state).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "next": "https://api.github.com/app/hook/deliveries?cursor=v1_42",
+                "items_seen": 3,
+            })
+        );
+
+        let mut state: super::PaginationState = serde_json::from_value(json).unwrap();
+        assert!(!state.is_complete());
+        state.advance(&Page::<u8> {
+            items: vec![4],
+            ..Page::default()
+        });
+        assert_eq!(state.items_seen, 4);
+        assert!(state.is_complete());
+    }
 }
@@ -33,7 +33,7 @@ pub enum Direction {
 }
 
 /// The reason for locking an issue.
-#[derive(Debug, Clone, Copy, serde::Serialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 #[non_exhaustive]
 pub enum LockReason {
     #[serde(rename = "off-topic")]
@@ -46,6 +46,53 @@ pub enum LockReason {
     Spam,
 }
 
+/// An alternative representation of a resource or a preview of the API,
+/// requested through the `Accept` header. Use it on a single call with
+/// [`Octocrab::accept`](crate::Octocrab::accept).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MediaType {
+    /// The raw markdown body of issues and comments, or the raw contents of
+    /// files.
+    Raw,
+    /// The plain text body of issues and comments.
+    Text,
+    /// The HTML body of issues and comments, or the rendered contents of
+    /// files.
+    Html,
+    /// The raw, text and HTML bodies of issues and comments.
+    Full,
+    /// The diff of a pull request or commit.
+    Diff,
+    /// The patch of a pull request or commit.
+    Patch,
+    /// The SHA of a commit.
+    Sha,
+    /// Stargazers with the time they starred the repository.
+    Star,
+    /// Search results with the fragments matching the query.
+    TextMatch,
+    /// A preview of the API by its name, e.g. `"mercy"`.
+    Preview(&'static str),
+}
+
+impl std::fmt::Display for MediaType {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Raw => f.write_str(&crate::format_media_type("raw")),
+            Self::Text => f.write_str(&crate::format_media_type("text")),
+            Self::Html => f.write_str(&crate::format_media_type("html")),
+            Self::Full => f.write_str(&crate::format_media_type("full")),
+            Self::Diff => f.write_str(&crate::format_media_type("diff")),
+            Self::Patch => f.write_str(&crate::format_media_type("patch")),
+            Self::Sha => f.write_str(&crate::format_media_type("sha")),
+            Self::Star => f.write_str("application/vnd.github.star+json"),
+            Self::TextMatch => f.write_str("application/vnd.github.text-match+json"),
+            Self::Preview(preview) => f.write_str(&crate::format_preview(preview)),
+        }
+    }
+}
+
 pub mod actions {
     //! Parameter types for the actions API.
 
@@ -91,6 +138,15 @@ pub mod apps {
         pub repositories: Vec<String>,
         pub repository_ids: Vec<RepositoryId>,
     }
+
+    /// What to sort the accounts subscribed to a Marketplace plan by.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum MarketplaceAccountSort {
+        Created,
+        Updated,
+    }
 }
 
 pub mod checks {
@@ -284,6 +340,15 @@ pub mod issues {
         Comments,
     }
 
+    /// What to sort milestones by. Can be either `due_on` or `completeness`.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum MilestoneSort {
+        DueOn,
+        Completeness,
+    }
+
     /// A generic filter type that allows you to filter either by exact match,
     /// any match, or no matches.
     #[derive(Debug, Clone, Copy)]
@@ -367,6 +432,69 @@ pub mod orgs {
         Member,
         Admin,
     }
+
+    /// The repository permission level a role builds on.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum BaseRole {
+        Read,
+        Triage,
+        Write,
+        Maintain,
+        /// Only used by organization roles; custom repository roles can't be
+        /// based on `admin`.
+        Admin,
+    }
+
+    /// A security feature which can be enabled or disabled for all
+    /// repositories of an organization at once.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum SecurityProduct {
+        DependencyGraph,
+        DependabotAlerts,
+        DependabotSecurityUpdates,
+        AdvancedSecurity,
+        CodeScanningDefaultSetup,
+        SecretScanning,
+        SecretScanningPushProtection,
+    }
+
+    impl std::fmt::Display for SecurityProduct {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let text = match self {
+                Self::DependencyGraph => "dependency_graph",
+                Self::DependabotAlerts => "dependabot_alerts",
+                Self::DependabotSecurityUpdates => "dependabot_security_updates",
+                Self::AdvancedSecurity => "advanced_security",
+                Self::CodeScanningDefaultSetup => "code_scanning_default_setup",
+                Self::SecretScanning => "secret_scanning",
+                Self::SecretScanningPushProtection => "secret_scanning_push_protection",
+            };
+
+            f.write_str(text)
+        }
+    }
+}
+
+pub mod projects_v2 {
+    //! Parameter types for projects (v2).
+
+    /// A new value for a field of a project item.
+    #[derive(Debug, Clone, PartialEq, serde::Serialize)]
+    #[serde(rename_all = "camelCase")]
+    #[non_exhaustive]
+    pub enum FieldValue {
+        Text(String),
+        Number(f64),
+        Date(chrono::NaiveDate),
+        /// The id of an option of a single select field.
+        SingleSelectOptionId(String),
+        /// The id of an iteration of an iteration field.
+        IterationId(String),
+    }
 }
 
 pub mod pulls {
@@ -440,6 +568,15 @@ pub mod pulls {
             Created,
             Updated,
         }
+
+        /// The level at which a review comment is targeted.
+        #[derive(Debug, Clone, Copy, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[non_exhaustive]
+        pub enum SubjectType {
+            Line,
+            File,
+        }
     }
 }
 
@@ -531,6 +668,42 @@ pub mod repos {
         }
     }
 
+    /// The media types the contents and readme endpoints can render a file
+    /// as, instead of the default base64 encoded JSON object.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[non_exhaustive]
+    pub enum ContentMediaType {
+        /// The raw contents of the file.
+        Raw,
+        /// The file rendered as HTML, e.g. for Markdown files.
+        Html,
+    }
+
+    impl std::fmt::Display for ContentMediaType {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            let text = match self {
+                Self::Raw => "raw",
+                Self::Html => "html",
+            };
+
+            f.write_str(text)
+        }
+    }
+
+    pub mod activity {
+        /// The time period to filter the activity of a repository by.
+        #[derive(Debug, Clone, Copy, serde::Serialize)]
+        #[serde(rename_all = "snake_case")]
+        #[non_exhaustive]
+        pub enum TimePeriod {
+            Day,
+            Week,
+            Month,
+            Quarter,
+            Year,
+        }
+    }
+
     pub mod forks {
         /// The available methods to sort repository forks by.
         #[derive(Debug, Clone, Copy, serde::Serialize)]
@@ -556,6 +729,182 @@ pub mod repos {
     }
 }
 
+pub mod search {
+    //! Parameter types for the search API.
+
+    /// What to sort searched labels by.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum LabelSort {
+        Created,
+        Updated,
+    }
+
+    impl From<LabelSort> for String {
+        fn from(sort: LabelSort) -> Self {
+            match sort {
+                LabelSort::Created => "created",
+                LabelSort::Updated => "updated",
+            }
+            .to_string()
+        }
+    }
+
+    /// What to sort searched users by.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum UserSort {
+        Followers,
+        Repositories,
+        Joined,
+    }
+
+    impl From<UserSort> for String {
+        fn from(sort: UserSort) -> Self {
+            match sort {
+                UserSort::Followers => "followers",
+                UserSort::Repositories => "repositories",
+                UserSort::Joined => "joined",
+            }
+            .to_string()
+        }
+    }
+}
+
+pub mod secret_scanning {
+    //! Parameter types for the secret scanning API.
+
+    /// Which push protection bypass requests to list.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum BypassRequestStatus {
+        Completed,
+        Cancelled,
+        Expired,
+        Denied,
+        Open,
+        All,
+    }
+
+    /// How far back to list push protection bypass requests.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum TimePeriod {
+        Hour,
+        Day,
+        Week,
+        Month,
+    }
+
+    /// The decision on a push protection bypass request.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum BypassReview {
+        Approve,
+        Deny,
+    }
+
+    /// Why a secret blocked by push protection is pushed anyway.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum BypassReason {
+        FalsePositive,
+        UsedInTests,
+        WillFixLater,
+    }
+}
+
+pub mod security_advisories {
+    //! Parameter types for the security advisories API.
+
+    /// The state of a repository security advisory.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum AdvisoryState {
+        Triage,
+        Draft,
+        Published,
+        Closed,
+        Withdrawn,
+    }
+
+    /// The severity of an advisory.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Severity {
+        Unknown,
+        Low,
+        Medium,
+        High,
+        Critical,
+    }
+
+    /// The type of an advisory in the global advisory database.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum AdvisoryType {
+        Reviewed,
+        Malware,
+        Unreviewed,
+    }
+
+    /// The package ecosystem an advisory applies to.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Ecosystem {
+        Rubygems,
+        Npm,
+        Pip,
+        Maven,
+        Nuget,
+        Composer,
+        Go,
+        Rust,
+        Erlang,
+        Actions,
+        Pub,
+        Swift,
+        Other,
+    }
+
+    /// The role of a credited user.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum CreditType {
+        Analyst,
+        Finder,
+        Reporter,
+        Coordinator,
+        RemediationDeveloper,
+        RemediationReviewer,
+        RemediationVerifier,
+        Tool,
+        Sponsor,
+        Other,
+    }
+
+    /// What to sort advisories by.
+    #[derive(Debug, Clone, Copy, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Sort {
+        Created,
+        Updated,
+        Published,
+    }
+}
+
 pub mod teams {
     #[derive(Debug, Clone, Copy, serde::Serialize)]
     #[serde(rename_all = "snake_case")]
@@ -575,6 +924,15 @@ pub mod teams {
         Maintain,
         Triage,
     }
+
+    /// A user's role within a team.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum Role {
+        Member,
+        Maintainer,
+    }
 }
 
 pub mod workflows {
@@ -616,4 +974,15 @@ pub mod users {
             Private,
         }
     }
+
+    /// The kind of resource a hovercard is requested in the context of.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+    #[serde(rename_all = "snake_case")]
+    #[non_exhaustive]
+    pub enum HovercardSubjectType {
+        Organization,
+        Repository,
+        Issue,
+        PullRequest,
+    }
 }
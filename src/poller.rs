@@ -0,0 +1,242 @@
+//! Consuming GitHub's quasi-realtime feeds, such as events and notifications,
+//! by polling them.
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use futures_core::Stream;
+use http::request::Builder;
+use http::{header::HeaderMap, Method, StatusCode, Uri};
+use serde::de::DeserializeOwned;
+
+use crate::etag::{EntityTag, Polled};
+use crate::models::{activity::Notification, events::Event};
+use crate::{FromResponse, Octocrab, Page};
+
+/// An item of a feed which a [`Poller`] can tell apart from the items it has
+/// already yielded.
+pub trait Pollable: DeserializeOwned {
+    /// Identifies the item, and changes whenever the item should be yielded
+    /// again.
+    fn poll_key(&self) -> String;
+}
+
+impl Pollable for Event {
+    fn poll_key(&self) -> String {
+        self.id.clone()
+    }
+}
+
+impl Pollable for Notification {
+    // Notifications are yielded again whenever their thread is updated.
+    fn poll_key(&self) -> String {
+        format!("{}@{}", self.id, self.updated_at.timestamp())
+    }
+}
+
+/// Polls a feed, e.g. events or notifications, and streams the items which
+/// are new since the previous poll, oldest first.
+///
+/// Polls are conditional, with `If-None-Match` and `If-Modified-Since`, so
+/// that polls which find nothing new don't count against the rate limit,
+/// and are spaced by at least the `X-Poll-Interval` GitHub asks for. When
+/// more items than fit on a page arrived between two polls, the following
+/// pages are fetched until reaching items which were already yielded.
+///
+/// Created with the `poller` method of the event and notification listing
+/// builders, or with [`Poller::new`] for other feeds.
+/// ```no_run
+/// # async fn run() -> octocrab::Result<()> {
+/// use futures_util::TryStreamExt;
+///
+/// let mut events = octocrab::instance()
+///     .repos("owner", "repo")
+///     .events()
+///     .per_page(100)
+///     .poller()?
+///     .skip_initial(true)
+///     .into_stream();
+/// futures_util::pin_mut!(events);
+/// while let Some(event) = events.try_next().await? {
+///     println!("{:?}", event.r#type);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct Poller<T> {
+    crab: Octocrab,
+    uri: Uri,
+    interval: Duration,
+    max_pages: usize,
+    skip_initial: bool,
+    seen_capacity: usize,
+    started: bool,
+    etag: Option<EntityTag>,
+    last_modified: Option<String>,
+    wait: Option<Duration>,
+    seen: HashSet<String>,
+    seen_order: VecDeque<String>,
+    pending: VecDeque<T>,
+}
+
+impl<T: Pollable> Poller<T> {
+    /// Polls the feed at `route`, which lists its newest items first.
+    pub fn new(crab: &Octocrab, route: impl AsRef<str>) -> crate::Result<Self> {
+        let uri = crab.list_uri(route, None::<&()>)?;
+        Ok(Self::from_uri(crab, uri))
+    }
+
+    pub(crate) fn from_uri(crab: &Octocrab, uri: Uri) -> Self {
+        Self {
+            crab: crab.clone(),
+            uri,
+            interval: Duration::from_secs(60),
+            max_pages: 10,
+            skip_initial: false,
+            seen_capacity: 1000,
+            started: false,
+            etag: None,
+            last_modified: None,
+            wait: None,
+            seen: HashSet::new(),
+            seen_order: VecDeque::new(),
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// The least time between two polls, one minute by default. GitHub's
+    /// `X-Poll-Interval` is used instead when it's longer.
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// The most pages fetched by a single poll, 10 by default.
+    pub fn max_pages(mut self, max_pages: usize) -> Self {
+        self.max_pages = max_pages.max(1);
+        self
+    }
+
+    /// Only yields items which arrive after the first poll, instead of also
+    /// yielding the items of the first page.
+    pub fn skip_initial(mut self, skip_initial: bool) -> Self {
+        self.skip_initial = skip_initial;
+        self
+    }
+
+    /// How many of the latest items are remembered to deduplicate them, 1000
+    /// by default.
+    pub fn seen_capacity(mut self, capacity: usize) -> Self {
+        self.seen_capacity = capacity.max(1);
+        self
+    }
+
+    /// Streams the new items of the feed, polling it forever. Errors are
+    /// yielded without ending the stream, and the next poll is sent after
+    /// the usual interval.
+    pub fn into_stream(self) -> impl Stream<Item = crate::Result<T>>
+    where
+        T: 'static,
+    {
+        futures_util::stream::unfold(self, |mut poller| async move {
+            loop {
+                if let Some(item) = poller.pending.pop_front() {
+                    return Some((Ok(item), poller));
+                }
+                if let Some(wait) = poller.wait.take() {
+                    crate::internal::async_runtime::sleep(wait).await;
+                }
+                if let Err(error) = poller.poll().await {
+                    poller.wait = Some(poller.interval);
+                    return Some((Err(error), poller));
+                }
+            }
+        })
+    }
+
+    /// Sends one conditional poll, queueing the new items.
+    async fn poll(&mut self) -> crate::Result<()> {
+        let mut headers = HeaderMap::new();
+        if let Some(etag) = self.etag.clone() {
+            EntityTag::insert_if_none_match_header(&mut headers, etag)?;
+        }
+        let mut builder = Builder::new().method(Method::GET).uri(self.uri.clone());
+        for (key, value) in headers.iter() {
+            builder = builder.header(key, value);
+        }
+        if let Some(last_modified) = &self.last_modified {
+            builder = builder.header(http::header::IF_MODIFIED_SINCE, last_modified.as_str());
+        }
+        let request = self.crab.build_request(builder, None::<&()>)?;
+
+        let response = self.crab.execute(request).await?;
+        let polled = if response.status() == StatusCode::NOT_MODIFIED {
+            Polled::extract_from_response(&response, None)
+        } else {
+            let response = crate::map_github_error(response).await?;
+            let polled = Polled::extract_from_response(&response, None);
+            let page = <Page<T>>::from_response(response).await?;
+            Polled {
+                value: Some(page),
+                ..polled
+            }
+        };
+
+        self.wait = Some(
+            polled
+                .poll_interval
+                .map_or(self.interval, |interval| interval.max(self.interval)),
+        );
+        if polled.etag.is_some() {
+            self.etag = polled.etag;
+        }
+        if polled.last_modified.is_some() {
+            self.last_modified = polled.last_modified;
+        }
+        let Some(mut page) = polled.value else {
+            return Ok(());
+        };
+
+        let initial = !self.started;
+        self.started = true;
+        let mut new_items = Vec::new();
+        let mut pages = 1;
+        loop {
+            let mut reached_seen = false;
+            for item in page.items.drain(..) {
+                if self.seen.contains(&item.poll_key()) {
+                    reached_seen = true;
+                } else {
+                    new_items.push(item);
+                }
+            }
+            if initial || reached_seen || pages >= self.max_pages {
+                break;
+            }
+            match self.crab.get_page::<T>(&page.next).await? {
+                Some(next) => page = next,
+                None => break,
+            }
+            pages += 1;
+        }
+
+        // Feeds list their newest items first.
+        for item in new_items.into_iter().rev() {
+            self.remember(item.poll_key());
+            if !(initial && self.skip_initial) {
+                self.pending.push_back(item);
+            }
+        }
+        Ok(())
+    }
+
+    fn remember(&mut self, key: String) {
+        if self.seen.insert(key.clone()) {
+            self.seen_order.push_back(key);
+        }
+        while self.seen_order.len() > self.seen_capacity {
+            if let Some(oldest) = self.seen_order.pop_front() {
+                self.seen.remove(&oldest);
+            }
+        }
+    }
+}
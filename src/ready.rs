@@ -0,0 +1,62 @@
+//! Waiting on resources which GitHub computes in the background, and answers
+//! `202 Accepted` for until they're ready.
+use std::time::Duration;
+
+/// How to wait on a resource GitHub is still computing, e.g. the statistics
+/// of a repository, used by [`Octocrab::get_when_ready`](crate::Octocrab::get_when_ready).
+///
+/// Requests are retried with an exponential backoff, starting from
+/// half a second and up to eight seconds between attempts by default, until
+/// the resource is ready or the timeout of one minute has elapsed.
+/// ```
+/// use std::time::Duration;
+/// use octocrab::ReadyBackoff;
+///
+/// let backoff = ReadyBackoff::new(Duration::from_secs(30))
+///     .initial_delay(Duration::from_secs(1))
+///     .max_delay(Duration::from_secs(5));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReadyBackoff {
+    pub(crate) timeout: Duration,
+    pub(crate) initial_delay: Duration,
+    pub(crate) max_delay: Duration,
+}
+
+impl ReadyBackoff {
+    /// Waits for up to `timeout` before giving up with an error.
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            timeout,
+            ..Self::default()
+        }
+    }
+
+    /// Doesn't wait at all, failing if the resource isn't ready on the first
+    /// request.
+    pub fn none() -> Self {
+        Self::new(Duration::ZERO)
+    }
+
+    /// The delay before the first retry, doubled for each retry after it.
+    pub fn initial_delay(mut self, delay: Duration) -> Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// The longest delay between two retries.
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+}
+
+impl Default for ReadyBackoff {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(60),
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(8),
+        }
+    }
+}
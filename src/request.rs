@@ -0,0 +1,159 @@
+//! Requests to any endpoint, for endpoints octocrab doesn't have methods
+//! for yet.
+//!
+//! ```no_run
+//! # async fn run() -> octocrab::Result<()> {
+//! use http::Method;
+//!
+//! #[derive(serde::Serialize)]
+//! struct Query {
+//!     per_page: u8,
+//! }
+//!
+//! let rulesets: Vec<serde_json::Value> = octocrab::instance()
+//!     .request(Method::GET, "/repos/XAMPPRocky/octocrab/rulesets")
+//!     .query(&Query { per_page: 100 })
+//!     .send_json()
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+use bytes::Bytes;
+use http::{header::CONTENT_TYPE, request::Builder, HeaderName, HeaderValue, Method};
+use http_body_util::{combinators::BoxBody, BodyExt};
+use snafu::ResultExt;
+
+use crate::body::OctoBody;
+use crate::error::{SerdeSnafu, SerdeUrlEncodedSnafu};
+use crate::{FromResponse, Octocrab};
+
+/// A request to any endpoint, created by [`Octocrab::request`].
+///
+/// Errors serializing the query or the body are returned when the request is
+/// sent.
+pub struct RequestBuilder<'octo> {
+    crab: &'octo Octocrab,
+    method: Method,
+    route: String,
+    query: crate::Result<Vec<String>>,
+    headers: Vec<(HeaderName, HeaderValue)>,
+    body: crate::Result<Option<RequestBody>>,
+}
+
+enum RequestBody {
+    Json(serde_json::Value),
+    Stream(OctoBody),
+}
+
+impl<'octo> RequestBuilder<'octo> {
+    pub(crate) fn new(crab: &'octo Octocrab, method: Method, route: impl Into<String>) -> Self {
+        Self {
+            crab,
+            method,
+            route: route.into(),
+            query: Ok(Vec::new()),
+            headers: Vec::new(),
+            body: Ok(None),
+        }
+    }
+
+    /// Adds the fields of `parameters` to the query string. Can be called
+    /// several times.
+    pub fn query(mut self, parameters: &(impl serde::Serialize + ?Sized)) -> Self {
+        if let Ok(query) = &mut self.query {
+            match serde_urlencoded::to_string(parameters).context(SerdeUrlEncodedSnafu) {
+                Ok(encoded) if encoded.is_empty() => {}
+                Ok(encoded) => query.push(encoded),
+                Err(error) => self.query = Err(error),
+            }
+        }
+        self
+    }
+
+    /// Adds a header to the request, in addition to the headers octocrab
+    /// sends with every request.
+    pub fn header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.push((name, value));
+        self
+    }
+
+    /// Sends `body` serialized as JSON, replacing any previous body.
+    pub fn json(mut self, body: &(impl serde::Serialize + ?Sized)) -> Self {
+        self.body = serde_json::to_value(body)
+            .map(|body| Some(RequestBody::Json(body)))
+            .context(SerdeSnafu);
+        self
+    }
+
+    /// Sends `body` as is, e.g. a file streamed from disk, replacing any
+    /// previous body. Set its `Content-Type` with [`header`](Self::header).
+    pub fn body<B>(mut self, body: B) -> Self
+    where
+        B: http_body::Body<Data = Bytes> + Send + Sync + 'static,
+        B::Error: Into<Box<dyn std::error::Error + Send + Sync>>,
+    {
+        self.body = Ok(Some(RequestBody::Stream(OctoBody::new(body))));
+        self
+    }
+
+    /// Sends the request, returning the response if its status is a success.
+    pub async fn send(self) -> crate::Result<http::Response<BoxBody<Bytes, crate::Error>>> {
+        let mut route = self.route;
+        let query = self.query?;
+        if !query.is_empty() {
+            let separator = if route.contains('?') { '&' } else { '?' };
+            route = format!("{route}{separator}{}", query.join("&"));
+        }
+        let uri = self.crab.parameterized_uri(route, None::<&()>)?;
+
+        let mut builder = Builder::new().method(self.method).uri(uri);
+        for (name, value) in self.headers {
+            builder = builder.header(name, value);
+        }
+        let request = match self.body? {
+            Some(RequestBody::Json(body)) => self.crab.build_request(builder, Some(&body))?,
+            Some(RequestBody::Stream(body)) => {
+                let mut request = self.crab.build_request(builder, None::<&()>)?;
+                request.headers_mut().remove(http::header::CONTENT_LENGTH);
+                if !request.headers().contains_key(CONTENT_TYPE) {
+                    request.headers_mut().insert(
+                        CONTENT_TYPE,
+                        HeaderValue::from_static("application/octet-stream"),
+                    );
+                }
+                *request.body_mut() = body;
+                request
+            }
+            None => self.crab.build_request(builder, None::<&()>)?,
+        };
+
+        crate::map_github_error(self.crab.execute(request).await?).await
+    }
+
+    /// Sends the request and deserializes the JSON body of the response.
+    /// Any [`FromResponse`] type can be used, e.g. [`RawResponse`](crate::RawResponse)
+    /// to keep the headers too.
+    pub async fn send_json<R: FromResponse>(self) -> crate::Result<R> {
+        R::from_response(self.send().await?).await
+    }
+
+    /// Sends the request and returns the body of the response, e.g. for
+    /// endpoints which don't return JSON.
+    pub async fn send_bytes(self) -> crate::Result<Bytes> {
+        Ok(self.send().await?.into_body().collect().await?.to_bytes())
+    }
+
+    /// Sends the request and streams the body of the response, e.g. to
+    /// download a large archive without keeping it in memory.
+    #[cfg(feature = "stream")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "stream")))]
+    pub async fn send_stream(
+        self,
+    ) -> crate::Result<impl futures_core::Stream<Item = crate::Result<Bytes>>> {
+        use futures_util::TryStreamExt;
+
+        let response = self.send().await?;
+        Ok(http_body_util::BodyStream::new(response.into_body())
+            .try_filter_map(|frame| futures_util::future::ok(frame.into_data().ok())))
+    }
+}
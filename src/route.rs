@@ -0,0 +1,138 @@
+//! Building the routes of requests from values such as branch names and
+//! file paths, which need percent-encoding to end up in the path intact.
+use std::convert::TryFrom;
+use std::fmt::{self, Write};
+
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+
+/// The characters which can't appear literally in a path, besides `/`.
+const PATH: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'[')
+    .add(b'\\')
+    .add(b']')
+    .add(b'^')
+    .add(b'`')
+    .add(b'{')
+    .add(b'|')
+    .add(b'}');
+
+/// The characters which can't appear literally in a path segment.
+const SEGMENT: &AsciiSet = &PATH.add(b'/');
+
+/// A route, written into a single string as it's built, so that building
+/// it doesn't allocate more than needed.
+#[derive(Debug, Clone)]
+pub(crate) struct Route {
+    route: String,
+    has_query: bool,
+}
+
+impl Route {
+    /// Starts a route with `prefix`, which is written as is, e.g. the route
+    /// of a repository or a literal path.
+    pub(crate) fn new(prefix: impl fmt::Display) -> Self {
+        let mut route = String::with_capacity(64);
+        write!(route, "{prefix}").expect("writing to a String never fails");
+        Self {
+            route,
+            has_query: false,
+        }
+    }
+
+    /// Appends the literal segments of `path`, e.g. `"git/refs"`.
+    pub(crate) fn path(mut self, path: &str) -> Self {
+        self.route.push('/');
+        self.route.push_str(path);
+        self
+    }
+
+    /// Appends `value` as a single segment, encoding any `/` in it, e.g. for
+    /// the name of a secret or label.
+    pub(crate) fn segment(mut self, value: impl AsRef<str>) -> Self {
+        self.route.push('/');
+        self.route
+            .extend(utf8_percent_encode(value.as_ref(), SEGMENT));
+        self
+    }
+
+    /// Appends `value` as segments separated by its `/`, e.g. for a file
+    /// path or a branch name like `feature/login`.
+    pub(crate) fn segments(mut self, value: impl AsRef<str>) -> Self {
+        self.route.push('/');
+        self.route.extend(utf8_percent_encode(value.as_ref(), PATH));
+        self
+    }
+
+    /// Appends a parameter to the query string.
+    pub(crate) fn query(mut self, key: &str, value: impl AsRef<str>) -> Self {
+        self.route.push(if self.has_query { '&' } else { '?' });
+        self.has_query = true;
+        self.route
+            .extend(url::form_urlencoded::byte_serialize(key.as_bytes()));
+        self.route.push('=');
+        self.route.extend(url::form_urlencoded::byte_serialize(
+            value.as_ref().as_bytes(),
+        ));
+        self
+    }
+}
+
+impl AsRef<str> for Route {
+    fn as_ref(&self) -> &str {
+        &self.route
+    }
+}
+
+impl fmt::Display for Route {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.route)
+    }
+}
+
+impl TryFrom<Route> for http::Uri {
+    type Error = http::uri::InvalidUri;
+
+    fn try_from(route: Route) -> Result<Self, Self::Error> {
+        route.route.parse()
+    }
+}
+
+impl From<Route> for String {
+    fn from(route: Route) -> String {
+        route.route
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_segments() {
+        let route = Route::new("/repos/owner/repo")
+            .path("git/ref")
+            .segments("heads/feature/#42 100%")
+            .query("ref", "a b&c");
+        assert_eq!(
+            route.as_ref(),
+            "/repos/owner/repo/git/ref/heads/feature/%2342%20100%25?ref=a+b%26c"
+        );
+
+        let route = Route::new("/repos/owner/repo")
+            .path("labels")
+            .segment("area/api ✨")
+            .query("per_page", "1")
+            .query("page", "2");
+        assert_eq!(
+            route.as_ref(),
+            "/repos/owner/repo/labels/area%2Fapi%20%E2%9C%A8?per_page=1&page=2"
+        );
+    }
+}
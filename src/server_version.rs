@@ -0,0 +1,144 @@
+use std::fmt;
+use std::num::ParseIntError;
+use std::str::FromStr;
+
+use http::Uri;
+use snafu::Backtrace;
+
+/// The version of a GitHub Enterprise Server instance, e.g. `3.12`.
+///
+/// Declare it with
+/// [`OctocrabBuilder::enterprise_server_version`](crate::OctocrabBuilder::enterprise_server_version)
+/// or detect it with
+/// [`Octocrab::detect_server_version`](crate::Octocrab::detect_server_version),
+/// and requests to endpoints the server doesn't have fail with
+/// [`Error::UnsupportedOnThisServer`](crate::Error::UnsupportedOnThisServer)
+/// instead of an opaque 404.
+/// ```
+/// use octocrab::ServerVersion;
+///
+/// let version: ServerVersion = "3.12.4".parse().unwrap();
+/// assert_eq!(version, ServerVersion::new(3, 12));
+/// assert!(version < ServerVersion::new(3, 14));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+}
+
+impl ServerVersion {
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self { major, minor }
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}", self.major, self.minor)
+    }
+}
+
+/// Parses the major and minor version from a version like `3.12` or
+/// `3.12.4`, ignoring the patch version.
+impl FromStr for ServerVersion {
+    type Err = ParseIntError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.trim().split('.');
+        let major = parts.next().unwrap_or_default().parse()?;
+        let minor = parts.next().unwrap_or("0").parse()?;
+        Ok(Self { major, minor })
+    }
+}
+
+/// Which GitHub Enterprise Server versions have an endpoint.
+#[derive(Debug, Clone, Copy)]
+enum Availability {
+    /// Only available on GitHub.com.
+    DotComOnly,
+    /// Available from this version on.
+    Since(ServerVersion),
+}
+
+/// The endpoints which aren't available on all GitHub Enterprise Server
+/// versions, by the start of their path, where `*` matches any segment.
+const AVAILABILITY: &[(&str, Availability)] = &[
+    ("/classrooms", Availability::DotComOnly),
+    ("/assignments", Availability::DotComOnly),
+    ("/user/codespaces", Availability::DotComOnly),
+    ("/orgs/*/codespaces", Availability::DotComOnly),
+    ("/repos/*/*/codespaces", Availability::DotComOnly),
+    ("/orgs/*/copilot", Availability::DotComOnly),
+    ("/orgs/*/team/*/copilot", Availability::DotComOnly),
+    ("/enterprises/*/copilot", Availability::DotComOnly),
+    ("/marketplace_listing", Availability::DotComOnly),
+    (
+        "/repos/*/*/rulesets",
+        Availability::Since(ServerVersion::new(3, 11)),
+    ),
+    (
+        "/orgs/*/rulesets",
+        Availability::Since(ServerVersion::new(3, 11)),
+    ),
+    (
+        "/orgs/*/organization-roles",
+        Availability::Since(ServerVersion::new(3, 14)),
+    ),
+];
+
+/// Fails with [`Error::UnsupportedOnThisServer`](crate::Error::UnsupportedOnThisServer)
+/// if a server of the given version doesn't have the endpoint at `uri`.
+pub(crate) fn ensure_available(uri: &Uri, version: ServerVersion) -> crate::Result<()> {
+    let path = uri.path();
+    let path = path.strip_prefix("/api/v3").unwrap_or(path);
+    let Some((_, availability)) = AVAILABILITY
+        .iter()
+        .find(|(pattern, _)| path_starts_with(path, pattern))
+    else {
+        return Ok(());
+    };
+    let required = match availability {
+        Availability::DotComOnly => None,
+        Availability::Since(required) if version >= *required => return Ok(()),
+        Availability::Since(required) => Some(*required),
+    };
+    Err(crate::Error::UnsupportedOnThisServer {
+        endpoint: path.to_owned(),
+        version,
+        required,
+        backtrace: Backtrace::capture(),
+    })
+}
+
+/// Whether the segments of `path` start with those of `pattern`.
+fn path_starts_with(path: &str, pattern: &str) -> bool {
+    let mut segments = path.trim_start_matches('/').split('/');
+    pattern
+        .trim_start_matches('/')
+        .split('/')
+        .all(|expected| match segments.next() {
+            Some(segment) => expected == "*" || expected == segment,
+            None => false,
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gates_endpoints_by_version() {
+        let check = |path: &str, version| ensure_available(&path.parse().unwrap(), version);
+        let version = ServerVersion::new(3, 12);
+
+        assert!(check("https://ghe.example.com/api/v3/repos/o/r/issues", version).is_ok());
+        assert!(check("https://ghe.example.com/api/v3/repos/o/r/rulesets", version).is_ok());
+        assert!(check("/orgs/org/organization-roles/1", version).is_err());
+        assert!(check("/orgs/org/organization-roles", ServerVersion::new(3, 14)).is_ok());
+        assert!(check("/orgs/org/copilot/billing", ServerVersion::new(3, 99)).is_err());
+        assert!(check("/user/codespaces", version).is_err());
+        assert!(check("/repos/o/copilot/issues", version).is_ok());
+        assert!(check("/repos/o/codespaces-demo/issues", version).is_ok());
+    }
+}
@@ -9,6 +9,8 @@ pub struct AuthHeaderLayer {
     pub(crate) auth_header: Arc<Option<HeaderValue>>,
     base_uri: Uri,
     upload_uri: Uri,
+    graphql_uri: Option<Uri>,
+    models_uri: Option<Uri>,
 }
 
 impl AuthHeaderLayer {
@@ -17,8 +19,22 @@ impl AuthHeaderLayer {
             auth_header: Arc::new(auth_header),
             base_uri,
             upload_uri,
+            graphql_uri: None,
+            models_uri: None,
         }
     }
+
+    /// Also authenticate requests to the host of the GraphQL API.
+    pub fn with_graphql_uri(mut self, graphql_uri: Uri) -> Self {
+        self.graphql_uri = Some(graphql_uri);
+        self
+    }
+
+    /// Also authenticate requests to the host of the GitHub Models API.
+    pub fn with_models_uri(mut self, models_uri: Uri) -> Self {
+        self.models_uri = Some(models_uri);
+        self
+    }
 }
 
 impl<S> Layer<S> for AuthHeaderLayer {
@@ -30,6 +46,8 @@ impl<S> Layer<S> for AuthHeaderLayer {
             auth_header: self.auth_header.clone(),
             base_uri: self.base_uri.clone(),
             upload_uri: self.upload_uri.clone(),
+            graphql_uri: self.graphql_uri.clone(),
+            models_uri: self.models_uri.clone(),
         }
     }
 }
@@ -41,6 +59,8 @@ pub struct AuthHeader<S> {
     pub(crate) auth_header: Arc<Option<HeaderValue>>,
     base_uri: Uri,
     upload_uri: Uri,
+    graphql_uri: Option<Uri>,
+    models_uri: Option<Uri>,
 }
 
 impl<S, ReqBody> Service<Request<ReqBody>> for AuthHeader<S>
@@ -64,7 +84,12 @@ where
         // away from GitHub (via follow_location_to_data()), and we don't
         // want to give our credentials to third-party services.
         let authority = req.uri().authority();
-        let allowed_authorities = [self.base_uri.authority(), self.upload_uri.authority()];
+        let allowed_authorities = [
+            self.base_uri.authority(),
+            self.upload_uri.authority(),
+            self.graphql_uri.as_ref().and_then(Uri::authority),
+            self.models_uri.as_ref().and_then(Uri::authority),
+        ];
         if authority.is_none() || allowed_authorities.contains(&authority) {
             if let Some(auth_header) = &*self.auth_header {
                 req.headers_mut().append(AUTHORIZATION, auth_header.clone());
@@ -28,7 +28,7 @@ pub enum CacheKey {
 #[derive(Debug, Clone, Default)]
 /// Cache entry containing the response data as well as response headers.
 pub struct CachedResponse {
-    pub body: Vec<u8>,
+    pub body: Bytes,
     pub headers: HeaderMap,
 }
 
@@ -52,7 +52,9 @@ pub trait CacheStorage: Send + Sync {
 
 /// Writes the response body to the cache.
 pub trait CacheWriter: Send + Sync {
-    fn write_body(&mut self, data: &[u8]);
+    /// Called with each chunk of the body as it's received. The chunk shares
+    /// its memory with the response, so keeping it doesn't copy it.
+    fn write_body(&mut self, data: Bytes);
 }
 
 #[derive(Clone)]
@@ -168,7 +170,7 @@ where
 
                 // Replace the body.
                 *response.body_mut() = BoxBody::new(Box::new(
-                    Full::new(Bytes::from(cached.body)).map_err(|infallible| match infallible {}),
+                    Full::new(cached.body).map_err(|infallible| match infallible {}),
                 ));
                 *response.status_mut() = StatusCode::OK;
             } else {
@@ -219,7 +221,7 @@ where
             Poll::Ready(frame) => {
                 if let Some(Ok(ref data)) = frame {
                     if let Some(data) = data.data_ref() {
-                        this.writer.write_body(data);
+                        this.writer.write_body(data.clone());
                     }
                 }
 
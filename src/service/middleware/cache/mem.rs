@@ -1,18 +1,21 @@
 use crate::internal::concurrent::ConcurrentMap;
 
 use super::{CacheKey, CacheStorage, CacheWriter, CachedResponse};
+use bytes::{Bytes, BytesMut};
 use http::{HeaderMap, Uri};
+use std::sync::Arc;
 
 pub struct InMemoryCache {
-    keys: ConcurrentMap<Uri, CacheKey>,
-    responses: ConcurrentMap<Uri, CachedResponse>,
+    // Shared with the writers, as cloning a `ConcurrentMap` copies it
+    keys: Arc<ConcurrentMap<Uri, CacheKey>>,
+    responses: Arc<ConcurrentMap<Uri, CachedResponse>>,
 }
 
 impl InMemoryCache {
     pub fn new() -> Self {
         Self {
-            keys: ConcurrentMap::new(),
-            responses: ConcurrentMap::new(),
+            keys: Arc::new(ConcurrentMap::new()),
+            responses: Arc::new(ConcurrentMap::new()),
         }
     }
 }
@@ -24,11 +27,13 @@ impl Default for InMemoryCache {
 }
 
 struct InMemoryWriter {
-    keys: ConcurrentMap<Uri, CacheKey>,
-    responses: ConcurrentMap<Uri, CachedResponse>,
+    keys: Arc<ConcurrentMap<Uri, CacheKey>>,
+    responses: Arc<ConcurrentMap<Uri, CachedResponse>>,
     uri: Uri,
     key: CacheKey,
-    response: CachedResponse,
+    headers: HeaderMap,
+    // The chunks of the body, joined once the whole body was received
+    chunks: Vec<Bytes>,
 }
 
 impl CacheStorage for InMemoryCache {
@@ -42,21 +47,19 @@ impl CacheStorage for InMemoryCache {
 
     fn writer(&self, uri: &Uri, key: CacheKey, headers: HeaderMap) -> Box<dyn CacheWriter> {
         Box::new(InMemoryWriter {
-            keys: self.keys.clone(),
-            responses: self.responses.clone(),
+            keys: Arc::clone(&self.keys),
+            responses: Arc::clone(&self.responses),
             uri: uri.clone(),
             key,
-            response: CachedResponse {
-                body: Vec::new(),
-                headers,
-            },
+            headers,
+            chunks: Vec::new(),
         })
     }
 }
 
 impl CacheWriter for InMemoryWriter {
-    fn write_body(&mut self, data: &[u8]) {
-        self.response.body.extend_from_slice(data);
+    fn write_body(&mut self, data: Bytes) {
+        self.chunks.push(data);
     }
 }
 
@@ -66,9 +69,45 @@ impl Drop for InMemoryWriter {
         // to add the response body to the cache.
         let uri = self.uri.clone();
         let key = self.key.clone();
-        let response = std::mem::take(&mut self.response);
+        let body = match self.chunks.len() {
+            0 => Bytes::new(),
+            1 => self.chunks.pop().unwrap(),
+            _ => {
+                let len = self.chunks.iter().map(Bytes::len).sum();
+                let mut body = BytesMut::with_capacity(len);
+                for chunk in self.chunks.drain(..) {
+                    body.extend_from_slice(&chunk);
+                }
+                body.freeze()
+            }
+        };
+        let response = CachedResponse {
+            body,
+            headers: std::mem::take(&mut self.headers),
+        };
 
         self.keys.insert(uri.clone(), key);
         self.responses.insert(uri, response);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cache_sees_writes_from_its_writers() {
+        let cache = InMemoryCache::new();
+        let uri = Uri::from_static("https://api.github.com/repos/owner/repo");
+        let key = CacheKey::ETag("\"abc\"".to_string());
+
+        let mut writer = cache.writer(&uri, key.clone(), HeaderMap::new());
+        writer.write_body(Bytes::from_static(b"{\"id\":"));
+        writer.write_body(Bytes::from_static(b"1}"));
+        assert_eq!(cache.try_hit(&uri), None);
+        drop(writer);
+
+        assert_eq!(cache.try_hit(&uri), Some(key));
+        assert_eq!(cache.load(&uri).unwrap().body, "{\"id\":1}");
+    }
+}
@@ -14,55 +14,74 @@ pub enum RetryConfig {
     Simple(usize),
 }
 
-impl<B> Policy<Request<OctoBody>, Response<B>, Error> for RetryConfig {
+/// The retry policy of a client, built from its [`RetryConfig`].
+///
+/// Only idempotent requests, e.g. `GET`, `PUT` and `DELETE`, are retried
+/// after a server error, a `429` or a connection dropping mid-request, so
+/// that retries can't create duplicate comments or releases. Requests which
+/// failed to connect never reached GitHub and are retried regardless of their
+/// method. Retrying `POST` and `PATCH` requests too is opt-in, see
+/// [`OctocrabBuilder::retry_non_idempotent`](crate::OctocrabBuilder::retry_non_idempotent).
+#[derive(Clone)]
+pub struct RetryPolicy {
+    config: RetryConfig,
+    retry_non_idempotent: bool,
+    attempts: usize,
+}
+
+impl RetryPolicy {
+    /// Creates a policy retrying requests as configured by `config`.
+    /// `retry_non_idempotent` also retries `POST` and `PATCH` requests after
+    /// they failed.
+    pub fn new(config: RetryConfig, retry_non_idempotent: bool) -> Self {
+        Self {
+            config,
+            retry_non_idempotent,
+            attempts: 0,
+        }
+    }
+
+    fn is_retryable(&self, method: &http::Method) -> bool {
+        method.is_idempotent() || self.retry_non_idempotent
+    }
+}
+
+impl<B> Policy<Request<OctoBody>, Response<B>, Error> for RetryPolicy {
     type Future = Pin<Box<dyn Future<Output = ()> + Send + Sync + 'static>>;
 
     fn retry(
         &mut self,
-        _req: &mut Request<OctoBody>,
+        req: &mut Request<OctoBody>,
         result: &mut Result<Response<B>, Error>,
     ) -> Option<Self::Future> {
-        match self {
-            RetryConfig::None => None,
-            RetryConfig::Simple(count) => match result {
-                Ok(response) => {
-                    if response.status().is_server_error() || response.status() == 429 {
-                        if *count > 0 {
-                            *count -= 1;
-                            // Exponential backoff: delay doubles with each retry attempt
-                            let attempt = 3 - *count;
-                            let delay_ms = 2u64.pow(attempt.min(6) as u32) * 100;
-                            let delay = sleep(Duration::from_millis(delay_ms));
-                            Some(Box::pin(async move {
-                                delay.await;
-                            }))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                }
-                Err(_) => {
-                    if *count > 0 {
-                        *count -= 1;
-                        // Exponential backoff: delay doubles with each retry attempt
-                        let attempt = 3 - *count;
-                        let delay_ms = 2u64.pow(attempt.min(6) as u32) * 100;
-                        let delay = sleep(Duration::from_millis(delay_ms));
-                        Some(Box::pin(async move {
-                            delay.await;
-                        }))
-                    } else {
-                        None
-                    }
-                }
-            },
+        let RetryConfig::Simple(retries) = &self.config else {
+            return None;
+        };
+        if self.attempts >= *retries {
+            return None;
         }
+        let retryable = match result {
+            Ok(response) => {
+                (response.status().is_server_error() || response.status() == 429)
+                    && self.is_retryable(req.method())
+            }
+            Err(error) => error.is_connect() || self.is_retryable(req.method()),
+        };
+        if !retryable {
+            return None;
+        }
+
+        self.attempts += 1;
+        // Exponential backoff: delay doubles with each retry attempt
+        let delay_ms = 2u64.pow(self.attempts.min(6) as u32) * 100;
+        let delay = sleep(Duration::from_millis(delay_ms));
+        Some(Box::pin(async move {
+            delay.await;
+        }))
     }
 
     fn clone_request(&mut self, req: &Request<OctoBody>) -> Option<Request<OctoBody>> {
-        match self {
+        match self.config {
             RetryConfig::None => None,
             _ => {
                 let body = req.body().try_clone()?;
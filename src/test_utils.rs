@@ -0,0 +1,185 @@
+//! Helpers for testing code which uses octocrab against a
+//! [`wiremock`](https://docs.rs/wiremock) server instead of GitHub.
+//!
+//! ```no_run
+//! # async fn run() -> octocrab::Result<()> {
+//! use octocrab::test_utils::{json_response, mock_get, setup_error_handler, setup_octocrab};
+//! use wiremock::MockServer;
+//!
+//! let mock_server = MockServer::start().await;
+//! mock_get("/repos/owner/repo/labels/bug")
+//!     .respond_with(json_response(serde_json::json!({
+//!         "id": 208045946,
+//!         "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+//!         "url": "https://api.github.com/repos/owner/repo/labels/bug",
+//!         "name": "bug",
+//!         "color": "f29513",
+//!         "default": true
+//!     })))
+//!     .mount(&mock_server)
+//!     .await;
+//! setup_error_handler(&mock_server, "GET on /repos/owner/repo/labels/bug was not received")
+//!     .await;
+//!
+//! let octocrab = setup_octocrab(&mock_server.uri());
+//! let label = octocrab.issues("owner", "repo").get_label("bug").await?;
+//! assert_eq!(label.name, "bug");
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::Serialize;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, path_regex},
+    Mock, MockBuilder, MockServer, ResponseTemplate,
+};
+
+/// Creates a client which sends its requests to the mock server at `uri`.
+#[cfg(feature = "default-client")]
+#[cfg_attr(docsrs, doc(cfg(feature = "default-client")))]
+pub fn setup_octocrab(uri: &str) -> crate::Octocrab {
+    crate::Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+#[cfg(all(
+    feature = "rustls",
+    any(feature = "rustls-ring", feature = "rustls-aws-lc-rs")
+))]
+#[cfg_attr(docsrs, doc(cfg(feature = "rustls")))]
+#[doc(inline)]
+pub use crate::ensure_crypto_provider_initialized;
+
+/// Sets up a handler on the mock server which responds to any `GET` request
+/// with a 500 and the given message, which octocrab reports as a GitHub
+/// error. This makes a request the test didn't mock fail with a message
+/// saying what was expected instead of a confusing deserialization error.
+///
+/// Mount it after the mocks of the test, as it matches any `GET` request.
+pub async fn setup_error_handler(mock_server: &MockServer, message: &str) {
+    Mock::given(method("GET"))
+        .and(path_regex(".*"))
+        .respond_with(github_error(500, message))
+        .mount(mock_server)
+        .await;
+}
+
+/// A response with the body GitHub sends for errors.
+pub fn github_error(status: u16, message: &str) -> ResponseTemplate {
+    ResponseTemplate::new(status).set_body_json(json!({
+        "documentation_url": "",
+        "errors": None::<Vec<serde_json::Value>>,
+        "message": message,
+    }))
+}
+
+/// A `200 OK` response with `body` as JSON.
+pub fn json_response(body: impl Serialize) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(body)
+}
+
+/// A `200 OK` response with one page of `items`, linking to the following
+/// page `page + 1` of the listing at `uri` unless `page` is `last_page`, so
+/// that octocrab's pagination can be tested.
+pub fn page_response(
+    uri: &str,
+    items: impl Serialize,
+    page: u32,
+    last_page: u32,
+) -> ResponseTemplate {
+    let separator = if uri.contains('?') { '&' } else { '?' };
+    let link = |page: u32, rel: &str| format!("<{uri}{separator}page={page}>; rel=\"{rel}\"");
+    let mut links = Vec::new();
+    if page > 1 {
+        links.push(link(1, "first"));
+        links.push(link(page - 1, "prev"));
+    }
+    if page < last_page {
+        links.push(link(page + 1, "next"));
+        links.push(link(last_page, "last"));
+    }
+    let response = json_response(items);
+    if links.is_empty() {
+        response
+    } else {
+        response.insert_header("link", links.join(", "))
+    }
+}
+
+/// Matches `GET` requests to `path`.
+pub fn mock_get(path: &str) -> MockBuilder {
+    mock_request("GET", path)
+}
+
+/// Matches `POST` requests to `path`.
+pub fn mock_post(path: &str) -> MockBuilder {
+    mock_request("POST", path)
+}
+
+/// Matches `PATCH` requests to `path`.
+pub fn mock_patch(path: &str) -> MockBuilder {
+    mock_request("PATCH", path)
+}
+
+/// Matches `PUT` requests to `path`.
+pub fn mock_put(path: &str) -> MockBuilder {
+    mock_request("PUT", path)
+}
+
+/// Matches `DELETE` requests to `path`.
+pub fn mock_delete(path: &str) -> MockBuilder {
+    mock_request("DELETE", path)
+}
+
+fn mock_request(http_method: &str, request_path: &str) -> MockBuilder {
+    Mock::given(method(http_method)).and(path(request_path))
+}
+
+/// Matches requests getting a repository.
+pub fn get_repo(owner: &str, repo: &str) -> MockBuilder {
+    mock_get(&format!("/repos/{owner}/{repo}"))
+}
+
+/// Matches requests getting an issue.
+pub fn get_issue(owner: &str, repo: &str, number: u64) -> MockBuilder {
+    mock_get(&format!("/repos/{owner}/{repo}/issues/{number}"))
+}
+
+/// Matches requests listing the issues of a repository.
+pub fn list_issues(owner: &str, repo: &str) -> MockBuilder {
+    mock_get(&format!("/repos/{owner}/{repo}/issues"))
+}
+
+/// Matches requests creating a comment on an issue or pull request.
+pub fn create_issue_comment(owner: &str, repo: &str, number: u64) -> MockBuilder {
+    mock_post(&format!("/repos/{owner}/{repo}/issues/{number}/comments"))
+}
+
+/// Matches requests getting a pull request.
+pub fn get_pull(owner: &str, repo: &str, number: u64) -> MockBuilder {
+    mock_get(&format!("/repos/{owner}/{repo}/pulls/{number}"))
+}
+
+/// Matches requests listing the pull requests of a repository.
+pub fn list_pulls(owner: &str, repo: &str) -> MockBuilder {
+    mock_get(&format!("/repos/{owner}/{repo}/pulls"))
+}
+
+/// Matches requests getting a user.
+pub fn get_user(username: &str) -> MockBuilder {
+    mock_get(&format!("/users/{username}"))
+}
+
+/// Matches requests getting the authenticated user.
+pub fn get_current_user() -> MockBuilder {
+    mock_get("/user")
+}
+
+/// Matches requests getting an organization.
+pub fn get_org(org: &str) -> MockBuilder {
+    mock_get(&format!("/orgs/{org}"))
+}
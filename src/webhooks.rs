@@ -0,0 +1,227 @@
+//! Utilities for receiving GitHub webhooks, see also
+//! [`models::webhook_events`](crate::models::webhook_events) for the payloads.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use snafu::{OptionExt, ResultExt, Snafu};
+
+use crate::models::webhook_events::WebhookEvent;
+
+/// The header naming the event of a webhook delivery.
+pub const EVENT_HEADER: &str = "X-GitHub-Event";
+/// The header with the signature of a webhook delivery.
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+
+/// An error while receiving a webhook delivery.
+#[derive(Snafu, Debug)]
+#[non_exhaustive]
+pub enum WebhookError {
+    #[snafu(display("The webhook delivery has no `{header}` header"))]
+    MissingHeader { header: &'static str },
+    #[snafu(display("The signature of the webhook delivery doesn't match its body"))]
+    InvalidSignature,
+    #[snafu(display("Failed to deserialize the webhook payload: {source}"))]
+    Payload { source: serde_json::Error },
+    #[cfg(all(feature = "worker", target_arch = "wasm32"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "worker", target_arch = "wasm32"))))]
+    #[snafu(display("Failed to read the webhook delivery: {source}"))]
+    Worker { source: worker::Error },
+}
+
+/// Checks the `X-Hub-Signature-256` header of a webhook delivery, which is
+/// the HMAC-SHA256 of the raw request body keyed with the webhook's secret,
+/// formatted as `sha256=<hex digest>`. Returns `false` when the signature
+/// doesn't match or is malformed.
+///
+/// The comparison takes constant time, so it doesn't leak how much of a
+/// forged signature is correct. Pass the body exactly as received, as
+/// re-serializing the payload changes the signature.
+/// ```
+/// use octocrab::webhooks::verify_signature;
+///
+/// let body = br#"{"zen":"Keep it logically awesome."}"#;
+/// let signature = "sha256=b9f180c4171a9926a5055962b54ec47b0ebee85e62e76c83ebdbb382f77b05ac";
+///
+/// // Reject requests with a wrong signature, e.g. with a 401.
+/// assert!(verify_signature("It's a Secret to Everybody", body, signature));
+/// ```
+pub fn verify_signature(
+    secret: impl AsRef<[u8]>,
+    body: impl AsRef<[u8]>,
+    signature: impl AsRef<str>,
+) -> bool {
+    let Some(signature) = signature
+        .as_ref()
+        .strip_prefix("sha256=")
+        .and_then(decode_hex)
+    else {
+        return false;
+    };
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret.as_ref()) else {
+        return false;
+    };
+    mac.update(body.as_ref());
+    mac.verify_slice(&signature).is_ok()
+}
+
+/// Verifies the signature of a webhook delivery and deserializes its
+/// payload, from the request's headers and raw body.
+/// ```no_run
+/// # fn run(request: http::Request<Vec<u8>>) -> Result<(), octocrab::webhooks::WebhookError> {
+/// let event = octocrab::webhooks::event_from_request(
+///     request.headers(),
+///     request.body(),
+///     "It's a Secret to Everybody",
+/// )?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn event_from_request(
+    headers: &http::HeaderMap,
+    body: impl AsRef<[u8]>,
+    secret: impl AsRef<[u8]>,
+) -> Result<WebhookEvent, WebhookError> {
+    let header = |header: &'static str| {
+        headers
+            .get(header)
+            .and_then(|value| value.to_str().ok())
+            .context(MissingHeaderSnafu { header })
+    };
+    verify_event(
+        header(EVENT_HEADER)?,
+        header(SIGNATURE_HEADER)?,
+        body.as_ref(),
+        secret.as_ref(),
+    )
+}
+
+/// Verifies the signature of a webhook delivery received by a Cloudflare
+/// Worker and deserializes its payload, which consumes the request's body.
+/// ```ignore
+/// use octocrab::models::webhook_events::WebhookEventType;
+/// use worker::{event, Context, Env, Request, Response, Result};
+///
+/// #[event(fetch)]
+/// async fn fetch(mut request: Request, env: Env, _ctx: Context) -> Result<Response> {
+///     let secret = env.secret("WEBHOOK_SECRET")?.to_string();
+///     let Ok(event) = octocrab::webhooks::event_from_worker_request(&mut request, secret).await
+///     else {
+///         return Response::error("Bad request", 400);
+///     };
+///     match event.kind {
+///         WebhookEventType::Ping => Response::ok("pong"),
+///         _ => Response::ok("ignored"),
+///     }
+/// }
+/// ```
+#[cfg(all(feature = "worker", target_arch = "wasm32"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "worker", target_arch = "wasm32"))))]
+pub async fn event_from_worker_request(
+    request: &mut worker::Request,
+    secret: impl AsRef<[u8]>,
+) -> Result<WebhookEvent, WebhookError> {
+    let header = |header: &'static str| -> Result<String, WebhookError> {
+        request
+            .headers()
+            .get(header)
+            .context(WorkerSnafu)?
+            .context(MissingHeaderSnafu { header })
+    };
+    let event = header(EVENT_HEADER)?;
+    let signature = header(SIGNATURE_HEADER)?;
+    let body = request.bytes().await.context(WorkerSnafu)?;
+    verify_event(&event, &signature, &body, secret.as_ref())
+}
+
+fn verify_event(
+    event: &str,
+    signature: &str,
+    body: &[u8],
+    secret: &[u8],
+) -> Result<WebhookEvent, WebhookError> {
+    if !verify_signature(secret, body, signature) {
+        return InvalidSignatureSnafu.fail();
+    }
+    WebhookEvent::try_from_header_and_body(event, body).context(PayloadSnafu)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example from GitHub's documentation on validating webhook deliveries.
+    const SECRET: &str = "It's a Secret to Everybody";
+    const BODY: &str = "Hello, World!";
+    const SIGNATURE: &str =
+        "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+
+    #[test]
+    fn verifies_valid_signature() {
+        assert!(verify_signature(SECRET, BODY, SIGNATURE));
+    }
+
+    #[test]
+    fn rejects_invalid_signatures() {
+        assert!(!verify_signature("wrong secret", BODY, SIGNATURE));
+        assert!(!verify_signature(SECRET, "Hello, World?", SIGNATURE));
+        assert!(!verify_signature(SECRET, BODY, &SIGNATURE[..70]));
+        assert!(!verify_signature(
+            SECRET,
+            BODY,
+            &SIGNATURE["sha256=".len()..]
+        ));
+        assert!(!verify_signature(SECRET, BODY, "sha256=zz"));
+    }
+
+    #[test]
+    fn verifies_and_deserializes_request() {
+        use crate::models::webhook_events::WebhookEventType;
+
+        let body = br#"{"zen": "Design for failure.", "hook_id": 423885699}"#;
+        let mut headers = http::HeaderMap::new();
+        headers.insert(EVENT_HEADER, "ping".parse().unwrap());
+        headers.insert(
+            SIGNATURE_HEADER,
+            "sha256=a3f3b5b4e6ba6ab3e8b93bbd4d2e75d7b1a5e9a6c8cd6c6e6f1d1bb0f2a6a5c1"
+                .parse()
+                .unwrap(),
+        );
+        assert!(matches!(
+            event_from_request(&headers, body, SECRET),
+            Err(WebhookError::InvalidSignature)
+        ));
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+        mac.update(body);
+        let signature: String = mac
+            .finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect();
+        headers.insert(
+            SIGNATURE_HEADER,
+            format!("sha256={signature}").parse().unwrap(),
+        );
+        let event = event_from_request(&headers, body, SECRET).unwrap();
+        assert_eq!(event.kind, WebhookEventType::Ping);
+
+        headers.remove(EVENT_HEADER);
+        assert!(matches!(
+            event_from_request(&headers, body, SECRET),
+            Err(WebhookError::MissingHeader {
+                header: EVENT_HEADER
+            })
+        ));
+    }
+}
@@ -0,0 +1,41 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_feeds() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let feeds: serde_json::Value =
+        serde_json::from_str(include_str!("resources/feeds.json")).unwrap();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/feeds"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&feeds))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /feeds was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.activity().feeds().await;
+
+    let feeds = result.unwrap();
+    assert_eq!(feeds.user_url, "https://github.com/{user}");
+    assert_eq!(feeds.links.timeline.r#type, "application/atom+xml");
+    assert_eq!(feeds.current_user_organization_urls.len(), 1);
+    assert_eq!(
+        feeds.links.current_user_organizations[0].href,
+        "https://github.com/organizations/github/octocat.private.atom?token=abc123"
+    );
+}
@@ -0,0 +1,167 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::hooks::{Config, ContentType};
+use octocrab::models::HookDeliveryId;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_update_hook_config() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path("/app/hook/config"))
+        .and(body_json(json!({
+            "content_type": "json",
+            "url": "https://example.com/webhook",
+            "secret": "hunter2"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "content_type": "json",
+            "insecure_ssl": "0",
+            "secret": "********",
+            "url": "https://example.com/webhook"
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "PATCH on /app/hook/config was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let config = Config {
+        url: "https://example.com/webhook".to_string(),
+        content_type: Some(ContentType::Json),
+        secret: Some("hunter2".to_string()),
+        ..Config::default()
+    };
+    let result = client.apps().update_hook_config(&config).await;
+
+    let config = result.unwrap();
+    assert_eq!(config.insecure_ssl.as_deref(), Some("0"));
+    assert_eq!(config.secret.as_deref(), Some("********"));
+}
+
+#[tokio::test]
+async fn should_list_hook_deliveries_from_cursor() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let deliveries: serde_json::Value =
+        serde_json::from_str(include_str!("resources/hooks_delivery_list.json")).unwrap();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/app/hook/deliveries"))
+        .and(query_param("cursor", "v1_12077215967"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&deliveries))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /app/hook/deliveries was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .apps()
+        .list_hook_deliveries()
+        .cursor("v1_12077215967")
+        .send()
+        .await;
+
+    let page = result.unwrap();
+    assert_eq!(page.items.len(), deliveries.as_array().unwrap().len());
+}
+
+#[tokio::test]
+async fn should_redeliver_hook_delivery() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/app/hook/deliveries/12345678/attempts"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({})))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /app/hook/deliveries/12345678/attempts was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .apps()
+        .redeliver_hook_delivery(HookDeliveryId(12345678))
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn should_redeliver_failed_hook_deliveries_since() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let delivery = |id: u64, guid: &str, delivered_at: &str, status_code: u16| {
+        json!({
+            "id": id,
+            "guid": guid,
+            "delivered_at": delivered_at,
+            "redelivery": false,
+            "duration": 0.1,
+            "status": "",
+            "status_code": status_code,
+            "event": "push",
+            "action": null,
+            "installation_id": null,
+            "repository_id": 1
+        })
+    };
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/app/hook/deliveries"))
+        .and(query_param("per_page", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            delivery(4, "redelivered", "2024-07-25T12:00:00Z", 200),
+            delivery(3, "redelivered", "2024-07-25T11:00:00Z", 503),
+            delivery(2, "failed", "2024-07-25T10:00:00Z", 502),
+            delivery(1, "too-old", "2024-07-24T10:00:00Z", 503),
+        ])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/app/hook/deliveries/2/attempts"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /app/hook/deliveries or POST on /app/hook/deliveries/2/attempts was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let results = client
+        .apps()
+        .redeliver_failed_hook_deliveries()
+        .since("2024-07-25T00:00:00Z".parse().unwrap())
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 1);
+    let (delivery, result) = &results[0];
+    assert_eq!(delivery.guid, "failed");
+    assert!(result.is_ok());
+}
@@ -0,0 +1,151 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_org_actions_billing() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/settings/billing/actions"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_minutes_used": 305,
+            "total_paid_minutes_used": 0,
+            "included_minutes": 3000,
+            "minutes_used_breakdown": {
+                "UBUNTU": 205,
+                "MACOS": 10,
+                "WINDOWS": 90
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /orgs/org/settings/billing/actions was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.orgs("org").billing().actions().await;
+
+    let usage = result.unwrap();
+    assert_eq!(usage.total_minutes_used, 305);
+    assert_eq!(usage.minutes_used_breakdown["UBUNTU"], 205);
+}
+
+#[tokio::test]
+async fn should_get_user_packages_billing() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/settings/billing/packages"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_gigabytes_bandwidth_used": 50,
+            "total_paid_gigabytes_bandwidth_used": 40,
+            "included_gigabytes_bandwidth": 10
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /users/octocat/settings/billing/packages was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.users("octocat").billing().packages().await;
+
+    assert_eq!(result.unwrap().total_paid_gigabytes_bandwidth_used, 40);
+}
+
+#[tokio::test]
+async fn should_get_enterprise_shared_storage_billing() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/enterprises/acme/settings/billing/shared-storage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "days_left_in_billing_cycle": 20,
+            "estimated_paid_storage_for_month": 15,
+            "estimated_storage_for_month": 40.5
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /enterprises/acme/settings/billing/shared-storage was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.enterprises("acme").billing().shared_storage().await;
+
+    let usage = result.unwrap();
+    assert_eq!(usage.days_left_in_billing_cycle, 20);
+    assert_eq!(usage.estimated_storage_for_month, 40.5);
+}
+
+#[tokio::test]
+async fn should_get_org_usage_report() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/organizations/org/settings/billing/usage"))
+        .and(query_param("year", "2024"))
+        .and(query_param("month", "6"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "usageItems": [{
+                "date": "2024-06-01T00:00:00Z",
+                "product": "Actions",
+                "sku": "Actions Linux",
+                "quantity": 100,
+                "unitType": "minutes",
+                "pricePerUnit": 0.008,
+                "grossAmount": 0.8,
+                "discountAmount": 0,
+                "netAmount": 0.8,
+                "organizationName": "org",
+                "repositoryName": "org/some-repo"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /organizations/org/settings/billing/usage was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .orgs("org")
+        .billing()
+        .usage()
+        .year(2024)
+        .month(6)
+        .send()
+        .await;
+
+    let report = result.unwrap();
+    assert_eq!(report.usage_items[0].sku, "Actions Linux");
+    assert_eq!(report.usage_items[0].net_amount, 0.8);
+}
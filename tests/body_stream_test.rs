@@ -0,0 +1,104 @@
+mod mock_error;
+
+use http::Method;
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{OctoBody, Octocrab};
+use wiremock::{
+    matchers::{body_bytes, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn should_send_stream_with_content_length() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/some/upload"))
+        .and(header("content-length", "9"))
+        .and(body_bytes(b"some_data".to_vec()))
+        .respond_with(ResponseTemplate::new(200).set_body_string("stored"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "PUT on /some/upload was not received").await;
+
+    let chunks = vec![
+        Ok::<_, std::io::Error>(bytes::Bytes::from("some_")),
+        Ok(bytes::Bytes::from("data")),
+    ];
+    let client = setup_octocrab(&mock_server.uri());
+    let response = client
+        .request(Method::PUT, "/some/upload")
+        .body(OctoBody::from_stream(
+            futures::stream::iter(chunks),
+            Some(9),
+        ))
+        .send_bytes()
+        .await
+        .unwrap();
+
+    assert_eq!(response, "stored");
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn should_send_reader_with_content_length() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let data = vec![7u8; 200 * 1024];
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/some/upload"))
+        .and(header("content-length", "204800"))
+        .and(body_bytes(data.clone()))
+        .respond_with(ResponseTemplate::new(200).set_body_string("stored"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "PUT on /some/upload was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let response = client
+        .request(Method::PUT, "/some/upload")
+        .body(OctoBody::from_reader(
+            std::io::Cursor::new(data.clone()),
+            data.len() as u64,
+        ))
+        .send_bytes()
+        .await
+        .unwrap();
+
+    assert_eq!(response, "stored");
+}
+
+#[cfg(all(feature = "tokio", feature = "retry"))]
+#[tokio::test]
+async fn should_not_retry_streamed_bodies() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/some/upload"))
+        .respond_with(ResponseTemplate::new(503))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .request(Method::PUT, "/some/upload")
+        .body(OctoBody::from_reader(std::io::Cursor::new(b"some_data"), 9))
+        .send_bytes()
+        .await;
+
+    assert!(result.is_err());
+}
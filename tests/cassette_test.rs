@@ -0,0 +1,88 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::repos::Release;
+use octocrab::service::middleware::cassette::Cassette;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+
+fn setup_octocrab(uri: &str, cassette: Cassette) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .personal_token("ghp_secret".to_string())
+        .cassette(cassette)
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_replay_recorded_responses() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let cassette_path = std::env::temp_dir().join(format!(
+        "octocrab-cassette-test-{}.json",
+        std::process::id()
+    ));
+    let _ = std::fs::remove_file(&cassette_path);
+
+    let release: Release =
+        serde_json::from_str(include_str!("resources/repos_releases_get_latest.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/latest")))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("set-cookie", "session=ghu_cookie")
+                .set_body_json(&release),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/releases/latest was not received"),
+    )
+    .await;
+    let uri = mock_server.uri();
+
+    let cassette = Cassette::auto(&cassette_path).unwrap();
+    assert!(!cassette.is_replaying());
+    let client = setup_octocrab(&uri, cassette);
+    let recorded = client
+        .repos(OWNER, REPO)
+        .releases()
+        .get_latest()
+        .await
+        .unwrap();
+    assert_eq!(recorded, release);
+
+    let file = std::fs::read_to_string(&cassette_path).unwrap();
+    assert!(!file.contains("secret"));
+    assert!(!file.contains("set-cookie"));
+    drop(mock_server);
+
+    let cassette = Cassette::auto(&cassette_path).unwrap();
+    assert!(cassette.is_replaying());
+    let client = setup_octocrab(&uri, cassette);
+    let replayed = client
+        .repos(OWNER, REPO)
+        .releases()
+        .get_latest()
+        .await
+        .unwrap();
+    assert_eq!(replayed, release);
+
+    // Each recorded response is only replayed once.
+    let result = client.repos(OWNER, REPO).releases().get_latest().await;
+    assert!(result.is_err());
+
+    std::fs::remove_file(&cassette_path).unwrap();
+}
@@ -87,3 +87,48 @@ async fn should_respond_to_get_code_of_conduct() {
         "https://api.github.com/codes_of_conduct/contributor_covenant",
     );
 }
+
+#[tokio::test]
+async fn should_expose_detected_code_of_conduct_in_community_profile() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let template = ResponseTemplate::new(200).set_body_json(serde_json::json!({
+        "health_percentage": 100,
+        "description": "My first repository on GitHub!",
+        "documentation": null,
+        "files": {
+            "code_of_conduct": {
+                "name": "Contributor Covenant",
+                "key": "contributor_covenant",
+                "url": "https://api.github.com/codes_of_conduct/contributor_covenant",
+                "html_url": "https://github.com/octocat/Hello-World/blob/master/CODE_OF_CONDUCT.md"
+            },
+            "code_of_conduct_file": {
+                "url": "https://api.github.com/repos/octocat/Hello-World/contents/CODE_OF_CONDUCT.md",
+                "html_url": "https://github.com/octocat/Hello-World/blob/master/CODE_OF_CONDUCT.md"
+            },
+            "contributing": null,
+            "license": null,
+            "readme": null
+        },
+        "updated_at": "2017-02-28T19:09:29Z",
+        "content_reports_enabled": true
+    }));
+    let mock_server = setup_mock_http_server(
+        "GET",
+        "/repos/octocat/Hello-World/community/profile",
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let metrics = client
+        .repos("octocat", "Hello-World")
+        .get_community_profile_metrics()
+        .await
+        .unwrap();
+
+    let code_of_conduct = metrics.code_of_conduct().unwrap();
+    assert_eq!(code_of_conduct.key.as_deref(), Some("contributor_covenant"));
+    assert!(metrics.code_of_conduct_file().is_some());
+}
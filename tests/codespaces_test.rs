@@ -0,0 +1,274 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::codespaces::CodespaceState;
+use octocrab::models::repos::secrets::CreateRepositorySecret;
+use octocrab::models::RepositoryId;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const NAME: &str = "monalisa-octocat-hello-world-g4wpq6h95q";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn codespace(state: &str) -> serde_json::Value {
+    let users: serde_json::Value =
+        serde_json::from_str(include_str!("resources/user_blocks.json")).unwrap();
+    let user = users[0].clone();
+    json!({
+        "id": 1,
+        "name": NAME,
+        "display_name": "hello world",
+        "environment_id": "26a7c758-7299-4a73-b978-5a92a7ae98a0",
+        "owner": user,
+        "billable_owner": user,
+        "repository": {
+            "id": 1296269,
+            "name": "some-repo",
+            "url": "https://api.github.com/repos/org/some-repo"
+        },
+        "machine": {
+            "name": "standardLinux",
+            "display_name": "4 cores, 8 GB RAM, 64 GB storage",
+            "operating_system": "linux",
+            "storage_in_bytes": 68719476736u64,
+            "memory_in_bytes": 8589934592u64,
+            "cpus": 4,
+            "prebuild_availability": "none"
+        },
+        "prebuild": false,
+        "devcontainer_path": ".devcontainer/devcontainer.json",
+        "created_at": "2021-10-14T00:53:30-06:00",
+        "updated_at": "2021-10-14T00:53:32-06:00",
+        "last_used_at": "2021-10-14T00:53:30-06:00",
+        "state": state,
+        "url": "https://api.github.com/user/codespaces/monalisa-octocat-hello-world-g4wpq6h95q",
+        "git_status": {
+            "ahead": 0,
+            "behind": 0,
+            "has_unpushed_changes": false,
+            "has_uncommitted_changes": false,
+            "ref": "main"
+        },
+        "location": "WestUs2",
+        "idle_timeout_minutes": 60,
+        "web_url": "https://monalisa-octocat-hello-world-g4wpq6h95q.github.dev",
+        "machines_url": "https://api.github.com/user/codespaces/monalisa-octocat-hello-world-g4wpq6h95q/machines",
+        "start_url": "https://api.github.com/user/codespaces/monalisa-octocat-hello-world-g4wpq6h95q/start",
+        "stop_url": "https://api.github.com/user/codespaces/monalisa-octocat-hello-world-g4wpq6h95q/stop",
+        "recent_folders": []
+    })
+}
+
+#[tokio::test]
+async fn should_list_user_codespaces() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user/codespaces"))
+        .and(query_param("repository_id", "1296269"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "codespaces": [codespace("Available")]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /user/codespaces was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .codespaces()
+        .list()
+        .repository_id(RepositoryId(1296269))
+        .send()
+        .await;
+
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(1));
+    assert_eq!(page.items[0].name, NAME);
+    assert_eq!(page.items[0].state, CodespaceState::Available);
+}
+
+#[tokio::test]
+async fn should_stop_codespace() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/user/codespaces/{NAME}/stop")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(codespace("ShuttingDown")))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /user/codespaces/{name}/stop was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.codespaces().stop(NAME).await;
+
+    assert_eq!(result.unwrap().state, CodespaceState::ShuttingDown);
+}
+
+#[tokio::test]
+async fn should_delete_codespace() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/user/codespaces/{NAME}")))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({})))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "DELETE on /user/codespaces/{name} was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.codespaces().delete(NAME).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn should_create_repo_codespace() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/codespaces")))
+        .and(body_json(json!({
+            "ref": "main",
+            "machine": "standardLinux"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(codespace("Queued")))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /repos/org/some-repo/codespaces was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .codespaces()
+        .create()
+        .r#ref("main")
+        .machine("standardLinux")
+        .send()
+        .await;
+
+    assert_eq!(result.unwrap().state, CodespaceState::Queued);
+}
+
+#[tokio::test]
+async fn should_list_repo_machines_and_devcontainers() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/codespaces/machines")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "machines": [{
+                "name": "standardLinux",
+                "display_name": "4 cores, 8 GB RAM, 64 GB storage",
+                "operating_system": "linux",
+                "storage_in_bytes": 68719476736u64,
+                "memory_in_bytes": 8589934592u64,
+                "cpus": 4,
+                "prebuild_availability": "ready"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/codespaces/devcontainers"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "devcontainers": [{
+                "path": ".devcontainer/devcontainer.json",
+                "name": "Rust",
+                "display_name": "Rust"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/org/some-repo/codespaces/{machines,devcontainers} was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+
+    let machines = repos.codespaces().list_machines().await.unwrap();
+    assert_eq!(machines.items[0].cpus, 4);
+
+    let devcontainers = repos.codespaces().list_devcontainers().await.unwrap();
+    assert_eq!(
+        devcontainers.items[0].path,
+        ".devcontainer/devcontainer.json"
+    );
+}
+
+#[tokio::test]
+async fn should_create_repo_codespaces_secret() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/codespaces/secrets/TOKEN"
+        )))
+        .and(body_json(json!({
+            "encrypted_value": "some-b64-encrypted-string",
+            "key_id": "123456"
+        })))
+        .respond_with(ResponseTemplate::new(201))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "PUT on /repos/org/some-repo/codespaces/secrets/TOKEN was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .codespaces_secrets()
+        .create_or_update_secret(
+            "TOKEN",
+            &CreateRepositorySecret {
+                key_id: "123456",
+                encrypted_value: "some-b64-encrypted-string",
+            },
+        )
+        .await;
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,119 @@
+mod mock_error;
+
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll};
+
+use http::{HeaderValue, Request};
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use tower::{Layer, Service};
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+/// Records the URI of every request and tags it with a header.
+#[derive(Clone)]
+struct RecordLayer {
+    tag: &'static str,
+    seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl<S> Layer<S> for RecordLayer {
+    type Service = RecordService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RecordService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+struct RecordService<S> {
+    inner: S,
+    layer: RecordLayer,
+}
+
+impl<S, B> Service<Request<B>> for RecordService<S>
+where
+    S: Service<Request<B>>,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = S::Future;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: Request<B>) -> Self::Future {
+        self.layer
+            .seen
+            .lock()
+            .unwrap()
+            .push(format!("{} {}", self.layer.tag, req.uri()));
+        req.headers_mut()
+            .append("x-layer", HeaderValue::from_static(self.layer.tag));
+        self.inner.call(req)
+    }
+}
+
+#[tokio::test]
+async fn should_run_requests_through_custom_layers() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/labels/bug"))
+        .and(header("authorization", "Bearer secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 208045946,
+            "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+            "url": "https://api.github.com/repos/owner/repo/labels/bug",
+            "name": "bug",
+            "color": "f29513",
+            "default": true
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/labels/bug with layer headers was not received",
+    )
+    .await;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .personal_token("secret".to_string())
+        .layer(RecordLayer {
+            tag: "outer",
+            seen: seen.clone(),
+        })
+        .layer(RecordLayer {
+            tag: "inner",
+            seen: seen.clone(),
+        })
+        .build()
+        .unwrap();
+    let label = client
+        .issues("owner", "repo")
+        .get_label("bug")
+        .await
+        .unwrap();
+
+    assert_eq!(label.name, "bug");
+    let uri = format!("{}/repos/owner/repo/labels/bug", mock_server.uri());
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![format!("outer {uri}"), format!("inner {uri}")]
+    );
+    let requests = mock_server.received_requests().await.unwrap();
+    let tags: Vec<_> = requests[0].headers.get_all("x-layer").iter().collect();
+    assert_eq!(tags, ["outer", "inner"]);
+}
@@ -0,0 +1,113 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_consumed_licenses() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/enterprises/acme/consumed-licenses"))
+        .and(query_param("per_page", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_seats_consumed": 5000,
+            "total_seats_purchased": 4500,
+            "users": [{
+                "github_com_login": "monalisa",
+                "github_com_name": "Mona Lisa",
+                "enterprise_server_user_ids": ["example_host_name.com:123"],
+                "github_com_user": true,
+                "enterprise_server_user": false,
+                "visual_studio_subscription_user": false,
+                "license_type": "enterprise",
+                "github_com_profile": "https://github.com/monalisa",
+                "github_com_member_roles": ["org1:Owner"],
+                "github_com_enterprise_roles": ["owner"],
+                "github_com_verified_domain_emails": ["monalisa@github.com"],
+                "github_com_saml_name_id": "monalisa",
+                "github_com_orgs_with_pending_invites": [],
+                "github_com_two_factor_auth": true,
+                "enterprise_server_emails": ["monalisa@github.com"],
+                "visual_studio_license_status": "",
+                "visual_studio_subscription_email": "",
+                "total_user_accounts": 3
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /enterprises/acme/consumed-licenses was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let licenses = client
+        .enterprises("acme")
+        .consumed_licenses()
+        .per_page(100)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(licenses.total_seats_consumed, 5000);
+    assert_eq!(licenses.users.len(), 1);
+    assert_eq!(
+        licenses.users[0].github_com_login.as_deref(),
+        Some("monalisa")
+    );
+    assert_eq!(licenses.users[0].total_user_accounts, 3);
+}
+
+#[tokio::test]
+async fn should_get_license_sync_status() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/enterprises/acme/license-sync-status"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "server_instances": [{
+                "server_id": "deadbeef1",
+                "hostname": "github.example.com",
+                "last_sync": {
+                    "date": "2020-01-01T00:00:00Z",
+                    "status": "success",
+                    "error": ""
+                }
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /enterprises/acme/license-sync-status was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let status = client
+        .enterprises("acme")
+        .license_sync_status()
+        .await
+        .unwrap();
+
+    assert_eq!(status.server_instances[0].hostname, "github.example.com");
+    let last_sync = status.server_instances[0].last_sync.as_ref().unwrap();
+    assert_eq!(last_sync.status.as_deref(), Some("success"));
+}
@@ -0,0 +1,126 @@
+mod mock_error;
+
+use bytes::Bytes;
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const RELEASE_ID: u64 = 148681297;
+
+fn setup_enterprise_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(format!("{uri}/api/v3"))
+        .unwrap()
+        .build()
+        .unwrap()
+}
+
+async fn setup_graphql(mock_server: &MockServer, graphql_path: &str) {
+    Mock::given(method("POST"))
+        .and(path(graphql_path))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "viewer": { "login": "octocat" } }
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_derive_graphql_uri_from_enterprise_base_uri() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    setup_graphql(&mock_server, "/api/graphql").await;
+
+    let client = setup_enterprise_octocrab(&mock_server.uri());
+    let response: Value = client
+        .graphql(&json!({ "query": "{ viewer { login } }" }))
+        .await
+        .unwrap();
+
+    assert_eq!(response["data"]["viewer"]["login"], "octocat");
+}
+
+#[tokio::test]
+async fn should_send_graphql_to_configured_uri() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    setup_graphql(&mock_server, "/custom/graphql").await;
+
+    let client = Octocrab::builder()
+        .base_uri(format!("{}/api/v3", mock_server.uri()))
+        .unwrap()
+        .graphql_uri(format!("{}/custom/graphql", mock_server.uri()))
+        .unwrap()
+        .build()
+        .unwrap();
+    let response: Value = client
+        .graphql(&json!({ "query": "{ viewer { login } }" }))
+        .await
+        .unwrap();
+
+    assert_eq!(response["data"]["viewer"]["login"], "octocat");
+}
+
+#[tokio::test]
+async fn should_upload_assets_to_enterprise_uploads_uri() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    let release: Value =
+        serde_json::from_str(include_str!("resources/repos_releases_get_by_id.json")).unwrap();
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/api/v3/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(release))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/api/uploads/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}/assets"
+        )))
+        .and(query_param("name", "example.zip"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "url": "https://ghe.example.com/api/v3/repos/XAMPPRocky/octocrab/releases/assets/1",
+            "browser_download_url": "https://ghe.example.com/XAMPPRocky/octocrab/releases/download/v1.0.0/example.zip",
+            "id": 1,
+            "node_id": "MDEyOlJlbGVhc2VBc3NldDE=",
+            "name": "example.zip",
+            "label": null,
+            "state": "uploaded",
+            "content_type": "application/octet-stream",
+            "size": 9,
+            "digest": null,
+            "download_count": 0,
+            "created_at": "2013-02-27T19:35:32Z",
+            "updated_at": "2013-02-27T19:35:32Z",
+            "uploader": null
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on release was not received").await;
+
+    let client = setup_enterprise_octocrab(&mock_server.uri());
+    let asset = client
+        .repos(OWNER, REPO)
+        .releases()
+        .upload_asset(RELEASE_ID, "example.zip", Bytes::from("some_data"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(asset.name, "example.zip");
+}
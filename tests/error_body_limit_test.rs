@@ -0,0 +1,70 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[tokio::test]
+async fn should_truncate_unexpected_error_bodies() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let page = format!("<html>{}</html>", "x".repeat(4096));
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo"))
+        .respond_with(
+            ResponseTemplate::new(403)
+                .insert_header("content-type", "text/html")
+                .set_body_string(page),
+        )
+        .mount(&mock_server)
+        .await;
+
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .error_body_limit(16)
+        .build()
+        .unwrap();
+    let error = client.repos("owner", "repo").get().await.unwrap_err();
+
+    match error {
+        octocrab::Error::UnexpectedErrorBody {
+            status_code,
+            body,
+            truncated,
+            ..
+        } => {
+            assert_eq!(status_code, 403);
+            assert_eq!(body, "<html>xxxxxxxxxx");
+            assert!(truncated);
+        }
+        error => panic!("expected an unexpected error body, got {:?}", error),
+    }
+}
+
+#[tokio::test]
+async fn should_parse_github_errors_within_limit() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    setup_error_handler(&mock_server, "Not Found").await;
+
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .error_body_limit(1024)
+        .build()
+        .unwrap();
+    let error = client.repos("owner", "repo").get().await.unwrap_err();
+
+    match error {
+        octocrab::Error::GitHub { source, .. } => assert_eq!(source.message, "Not Found"),
+        error => panic!("expected a GitHub error, got {:?}", error),
+    }
+}
@@ -0,0 +1,110 @@
+mod mock_error;
+
+use chrono::{TimeZone, Utc};
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{models::repos::CommitAuthor, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn author() -> CommitAuthor {
+    CommitAuthor {
+        name: "Mona Octocat".to_owned(),
+        email: Some("octocat@github.com".to_owned()),
+        date: Some(Utc.with_ymd_and_hms(2008, 7, 9, 16, 13, 30).unwrap()),
+    }
+}
+
+#[tokio::test]
+async fn should_build_signing_payload_and_send_signature() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let payload = "tree 827efc6d56897b048c772eb4087f854f46256132\n\
+        parent 7d1b31e74ee336d15cbd21741bc88a537ed063a0\n\
+        author Mona Octocat <octocat@github.com> 1215620010 +0000\n\
+        committer Mona Octocat <octocat@github.com> 1215620010 +0000\n\
+        \n\
+        my commit message";
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/git/commits"))
+        .and(body_partial_json(json!({
+            "message": "my commit message",
+            "signature": "-----BEGIN PGP SIGNATURE-----"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "sha": "7638417db6d59f3c431d3e1f261cc637155684cd",
+            "node_id": "MDY6Q29tbWl0NzYzODQxN2RiNmQ1OWYzYzQzMWQzZTFmMjYxY2M2MzcxNTU2ODRjZA==",
+            "url": "https://api.github.com/repos/owner/repo/git/commits/7638417db6d59f3c431d3e1f261cc637155684cd",
+            "html_url": "https://github.com/owner/repo/commit/7638417db6d59f3c431d3e1f261cc637155684cd",
+            "author": { "name": "Mona Octocat", "email": "octocat@github.com", "date": "2008-07-09T16:13:30Z" },
+            "committer": { "name": "Mona Octocat", "email": "octocat@github.com", "date": "2008-07-09T16:13:30Z" },
+            "message": "my commit message",
+            "tree": {
+                "url": "https://api.github.com/repos/owner/repo/git/trees/827efc6d56897b048c772eb4087f854f46256132",
+                "sha": "827efc6d56897b048c772eb4087f854f46256132"
+            },
+            "parents": [{
+                "url": "https://api.github.com/repos/owner/repo/git/commits/7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+                "sha": "7d1b31e74ee336d15cbd21741bc88a537ed063a0",
+                "html_url": "https://github.com/owner/repo/commit/7d1b31e74ee336d15cbd21741bc88a537ed063a0"
+            }],
+            "verification": {
+                "verified": true,
+                "reason": "valid",
+                "signature": "-----BEGIN PGP SIGNATURE-----",
+                "payload": payload
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /repos/owner/repo/git/commits was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos("owner", "repo");
+    let commit = repos
+        .create_git_commit_object(
+            "my commit message",
+            "827efc6d56897b048c772eb4087f854f46256132",
+        )
+        .parents(vec!["7d1b31e74ee336d15cbd21741bc88a537ed063a0".to_owned()])
+        .author(author());
+
+    assert_eq!(commit.signing_payload().unwrap(), payload);
+
+    let commit = commit
+        .signature("-----BEGIN PGP SIGNATURE-----")
+        .send()
+        .await
+        .unwrap();
+    assert!(commit.verification.verified);
+}
+
+#[tokio::test]
+async fn should_require_author_date_for_signing_payload() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let client = Octocrab::builder().build().unwrap();
+    let repos = client.repos("owner", "repo");
+    let commit = repos
+        .create_git_commit_object("message", "tree")
+        .author(CommitAuthor {
+            date: None,
+            ..author()
+        });
+
+    assert!(commit.signing_payload().is_err());
+}
@@ -0,0 +1,255 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::graphql::GraphQLQuery;
+use octocrab::Octocrab;
+use serde::Deserialize;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[derive(Debug, Deserialize)]
+struct Data {
+    repository: Repository,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Repository {
+    stargazer_count: u64,
+}
+
+fn get_repo() -> GraphQLQuery {
+    GraphQLQuery::new(
+        "query GetRepo($owner: String!, $name: String!) { repository(owner: $owner, name: $name) { stargazerCount } }",
+    )
+    .var("owner", "owner")
+    .var("name", "repo")
+    .operation("GetRepo")
+}
+
+#[tokio::test]
+async fn should_send_variables_and_operation_name() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "operationName": "GetRepo",
+            "variables": { "owner": "owner", "name": "repo" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "repository": { "stargazerCount": 42 } }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let data: Data = client.graphql_send(&get_repo()).await.unwrap();
+
+    assert_eq!(data.repository.stargazer_count, 42);
+}
+
+#[tokio::test]
+async fn should_return_all_graphql_errors() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "repository": null },
+            "errors": [
+                {
+                    "type": "NOT_FOUND",
+                    "path": ["repository"],
+                    "message": "Could not resolve to a Repository with the name 'owner/repo'."
+                },
+                { "message": "Something else went wrong" }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.graphql_send::<Data>(&get_repo()).await;
+
+    match result.unwrap_err() {
+        octocrab::Error::GitHub { source, .. } => {
+            assert!(source.message.contains("owner/repo"));
+            assert_eq!(source.errors.unwrap().len(), 2);
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn should_stream_nodes_of_all_pages() {
+    use futures_util::TryStreamExt;
+
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    #[derive(Debug, Deserialize)]
+    struct Issue {
+        number: u64,
+    }
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": { "owner": "owner", "after": "Y3Vyc29yOjI=" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "repository": {
+                    "issues": {
+                        "pageInfo": { "hasNextPage": false, "endCursor": "Y3Vyc29yOjM=" },
+                        "edges": [{ "node": { "number": 3 } }]
+                    }
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(
+            json!({ "variables": { "owner": "owner" } }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "repository": {
+                    "issues": {
+                        "pageInfo": { "hasNextPage": true, "endCursor": "Y3Vyc29yOjI=" },
+                        "edges": [{ "node": { "number": 1 } }, { "node": { "number": 2 } }]
+                    }
+                }
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let issues: Vec<Issue> = GraphQLQuery::new(
+        "query($owner: String!, $after: String) { repository(owner: $owner, name: \"repo\") { issues(first: 2, after: $after) { pageInfo { hasNextPage endCursor } edges { node { number } } } } }",
+    )
+    .var("owner", "owner")
+    .into_stream(&client, "after")
+    .try_collect()
+    .await
+    .unwrap();
+
+    let numbers: Vec<u64> = issues.iter().map(|issue| issue.number).collect();
+    assert_eq!(numbers, [1, 2, 3]);
+}
+
+#[cfg(feature = "graphql-client")]
+#[tokio::test]
+async fn should_send_graphql_client_query() {
+    use graphql_client::QueryBody;
+
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    struct GetRepo;
+
+    impl graphql_client::GraphQLQuery for GetRepo {
+        type Variables = serde_json::Value;
+        type ResponseData = Data;
+
+        fn build_query(variables: Self::Variables) -> QueryBody<Self::Variables> {
+            QueryBody {
+                variables,
+                query: "query GetRepo($owner: String!, $name: String!) { repository(owner: $owner, name: $name) { stargazerCount } }",
+                operation_name: "GetRepo",
+            }
+        }
+    }
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "operationName": "GetRepo",
+            "variables": { "owner": "owner", "name": "repo" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "repository": { "stargazerCount": 42 } }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let data = client
+        .graphql_typed::<GetRepo>(json!({ "owner": "owner", "name": "repo" }))
+        .await
+        .unwrap();
+
+    assert_eq!(data.repository.stargazer_count, 42);
+}
+
+#[tokio::test]
+async fn should_fan_out_batch_results() {
+    use octocrab::graphql::{quote, GraphQLBatch};
+
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "query": "query {\n  q0: repository(owner: \"owner\", name: \"repo\") { stargazerCount }\n  q1: repository(owner: \"owner\", name: \"missing\") { stargazerCount }\n}"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "q0": { "stargazerCount": 42 },
+                "q1": null
+            },
+            "errors": [{
+                "type": "NOT_FOUND",
+                "path": ["q1"],
+                "message": "Could not resolve to a Repository with the name 'owner/missing'."
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let batch = ["repo", "missing"]
+        .iter()
+        .fold(GraphQLBatch::new(), |batch, name| {
+            batch.query(format!(
+                "repository(owner: \"owner\", name: {}) {{ stargazerCount }}",
+                quote(name)
+            ))
+        });
+    let client = setup_octocrab(&mock_server.uri());
+    let results = client.graphql_batch::<Repository>(&batch).await.unwrap();
+
+    assert_eq!(results.len(), 2);
+    assert_eq!(results[0].as_ref().unwrap().stargazer_count, 42);
+    match &results[1] {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert!(source.message.contains("owner/missing"));
+        }
+        other => panic!("Unexpected result: {:?}", other),
+    }
+}
@@ -0,0 +1,67 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{service::middleware::cache::mem::InMemoryCache, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri(uri)
+        .unwrap()
+        .cache(InMemoryCache::new())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_reuse_cached_body_when_not_modified() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let label = json!({
+        "id": 208045946,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": "https://api.github.com/repos/owner/repo/labels/bug",
+        "name": "bug",
+        "color": "f29513",
+        "default": true
+    });
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/labels/bug"))
+        .and(header("if-none-match", "\"abc\""))
+        .respond_with(ResponseTemplate::new(304))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/labels/bug"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"abc\"")
+                .set_body_json(&label),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/labels/bug was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    for _ in 0..2 {
+        let label = client
+            .issues("owner", "repo")
+            .get_label("bug")
+            .await
+            .unwrap();
+        assert_eq!(label.name, "bug");
+    }
+}
@@ -0,0 +1,65 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{Error, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api(have: &str, need: &str) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/hooks"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .insert_header("x-oauth-scopes", have)
+                .insert_header("x-accepted-oauth-scopes", need)
+                .set_body_json(json!({
+                    "message": "Not Found",
+                    "documentation_url": "https://docs.github.com/rest/orgs/webhooks"
+                })),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /orgs/org/hooks was not received").await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_report_missing_scopes() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api("repo, read:org", "admin:org_hook").await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.orgs("org").hooks().list().send().await;
+
+    match result {
+        Err(Error::InsufficientScopes {
+            have, need, source, ..
+        }) => {
+            assert_eq!(have, ["repo", "read:org"]);
+            assert_eq!(need, ["admin:org_hook"]);
+            assert_eq!(source.status_code, 404);
+        }
+        result => panic!("expected missing scopes, got {:?}", result),
+    }
+}
+
+#[tokio::test]
+async fn should_not_report_scopes_granted_by_broader_scopes() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api("admin:org, repo", "read:org, repo:status").await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.orgs("org").hooks().list().send().await;
+
+    assert!(matches!(result, Err(Error::GitHub { .. })), "{:?}", result);
+}
@@ -96,3 +96,58 @@ async fn check_assignee_500() {
         result
     );
 }
+
+const ISSUE_NUMBER: u64 = 42;
+
+async fn setup_issue_check_assignee_for_issue_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{ISSUE_NUMBER}/assignees/{ASSIGNEE}"
+        )))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+
+    setup_error_handler(
+        &mock_server,
+        &format!(
+            "GET on /repos/{OWNER}/{REPO}/issues/{ISSUE_NUMBER}/assignees/{ASSIGNEE} was not received"
+        ),
+    )
+    .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn check_assignee_for_issue_204() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_issue_check_assignee_for_issue_api(ResponseTemplate::new(204)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .check_assignee_for_issue(ISSUE_NUMBER, ASSIGNEE)
+        .await;
+
+    assert!(result.unwrap(), "expected the user to be assignable");
+}
+
+#[tokio::test]
+async fn check_assignee_for_issue_404() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_issue_check_assignee_for_issue_api(ResponseTemplate::new(404)).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .check_assignee_for_issue(ISSUE_NUMBER, ASSIGNEE)
+        .await;
+
+    assert!(!result.unwrap(), "expected the user not to be assignable");
+}
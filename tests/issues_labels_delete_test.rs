@@ -12,7 +12,7 @@ async fn setup_remove_label_api(template: ResponseTemplate) -> MockServer {
     let repo: &str = "some-repo";
     let issue_number: u64 = 123;
     // Gotta love URL encoding
-    let label_name: &str = "some%2Dlabel";
+    let label_name: &str = "help%20wanted";
 
     let mock_server = MockServer::start().await;
 
@@ -35,7 +35,7 @@ async fn setup_remove_label_api(template: ResponseTemplate) -> MockServer {
 async fn setup_delete_label_api(template: ResponseTemplate) -> MockServer {
     let owner: &str = "org";
     let repo: &str = "some-repo";
-    let label_name: &str = "some%2Dlabel";
+    let label_name: &str = "help%20wanted";
 
     let mock_server = MockServer::start().await;
 
@@ -60,7 +60,7 @@ fn setup_octocrab(uri: &str) -> Octocrab {
 const OWNER: &str = "org";
 const REPO: &str = "some-repo";
 const ISSUE_NUMBER: u64 = 123;
-const LABEL_NAME: &str = "some-label";
+const LABEL_NAME: &str = "help wanted";
 
 #[tokio::test]
 async fn should_remove_label() {
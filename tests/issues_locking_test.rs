@@ -3,7 +3,7 @@ mod mock_error;
 use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
 use octocrab::{params::LockReason, Octocrab};
 use wiremock::{
-    matchers::{method, path},
+    matchers::{body_json, method, path},
     Mock, MockServer, ResponseTemplate,
 };
 
@@ -163,3 +163,45 @@ async fn lock_returns_false() {
     let result = result.unwrap();
     assert!(!result, "expected the result to be false: {}", result);
 }
+
+#[tokio::test]
+async fn lock_sends_lock_reason() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{ISSUE_NUMBER}/lock"
+        )))
+        .and(body_json(
+            serde_json::json!({ "lock_reason": "too heated" }),
+        ))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("PUT on /repos/{OWNER}/{REPO}/issues/{ISSUE_NUMBER}/lock was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .lock(ISSUE_NUMBER, LockReason::TooHeated)
+        .await;
+
+    assert!(result.unwrap(), "expected the issue to be locked");
+}
+
+#[test]
+fn lock_reason_round_trips() {
+    let reason: LockReason = serde_json::from_str(r#""off-topic""#).unwrap();
+
+    assert_eq!(reason, LockReason::OffTopic);
+    assert_eq!(
+        serde_json::to_string(&LockReason::TooHeated).unwrap(),
+        r#""too heated""#
+    );
+}
@@ -0,0 +1,174 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::models::IssueState;
+use octocrab::params;
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const MILESTONE_NUMBER: u64 = 1;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn milestone(state: &str) -> Value {
+    json!({
+        "url": "https://api.github.com/repos/octocat/Hello-World/milestones/1",
+        "html_url": "https://github.com/octocat/Hello-World/milestones/v1.0",
+        "labels_url": "https://api.github.com/repos/octocat/Hello-World/milestones/1/labels",
+        "id": 1002604,
+        "node_id": "MDk6TWlsZXN0b25lMTAwMjYwNA==",
+        "number": 1,
+        "state": state,
+        "title": "v1.0",
+        "description": "Tracking milestone for version 1.0",
+        "open_issues": 4,
+        "closed_issues": 8,
+        "created_at": "2011-04-10T20:09:31Z",
+        "updated_at": "2014-03-03T18:58:10Z",
+        "closed_at": null,
+        "due_on": "2012-10-09T23:39:01Z"
+    })
+}
+
+#[tokio::test]
+async fn should_list_milestones() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/milestones")))
+        .and(query_param("state", "all"))
+        .and(query_param("sort", "due_on"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([milestone("open")])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/milestones was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .list_milestones()
+        .state(params::State::All)
+        .sort(params::issues::MilestoneSort::DueOn)
+        .send()
+        .await;
+
+    let milestones = result.unwrap().items;
+    assert_eq!(milestones.len(), 1);
+    assert_eq!(milestones[0].title, "v1.0");
+}
+
+#[tokio::test]
+async fn should_create_update_and_delete_milestone() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/milestones")))
+        .and(body_json(json!({
+            "title": "v1.0",
+            "description": "Tracking milestone for version 1.0",
+            "due_on": "2012-10-09T23:39:01Z"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(milestone("open")))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/milestones/{MILESTONE_NUMBER}"
+        )))
+        .and(body_json(json!({ "state": "closed" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(milestone("closed")))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/milestones/{MILESTONE_NUMBER}"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("request on /repos/{OWNER}/{REPO}/milestones was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let issues = client.issues(OWNER, REPO);
+
+    let due_on = "2012-10-09T23:39:01Z"
+        .parse::<chrono::DateTime<chrono::Utc>>()
+        .unwrap();
+    let created = issues
+        .create_milestone("v1.0")
+        .description("Tracking milestone for version 1.0")
+        .due_on(due_on)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(created.due_on, Some(due_on));
+
+    let updated = issues
+        .update_milestone(MILESTONE_NUMBER)
+        .state(IssueState::Closed)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(updated.state.as_deref(), Some("closed"));
+
+    issues.delete_milestone(MILESTONE_NUMBER).await.unwrap();
+}
+
+#[tokio::test]
+async fn should_list_labels_for_milestone() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/milestones/{MILESTONE_NUMBER}/labels"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": 208045946,
+            "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+            "url": "https://api.github.com/repos/octocat/Hello-World/labels/bug",
+            "name": "bug",
+            "description": "Something isn't working",
+            "color": "f29513",
+            "default": true
+        }])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!(
+            "GET on /repos/{OWNER}/{REPO}/milestones/{MILESTONE_NUMBER}/labels was not received"
+        ),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .list_labels_for_milestone(MILESTONE_NUMBER)
+        .send()
+        .await;
+
+    let labels = result.unwrap().items;
+    assert_eq!(labels[0].name, "bug");
+}
@@ -0,0 +1,148 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::models::IssueId;
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const PARENT_NUMBER: u64 = 1;
+const SUB_ISSUE_ID: u64 = 1802626324;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn issue() -> Value {
+    let event: Value =
+        serde_json::from_str(include_str!("resources/issues_opened_webhook_event.json")).unwrap();
+    let mut issue = event["issue"].clone();
+    issue["sub_issues_summary"] = json!({
+        "total": 3,
+        "completed": 1,
+        "percent_completed": 33
+    });
+    issue
+}
+
+#[tokio::test]
+async fn should_list_sub_issues() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{PARENT_NUMBER}/sub_issues"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([issue()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/issues/{PARENT_NUMBER}/sub_issues was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .list_sub_issues(PARENT_NUMBER)
+        .send()
+        .await;
+
+    let sub_issues = result.unwrap().items;
+    assert_eq!(sub_issues.len(), 1);
+    let summary = sub_issues[0].sub_issues_summary.unwrap();
+    assert_eq!((summary.total, summary.completed), (3, 1));
+}
+
+#[tokio::test]
+async fn should_add_reprioritize_and_remove_sub_issue() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{PARENT_NUMBER}/sub_issues"
+        )))
+        .and(body_json(json!({
+            "sub_issue_id": SUB_ISSUE_ID,
+            "replace_parent": true
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(issue()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{PARENT_NUMBER}/sub_issues/priority"
+        )))
+        .and(body_json(json!({
+            "sub_issue_id": SUB_ISSUE_ID,
+            "before_id": 42
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(issue()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/issues/{PARENT_NUMBER}/sub_issue"
+        )))
+        .and(body_json(json!({ "sub_issue_id": SUB_ISSUE_ID })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(issue()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("request on /repos/{OWNER}/{REPO}/issues/{PARENT_NUMBER} was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let issues = client.issues(OWNER, REPO);
+
+    issues
+        .add_sub_issue(PARENT_NUMBER, IssueId(SUB_ISSUE_ID))
+        .replace_parent(true)
+        .send()
+        .await
+        .unwrap();
+    issues
+        .reprioritize_sub_issue(PARENT_NUMBER, IssueId(SUB_ISSUE_ID))
+        .before(IssueId(42))
+        .send()
+        .await
+        .unwrap();
+    issues
+        .remove_sub_issue(PARENT_NUMBER, IssueId(SUB_ISSUE_ID))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn should_get_parent() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/issues/2/parent")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(issue()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/issues/2/parent was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.issues(OWNER, REPO).get_parent(2).await;
+
+    assert_eq!(result.unwrap().number, 1);
+}
@@ -0,0 +1,132 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const NEW_REPO: &str = "octocrab-issues";
+const ISSUE_NUMBER: u64 = 1;
+const ISSUE_NODE_ID: &str = "I_kwDOI6LULc5rceUU";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn event() -> Value {
+    serde_json::from_str(include_str!("resources/issues_opened_webhook_event.json")).unwrap()
+}
+
+async fn mount_issue_id(mock_server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("issue(number: $number) { id }"))
+        .and(body_partial_json(json!({
+            "variables": { "owner": OWNER, "name": REPO, "number": ISSUE_NUMBER }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "repository": { "issue": { "id": ISSUE_NODE_ID } } }
+        })))
+        .mount(mock_server)
+        .await;
+}
+
+async fn mount_get_issue(mock_server: &MockServer, repo: &str, number: u64) {
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{repo}/issues/{number}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&event()["issue"]))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_transfer_issue() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    mount_get_issue(&mock_server, NEW_REPO, 7).await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": {
+                "owner": OWNER,
+                "name": REPO,
+                "number": ISSUE_NUMBER,
+                "newOwner": OWNER,
+                "newName": NEW_REPO
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "repository": { "issue": { "id": ISSUE_NODE_ID } },
+                "target": { "id": "R_kgDOI6LULQ" }
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": { "input": {
+                "issueId": ISSUE_NODE_ID,
+                "repositoryId": "R_kgDOI6LULQ",
+                "createLabelsIfMissing": true
+            } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "transferIssue": { "issue": { "number": 7 } } }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "transfer request was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .issues(OWNER, REPO)
+        .transfer(ISSUE_NUMBER, OWNER, NEW_REPO)
+        .create_labels_if_missing(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_pin_and_unpin_issue() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    mount_issue_id(&mock_server).await;
+    for mutation in ["pinIssue", "unpinIssue"] {
+        Mock::given(method("POST"))
+            .and(path("/graphql"))
+            .and(body_partial_json(json!({
+                "variables": { "input": { "issueId": ISSUE_NODE_ID } }
+            })))
+            .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+                "data": { mutation: { "clientMutationId": null } }
+            })))
+            .expect(1)
+            .up_to_n_times(1)
+            .mount(&mock_server)
+            .await;
+    }
+    setup_error_handler(&mock_server, "pin request was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+    let issues = client.issues(OWNER, REPO);
+
+    issues.pin(ISSUE_NUMBER).await.unwrap();
+    issues.unpin(ISSUE_NUMBER).await.unwrap();
+}
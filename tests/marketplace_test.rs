@@ -0,0 +1,148 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::marketplace::MarketplacePriceModel;
+use octocrab::params::apps::MarketplaceAccountSort;
+use octocrab::params::Direction;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn plan() -> serde_json::Value {
+    json!({
+        "url": "https://api.github.com/marketplace_listing/plans/1313",
+        "accounts_url": "https://api.github.com/marketplace_listing/plans/1313/accounts",
+        "id": 1313,
+        "number": 3,
+        "name": "Pro",
+        "description": "A professional-grade CI solution",
+        "monthly_price_in_cents": 1099,
+        "yearly_price_in_cents": 11870,
+        "price_model": "FLAT_RATE",
+        "has_free_trial": true,
+        "unit_name": null,
+        "state": "published",
+        "bullets": ["Up to 25 private repositories", "11 concurrent builds"]
+    })
+}
+
+fn account() -> serde_json::Value {
+    json!({
+        "url": "https://api.github.com/orgs/github",
+        "type": "Organization",
+        "id": 4,
+        "login": "github",
+        "organization_billing_email": "billing@github.com",
+        "marketplace_pending_change": null,
+        "marketplace_purchase": {
+            "billing_cycle": "monthly",
+            "next_billing_date": "2017-11-11T00:00:00Z",
+            "unit_count": null,
+            "on_free_trial": true,
+            "free_trial_ends_on": "2017-11-11T00:00:00Z",
+            "updated_at": "2017-11-02T01:12:12Z",
+            "plan": plan()
+        }
+    })
+}
+
+#[tokio::test]
+async fn should_list_plans() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/marketplace_listing/plans"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([plan()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /marketplace_listing/plans was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.apps().marketplace().list_plans().send().await;
+
+    let plans = result.unwrap();
+    assert_eq!(plans.items[0].id, 1313);
+    assert_eq!(plans.items[0].price_model, MarketplacePriceModel::FlatRate);
+}
+
+#[tokio::test]
+async fn should_list_accounts_for_plan() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/marketplace_listing/plans/1313/accounts"))
+        .and(query_param("sort", "updated"))
+        .and(query_param("direction", "desc"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([account()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /marketplace_listing/plans/1313/accounts was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .apps()
+        .marketplace()
+        .list_accounts_for_plan(1313)
+        .sort(MarketplaceAccountSort::Updated)
+        .direction(Direction::Descending)
+        .send()
+        .await;
+
+    let accounts = result.unwrap();
+    assert_eq!(accounts.items[0].login, "github");
+    assert_eq!(
+        accounts.items[0]
+            .marketplace_purchase
+            .billing_cycle
+            .as_deref(),
+        Some("monthly")
+    );
+}
+
+#[tokio::test]
+async fn should_get_stubbed_subscription_for_account() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/marketplace_listing/stubbed/accounts/4"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(account()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /marketplace_listing/stubbed/accounts/4 was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .apps()
+        .marketplace_stubbed()
+        .get_subscription_for_account(4)
+        .await;
+
+    let account = result.unwrap();
+    assert_eq!(account.id, 4);
+    assert_eq!(account.marketplace_purchase.on_free_trial, Some(true));
+}
@@ -0,0 +1,98 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{params::MediaType, Octocrab, RawResponse};
+use wiremock::{
+    matchers::{header, headers, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_request_media_type_on_single_call() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/1")))
+        .and(header("accept", "application/vnd.github.v3.patch"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("From 1a2b3c"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/pulls/1 with patch media type was not received"),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let patch = client
+        .accept(MediaType::Patch)
+        .pulls(OWNER, REPO)
+        .get_diff(1)
+        .await
+        .unwrap();
+
+    assert_eq!(patch, "From 1a2b3c");
+}
+
+#[tokio::test]
+async fn should_combine_chained_media_types() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/issues"))
+        // wiremock splits header values on commas.
+        .and(headers(
+            "accept",
+            vec![
+                "application/vnd.github.text-match+json",
+                "application/vnd.github.mercy-preview",
+            ],
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("{}"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /search/issues with text-match media type was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let response: RawResponse = client
+        .accept(MediaType::TextMatch)
+        .accept(MediaType::Preview("mercy"))
+        .get("/search/issues", None::<&()>)
+        .await
+        .unwrap();
+
+    assert_eq!(response.body, "{}");
+}
+
+#[test]
+fn should_format_media_types() {
+    assert_eq!(
+        MediaType::Html.to_string(),
+        "application/vnd.github.v3.html+json"
+    );
+    assert_eq!(
+        MediaType::Diff.to_string(),
+        "application/vnd.github.v3.diff"
+    );
+    assert_eq!(
+        MediaType::Star.to_string(),
+        "application/vnd.github.star+json"
+    );
+}
@@ -0,0 +1,131 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_meta() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/meta"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "verifiable_password_authentication": true,
+            "ssh_key_fingerprints": {
+                "SHA256_RSA": "uNiVztksCsDhcc0u9e8BujQXVUpKZIDTMczCvj3tD2s",
+                "SHA256_ECDSA": "p2QAMXNIC1TJYWeIOttrVc98/R1BUFWu3/LiyKgUfQM",
+                "SHA256_ED25519": "+DiY3wvvV6TuJJhbpZisF/zLDA0zPMSvHdkr4UvCOqU"
+            },
+            "hooks": ["192.30.252.0/22", "2a0a:a440::/29"],
+            "web": ["192.30.252.0/22"],
+            "api": ["192.30.252.0/22"],
+            "git": ["192.30.252.0/22"],
+            "actions": ["4.148.0.0/16"],
+            "dependabot": [],
+            "domains": {
+                "website": ["*.github.com"],
+                "artifact_attestations": {
+                    "trust_domain": "",
+                    "services": ["*.actions.githubusercontent.com"]
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /meta was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let meta = client.meta().get().await.unwrap();
+
+    assert!(meta.verifiable_password_authentication);
+    assert_eq!(
+        meta.hooks.unwrap(),
+        vec!["192.30.252.0/22".to_string(), "2a0a:a440::/29".to_string()]
+    );
+    assert!(meta.ssh_key_fingerprints.unwrap().sha256_dsa.is_none());
+    assert!(meta.pages.is_none());
+    assert_eq!(
+        meta.domains
+            .unwrap()
+            .artifact_attestations
+            .unwrap()
+            .services,
+        Some(vec!["*.actions.githubusercontent.com".to_string()])
+    );
+}
+
+#[tokio::test]
+async fn should_list_emojis() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/emojis"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "+1": "https://github.githubassets.com/images/icons/emoji/unicode/1f44d.png?v8",
+            "octocat": "https://github.githubassets.com/images/icons/emoji/octocat.png?v8"
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /emojis was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let emojis = client.meta().emojis().await.unwrap();
+
+    assert_eq!(emojis.len(), 2);
+    assert_eq!(
+        emojis["octocat"],
+        "https://github.githubassets.com/images/icons/emoji/octocat.png?v8"
+    );
+}
+
+#[tokio::test]
+async fn should_get_octocat_saying_words() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/octocat"))
+        .and(query_param("s", "Hello"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("MMM Hello MMM"))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /octocat was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let octocat = client.meta().octocat(Some("Hello")).await.unwrap();
+
+    assert_eq!(octocat, "MMM Hello MMM");
+}
+
+#[tokio::test]
+async fn should_get_zen() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/zen"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("Keep it logically awesome."))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /zen was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let zen = client.meta().zen().await.unwrap();
+
+    assert_eq!(zen, "Keep it logically awesome.");
+}
@@ -0,0 +1,167 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::migrations::MigrationState;
+use octocrab::models::MigrationId;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const MIGRATION_ID: u64 = 79;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn migration(state: &str) -> serde_json::Value {
+    json!({
+        "id": MIGRATION_ID,
+        "node_id": "MDQ6TWlncmF0aW9uNzk=",
+        "guid": "0b989ba4-242f-11e5-81e1-c7b6966d2516",
+        "owner": null,
+        "state": state,
+        "lock_repositories": true,
+        "exclude_metadata": false,
+        "exclude_git_data": false,
+        "exclude_attachments": false,
+        "exclude_releases": true,
+        "exclude_owner_projects": false,
+        "org_metadata_only": false,
+        "repositories": [],
+        "url": "https://api.github.com/orgs/org/migrations/79",
+        "created_at": "2015-07-06T15:33:38-07:00",
+        "updated_at": "2015-07-06T15:33:38-07:00"
+    })
+}
+
+#[tokio::test]
+async fn should_start_org_migration() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/orgs/{ORG}/migrations")))
+        .and(body_json(json!({
+            "repositories": ["repo-a", "repo-b"],
+            "lock_repositories": true,
+            "exclude_releases": true
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(migration("pending")))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /orgs/org/migrations was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .orgs(ORG)
+        .migrations()
+        .start(["repo-a", "repo-b"])
+        .lock_repositories(true)
+        .exclude_releases(true)
+        .send()
+        .await;
+
+    let migration = result.unwrap();
+    assert_eq!(migration.id, MigrationId(MIGRATION_ID));
+    assert_eq!(migration.state, MigrationState::Pending);
+}
+
+#[tokio::test]
+async fn should_get_user_migration_status() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/user/migrations/{MIGRATION_ID}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(migration("exported")))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /user/migrations/{id} was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .current()
+        .migrations()
+        .get(MigrationId(MIGRATION_ID))
+        .await;
+
+    assert_eq!(result.unwrap().state, MigrationState::Exported);
+}
+
+#[tokio::test]
+async fn should_download_archive_following_redirect() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    let storage_url = format!("{}/storage/migration_archive.tar.gz", mock_server.uri());
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/orgs/{ORG}/migrations/{MIGRATION_ID}/archive"
+        )))
+        .respond_with(ResponseTemplate::new(302).insert_header("Location", storage_url.as_str()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/storage/migration_archive.tar.gz"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"archive".to_vec()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /orgs/org/migrations/{id}/archive was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .orgs(ORG)
+        .migrations()
+        .download_archive(MigrationId(MIGRATION_ID))
+        .await;
+
+    assert_eq!(result.unwrap().as_ref(), b"archive");
+}
+
+#[tokio::test]
+async fn should_unlock_repository() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/orgs/{ORG}/migrations/{MIGRATION_ID}/repos/repo-a/lock"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "DELETE on /orgs/org/migrations/{id}/repos/repo-a/lock was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .orgs(ORG)
+        .migrations()
+        .unlock_repository(MigrationId(MIGRATION_ID), "repo-a")
+        .await;
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "models-api")]
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::inference::{ChatMessage, ChatRole};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder()
+        .base_uri("https://api.github.com")
+        .unwrap()
+        .models_uri(uri)
+        .unwrap()
+        .personal_token("secret".to_string())
+        .build()
+        .unwrap()
+}
+
+#[tokio::test]
+async fn should_list_catalog_models() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/catalog/models"))
+        .and(header("authorization", "Bearer secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": "openai/gpt-4.1",
+            "name": "OpenAI GPT-4.1",
+            "publisher": "OpenAI",
+            "registry": "azure-openai",
+            "summary": "gpt-4.1 outperforms gpt-4o across the board",
+            "html_url": "https://github.com/marketplace/models/azure-openai/gpt-4-1",
+            "version": "1",
+            "capabilities": ["streaming", "tool-calling"],
+            "limits": {
+                "max_input_tokens": 1048576,
+                "max_output_tokens": 32768
+            },
+            "rate_limit_tier": "high",
+            "supported_input_modalities": ["text", "image"],
+            "supported_output_modalities": ["text"],
+            "tags": ["multipurpose"]
+        }])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /catalog/models was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let models = client.models_api().list().await.unwrap();
+
+    assert_eq!(models.len(), 1);
+    assert_eq!(models[0].id, "openai/gpt-4.1");
+    assert_eq!(models[0].limits.unwrap().max_output_tokens, Some(32768));
+}
+
+#[tokio::test]
+async fn should_run_org_chat_completion() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/acme/inference/chat/completions"))
+        .and(header("authorization", "Bearer secret"))
+        .and(body_partial_json(json!({
+            "model": "openai/gpt-4.1",
+            "messages": [
+                { "role": "system", "content": "Be brief." },
+                { "role": "user", "content": "What is the capital of France?" }
+            ],
+            "temperature": 0.5
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "chatcmpl-1",
+            "object": "chat.completion",
+            "created": 1745000000,
+            "model": "gpt-4.1-2025-04-14",
+            "choices": [{
+                "index": 0,
+                "message": { "role": "assistant", "content": "Paris." },
+                "finish_reason": "stop"
+            }],
+            "usage": {
+                "prompt_tokens": 20,
+                "completion_tokens": 2,
+                "total_tokens": 22
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let completion = client
+        .models_api()
+        .chat_completion("openai/gpt-4.1")
+        .org("acme")
+        .message(ChatMessage::system("Be brief."))
+        .message(ChatMessage::user("What is the capital of France?"))
+        .temperature(0.5)
+        .send()
+        .await
+        .unwrap();
+
+    let message = &completion.choices[0].message;
+    assert_eq!(message.role, ChatRole::Assistant);
+    assert_eq!(message.content.as_deref(), Some("Paris."));
+    assert_eq!(completion.usage.unwrap().total_tokens, 22);
+}
@@ -0,0 +1,119 @@
+// Tests for polling /notifications with If-Modified-Since.
+mod mock_error;
+
+use std::time::Duration;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{headers, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const LAST_MODIFIED: &str = "Thu, 25 Oct 2012 15:16:27 GMT";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_expose_polling_headers() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/notifications"))
+        .and(query_param("participating", "true"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(serde_json::json!([]))
+                .insert_header("Last-Modified", LAST_MODIFIED)
+                .insert_header("X-Poll-Interval", "60"),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /notifications was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let polled = client
+        .activity()
+        .notifications()
+        .list()
+        .participating(true)
+        .poll()
+        .await
+        .unwrap();
+    assert_eq!(polled.last_modified.as_deref(), Some(LAST_MODIFIED));
+    assert_eq!(polled.poll_interval, Some(Duration::from_secs(60)));
+    assert!(polled.value.unwrap().items.is_empty());
+}
+
+#[tokio::test]
+async fn should_return_no_page_when_not_modified() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/notifications"))
+        // wiremock splits header values on commas.
+        .and(headers(
+            "If-Modified-Since",
+            vec!["Thu", "25 Oct 2012 15:16:27 GMT"],
+        ))
+        .respond_with(
+            ResponseTemplate::new(304)
+                .insert_header("Last-Modified", LAST_MODIFIED)
+                .insert_header("X-Poll-Interval", "120"),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "conditional GET on /notifications was not received",
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let polled = client
+        .activity()
+        .notifications()
+        .list()
+        .if_modified_since(LAST_MODIFIED)
+        .poll()
+        .await
+        .unwrap();
+    assert!(polled.value.is_none());
+    assert_eq!(polled.poll_interval, Some(Duration::from_secs(120)));
+}
+
+#[tokio::test]
+async fn should_mark_thread_as_done() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/notifications/threads/123"))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "DELETE on /notifications/threads/123 was not received",
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .activity()
+        .notifications()
+        .mark_as_done(123u64.into())
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got {:#?}",
+        result
+    );
+}
@@ -0,0 +1,102 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn subscription(subscribed: bool, ignored: bool) -> serde_json::Value {
+    json!({
+        "subscribed": subscribed,
+        "ignored": ignored,
+        "reason": null,
+        "created_at": "2012-10-06T21:34:12Z",
+        "url": "https://api.github.com/repos/octocat/example/subscription",
+        "repository_url": "https://api.github.com/repos/octocat/example"
+    })
+}
+
+#[tokio::test]
+async fn should_get_repo_subscription() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/octocat/example/subscription"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(subscription(true, false)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/octocat/example/subscription was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let subscription = client
+        .activity()
+        .notifications()
+        .get_repo_subscription("octocat", "example")
+        .await
+        .unwrap();
+
+    assert!(subscription.subscribed);
+    assert!(!subscription.ignored);
+}
+
+#[tokio::test]
+async fn should_ignore_repo() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/repos/octocat/example/subscription"))
+        .and(body_json(json!({ "subscribed": false, "ignored": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(subscription(false, true)))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let subscription = client
+        .activity()
+        .notifications()
+        .set_repo_subscription("octocat", "example", false, true)
+        .await
+        .unwrap();
+
+    assert!(subscription.ignored);
+}
+
+#[tokio::test]
+async fn should_delete_repo_subscription() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path("/repos/octocat/example/subscription"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .activity()
+        .notifications()
+        .delete_repo_subscription("octocat", "example")
+        .await;
+
+    assert!(result.is_ok(), "expected success, got {:#?}", result);
+}
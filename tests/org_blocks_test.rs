@@ -0,0 +1,396 @@
+// Tests for calls to the /orgs/{org}/blocks API.
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::SimpleUser;
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const USER: &str = "octocat";
+
+async fn setup_blocks_mock(
+    http_method: &str,
+    mocked_path: String,
+    template: ResponseTemplate,
+) -> MockServer {
+    let mock_server = MockServer::start().await;
+
+    Mock::given(method(http_method))
+        .and(path(mocked_path.clone()))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{http_method} on {mocked_path} was not received"),
+    )
+    .await;
+    mock_server
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_users_blocked_by_org() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let blocked: Vec<SimpleUser> =
+        serde_json::from_str(include_str!("resources/user_blocks.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/blocks")))
+        .and(query_param("per_page", "50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&blocked))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on org blocks was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .orgs(ORG)
+        .list_blocked_users()
+        .per_page(50)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].login, USER);
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn should_stream_users_blocked_by_org_across_pages() {
+    use futures_util::TryStreamExt;
+    use wiremock::matchers::query_param_is_missing;
+
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mut blocked: Vec<SimpleUser> =
+        serde_json::from_str(include_str!("resources/user_blocks.json")).unwrap();
+    let mut second = blocked[0].clone();
+    second.login = "monalisa".to_string();
+    let mock_server = MockServer::start().await;
+    let next = format!("{}/orgs/{ORG}/blocks?per_page=1&page=2", mock_server.uri());
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/blocks")))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json([&second]))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/blocks")))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Link", format!("<{next}>; rel=\"next\"").as_str())
+                .set_body_json(&blocked),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on org blocks was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let users: Vec<SimpleUser> = client
+        .orgs(ORG)
+        .list_blocked_users()
+        .per_page(1)
+        .into_stream()
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    blocked.push(second);
+    let logins = |users: &[SimpleUser]| users.iter().map(|u| u.login.clone()).collect::<Vec<_>>();
+    assert_eq!(logins(&users), logins(&blocked));
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn should_prefetch_pages_of_users_blocked_by_org_in_order() {
+    use futures_util::TryStreamExt;
+    use wiremock::matchers::query_param_is_missing;
+
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let blocked: Vec<SimpleUser> =
+        serde_json::from_str(include_str!("resources/user_blocks.json")).unwrap();
+    let user = |login: &str| {
+        let mut user = blocked[0].clone();
+        user.login = login.to_string();
+        user
+    };
+    let mock_server = MockServer::start().await;
+    let link = |page: u32| {
+        format!(
+            "{}/orgs/{ORG}/blocks?per_page=1&page={page}",
+            mock_server.uri()
+        )
+    };
+    for (page, login) in [(2, "monalisa"), (3, "hubot")] {
+        Mock::given(method("GET"))
+            .and(path(format!("/orgs/{ORG}/blocks")))
+            .and(query_param("page", page.to_string()))
+            .respond_with(ResponseTemplate::new(200).set_body_json([user(login)]))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/blocks")))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!("<{}>; rel=\"next\", <{}>; rel=\"last\"", link(2), link(3)).as_str(),
+                )
+                .set_body_json([user(USER)]),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on org blocks was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .orgs(ORG)
+        .list_blocked_users()
+        .per_page(1)
+        .send()
+        .await
+        .unwrap();
+    let users: Vec<SimpleUser> = page
+        .into_stream_prefetching(&client, 2)
+        .try_collect()
+        .await
+        .unwrap();
+    let logins: Vec<_> = users.iter().map(|user| user.login.as_str()).collect();
+    assert_eq!(logins, [USER, "monalisa", "hubot"]);
+}
+
+#[tokio::test]
+async fn should_apply_default_per_page_to_list_requests() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let blocked: Vec<SimpleUser> =
+        serde_json::from_str(include_str!("resources/user_blocks.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    for per_page in ["100", "50"] {
+        Mock::given(method("GET"))
+            .and(path(format!("/orgs/{ORG}/blocks")))
+            .and(query_param("per_page", per_page))
+            .respond_with(ResponseTemplate::new(200).set_body_json(&blocked))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    setup_error_handler(&mock_server, "GET on org blocks was not received").await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .default_per_page(100)
+        .build()
+        .unwrap();
+
+    let orgs = client.orgs(ORG);
+    orgs.list_blocked_users().send().await.unwrap();
+    orgs.list_blocked_users().per_page(50).send().await.unwrap();
+}
+
+#[tokio::test]
+async fn should_not_apply_default_per_page_to_single_objects() {
+    use wiremock::matchers::query_param_is_missing;
+
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}")))
+        .and(query_param_is_missing("per_page"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on org without per_page was not received").await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .default_per_page(100)
+        .build()
+        .unwrap();
+
+    let _: serde_json::Value = client
+        .get(format!("/orgs/{ORG}"), None::<&()>)
+        .await
+        .unwrap();
+}
+
+#[test]
+#[should_panic]
+fn should_reject_default_per_page_over_100() {
+    Octocrab::builder().default_per_page(101);
+}
+
+#[tokio::test]
+async fn should_collect_all_pages_up_to_max_items_with_progress() {
+    use octocrab::{AllPagesOptions, Progress};
+    use std::ops::ControlFlow;
+    use wiremock::matchers::query_param_is_missing;
+
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let blocked: Vec<SimpleUser> =
+        serde_json::from_str(include_str!("resources/user_blocks.json")).unwrap();
+    let page = [&blocked[0], &blocked[0]];
+    let mock_server = MockServer::start().await;
+    let next = format!("{}/orgs/{ORG}/blocks?per_page=2&page=2", mock_server.uri());
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/blocks")))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(page))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/blocks")))
+        .and(query_param_is_missing("page"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("Link", format!("<{next}>; rel=\"next\"").as_str())
+                .set_body_json(page),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on org blocks was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let first = client
+        .orgs(ORG)
+        .list_blocked_users()
+        .per_page(2)
+        .send()
+        .await
+        .unwrap();
+    let mut progress = Vec::new();
+    let users = client
+        .all_pages_with(
+            first,
+            AllPagesOptions::new()
+                .max_items(3)
+                .on_progress(|p: &Progress| {
+                    progress.push((p.items, p.pages));
+                    ControlFlow::Continue(())
+                }),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(users.len(), 3);
+    assert_eq!(progress, [(2, 1), (3, 2)]);
+}
+
+#[tokio::test]
+async fn should_report_blocked_user() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_blocks_mock(
+        "GET",
+        format!("/orgs/{ORG}/blocks/{USER}"),
+        ResponseTemplate::new(204),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    assert!(client.orgs(ORG).is_blocked(USER).await.unwrap());
+}
+
+#[tokio::test]
+async fn should_report_user_not_blocked() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_blocks_mock(
+        "GET",
+        format!("/orgs/{ORG}/blocks/{USER}"),
+        ResponseTemplate::new(404),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    assert!(!client.orgs(ORG).is_blocked(USER).await.unwrap());
+}
+
+#[tokio::test]
+async fn should_block_user() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_blocks_mock(
+        "PUT",
+        format!("/orgs/{ORG}/blocks/{USER}"),
+        ResponseTemplate::new(204),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).block_user(USER).await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_fail_to_block_user_twice() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_blocks_mock(
+        "PUT",
+        format!("/orgs/{ORG}/blocks/{USER}"),
+        ResponseTemplate::new(422).set_body_json(serde_json::json!({
+            "message": "Blocked user has already been blocked",
+            "documentation_url": "https://docs.github.com/rest/orgs/blocking"
+        })),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).block_user(USER).await;
+    assert!(result.is_err(), "expected error, got {:#?}", result);
+}
+
+#[tokio::test]
+async fn should_unblock_user() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_blocks_mock(
+        "DELETE",
+        format!("/orgs/{ORG}/blocks/{USER}"),
+        ResponseTemplate::new(204),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).unblock_user(USER).await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got {:#?}",
+        result
+    );
+}
@@ -0,0 +1,236 @@
+// Tests for calls to the /orgs/{org}/hooks API.
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::hooks::{Config, ContentType};
+use octocrab::models::webhook_events::WebhookEventType;
+use octocrab::models::HookId;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const HOOK_ID: u64 = 12345678;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn hook_json(active: bool) -> serde_json::Value {
+    json!({
+        "type": "Organization",
+        "id": HOOK_ID,
+        "name": "web",
+        "active": active,
+        "events": ["push", "pull_request"],
+        "config": {
+            "url": "https://example.com/webhook",
+            "content_type": "json",
+            "insecure_ssl": "0"
+        },
+        "url": format!("https://api.github.com/orgs/{ORG}/hooks/{HOOK_ID}"),
+        "ping_url": format!("https://api.github.com/orgs/{ORG}/hooks/{HOOK_ID}/pings"),
+        "deliveries_url": format!("https://api.github.com/orgs/{ORG}/hooks/{HOOK_ID}/deliveries"),
+        "updated_at": "2024-01-01T00:00:00Z",
+        "created_at": "2024-01-01T00:00:00Z"
+    })
+}
+
+async fn mock(
+    mock_server: &MockServer,
+    http_method: &str,
+    route: String,
+    template: ResponseTemplate,
+) {
+    Mock::given(method(http_method))
+        .and(path(route.clone()))
+        .respond_with(template)
+        .mount(mock_server)
+        .await;
+    setup_error_handler(
+        mock_server,
+        &format!("{http_method} on {route} was not received"),
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn should_list_org_hooks() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    mock(
+        &mock_server,
+        "GET",
+        format!("/orgs/{ORG}/hooks"),
+        ResponseTemplate::new(200).set_body_json(json!([hook_json(true)])),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let hooks = client.orgs(ORG).hooks().list().send().await.unwrap();
+    assert_eq!(hooks.items.len(), 1);
+    assert_eq!(hooks.items[0].id, HOOK_ID);
+    assert_eq!(
+        hooks.items[0].events,
+        vec![WebhookEventType::Push, WebhookEventType::PullRequest]
+    );
+}
+
+#[tokio::test]
+async fn should_get_org_hook() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    mock(
+        &mock_server,
+        "GET",
+        format!("/orgs/{ORG}/hooks/{HOOK_ID}"),
+        ResponseTemplate::new(200).set_body_json(hook_json(true)),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let hook = client.orgs(ORG).hooks().get(HookId(HOOK_ID)).await.unwrap();
+    assert!(hook.active);
+    assert_eq!(hook.config.content_type, Some(ContentType::Json));
+}
+
+#[tokio::test]
+async fn should_update_org_hook() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/orgs/{ORG}/hooks/{HOOK_ID}")))
+        .and(body_json(json!({ "active": false, "events": ["push"] })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(hook_json(false)))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "PATCH on org hook was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let hook = client
+        .orgs(ORG)
+        .hooks()
+        .update(HookId(HOOK_ID))
+        .events(vec![WebhookEventType::Push])
+        .active(false)
+        .send()
+        .await
+        .unwrap();
+    assert!(!hook.active);
+}
+
+#[tokio::test]
+async fn should_update_org_hook_config() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    let config = json!({
+        "url": "https://example.com/new",
+        "content_type": "json"
+    });
+    Mock::given(method("PATCH"))
+        .and(path(format!("/orgs/{ORG}/hooks/{HOOK_ID}/config")))
+        .and(body_json(&config))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&config))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "PATCH on org hook config was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let updated = client
+        .orgs(ORG)
+        .hooks()
+        .update_config(
+            HookId(HOOK_ID),
+            &Config {
+                url: "https://example.com/new".to_string(),
+                content_type: Some(ContentType::Json),
+                ..Config::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(updated.url, "https://example.com/new");
+}
+
+#[tokio::test]
+async fn should_ping_and_delete_org_hook() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/orgs/{ORG}/hooks/{HOOK_ID}/pings")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/orgs/{ORG}/hooks/{HOOK_ID}")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let hooks = client.orgs(ORG).hooks();
+
+    hooks.ping(HookId(HOOK_ID)).await.unwrap();
+    hooks.delete(HookId(HOOK_ID)).await.unwrap();
+}
+
+#[tokio::test]
+async fn should_fail_to_delete_missing_org_hook() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    mock(
+        &mock_server,
+        "DELETE",
+        format!("/orgs/{ORG}/hooks/{HOOK_ID}"),
+        ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest/orgs/webhooks"
+        })),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.orgs(ORG).hooks().delete(HookId(HOOK_ID)).await;
+    assert!(result.is_err(), "expected error, got {:#?}", result);
+}
+
+#[tokio::test]
+async fn should_list_repo_hooks() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    mock(
+        &mock_server,
+        "GET",
+        format!("/repos/{ORG}/repo/hooks"),
+        ResponseTemplate::new(200).set_body_json(json!([hook_json(true)])),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let hooks = client
+        .hooks(ORG)
+        .repo("repo".to_string())
+        .list()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(hooks.items.len(), 1);
+}
@@ -0,0 +1,246 @@
+// Tests for calls to the /orgs/{org}/custom-repository-roles and
+// /orgs/{org}/organization-roles APIs.
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::RoleId;
+use octocrab::params::orgs::BaseRole;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const ROLE_ID: u64 = 8030;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn custom_role_json() -> serde_json::Value {
+    json!({
+        "id": ROLE_ID,
+        "name": "Security Engineer",
+        "description": "Able to contribute code and maintain the security pipeline",
+        "base_role": "maintain",
+        "permissions": ["delete_alerts_code_scanning"],
+        "created_at": "2022-07-04T22:19:11Z",
+        "updated_at": "2022-07-04T22:20:11Z"
+    })
+}
+
+fn org_role_json() -> serde_json::Value {
+    json!({
+        "id": ROLE_ID,
+        "name": "Custom Role Manager",
+        "description": "Permissions to manage custom roles within an org",
+        "permissions": ["write_organization_custom_repo_role"],
+        "base_role": null,
+        "source": "Organization",
+        "created_at": "2022-07-04T22:19:11Z",
+        "updated_at": "2022-07-04T22:20:11Z"
+    })
+}
+
+#[tokio::test]
+async fn should_list_custom_repository_roles() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/custom-repository-roles")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "custom_roles": [custom_role_json()]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on custom roles was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let roles = client
+        .orgs(ORG)
+        .custom_repository_roles()
+        .list()
+        .await
+        .unwrap();
+    assert_eq!(roles.total_count, 1);
+    assert_eq!(roles.custom_roles[0].id, RoleId(ROLE_ID));
+    assert_eq!(roles.custom_roles[0].base_role, BaseRole::Maintain);
+}
+
+#[tokio::test]
+async fn should_create_and_update_custom_repository_role() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/orgs/{ORG}/custom-repository-roles")))
+        .and(body_json(json!({
+            "name": "Security Engineer",
+            "base_role": "maintain",
+            "permissions": ["delete_alerts_code_scanning"]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(custom_role_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/orgs/{ORG}/custom-repository-roles/{ROLE_ID}"
+        )))
+        .and(body_json(json!({ "base_role": "write" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(custom_role_json()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let org = client.orgs(ORG);
+    let roles = org.custom_repository_roles();
+
+    let created = roles
+        .create("Security Engineer", BaseRole::Maintain)
+        .permissions(vec!["delete_alerts_code_scanning".to_string()])
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(created.name, "Security Engineer");
+
+    roles
+        .update(RoleId(ROLE_ID))
+        .base_role(BaseRole::Write)
+        .send()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn should_delete_custom_repository_role() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/orgs/{ORG}/custom-repository-roles/{ROLE_ID}"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "DELETE on custom role was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .custom_repository_roles()
+        .delete(RoleId(ROLE_ID))
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_list_org_roles() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/organization-roles")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "roles": [org_role_json()]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on org roles was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let roles = client.orgs(ORG).roles().list().await.unwrap();
+    assert_eq!(roles.roles.len(), 1);
+    assert_eq!(roles.roles[0].base_role, None);
+    assert_eq!(roles.roles[0].source.as_deref(), Some("Organization"));
+}
+
+#[tokio::test]
+async fn should_assign_and_revoke_org_roles() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    for (http_method, route) in [
+        (
+            "PUT",
+            format!("/orgs/{ORG}/organization-roles/teams/security/{ROLE_ID}"),
+        ),
+        (
+            "DELETE",
+            format!("/orgs/{ORG}/organization-roles/teams/security/{ROLE_ID}"),
+        ),
+        (
+            "PUT",
+            format!("/orgs/{ORG}/organization-roles/users/ferris/{ROLE_ID}"),
+        ),
+        (
+            "DELETE",
+            format!("/orgs/{ORG}/organization-roles/users/ferris"),
+        ),
+    ] {
+        Mock::given(method(http_method))
+            .and(path(route))
+            .respond_with(ResponseTemplate::new(204))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    let client = setup_octocrab(&mock_server.uri());
+    let org = client.orgs(ORG);
+    let roles = org.roles();
+
+    roles
+        .assign_to_team("security", RoleId(ROLE_ID))
+        .await
+        .unwrap();
+    roles
+        .revoke_from_team("security", RoleId(ROLE_ID))
+        .await
+        .unwrap();
+    roles
+        .assign_to_user("ferris", RoleId(ROLE_ID))
+        .await
+        .unwrap();
+    roles.revoke_all_from_user("ferris").await.unwrap();
+}
+
+#[tokio::test]
+async fn should_fail_to_assign_unknown_role() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/orgs/{ORG}/organization-roles/users/ferris/1"
+        )))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": "Validation Failed",
+            "documentation_url": "https://docs.github.com/rest/orgs/organization-roles"
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .roles()
+        .assign_to_user("ferris", RoleId(1))
+        .await;
+    assert!(result.is_err(), "expected error, got {:#?}", result);
+}
@@ -0,0 +1,265 @@
+// Tests for the Dependabot and Codespaces /orgs/{ORG}/{product}/secrets APIs
+// and the /orgs/{ORG}/actions/variables API.
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::orgs::secrets::{CreateOrganizationSecret, Visibility};
+use octocrab::models::orgs::variables::{CreateOrganizationVariable, UpdateOrganizationVariable};
+use octocrab::models::RepositoryId;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "some-org";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn selected_repositories() -> serde_json::Value {
+    let repos: Vec<serde_json::Value> =
+        serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+    json!({ "total_count": 1, "repositories": [repos[0]] })
+}
+
+#[tokio::test]
+async fn should_list_dependabot_secrets() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/dependabot/secrets")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "secrets": [{
+                "name": "NPM_TOKEN",
+                "created_at": "2019-08-10T14:59:22Z",
+                "updated_at": "2020-01-10T14:59:22Z",
+                "visibility": "selected",
+                "selected_repositories_url": format!("https://api.github.com/orgs/{ORG}/dependabot/secrets/NPM_TOKEN/repositories")
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on dependabot secrets was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let secrets = client
+        .orgs(ORG)
+        .dependabot_secrets()
+        .get_secrets()
+        .await
+        .unwrap();
+    assert_eq!(secrets.total_count, 1);
+    assert_eq!(secrets.secrets[0].visibility, Visibility::Selected);
+}
+
+#[tokio::test]
+async fn should_create_codespaces_secret() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!("/orgs/{ORG}/codespaces/secrets/GH_TOKEN")))
+        .and(body_json(json!({
+            "encrypted_value": "c2VjcmV0",
+            "key_id": "123456",
+            "visibility": "all"
+        })))
+        .respond_with(ResponseTemplate::new(201))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "PUT on codespaces secret was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .codespaces_secrets()
+        .create_or_update_secret(
+            "GH_TOKEN",
+            &CreateOrganizationSecret {
+                encrypted_value: "c2VjcmV0",
+                key_id: "123456",
+                visibility: Visibility::All,
+                selected_repository_ids: None,
+            },
+        )
+        .await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_manage_dependabot_secret_repositories() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let route = format!("/orgs/{ORG}/dependabot/secrets/NPM_TOKEN/repositories");
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(route.clone()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(selected_repositories()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(route.clone()))
+        .and(body_json(json!({ "selected_repository_ids": [1, 2] })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path(format!("{route}/3")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("{route}/3")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let org = client.orgs(ORG);
+    let secrets = org.dependabot_secrets();
+
+    let repos = secrets
+        .list_selected_repositories("NPM_TOKEN")
+        .await
+        .unwrap();
+    assert_eq!(repos.total_count, 1);
+    assert_eq!(repos.repositories[0].name, "actix-examples");
+    secrets
+        .set_selected_repositories("NPM_TOKEN", &[RepositoryId(1), RepositoryId(2)])
+        .await
+        .unwrap();
+    secrets
+        .add_selected_repository("NPM_TOKEN", RepositoryId(3))
+        .await
+        .unwrap();
+    secrets
+        .remove_selected_repository("NPM_TOKEN", RepositoryId(3))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn should_get_org_variable() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/actions/variables/USERNAME")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "name": "USERNAME",
+            "value": "octocat",
+            "created_at": "2019-08-10T14:59:22Z",
+            "updated_at": "2020-01-10T14:59:22Z",
+            "visibility": "private"
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on org variable was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let variable = client
+        .orgs(ORG)
+        .variables()
+        .get_variable("USERNAME")
+        .await
+        .unwrap();
+    assert_eq!(variable.value, "octocat");
+    assert_eq!(variable.visibility, Visibility::Private);
+}
+
+#[tokio::test]
+async fn should_create_update_and_delete_org_variable() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/orgs/{ORG}/actions/variables")))
+        .and(body_json(json!({
+            "name": "USERNAME",
+            "value": "octocat",
+            "visibility": "selected",
+            "selected_repository_ids": [1]
+        })))
+        .respond_with(ResponseTemplate::new(201))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/orgs/{ORG}/actions/variables/USERNAME")))
+        .and(body_json(json!({ "value": "ferris" })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/orgs/{ORG}/actions/variables/USERNAME")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let org = client.orgs(ORG);
+    let variables = org.variables();
+
+    variables
+        .create_variable(&CreateOrganizationVariable {
+            name: "USERNAME",
+            value: "octocat",
+            visibility: Visibility::Selected,
+            selected_repository_ids: Some(&[1]),
+        })
+        .await
+        .unwrap();
+    variables
+        .update_variable(
+            "USERNAME",
+            &UpdateOrganizationVariable {
+                value: Some("ferris"),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    variables.delete_variable("USERNAME").await.unwrap();
+}
+
+#[tokio::test]
+async fn should_fail_to_delete_missing_org_variable() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/orgs/{ORG}/actions/variables/MISSING")))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest/actions/variables"
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .orgs(ORG)
+        .variables()
+        .delete_variable("MISSING")
+        .await;
+    assert!(result.is_err(), "expected error, got {:#?}", result);
+}
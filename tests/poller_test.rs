@@ -0,0 +1,127 @@
+#![cfg(feature = "stream")]
+
+mod mock_error;
+
+use std::time::Duration;
+
+use futures_util::{StreamExt, TryStreamExt};
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::Value;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn event(id: &str) -> Value {
+    let mut event: Value =
+        serde_json::from_str(include_str!("resources/create_event.json")).unwrap();
+    event["id"] = Value::from(id);
+    event
+}
+
+#[tokio::test]
+async fn should_stream_new_events_once() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/events"))
+        .and(header("if-none-match", "\"first\""))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"second\"")
+                .insert_header("x-poll-interval", "0")
+                .set_body_json(vec![event("3"), event("2")]),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/events"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"first\"")
+                .insert_header("x-poll-interval", "0")
+                .set_body_json(vec![event("2"), event("1")]),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/events was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let ids: Vec<String> = client
+        .repos("owner", "repo")
+        .events()
+        .poller()
+        .unwrap()
+        .interval(Duration::ZERO)
+        .into_stream()
+        .take(3)
+        .map_ok(|event| event.id)
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(ids, ["1", "2", "3"]);
+}
+
+#[tokio::test]
+async fn should_skip_initial_events() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/events"))
+        .and(header("if-none-match", "\"first\""))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"second\"")
+                .set_body_json(vec![event("3"), event("2")]),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/events"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"first\"")
+                .set_body_json(vec![event("2"), event("1")]),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/events was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let stream = client
+        .repos("owner", "repo")
+        .events()
+        .poller()
+        .unwrap()
+        .interval(Duration::ZERO)
+        .skip_initial(true)
+        .into_stream();
+    futures_util::pin_mut!(stream);
+    let event = stream.try_next().await.unwrap().unwrap();
+
+    assert_eq!(event.id, "3");
+}
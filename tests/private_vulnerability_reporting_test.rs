@@ -0,0 +1,207 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn reported_advisory() -> serde_json::Value {
+    json!({
+        "ghsa_id": "GHSA-abcd-1234-efgh",
+        "cve_id": null,
+        "url": "https://api.github.com/repos/org/some-repo/security-advisories/GHSA-abcd-1234-efgh",
+        "html_url": "https://github.com/org/some-repo/security/advisories/GHSA-abcd-1234-efgh",
+        "summary": "Remote code execution",
+        "description": "Templates are evaluated without sandboxing.",
+        "severity": null,
+        "author": null,
+        "publisher": null,
+        "identifiers": [{ "type": "GHSA", "value": "GHSA-abcd-1234-efgh" }],
+        "state": "triage",
+        "created_at": "2023-01-01T00:00:00Z",
+        "updated_at": "2023-01-01T00:00:00Z",
+        "published_at": null,
+        "closed_at": null,
+        "withdrawn_at": null,
+        "submission": { "accepted": false },
+        "vulnerabilities": null,
+        "cvss": null,
+        "cwes": null,
+        "cwe_ids": null,
+        "credits": null,
+        "collaborating_users": null
+    })
+}
+
+#[tokio::test]
+async fn should_check_private_reporting_is_enabled() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/private-vulnerability-reporting"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "enabled": true })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/org/some-repo/private-vulnerability-reporting was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .security_advisories()
+        .is_private_reporting_enabled()
+        .await;
+
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn should_enable_and_disable_private_reporting() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/private-vulnerability-reporting"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/private-vulnerability-reporting"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "PUT or DELETE on /repos/org/some-repo/private-vulnerability-reporting was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let advisories = repos.security_advisories();
+
+    assert!(advisories.enable_private_reporting().await.is_ok());
+    assert!(advisories.disable_private_reporting().await.is_ok());
+}
+
+#[tokio::test]
+async fn should_fail_to_enable_private_reporting_without_permission() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/private-vulnerability-reporting"
+        )))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": "Private vulnerability reporting can't be enabled",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "PUT on /repos/org/some-repo/private-vulnerability-reporting was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos.security_advisories().enable_private_reporting().await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn should_list_reported_advisories() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/security-advisories")))
+        .and(query_param("state", "triage"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([reported_advisory()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/org/some-repo/security-advisories was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos.security_advisories().list_reported().send().await;
+
+    let reports = result.unwrap().items;
+    assert_eq!(reports.len(), 1);
+    assert_eq!(
+        reports[0].submission.as_ref().map(|s| s.accepted),
+        Some(false)
+    );
+}
+
+#[tokio::test]
+async fn should_report_vulnerability() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/security-advisories/reports"
+        )))
+        .and(body_json(json!({
+            "summary": "Remote code execution",
+            "description": "Templates are evaluated without sandboxing.",
+            "cwe_ids": ["CWE-94"]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(reported_advisory()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /repos/org/some-repo/security-advisories/reports was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .security_advisories()
+        .report(
+            "Remote code execution",
+            "Templates are evaluated without sandboxing.",
+        )
+        .cwe_ids(vec!["CWE-94".to_string()])
+        .send()
+        .await;
+
+    assert_eq!(result.unwrap().ghsa_id, "GHSA-abcd-1234-efgh");
+}
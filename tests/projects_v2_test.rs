@@ -0,0 +1,274 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::projects_v2::{ProjectV2ItemContent, ProjectV2ItemFieldValue};
+use octocrab::params::projects_v2::FieldValue;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const PROJECT_ID: &str = "PVT_kwDOAAABcM4AAbcd";
+const ITEM_ID: &str = "PVTI_lADOAAABcM4AAbcd";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_org_project() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": { "login": "github", "number": 1 }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "organization": {
+                    "projectV2": {
+                        "id": PROJECT_ID,
+                        "number": 1,
+                        "title": "Roadmap",
+                        "shortDescription": null,
+                        "readme": null,
+                        "public": false,
+                        "closed": false,
+                        "url": "https://github.com/orgs/github/projects/1",
+                        "createdAt": "2023-01-01T00:00:00Z",
+                        "updatedAt": "2023-01-02T00:00:00Z",
+                        "fields": {
+                            "nodes": [
+                                { "id": "PVTF_1", "name": "Title", "dataType": "TITLE" },
+                                {
+                                    "id": "PVTSSF_1",
+                                    "name": "Status",
+                                    "dataType": "SINGLE_SELECT",
+                                    "options": [
+                                        { "id": "f75ad846", "name": "Todo" },
+                                        { "id": "47fc9ee4", "name": "In Progress" }
+                                    ]
+                                },
+                                {
+                                    "id": "PVTIF_1",
+                                    "name": "Iteration",
+                                    "dataType": "ITERATION",
+                                    "configuration": {
+                                        "iterations": [{
+                                            "id": "cfc16e4d",
+                                            "title": "Iteration 1",
+                                            "startDate": "2023-01-02",
+                                            "duration": 14
+                                        }],
+                                        "completedIterations": []
+                                    }
+                                }
+                            ]
+                        }
+                    }
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.projects().v2().get_org_project("github", 1).await;
+
+    let project = result.unwrap();
+    assert_eq!(project.id, PROJECT_ID);
+    assert_eq!(project.fields.len(), 3);
+    assert_eq!(
+        project.fields[1].options.as_ref().unwrap()[1].id,
+        "47fc9ee4"
+    );
+    assert_eq!(
+        project.fields[2].configuration.as_ref().unwrap().iterations[0].duration,
+        14
+    );
+}
+
+#[tokio::test]
+async fn should_list_project_items_with_field_values() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": { "id": PROJECT_ID, "first": 10, "after": "Y3Vyc29y" }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "node": {
+                    "items": {
+                        "pageInfo": { "hasNextPage": false, "endCursor": null },
+                        "nodes": [{
+                            "id": ITEM_ID,
+                            "type": "ISSUE",
+                            "isArchived": false,
+                            "content": {
+                                "__typename": "Issue",
+                                "id": "I_kwDOAAABcM5AAbcd",
+                                "number": 7,
+                                "title": "Crash on start",
+                                "url": "https://github.com/github/repo/issues/7"
+                            },
+                            "fieldValues": {
+                                "nodes": [
+                                    {
+                                        "__typename": "ProjectV2ItemFieldTextValue",
+                                        "text": "Crash on start",
+                                        "field": { "id": "PVTF_1", "name": "Title" }
+                                    },
+                                    {
+                                        "__typename": "ProjectV2ItemFieldSingleSelectValue",
+                                        "name": "In Progress",
+                                        "optionId": "47fc9ee4",
+                                        "field": { "id": "PVTSSF_1", "name": "Status" }
+                                    },
+                                    { "__typename": "ProjectV2ItemFieldLabelValue" }
+                                ]
+                            }
+                        }]
+                    }
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .projects()
+        .v2()
+        .list_items(PROJECT_ID)
+        .per_page(10)
+        .after("Y3Vyc29y")
+        .send()
+        .await;
+
+    let items = result.unwrap();
+    assert!(!items.page_info.has_next_page);
+    let item = &items.items[0];
+    assert!(matches!(
+        item.content,
+        Some(ProjectV2ItemContent::Issue { number: 7, .. })
+    ));
+    assert!(matches!(
+        &item.field_values[1],
+        ProjectV2ItemFieldValue::SingleSelect { option_id: Some(id), .. } if id == "47fc9ee4"
+    ));
+    assert_eq!(item.field_values[2], ProjectV2ItemFieldValue::Other);
+}
+
+#[tokio::test]
+async fn should_update_field_value() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": {
+                "input": {
+                    "projectId": PROJECT_ID,
+                    "itemId": ITEM_ID,
+                    "fieldId": "PVTF_2",
+                    "value": { "number": 3.0 }
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "updateProjectV2ItemFieldValue": { "projectV2Item": { "id": ITEM_ID } }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .projects()
+        .v2()
+        .update_field_value(PROJECT_ID, ITEM_ID, "PVTF_2", FieldValue::Number(3.0))
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn should_add_draft_issue() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": {
+                "input": { "projectId": PROJECT_ID, "title": "Investigate flaky test" }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "addProjectV2DraftIssue": { "projectItem": { "id": ITEM_ID } }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .projects()
+        .v2()
+        .add_draft_issue(PROJECT_ID, "Investigate flaky test", None)
+        .await;
+
+    assert_eq!(result.unwrap(), ITEM_ID);
+}
+
+#[tokio::test]
+async fn should_return_graphql_errors() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "deleteProjectV2Item": null },
+            "errors": [{
+                "type": "NOT_FOUND",
+                "message": "Could not resolve to a node with the global id of 'PVTI_missing'"
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .projects()
+        .v2()
+        .remove_item(PROJECT_ID, "PVTI_missing")
+        .await;
+
+    match result.unwrap_err() {
+        octocrab::Error::GitHub { source, .. } => {
+            assert!(source.message.contains("PVTI_missing"));
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
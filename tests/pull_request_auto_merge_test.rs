@@ -0,0 +1,108 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::params::pulls::MergeMethod;
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const PULL_NUMBER: u64 = 2;
+const NODE_ID: &str = "PR_kwDOIAlVv85VyQ2w";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api(mutation_response: Value, expected_input: Value) -> MockServer {
+    let event: Value = serde_json::from_str(include_str!(
+        "resources/pull_request_opened_webhook_event.json"
+    ))
+    .unwrap();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&event["pull_request"]))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": { "input": expected_input }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mutation_response))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on the pull request or POST on /graphql was not received",
+    )
+    .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_enable_auto_merge() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api(
+        json!({ "data": { "enablePullRequestAutoMerge": { "clientMutationId": null } } }),
+        json!({
+            "pullRequestId": NODE_ID,
+            "mergeMethod": "SQUASH",
+            "commitHeadline": "Squashed",
+            "expectedHeadOid": "0123456"
+        }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .enable_auto_merge(PULL_NUMBER)
+        .method(MergeMethod::Squash)
+        .title("Squashed")
+        .sha("0123456")
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_surface_graphql_errors_when_disabling_auto_merge() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api(
+        json!({
+            "data": { "disablePullRequestAutoMerge": null },
+            "errors": [{ "type": "UNPROCESSABLE", "message": "Auto merge is not enabled" }]
+        }),
+        json!({ "pullRequestId": NODE_ID }),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .disable_auto_merge(PULL_NUMBER)
+        .await;
+
+    match result.unwrap_err() {
+        octocrab::Error::GitHub { source, .. } => {
+            assert_eq!(source.message, "Auto merge is not enabled");
+        }
+        other => panic!("unexpected error: {:?}", other),
+    }
+}
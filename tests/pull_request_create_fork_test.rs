@@ -0,0 +1,86 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api() -> MockServer {
+    let event: Value = serde_json::from_str(include_str!(
+        "resources/pull_request_opened_webhook_event.json"
+    ))
+    .unwrap();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls")))
+        .and(body_partial_json(json!({
+            "head": "octocat:feature",
+            "base": "main",
+            "maintainer_can_modify": true
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(&event["pull_request"]))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /repos/{OWNER}/{REPO}/pulls was not received"),
+    )
+    .await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_create_pull_request_from_fork() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create("Add feature", "feature", "main")
+        .from_fork("octocat", "feature")
+        .maintainer_can_modify(true)
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_reject_malformed_fork_head_before_sending() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .create("Add feature", "octocat:", "main")
+        .send()
+        .await;
+
+    match result {
+        Err(octocrab::Error::Other { source, .. }) => {
+            assert!(source.to_string().contains("owner:branch"), "{}", source);
+        }
+        other => panic!("expected validation error, got {:?}", other),
+    }
+}
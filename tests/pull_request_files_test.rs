@@ -0,0 +1,116 @@
+use serde_json::json;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::models::repos::diff::{DiffLineKind, Patch};
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const PULL_NUMBER: u64 = 42;
+const PATCH: &str = "@@ -1,3 +1,4 @@ fn main() {\n     let a = 1;\n-    let b = 2;\n+    let b = 3;\n+    let c = 4;\n     println!(\"{a}\");\n\\ No newline at end of file";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_files_with_parsed_patch() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/files")))
+        .and(query_param("per_page", "100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "sha": "bbcd538c8e72b8c175046e27cc8f907076331401",
+                "filename": "src/main.rs",
+                "status": "modified",
+                "additions": 2,
+                "deletions": 1,
+                "changes": 3,
+                "blob_url": "https://github.com/octocat/Hello-World/blob/6dcb09b/src/main.rs",
+                "raw_url": "https://github.com/octocat/Hello-World/raw/6dcb09b/src/main.rs",
+                "contents_url": "https://api.github.com/repos/octocat/Hello-World/contents/src/main.rs?ref=6dcb09b",
+                "patch": PATCH
+            },
+            {
+                "sha": null,
+                "filename": "logo.png",
+                "status": "added",
+                "additions": 0,
+                "deletions": 0,
+                "changes": 0,
+                "blob_url": null,
+                "raw_url": null,
+                "contents_url": "https://api.github.com/repos/octocat/Hello-World/contents/logo.png?ref=6dcb09b"
+            }
+        ])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/files was not received"),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .pulls(OWNER, REPO)
+        .files(PULL_NUMBER)
+        .per_page(100)
+        .send()
+        .await;
+
+    let files = result.unwrap().items;
+    assert_eq!(files.len(), 2);
+    assert!(files[1].parsed_patch().is_none());
+
+    let patch = files[0].parsed_patch().unwrap().unwrap();
+    assert_eq!(patch.hunks.len(), 1);
+    let hunk = &patch.hunks[0];
+    assert_eq!((hunk.old_start, hunk.old_lines), (1, 3));
+    assert_eq!((hunk.new_start, hunk.new_lines), (1, 4));
+    assert_eq!(hunk.section.as_deref(), Some("fn main() {"));
+
+    let lines: Vec<_> = patch
+        .lines()
+        .map(|line| (line.kind, line.old_line, line.new_line))
+        .collect();
+    assert_eq!(
+        lines,
+        vec![
+            (DiffLineKind::Context, Some(1), Some(1)),
+            (DiffLineKind::Deletion, Some(2), None),
+            (DiffLineKind::Addition, None, Some(2)),
+            (DiffLineKind::Addition, None, Some(3)),
+            (DiffLineKind::Context, Some(3), Some(4)),
+        ]
+    );
+    assert_eq!(hunk.lines[2].content, "    let b = 3;");
+}
+
+#[test]
+fn should_parse_multiple_hunks() {
+    let patch: Patch = "@@ -10 +10,2 @@\n-a\n+b\n+c\n@@ -20,2 +21,2 @@\n x\n-y\n+z"
+        .parse()
+        .unwrap();
+
+    assert_eq!(patch.hunks.len(), 2);
+    assert_eq!(patch.hunks[0].old_lines, 1);
+    assert!(patch.hunks[0].section.is_none());
+    assert_eq!(patch.hunks[1].lines[2].new_line, Some(22));
+}
+
+#[test]
+fn should_reject_lines_outside_hunks() {
+    let error = Patch::parse("+orphan").unwrap_err();
+
+    assert_eq!(error.line(), 1);
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use serde_json::{json, Value};
+use wiremock::matchers::{method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::models::pulls::MergeableState;
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const PULL_NUMBER: u64 = 2;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn pull_request(mergeable: Option<bool>, mergeable_state: &str) -> Value {
+    let event: Value = serde_json::from_str(include_str!(
+        "resources/pull_request_opened_webhook_event.json"
+    ))
+    .unwrap();
+    let mut pull_request = event["pull_request"].clone();
+    pull_request["mergeable"] = json!(mergeable);
+    pull_request["mergeable_state"] = json!(mergeable_state);
+    pull_request
+}
+
+#[tokio::test]
+async fn should_poll_until_mergeability_is_known() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pull_request(None, "unknown")))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pull_request(Some(false), "dirty")))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER} was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .wait_for_mergeability(PULL_NUMBER, Duration::from_secs(10))
+        .await;
+
+    assert_eq!(result.unwrap(), MergeableState::Dirty);
+}
+
+#[tokio::test]
+async fn should_time_out_while_mergeability_is_unknown() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pull_request(None, "unknown")))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER} was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .pulls(OWNER, REPO)
+        .wait_for_mergeability(PULL_NUMBER, Duration::from_millis(200))
+        .await;
+
+    assert!(matches!(result, Err(octocrab::Error::Other { .. })));
+}
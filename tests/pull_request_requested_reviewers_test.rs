@@ -0,0 +1,115 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const PULL_NUMBER: u64 = 42;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn user() -> Value {
+    let comment: Value =
+        serde_json::from_str(include_str!("resources/pull_request_review_comment.json")).unwrap();
+    comment["user"].clone()
+}
+
+fn team() -> Value {
+    json!({
+        "id": 1,
+        "node_id": "MDQ6VGVhbTE=",
+        "url": "https://api.github.com/teams/1",
+        "html_url": "https://github.com/orgs/github/teams/justice-league",
+        "name": "Justice League",
+        "slug": "justice-league",
+        "description": "A great team.",
+        "privacy": "closed",
+        "permission": "admin",
+        "members_url": "https://api.github.com/teams/1/members{/member}",
+        "repositories_url": "https://api.github.com/teams/1/repos",
+        "parent": null
+    })
+}
+
+#[tokio::test]
+async fn should_list_requested_reviewers() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/requested_reviewers"
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "users": [user()],
+            "teams": [team()]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!(
+            "GET on /repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/requested_reviewers was not received"
+        ),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .pulls(OWNER, REPO)
+        .list_requested_reviewers(PULL_NUMBER)
+        .await;
+
+    let requested = result.unwrap();
+    assert_eq!(requested.users.len(), 1);
+    assert_eq!(requested.teams.len(), 1);
+    assert_eq!(requested.teams[0].slug, "justice-league");
+}
+
+#[tokio::test]
+async fn should_remove_requested_team_reviewers() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/requested_reviewers"
+        )))
+        .and(body_json(json!({
+            "reviewers": [],
+            "team_reviewers": ["justice-league"]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1,
+            "node_id": "MDExOlB1bGxSZXF1ZXN0MQ==",
+            "html_url": "https://github.com/octocat/Hello-World/pull/1347",
+            "user": null
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!(
+            "DELETE on /repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/requested_reviewers was not received"
+        ),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .pulls(OWNER, REPO)
+        .remove_requested_reviewers(PULL_NUMBER, Vec::new(), ["justice-league".to_string()])
+        .await;
+
+    assert!(result.is_ok());
+}
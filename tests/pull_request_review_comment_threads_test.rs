@@ -0,0 +1,93 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_partial_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::models::pulls::{Comment, CommentThread};
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const PULL_NUMBER: u64 = 42;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn comment(id: u64, in_reply_to: Option<u64>, created_at: &str) -> Comment {
+    let mut value: Value =
+        serde_json::from_str(include_str!("resources/pull_request_review_comment.json")).unwrap();
+    value["id"] = json!(id);
+    value["in_reply_to_id"] = json!(in_reply_to);
+    value["created_at"] = json!(created_at);
+    serde_json::from_value(value).unwrap()
+}
+
+#[tokio::test]
+async fn should_create_reply_comment() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/comments"
+        )))
+        .and(body_partial_json(json!({
+            "body": "Agreed",
+            "in_reply_to": 10
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(comment(
+            11,
+            Some(10),
+            "2011-04-14T16:01:49Z",
+        )))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("POST on /repos/{OWNER}/{REPO}/pulls/{PULL_NUMBER}/comments was not received"),
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .pulls(OWNER, REPO)
+        .create_comment(PULL_NUMBER, "Agreed")
+        .in_reply_to(10u64)
+        .send()
+        .await;
+
+    let comment = result.unwrap();
+    assert_eq!(comment.id.0, 11);
+    assert_eq!(comment.in_reply_to_id.map(|id| id.0), Some(10));
+}
+
+#[test]
+fn should_group_comments_into_threads() {
+    let comments = vec![
+        comment(3, Some(1), "2011-04-14T16:03:00Z"),
+        comment(2, None, "2011-04-14T16:02:00Z"),
+        comment(4, Some(3), "2011-04-14T16:04:00Z"),
+        comment(1, None, "2011-04-14T16:01:00Z"),
+    ];
+
+    let threads = CommentThread::from_comments(comments);
+
+    assert_eq!(threads.len(), 2);
+    assert_eq!(threads[0].root.id.0, 1);
+    assert_eq!(
+        threads[0]
+            .replies
+            .iter()
+            .map(|c| c.id.0)
+            .collect::<Vec<_>>(),
+        vec![3, 4]
+    );
+    assert_eq!(threads[0].path(), "file1.txt");
+    assert_eq!(threads[1].root.id.0, 2);
+    assert!(threads[1].replies.is_empty());
+}
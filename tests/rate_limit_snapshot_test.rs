@@ -0,0 +1,158 @@
+mod mock_error;
+
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn rate_limited(resource: &str, limit: u64, remaining: u64, reset: u64) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .set_body_json(json!({
+            "total_count": 40,
+            "incomplete_results": true,
+            "items": []
+        }))
+        .insert_header("x-ratelimit-limit", limit.to_string().as_str())
+        .insert_header("x-ratelimit-remaining", remaining.to_string().as_str())
+        .insert_header("x-ratelimit-used", (limit - remaining).to_string().as_str())
+        .insert_header("x-ratelimit-reset", reset.to_string().as_str())
+        .insert_header("x-ratelimit-resource", resource)
+}
+
+async fn setup_api(search_reset: u64) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/issues"))
+        .respond_with(rate_limited("search", 30, 0, search_reset))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/search/code"))
+        .respond_with(rate_limited("code_search", 10, 9, now() + 60))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /search was not received").await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_record_search_buckets_separately() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api(now() + 60).await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+
+    let page = client
+        .search()
+        .issues_and_pull_requests("is:open")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(page.total_count, Some(40));
+    assert_eq!(page.incomplete_results, Some(true));
+    client.search().code("octocrab").send().await.unwrap();
+
+    let snapshot = client.ratelimit().snapshot();
+    let search = snapshot.get("search").unwrap();
+    assert_eq!((search.limit, search.remaining, search.used), (30, 0, 30));
+    assert_eq!(snapshot.get("code_search").unwrap().remaining, 9);
+    assert!(snapshot.get("core").is_none());
+}
+
+#[tokio::test]
+async fn should_throttle_exhausted_search_bucket_until_reset() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api(now() + 2).await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .throttle_rate_limits(true)
+        .build()
+        .unwrap();
+
+    client
+        .search()
+        .issues_and_pull_requests("is:open")
+        .send()
+        .await
+        .unwrap();
+
+    // The code search budget is separate, so it isn't held up.
+    let start = Instant::now();
+    client.search().code("octocrab").send().await.unwrap();
+    assert!(start.elapsed() < Duration::from_secs(1));
+
+    let start = Instant::now();
+    client
+        .search()
+        .issues_and_pull_requests("is:open")
+        .send()
+        .await
+        .unwrap();
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn should_record_graphql_rate_limit_from_query() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains("octocrabRateLimit: rateLimit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "octocrabRateLimit": {
+                    "cost": 1,
+                    "limit": 5000,
+                    "used": 3,
+                    "remaining": 4997,
+                    "resetAt": "2030-01-01T00:00:00Z"
+                },
+                "viewer": { "login": "octocat" }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .track_graphql_rate_limit(true)
+        .build()
+        .unwrap();
+    let response: serde_json::Value = client
+        .graphql(&json!({ "query": "{ viewer { login } }" }))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        response,
+        json!({ "data": { "viewer": { "login": "octocat" } } })
+    );
+    let graphql = client.ratelimit().snapshot().get("graphql").unwrap();
+    assert_eq!(graphql.remaining, 4997);
+    assert_eq!(graphql.reset, 1893456000);
+    assert_eq!(client.ratelimit().snapshot().last_graphql_cost(), Some(1));
+}
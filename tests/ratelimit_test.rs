@@ -0,0 +1,61 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn rate(limit: usize, used: usize) -> serde_json::Value {
+    json!({
+        "limit": limit,
+        "used": used,
+        "remaining": limit - used,
+        "reset": 1691591363
+    })
+}
+
+#[tokio::test]
+async fn should_get_typed_rate_limit_resources() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/rate_limit"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "resources": {
+                "core": rate(5000, 1),
+                "search": rate(30, 12),
+                "graphql": rate(5000, 7),
+                "integration_manifest": rate(5000, 1),
+                "code_scanning_upload": rate(500, 1),
+                "actions_runner_registration": rate(10000, 0),
+                "scim": rate(15000, 0),
+                "dependency_snapshots": rate(100, 0),
+                "code_search": rate(10, 0)
+            },
+            "rate": rate(5000, 1)
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /rate_limit was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let ratelimit = client.ratelimit().get().await.unwrap();
+
+    let resources = ratelimit.resources;
+    assert_eq!(resources.search.remaining, 18);
+    assert_eq!(resources.graphql.unwrap().used, 7);
+    assert_eq!(resources.code_scanning_upload.unwrap().limit, 500);
+    assert_eq!(resources.dependency_snapshots.unwrap().limit, 100);
+    assert_eq!(resources.code_search.unwrap().limit, 10);
+    assert!(resources.audit_log.is_none());
+    assert_eq!(resources.core.reset_at().unwrap().timestamp(), 1691591363);
+}
@@ -0,0 +1,64 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{Octocrab, RawResponse};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_the_raw_response() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let body = r#"[{"login":"octocat","id":1},{"login":"hubot","id":2}]"#;
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/members"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("etag", "\"abc\"")
+                .set_body_raw(body, "application/json"),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /orgs/org/members was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let response: RawResponse = client.get("/orgs/org/members", None::<&()>).await.unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.headers["etag"], "\"abc\"");
+    assert_eq!(response.body, body.as_bytes());
+    assert_eq!(response.raw_json().unwrap().get(), body);
+    let logins: Vec<serde_json::Value> = response.json().unwrap();
+    assert_eq!(logins[1]["login"], "hubot");
+}
+
+#[tokio::test]
+async fn should_still_map_errors_of_raw_responses() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/org/members"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .get::<RawResponse, _, _>("/orgs/org/members", None::<&()>)
+        .await;
+
+    assert!(matches!(result, Err(octocrab::Error::GitHub { .. })));
+}
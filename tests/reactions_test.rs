@@ -0,0 +1,141 @@
+use serde_json::{json, Value};
+use wiremock::matchers::{body_json, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use octocrab::models::issues::Issue;
+use octocrab::models::reactions::ReactionContent;
+use octocrab::Octocrab;
+
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn reaction(content: &str) -> Value {
+    let comment: Value =
+        serde_json::from_str(include_str!("resources/pull_request_review_comment.json")).unwrap();
+    json!({
+        "id": 1,
+        "node_id": "MDg6UmVhY3Rpb24x",
+        "user": comment["user"],
+        "content": content,
+        "created_at": "2016-05-20T20:09:31Z"
+    })
+}
+
+#[tokio::test]
+async fn should_list_release_reactions_by_content() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/1/reactions")))
+        .and(query_param("content", "heart"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([reaction("heart")])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /repos/{OWNER}/{REPO}/releases/1/reactions was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .reactions(1)
+        .list()
+        .content(ReactionContent::Heart)
+        .send()
+        .await;
+
+    let reactions = result.unwrap().items;
+    assert_eq!(reactions[0].content, ReactionContent::Heart);
+}
+
+#[tokio::test]
+async fn should_create_and_delete_review_comment_reaction() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/comments/42/reactions"
+        )))
+        .and(body_json(json!({ "content": "rocket" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(reaction("rocket")))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/pulls/comments/42/reactions/1"
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("request on /repos/{OWNER}/{REPO}/pulls/comments/42/reactions was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let pulls = client.pulls(OWNER, REPO);
+    let reactions = pulls.comment_reactions(42);
+
+    let reaction = reactions.create(ReactionContent::Rocket).await.unwrap();
+    reactions.delete(reaction.id).await.unwrap();
+}
+
+#[tokio::test]
+async fn should_report_delete_errors() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    setup_error_handler(&mock_server, "no reaction to delete").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .teams("org")
+        .discussion_comment_reactions("team", 1, 2)
+        .delete(3)
+        .await;
+
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_summarize_reaction_counts() {
+    let event: Value =
+        serde_json::from_str(include_str!("resources/issues_opened_webhook_event.json")).unwrap();
+    let mut issue = event["issue"].clone();
+    issue["reactions"] = json!({
+        "url": "https://api.github.com/repos/octocat/Hello-World/issues/1/reactions",
+        "total_count": 5,
+        "+1": 3,
+        "-1": 0,
+        "laugh": 0,
+        "hooray": 0,
+        "confused": 0,
+        "heart": 2,
+        "rocket": 0,
+        "eyes": 0
+    });
+
+    let issue: Issue = serde_json::from_value(issue).unwrap();
+    let reactions = issue.reactions.unwrap();
+
+    assert_eq!(reactions.total_count, 5);
+    assert_eq!(reactions.count(ReactionContent::PlusOne), 3);
+    assert_eq!(reactions.count(ReactionContent::Heart), 2);
+    assert_eq!(reactions.count(ReactionContent::Eyes), 0);
+}
@@ -0,0 +1,94 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{
+    models::repos::activity::{ActivityType, RepositoryActivity},
+    params::repos::activity::TimePeriod,
+    Octocrab,
+};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn activity(id: u64, activity_type: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "node_id": "RA_kwDOAAABAAAAAQ",
+        "before": "6dcb09b5b57875f334f61aebed695e2e4193db5e",
+        "after": "827efc6d56897b048c772eb4087f854f46256132",
+        "ref": "refs/heads/main",
+        "timestamp": "2024-03-01T12:00:00Z",
+        "activity_type": activity_type,
+        "actor": null
+    })
+}
+
+#[tokio::test]
+async fn should_list_activity_with_filters_and_cursors() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    let next = format!(
+        "<{}/repos/owner/repo/activity?after=Y3Vyc29yOjI%3D&activity_type=force_push>; rel=\"next\"",
+        mock_server.uri()
+    );
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/activity"))
+        .and(query_param("after", "Y3Vyc29yOjI="))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([activity(2, "branch_rename")])),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/activity"))
+        .and(query_param("activity_type", "force_push"))
+        .and(query_param("time_period", "month"))
+        .and(query_param("actor", "octocat"))
+        .and(query_param("ref", "main"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("link", next.as_str())
+                .set_body_json(json!([activity(1, "force_push")])),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/activity was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let page = client
+        .repos("owner", "repo")
+        .list_activity()
+        .activity_type(ActivityType::ForcePush)
+        .time_period(TimePeriod::Month)
+        .actor("octocat")
+        .r#ref("main")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].activity_type, ActivityType::ForcePush);
+    assert_eq!(page.items[0].ref_field, "refs/heads/main");
+
+    let next: octocrab::Page<RepositoryActivity> =
+        client.get_page(&page.next).await.unwrap().unwrap();
+    assert_eq!(next.items[0].id, 2);
+    assert_eq!(
+        next.items[0].activity_type,
+        ActivityType::Unknown("branch_rename".to_string())
+    );
+}
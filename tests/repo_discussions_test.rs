@@ -0,0 +1,264 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, body_string_contains, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const REPOSITORY_ID: &str = "R_kgDOAAABcM";
+const DISCUSSION_ID: &str = "D_kwDOAAABcM4AAbcd";
+const CATEGORY_ID: &str = "DIC_kwDOAAABcM4CAbcd";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_repository_id(mock_server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_string_contains(
+            "repository(owner: $owner, name: $name) { id }",
+        ))
+        .and(body_partial_json(json!({
+            "variables": { "owner": OWNER, "name": REPO }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "repository": { "id": REPOSITORY_ID } }
+        })))
+        .expect(1)
+        .mount(mock_server)
+        .await;
+}
+
+fn category() -> serde_json::Value {
+    json!({
+        "id": CATEGORY_ID,
+        "name": "Q&A",
+        "slug": "q-a",
+        "emoji": ":pray:",
+        "description": "Ask the community for help",
+        "isAnswerable": true
+    })
+}
+
+fn discussion() -> serde_json::Value {
+    json!({
+        "id": DISCUSSION_ID,
+        "number": 2,
+        "title": "How do I build a closure?",
+        "body": "The README doesn't say.",
+        "url": "https://github.com/org/some-repo/discussions/2",
+        "author": { "login": "octocat" },
+        "category": category(),
+        "isAnswered": false,
+        "answerChosenAt": null,
+        "closed": false,
+        "locked": false,
+        "createdAt": "2023-07-20T09:15:32Z",
+        "updatedAt": "2023-07-20T09:15:32Z"
+    })
+}
+
+#[tokio::test]
+async fn should_list_categories() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": { "owner": OWNER, "name": REPO }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "repository": { "discussionCategories": { "nodes": [category()] } }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .repos(OWNER, REPO)
+        .discussions()
+        .list_categories()
+        .await;
+
+    let categories = result.unwrap();
+    assert_eq!(categories.len(), 1);
+    assert_eq!(categories[0].id, CATEGORY_ID);
+    assert!(categories[0].is_answerable);
+}
+
+#[tokio::test]
+async fn should_list_discussions() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": {
+                "owner": OWNER,
+                "name": REPO,
+                "categoryId": CATEGORY_ID,
+                "first": 30
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "repository": {
+                    "discussions": {
+                        "totalCount": 1,
+                        "pageInfo": { "hasNextPage": false, "endCursor": "Y3Vyc29yOjE=" },
+                        "nodes": [discussion()]
+                    }
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .repos(OWNER, REPO)
+        .discussions()
+        .list()
+        .category_id(CATEGORY_ID)
+        .send()
+        .await;
+
+    let discussions = result.unwrap();
+    assert_eq!(discussions.total_count, 1);
+    assert_eq!(discussions.discussions[0].number, 2);
+    assert_eq!(discussions.discussions[0].is_answered, Some(false));
+}
+
+#[tokio::test]
+async fn should_create_discussion() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    setup_repository_id(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": {
+                "input": {
+                    "repositoryId": REPOSITORY_ID,
+                    "categoryId": CATEGORY_ID,
+                    "title": "How do I build a closure?",
+                    "body": "The README doesn't say."
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": { "createDiscussion": { "discussion": discussion() } }
+        })))
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+    let discussions = repo.discussions();
+    for _ in 0..2 {
+        let discussion = discussions
+            .create(
+                CATEGORY_ID,
+                "How do I build a closure?",
+                "The README doesn't say.",
+            )
+            .await
+            .unwrap();
+        assert_eq!(discussion.id, DISCUSSION_ID);
+        assert_eq!(discussion.author.unwrap().login, "octocat");
+    }
+}
+
+#[tokio::test]
+async fn should_reply_to_comment() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": {
+                "input": {
+                    "discussionId": DISCUSSION_ID,
+                    "replyToId": "DC_kwDOAAABcM4AAbcd",
+                    "body": "Thanks, that works!"
+                }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "addDiscussionComment": {
+                    "comment": {
+                        "id": "DC_kwDOAAABcM4AAbce",
+                        "body": "Thanks, that works!",
+                        "url": "https://github.com/org/some-repo/discussions/2#discussioncomment-6432150",
+                        "author": { "login": "octocat" },
+                        "isAnswer": false,
+                        "createdAt": "2023-07-20T09:25:02Z",
+                        "updatedAt": "2023-07-20T09:25:02Z"
+                    }
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .repos(OWNER, REPO)
+        .discussions()
+        .reply_to_comment(DISCUSSION_ID, "DC_kwDOAAABcM4AAbcd", "Thanks, that works!")
+        .await;
+
+    assert_eq!(result.unwrap().id, "DC_kwDOAAABcM4AAbce");
+}
+
+#[tokio::test]
+async fn should_mark_answer() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": { "input": { "id": "DC_kwDOAAABcM4AAbcd" } }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "markDiscussionCommentAsAnswer": { "discussion": { "id": DISCUSSION_ID } }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .repos(OWNER, REPO)
+        .discussions()
+        .mark_answer("DC_kwDOAAABcM4AAbcd")
+        .await;
+
+    assert!(result.is_ok());
+}
@@ -0,0 +1,182 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::repos::{SecurityAndAnalysis, SecurityFeatureStatus};
+use octocrab::params::orgs::SecurityProduct;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_update_security_and_analysis() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path("/repos/owner/repo"))
+        .and(body_json(json!({
+            "delete_branch_on_merge": true,
+            "security_and_analysis": {
+                "secret_scanning": { "status": "enabled" },
+                "secret_scanning_push_protection": { "status": "disabled" }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": 1296269,
+            "name": "repo",
+            "url": "https://api.github.com/repos/owner/repo",
+            "delete_branch_on_merge": true,
+            "security_and_analysis": {
+                "dependabot_security_updates": { "status": "enabled" },
+                "secret_scanning": { "status": "enabled" },
+                "secret_scanning_push_protection": { "status": "disabled" }
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client
+        .repos("owner", "repo")
+        .update()
+        .delete_branch_on_merge(true)
+        .security_and_analysis(
+            SecurityAndAnalysis::new()
+                .secret_scanning(true)
+                .secret_scanning_push_protection(false),
+        )
+        .send()
+        .await
+        .unwrap();
+
+    let settings = repo.security_and_analysis.unwrap();
+    assert!(settings.secret_scanning.unwrap().is_enabled());
+    assert_eq!(
+        settings.secret_scanning_push_protection.unwrap().status,
+        SecurityFeatureStatus::Disabled
+    );
+    assert!(settings.advanced_security.is_none());
+}
+
+#[tokio::test]
+async fn should_check_vulnerability_alerts() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/enabled/vulnerability-alerts"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/disabled/vulnerability-alerts"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "message": "Not Found",
+            "documentation_url": "https://docs.github.com/rest"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/repos/owner/disabled/vulnerability-alerts"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/*/vulnerability-alerts was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let enabled = client.repos("owner", "enabled");
+    assert!(enabled
+        .dependabot()
+        .is_vulnerability_alerts_enabled()
+        .await
+        .unwrap());
+    let disabled = client.repos("owner", "disabled");
+    assert!(!disabled
+        .dependabot()
+        .is_vulnerability_alerts_enabled()
+        .await
+        .unwrap());
+    disabled
+        .dependabot()
+        .enable_vulnerability_alerts()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn should_get_automated_security_fixes() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/automated-security-fixes"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!({ "enabled": true, "paused": false })),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/automated-security-fixes was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let fixes = client
+        .repos("owner", "repo")
+        .dependabot()
+        .get_automated_security_fixes()
+        .await
+        .unwrap();
+
+    assert!(fixes.enabled);
+    assert!(!fixes.paused);
+}
+
+#[tokio::test]
+async fn should_enable_security_product_for_all_repos() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/org/secret_scanning_push_protection/enable_all"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/orgs/org/dependabot_alerts/disable_all"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let org = client.orgs("org");
+    org.enable_security_product(SecurityProduct::SecretScanningPushProtection)
+        .await
+        .unwrap();
+    org.disable_security_product(SecurityProduct::DependabotAlerts)
+        .await
+        .unwrap();
+}
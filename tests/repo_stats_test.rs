@@ -0,0 +1,79 @@
+mod mock_error;
+
+use std::time::Duration;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{Octocrab, ReadyBackoff};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_wait_until_stats_are_computed() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/stats/code_frequency"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({})))
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/stats/code_frequency"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([[1302998400, 1124, -435]])))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/stats/code_frequency was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let frequency = client
+        .repos("owner", "repo")
+        .stats()
+        .backoff(ReadyBackoff::default().initial_delay(Duration::from_millis(10)))
+        .code_frequency()
+        .await
+        .unwrap();
+
+    assert_eq!(frequency.len(), 1);
+    assert_eq!(frequency[0].week, 1302998400);
+    assert_eq!(frequency[0].additions, 1124);
+    assert_eq!(frequency[0].deletions, -435);
+}
+
+#[tokio::test]
+async fn should_give_up_once_backoff_times_out() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/stats/participation"))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({})))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .repos("owner", "repo")
+        .stats()
+        .backoff(ReadyBackoff::none())
+        .participation()
+        .await;
+
+    assert!(matches!(result, Err(octocrab::Error::Other { .. })));
+}
@@ -0,0 +1,190 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::repos::dependency_graph::{DependencyChangeType, DependencyScope};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn sbom() -> serde_json::Value {
+    json!({
+        "sbom": {
+            "SPDXID": "SPDXRef-DOCUMENT",
+            "spdxVersion": "SPDX-2.3",
+            "creationInfo": {
+                "created": "2021-09-01T00:00:00Z",
+                "creators": ["Tool: GitHub.com-Dependency-Graph"]
+            },
+            "name": "github/example",
+            "dataLicense": "CC0-1.0",
+            "documentDescribes": ["SPDXRef-Repository"],
+            "documentNamespace": "https://github.com/example/dependency_graph/sbom-123",
+            "packages": [
+                {
+                    "SPDXID": "SPDXRef-npm-rails-1.0.0",
+                    "name": "rails",
+                    "versionInfo": "1.0.0",
+                    "downloadLocation": "NOASSERTION",
+                    "filesAnalyzed": false,
+                    "licenseConcluded": "MIT",
+                    "licenseDeclared": "MIT",
+                    "supplier": "NOASSERTION",
+                    "copyrightText": "Copyright (c) 1985 GitHub.com",
+                    "externalRefs": [
+                        {
+                            "referenceCategory": "PACKAGE-MANAGER",
+                            "referenceLocator": "pkg:gem/rails@1.0.0",
+                            "referenceType": "purl"
+                        }
+                    ]
+                }
+            ],
+            "relationships": [
+                {
+                    "relationshipType": "DEPENDS_ON",
+                    "spdxElementId": "SPDXRef-Repository",
+                    "relatedSpdxElement": "SPDXRef-npm-rails-1.0.0"
+                }
+            ]
+        }
+    })
+}
+
+#[tokio::test]
+async fn should_export_sbom() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/dependency-graph/sbom")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(sbom()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/org/some-repo/dependency-graph/sbom was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos.dependency_graph().sbom().await;
+
+    let sbom = result.unwrap();
+    assert_eq!(sbom.spdx_version, "SPDX-2.3");
+    assert_eq!(sbom.packages.len(), 1);
+    let package = &sbom.packages[0];
+    assert_eq!(package.name.as_deref(), Some("rails"));
+    assert_eq!(package.version_info.as_deref(), Some("1.0.0"));
+    assert_eq!(package.external_refs[0].reference_type, "purl");
+    assert_eq!(sbom.relationships[0].relationship_type, "DEPENDS_ON");
+}
+
+#[tokio::test]
+async fn should_export_raw_sbom() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/dependency-graph/sbom")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(sbom()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/org/some-repo/dependency-graph/sbom was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos.dependency_graph().sbom_raw().await;
+
+    let sbom = result.unwrap();
+    assert_eq!(sbom["dataLicense"], "CC0-1.0");
+    assert_eq!(sbom["packages"][0]["SPDXID"], "SPDXRef-npm-rails-1.0.0");
+}
+
+#[tokio::test]
+async fn should_compare_dependencies() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/dependency-graph/compare/main...feature"
+        )))
+        .and(query_param("name", "package.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            {
+                "change_type": "removed",
+                "manifest": "package.json",
+                "ecosystem": "npm",
+                "name": "helmet",
+                "version": "4.6.0",
+                "package_url": "pkg:npm/helmet@4.6.0",
+                "license": "MIT",
+                "source_repository_url": "https://github.com/helmetjs/helmet",
+                "vulnerabilities": [],
+                "scope": "runtime"
+            },
+            {
+                "change_type": "added",
+                "manifest": "package.json",
+                "ecosystem": "npm",
+                "name": "helmet",
+                "version": "5.0.0",
+                "package_url": "pkg:npm/helmet@5.0.0",
+                "license": "MIT",
+                "source_repository_url": "https://github.com/helmetjs/helmet",
+                "vulnerabilities": [
+                    {
+                        "severity": "high",
+                        "advisory_ghsa_id": "GHSA-rf4j-j272-fj86",
+                        "advisory_summary": "Prototype pollution",
+                        "advisory_url": "https://github.com/advisories/GHSA-rf4j-j272-fj86"
+                    }
+                ],
+                "scope": "runtime"
+            }
+        ])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/org/some-repo/dependency-graph/compare/main...feature was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .dependency_graph()
+        .compare("main...feature")
+        .name("package.json")
+        .send()
+        .await;
+
+    let changes = result.unwrap();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(changes[0].change_type, DependencyChangeType::Removed);
+    assert_eq!(changes[1].change_type, DependencyChangeType::Added);
+    assert_eq!(changes[1].scope, Some(DependencyScope::Runtime));
+    assert_eq!(
+        changes[1].vulnerabilities[0].advisory_ghsa_id,
+        "GHSA-rf4j-j272-fj86"
+    );
+}
@@ -0,0 +1,115 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn license_content() -> serde_json::Value {
+    json!({
+        "name": "LICENSE",
+        "path": "LICENSE",
+        "sha": "401c59dcc4570b954dd6d345e76199e1f4e76266",
+        "size": 1077,
+        "url": "https://api.github.com/repos/benbalter/gman/contents/LICENSE?ref=master",
+        "html_url": "https://github.com/benbalter/gman/blob/master/LICENSE",
+        "git_url": "https://api.github.com/repos/benbalter/gman/git/blobs/401c59dcc4570b954dd6d345e76199e1f4e76266",
+        "download_url": "https://raw.githubusercontent.com/benbalter/gman/master/LICENSE?lab=true",
+        "type": "file",
+        "content": "VGhlIE1JVCBMaWNlbnNlIChNSVQpCg==\n",
+        "encoding": "base64",
+        "_links": {
+            "self": "https://api.github.com/repos/benbalter/gman/contents/LICENSE?ref=master",
+            "git": "https://api.github.com/repos/benbalter/gman/git/blobs/401c59dcc4570b954dd6d345e76199e1f4e76266",
+            "html": "https://github.com/benbalter/gman/blob/master/LICENSE"
+        },
+        "license": {
+            "key": "mit",
+            "name": "MIT License",
+            "spdx_id": "MIT",
+            "url": "https://api.github.com/licenses/mit",
+            "node_id": "MDc6TGljZW5zZW1pdA=="
+        }
+    })
+}
+
+#[tokio::test]
+async fn should_return_language_byte_counts() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/languages")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "Rust": 1049213,
+            "Shell": 1284
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /languages was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let languages = client.repos(OWNER, REPO).list_languages().await.unwrap();
+
+    assert_eq!(languages.len(), 2);
+    assert_eq!(languages["Rust"], 1049213);
+    assert_eq!(languages["Shell"], 1284);
+}
+
+#[tokio::test]
+async fn should_return_detected_license_with_content() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/license")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(license_content()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /license was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let content = client.repos(OWNER, REPO).license().await.unwrap();
+
+    assert_eq!(content.license.as_ref().unwrap().spdx_id, "MIT");
+    assert_eq!(
+        content.decoded_content().as_deref(),
+        Some("The MIT License (MIT)\n")
+    );
+}
+
+#[tokio::test]
+async fn should_request_license_at_reference() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/license")))
+        .and(query_param("ref", "v1.0.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(license_content()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /license?ref=v1.0.0 was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let content = client
+        .repos(OWNER, REPO)
+        .license_at("v1.0.0".to_string())
+        .await
+        .unwrap();
+
+    assert_eq!(content.license.unwrap().key, "mit");
+}
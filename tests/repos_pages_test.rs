@@ -0,0 +1,204 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::repos::pages::{PagesBuildType, PagesSource, PagesStatus};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn pages_site() -> serde_json::Value {
+    json!({
+        "url": "https://api.github.com/repos/github/developer.github.com/pages",
+        "status": "built",
+        "cname": "developer.github.com",
+        "custom_404": false,
+        "html_url": "https://developer.github.com",
+        "build_type": "legacy",
+        "source": {
+            "branch": "master",
+            "path": "/"
+        },
+        "public": true,
+        "https_certificate": {
+            "state": "approved",
+            "description": "Certificate is approved",
+            "domains": ["developer.github.com"],
+            "expires_at": "2020-04-23"
+        },
+        "https_enforced": true
+    })
+}
+
+fn pages_build() -> serde_json::Value {
+    json!({
+        "url": "https://api.github.com/repos/github/developer.github.com/pages/builds/5472601",
+        "status": "built",
+        "error": { "message": null },
+        "pusher": null,
+        "commit": "351391cdcb88ffae71ec3028c91f375a8036a26b",
+        "duration": 2104,
+        "created_at": "2014-02-10T19:00:49Z",
+        "updated_at": "2014-02-10T19:00:51Z"
+    })
+}
+
+#[tokio::test]
+async fn should_get_pages_site() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pages_site()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /pages was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let site = client.repos(OWNER, REPO).pages().get().await.unwrap();
+
+    assert_eq!(site.status, Some(PagesStatus::Built));
+    assert_eq!(site.build_type, Some(PagesBuildType::Legacy));
+    assert_eq!(site.cname.as_deref(), Some("developer.github.com"));
+    assert_eq!(site.source.unwrap().branch, "master");
+}
+
+#[tokio::test]
+async fn should_enable_pages_with_source() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages")))
+        .and(body_json(json!({
+            "source": { "branch": "main", "path": "/docs" }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(pages_site()))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .repos(OWNER, REPO)
+        .pages()
+        .create()
+        .source(PagesSource::new("main", Some("/docs".to_string())))
+        .send()
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_update_and_disable_pages() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages")))
+        .and(body_json(json!({ "cname": null, "https_enforced": true })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+    let pages = repo.pages();
+    pages
+        .update()
+        .remove_cname()
+        .https_enforced(true)
+        .send()
+        .await
+        .unwrap();
+    pages.delete().await.unwrap();
+}
+
+#[tokio::test]
+async fn should_list_and_request_builds() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages/builds")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([pages_build()])))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages/builds/latest")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(pages_build()))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages/builds")))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "url": "https://api.github.com/repos/github/developer.github.com/pages/builds/latest",
+            "status": "queued"
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /pages/builds was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+    let pages = repo.pages();
+
+    let builds = pages.list_builds().send().await.unwrap();
+    assert_eq!(builds.items.len(), 1);
+    assert_eq!(builds.items[0].duration, 2104);
+
+    let latest = pages.get_latest_build().await.unwrap();
+    assert_eq!(latest.commit, "351391cdcb88ffae71ec3028c91f375a8036a26b");
+
+    let status = pages.request_build().await.unwrap();
+    assert_eq!(status.status, "queued");
+}
+
+#[tokio::test]
+async fn should_return_none_while_health_check_is_pending() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/pages/health")))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({})))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /pages/health was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let health = client
+        .repos(OWNER, REPO)
+        .pages()
+        .health_check()
+        .await
+        .unwrap();
+    assert!(health.is_none());
+}
@@ -0,0 +1,70 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_fetch_raw_readme() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/readme/")))
+        .and(header("accept", "application/vnd.github.v3.raw+json"))
+        .and(query_param("ref", "main"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("# Octocrab\n"))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /readme was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let readme = client
+        .repos(OWNER, REPO)
+        .get_readme()
+        .r#ref("main")
+        .raw()
+        .await
+        .unwrap();
+
+    assert_eq!(readme.as_ref(), b"# Octocrab\n");
+}
+
+#[tokio::test]
+async fn should_fetch_rendered_content() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/contents/docs/index.md"
+        )))
+        .and(header("accept", "application/vnd.github.v3.html+json"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("<h1>Docs</h1>"))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /contents was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let html = client
+        .repos(OWNER, REPO)
+        .get_content()
+        .path("docs/index.md")
+        .html()
+        .await
+        .unwrap();
+
+    assert_eq!(html, "<h1>Docs</h1>");
+}
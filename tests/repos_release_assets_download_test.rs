@@ -0,0 +1,85 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use wiremock::{
+    matchers::{header, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const ASSET_ID: u64 = 42;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api() -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/releases/assets/{ASSET_ID}"
+        )))
+        .and(header("accept", "application/octet-stream"))
+        .respond_with(ResponseTemplate::new(302).append_header(
+            "location",
+            format!("{}/storage/asset.bin", mock_server.uri()),
+        ))
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/storage/asset.bin"))
+        .respond_with(ResponseTemplate::new(200).set_body_bytes(b"binary-contents".to_vec()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on release asset was not received").await;
+    mock_server
+}
+
+#[tokio::test]
+async fn should_download_asset_following_redirect() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+    let bytes = client
+        .repos(OWNER, REPO)
+        .release_assets()
+        .download(ASSET_ID)
+        .await
+        .unwrap();
+
+    assert_eq!(bytes.as_ref(), b"binary-contents");
+}
+
+#[cfg(feature = "stream")]
+#[tokio::test]
+async fn should_report_download_progress() {
+    use futures_util::TryStreamExt;
+    use std::sync::{Arc, Mutex};
+
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api().await;
+    let client = setup_octocrab(&mock_server.uri());
+    let progress = Arc::new(Mutex::new(Vec::new()));
+    let reported = progress.clone();
+    let chunks: Vec<bytes::Bytes> = client
+        .repos(OWNER, REPO)
+        .release_assets()
+        .stream_with_progress(ASSET_ID, move |received, total| {
+            reported.lock().unwrap().push((received, total));
+        })
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+
+    assert_eq!(chunks.concat(), b"binary-contents");
+    let progress = progress.lock().unwrap();
+    assert_eq!(progress.last(), Some(&(15, Some(15))));
+}
@@ -0,0 +1,107 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::repos::Release;
+use octocrab::{Error, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const RELEASE_ID: u64 = 148681297;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_publish_draft_release() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let release: Release =
+        serde_json::from_str(include_str!("resources/repos_releases_get_by_id.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}")))
+        .and(body_json(json!({ "draft": false })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&release))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "PATCH on release was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .publish(RELEASE_ID)
+        .await;
+
+    assert!(
+        result.is_ok(),
+        "expected successful result, got error: {:#?}",
+        result
+    );
+    assert_eq!(result.unwrap().id, release.id);
+}
+
+#[tokio::test]
+async fn should_delete_release_and_asset() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/assets/42")))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repo = client.repos(OWNER, REPO);
+    repo.releases().delete(RELEASE_ID).await.unwrap();
+    repo.releases().delete_asset(42).await.unwrap();
+}
+
+#[tokio::test]
+async fn should_fail_to_delete_missing_release() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}")))
+        .respond_with(ResponseTemplate::new(404).set_body_json(json!({
+            "documentation_url": "rtm",
+            "errors": null,
+            "message": "Not Found"
+        })))
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .repos(OWNER, REPO)
+        .releases()
+        .delete(RELEASE_ID)
+        .await;
+
+    match result.unwrap_err() {
+        Error::GitHub { source, .. } => {
+            assert_eq!(source.status_code, 404);
+            assert_eq!(source.message, "Not Found");
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
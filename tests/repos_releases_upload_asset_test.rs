@@ -0,0 +1,126 @@
+mod mock_error;
+
+use bytes::Bytes;
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::AssetId;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{body_bytes, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const RELEASE_ID: u64 = 148681297;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn asset(name: &str, content_type: &str) -> Value {
+    json!({
+        "url": "https://api.github.com/repos/XAMPPRocky/octocrab/releases/assets/1",
+        "browser_download_url": "https://github.com/XAMPPRocky/octocrab/releases/download/v1.0.0/example.zip",
+        "id": 1,
+        "node_id": "MDEyOlJlbGVhc2VBc3NldDE=",
+        "name": name,
+        "label": null,
+        "state": "uploaded",
+        "content_type": content_type,
+        "size": 9,
+        "digest": null,
+        "download_count": 0,
+        "created_at": "2013-02-27T19:35:32Z",
+        "updated_at": "2013-02-27T19:35:32Z",
+        "uploader": null
+    })
+}
+
+async fn setup_release(mock_server: &MockServer) {
+    let mut release: Value =
+        serde_json::from_str(include_str!("resources/repos_releases_get_by_id.json")).unwrap();
+    release["upload_url"] = json!(format!(
+        "{}/upload/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}/assets{{?name,label}}",
+        mock_server.uri()
+    ));
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(release))
+        .mount(mock_server)
+        .await;
+}
+
+#[tokio::test]
+async fn should_upload_asset_with_encoded_name_and_label() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    setup_release(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/upload/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}/assets"
+        )))
+        .and(query_param("name", "my asset.zip"))
+        .and(query_param("label", "Linux & macOS"))
+        .and(header("content-type", "application/zip"))
+        .and(header("content-length", "9"))
+        .and(body_bytes(b"some_data".to_vec()))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(asset("my asset.zip", "application/zip")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on release was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let asset = client
+        .repos(OWNER, REPO)
+        .releases()
+        .upload_asset(RELEASE_ID, "my asset.zip", Bytes::from("some_data"))
+        .label("Linux & macOS")
+        .content_type("application/zip")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(asset.id, AssetId(1));
+    assert_eq!(asset.content_type, "application/zip");
+}
+
+#[tokio::test]
+async fn should_upload_asset_from_streaming_body() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    setup_release(&mock_server).await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/upload/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}/assets"
+        )))
+        .and(query_param("name", "data.bin"))
+        .and(header("content-type", "application/octet-stream"))
+        .and(body_bytes(b"some_data".to_vec()))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(asset("data.bin", "application/octet-stream")),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on release was not received").await;
+
+    let body = http_body_util::Full::new(Bytes::from("some_data"));
+    let client = setup_octocrab(&mock_server.uri());
+    let asset = client
+        .repos(OWNER, REPO)
+        .releases()
+        .upload_asset_stream(RELEASE_ID, "data.bin", body, 9)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(asset.name, "data.bin");
+}
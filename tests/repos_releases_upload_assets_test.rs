@@ -0,0 +1,98 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::repos::releases::AssetUpload;
+use octocrab::Octocrab;
+use serde_json::{json, Value};
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "XAMPPRocky";
+const REPO: &str = "octocrab";
+const RELEASE_ID: u64 = 148681297;
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn asset(id: u64, name: &str) -> Value {
+    json!({
+        "url": format!("https://api.github.com/repos/XAMPPRocky/octocrab/releases/assets/{id}"),
+        "browser_download_url": format!("https://github.com/XAMPPRocky/octocrab/releases/download/v1.0.0/{name}"),
+        "id": id,
+        "node_id": "MDEyOlJlbGVhc2VBc3NldDE=",
+        "name": name,
+        "label": null,
+        "state": "uploaded",
+        "content_type": "application/octet-stream",
+        "size": 4,
+        "digest": null,
+        "download_count": 0,
+        "created_at": "2013-02-27T19:35:32Z",
+        "updated_at": "2013-02-27T19:35:32Z",
+        "uploader": null
+    })
+}
+
+#[tokio::test]
+async fn should_upload_all_assets_and_report_failures_individually() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    let mut release: Value =
+        serde_json::from_str(include_str!("resources/repos_releases_get_by_id.json")).unwrap();
+    release["upload_url"] = json!(format!(
+        "{}/upload/assets{{?name,label}}",
+        mock_server.uri()
+    ));
+    Mock::given(method("GET"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/releases/{RELEASE_ID}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(release))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    for (id, name) in [(1, "a.bin"), (3, "c.bin")] {
+        Mock::given(method("POST"))
+            .and(path("/upload/assets"))
+            .and(query_param("name", name))
+            .respond_with(ResponseTemplate::new(201).set_body_json(asset(id, name)))
+            .expect(1)
+            .mount(&mock_server)
+            .await;
+    }
+    Mock::given(method("POST"))
+        .and(path("/upload/assets"))
+        .and(query_param("name", "b.bin"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "documentation_url": "rtm",
+            "errors": [{ "resource": "ReleaseAsset", "code": "already_exists", "field": "name" }],
+            "message": "Validation Failed"
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on release was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let results = client
+        .repos(OWNER, REPO)
+        .releases()
+        .upload_assets(
+            RELEASE_ID,
+            ["a.bin", "b.bin", "c.bin"]
+                .iter()
+                .map(|name| AssetUpload::new(*name, b"data".to_vec())),
+        )
+        .concurrency(2)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].as_ref().unwrap().name, "a.bin");
+    assert!(results[1].is_err());
+    assert_eq!(results[2].as_ref().unwrap().name, "c.bin");
+}
@@ -0,0 +1,152 @@
+mod mock_error;
+
+use bytes::Bytes;
+use http::{HeaderName, HeaderValue, Method};
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_bytes, body_json, header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[derive(Serialize)]
+struct Query {
+    per_page: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    targets: Option<&'static str>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Ruleset {
+    id: u64,
+    name: String,
+}
+
+#[tokio::test]
+async fn should_send_query_and_headers_and_deserialize_json() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/rulesets"))
+        .and(query_param("per_page", "100"))
+        .and(query_param("includes_parents", "true"))
+        .and(header("x-custom", "value"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([{ "id": 42, "name": "main" }])),
+        )
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/rulesets was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let rulesets: Vec<Ruleset> = client
+        .request(Method::GET, "/repos/owner/repo/rulesets")
+        .query(&Query {
+            per_page: 100,
+            targets: None,
+        })
+        .query(&[("includes_parents", true)])
+        .header(
+            HeaderName::from_static("x-custom"),
+            HeaderValue::from_static("value"),
+        )
+        .send_json()
+        .await
+        .unwrap();
+
+    assert_eq!(rulesets.len(), 1);
+    assert_eq!(rulesets[0].id, 42);
+    assert_eq!(rulesets[0].name, "main");
+}
+
+#[tokio::test]
+async fn should_send_json_body() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/rulesets"))
+        .and(header("content-type", "application/json"))
+        .and(body_json(
+            json!({ "name": "main", "enforcement": "active" }),
+        ))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "id": 1, "name": "main" })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let ruleset: Ruleset = client
+        .request(Method::POST, "/repos/owner/repo/rulesets")
+        .json(&json!({ "name": "main", "enforcement": "active" }))
+        .send_json()
+        .await
+        .unwrap();
+
+    assert_eq!(ruleset.id, 1);
+}
+
+#[tokio::test]
+async fn should_send_streaming_body_and_return_bytes() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path("/some/upload"))
+        .and(header("content-type", "text/plain"))
+        .and(body_bytes(b"some_data".to_vec()))
+        .respond_with(ResponseTemplate::new(200).set_body_string("stored"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let body = http_body_util::Full::new(Bytes::from("some_data"));
+    let response = client
+        .request(Method::PUT, "/some/upload")
+        .header(
+            http::header::CONTENT_TYPE,
+            HeaderValue::from_static("text/plain"),
+        )
+        .body(body)
+        .send_bytes()
+        .await
+        .unwrap();
+
+    assert_eq!(response, "stored");
+}
+
+#[tokio::test]
+async fn should_return_github_errors() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    setup_error_handler(&mock_server, "Not Found").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .request(Method::GET, "/repos/owner/repo/unknown")
+        .send_bytes()
+        .await;
+
+    match result.unwrap_err() {
+        octocrab::Error::GitHub { source, .. } => assert_eq!(source.message, "Not Found"),
+        error => panic!("expected a GitHub error, got {:?}", error),
+    }
+}
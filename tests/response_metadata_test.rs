@@ -0,0 +1,79 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::repos::Release;
+use octocrab::{Octocrab, WithMetadata};
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+async fn setup_api(template: ResponseTemplate) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/releases/latest"))
+        .respond_with(template)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/releases/latest was not received",
+    )
+    .await;
+    mock_server
+}
+
+fn release_response() -> ResponseTemplate {
+    let release: Release =
+        serde_json::from_str(include_str!("resources/repos_releases_get_latest.json")).unwrap();
+    ResponseTemplate::new(200)
+        .insert_header("etag", "\"abc\"")
+        .insert_header("x-github-request-id", "CAFE:1234")
+        .insert_header("x-ratelimit-limit", "5000")
+        .insert_header("x-ratelimit-remaining", "4999")
+        .insert_header("x-ratelimit-used", "1")
+        .insert_header("x-ratelimit-reset", "1700000000")
+        .set_body_json(release)
+}
+
+#[tokio::test]
+async fn should_return_metadata_of_typed_methods() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api(release_response()).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let release = client
+        .with_metadata(
+            |crab| async move { crab.repos("owner", "repo").releases().get_latest().await },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(release.metadata.status, 200);
+    assert_eq!(release.metadata.etag().unwrap().tag(), "abc");
+    assert_eq!(release.metadata.request_id(), Some("CAFE:1234"));
+    let rate = release.metadata.rate_limit().unwrap();
+    assert_eq!((rate.limit, rate.remaining, rate.used), (5000, 4999, 1));
+    assert_eq!(release.id, release.clone().into_inner().id);
+}
+
+#[tokio::test]
+async fn should_return_metadata_of_generic_requests() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api(release_response()).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let release: WithMetadata<Release> = client
+        .get("/repos/owner/repo/releases/latest", None::<&()>)
+        .await
+        .unwrap();
+
+    assert_eq!(release.metadata.request_id(), Some("CAFE:1234"));
+    assert!(release.metadata.rate_limit().is_some());
+}
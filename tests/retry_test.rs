@@ -0,0 +1,100 @@
+#![cfg(feature = "retry")]
+
+mod mock_error;
+
+use http::Method;
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+async fn setup_api(method_name: &str) -> MockServer {
+    let mock_server = MockServer::start().await;
+    Mock::given(method(method_name))
+        .and(path("/repos/owner/repo/issues/1/comments"))
+        .respond_with(ResponseTemplate::new(503))
+        .up_to_n_times(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method(method_name))
+        .and(path("/repos/owner/repo/issues/1/comments"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("{method_name} on /repos/owner/repo/issues/1/comments was not received"),
+    )
+    .await;
+    mock_server
+}
+
+async fn received(mock_server: &MockServer) -> usize {
+    mock_server.received_requests().await.unwrap().len()
+}
+
+#[tokio::test]
+async fn should_retry_idempotent_requests() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api("GET").await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+    let response = client
+        .request(Method::GET, "/repos/owner/repo/issues/1/comments")
+        .send_bytes()
+        .await;
+
+    assert!(response.is_ok());
+    assert_eq!(received(&mock_server).await, 2);
+}
+
+#[tokio::test]
+async fn should_not_retry_post_by_default() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api("POST").await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .build()
+        .unwrap();
+    let response = client
+        .request(Method::POST, "/repos/owner/repo/issues/1/comments")
+        .json(&json!({ "body": "hello" }))
+        .send_bytes()
+        .await;
+
+    assert!(response.is_err());
+    assert_eq!(received(&mock_server).await, 1);
+}
+
+#[tokio::test]
+async fn should_retry_post_when_opted_in() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = setup_api("POST").await;
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .retry_non_idempotent(true)
+        .build()
+        .unwrap();
+    let response = client
+        .request(Method::POST, "/repos/owner/repo/issues/1/comments")
+        .json(&json!({ "body": "hello" }))
+        .send_bytes()
+        .await;
+
+    assert!(response.is_ok());
+    assert_eq!(received(&mock_server).await, 2);
+}
@@ -0,0 +1,71 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{params::repos::Reference, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_encode_branch_names_in_refs() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/git/ref/heads/feature/%2342-%C3%BCber%20100%25"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "ref": "refs/heads/feature/#42-über 100%",
+            "node_id": "MDM6UmVmcmVmcy9oZWFkcy9mZWF0dXJlQQ==",
+            "url": "https://api.github.com/repos/owner/repo/git/refs/heads/feature",
+            "object": {
+                "type": "commit",
+                "sha": "aa218f56b14c9653891f9e74264a383fa43fefbd",
+                "url": "https://api.github.com/repos/owner/repo/git/commits/aa218f56b14c9653891f9e74264a383fa43fefbd"
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on encoded ref was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let reference = client
+        .repos("owner", "repo")
+        .get_ref(&Reference::Branch("feature/#42-über 100%".to_string()))
+        .await
+        .unwrap();
+
+    assert_eq!(reference.ref_field, "refs/heads/feature/#42-über 100%");
+}
+
+#[tokio::test]
+async fn should_encode_file_paths_and_query_values() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/contents/docs/notes%20%231.md"))
+        .and(query_param("ref", "feature/a&b"))
+        .respond_with(ResponseTemplate::new(200).set_body_string("# Notes"))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on encoded contents was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let response = client
+        .repos("owner", "repo")
+        .raw_file("feature/a&b".to_string(), "docs/notes #1.md")
+        .await
+        .unwrap();
+
+    assert!(response.status().is_success());
+}
@@ -0,0 +1,193 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::search::GraphQLSearchResults;
+use octocrab::models::RepositoryId;
+use octocrab::params::search::{LabelSort, UserSort};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_partial_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_search_labels_of_repository() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/labels"))
+        .and(query_param("q", "bug"))
+        .and(query_param("repository_id", "64778136"))
+        .and(query_param("sort", "created"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "incomplete_results": false,
+            "items": [{
+                "id": 418327088,
+                "node_id": "MDU6TGFiZWw0MTgzMjcwODg=",
+                "url": "https://api.github.com/repos/octocat/linguist/labels/bug",
+                "name": "bug",
+                "color": "ee0701",
+                "default": true,
+                "description": "Something isn't working",
+                "score": 1.0
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /search/labels was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .search()
+        .labels(RepositoryId(64778136), "bug")
+        .sort(LabelSort::Created)
+        .send()
+        .await;
+
+    let page = result.unwrap();
+    assert_eq!(page.total_count, Some(1));
+    assert_eq!(page.items[0].name, "bug");
+}
+
+#[tokio::test]
+async fn should_search_topics() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/topics"))
+        .and(query_param("q", "ruby is:featured"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 1,
+            "incomplete_results": false,
+            "items": [{
+                "name": "ruby",
+                "display_name": "Ruby",
+                "short_description": "Ruby is a scripting language designed for simplified object-oriented programming.",
+                "description": "Ruby was developed by Yukihiro \"Matz\" Matsumoto in 1995.",
+                "created_by": "Yukihiro Matsumoto",
+                "released": "December 21, 1995",
+                "created_at": "2016-11-28T22:03:59Z",
+                "updated_at": "2017-10-30T18:16:32Z",
+                "featured": true,
+                "curated": true,
+                "score": 1750.5872,
+                "related": [{
+                    "topic_relation": {
+                        "id": 1001,
+                        "name": "rails",
+                        "topic_id": 3,
+                        "relation_type": "related"
+                    }
+                }],
+                "aliases": null
+            }]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /search/topics was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.search().topics("ruby is:featured").send().await;
+
+    let page = result.unwrap();
+    let topic = &page.items[0];
+    assert_eq!(topic.display_name.as_deref(), Some("Ruby"));
+    assert!(topic.featured);
+    assert_eq!(
+        topic.related.as_ref().unwrap()[0]
+            .topic_relation
+            .name
+            .as_deref(),
+        Some("rails")
+    );
+}
+
+#[tokio::test]
+async fn should_search_users_with_sort() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/search/users"))
+        .and(query_param("sort", "joined"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "total_count": 0,
+            "incomplete_results": false,
+            "items": []
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /search/users was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .search()
+        .users("tom repos:>42")
+        .sort(UserSort::Joined)
+        .send()
+        .await;
+
+    assert!(result.unwrap().items.is_empty());
+}
+
+#[tokio::test]
+async fn should_search_issues_through_graphql() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    #[derive(Debug, serde::Deserialize)]
+    struct SearchResult {
+        number: u64,
+        title: String,
+    }
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/graphql"))
+        .and(body_partial_json(json!({
+            "variables": { "query": "repo:owner/repo is:open", "first": 2, "after": null }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "data": {
+                "search": {
+                    "issueCount": 3,
+                    "pageInfo": { "hasNextPage": true, "endCursor": "Y3Vyc29yOjI=" },
+                    "nodes": [
+                        { "number": 7, "title": "Crash on start" },
+                        { "number": 9, "title": "Fix crash on start" }
+                    ]
+                }
+            }
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "POST on /graphql was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let results: GraphQLSearchResults<SearchResult> = client
+        .search()
+        .graphql_issues_and_pull_requests("repo:owner/repo is:open", "number title")
+        .per_page(2)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(results.issue_count, 3);
+    assert_eq!(
+        results.page_info.end_cursor.as_deref(),
+        Some("Y3Vyc29yOjI=")
+    );
+    assert_eq!(results.nodes[1].number, 9);
+    assert_eq!(results.nodes[1].title, "Fix crash on start");
+}
@@ -0,0 +1,177 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::repos::secret_scanning_bypass::BypassRequestState;
+use octocrab::params::secret_scanning::{BypassReason, BypassRequestStatus, BypassReview};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn bypass_request() -> serde_json::Value {
+    json!({
+        "id": 21,
+        "number": 42,
+        "repository": { "id": 1, "name": "some-repo", "full_name": "org/some-repo" },
+        "organization": { "id": 1, "name": "org" },
+        "requester": { "actor_id": 12, "actor_name": "monalisa" },
+        "request_type": "secret_scanning",
+        "data": [{ "secret_type": "adafruit_io_key", "bypass_reason": "used_in_tests" }],
+        "resource_identifier": "827efc6d56897b048c772eb4087f854f46256132",
+        "status": "pending",
+        "requester_comment": "Only a test token",
+        "expires_at": "2024-07-08T08:43:03Z",
+        "created_at": "2024-07-01T08:43:03Z",
+        "responses": [],
+        "url": "https://api.github.com/repos/org/some-repo/bypass-requests/secret-scanning/42",
+        "html_url": "https://github.com/org/some-repo/exemptions/42"
+    })
+}
+
+#[tokio::test]
+async fn should_list_repo_bypass_requests() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/bypass-requests/secret-scanning"
+        )))
+        .and(query_param("request_status", "open"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([bypass_request()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/org/some-repo/bypass-requests/secret-scanning was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .secrets_scanning()
+        .list_bypass_requests()
+        .request_status(BypassRequestStatus::Open)
+        .send()
+        .await;
+
+    let requests = result.unwrap().items;
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].number, 42);
+    assert_eq!(requests[0].status, BypassRequestState::Pending);
+}
+
+#[tokio::test]
+async fn should_list_org_bypass_requests() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/orgs/{OWNER}/bypass-requests/secret-scanning"
+        )))
+        .and(query_param("repository_name", REPO))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([bypass_request()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /orgs/org/bypass-requests/secret-scanning was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .orgs(OWNER)
+        .list_secret_scanning_bypass_requests()
+        .repository_name(REPO)
+        .send()
+        .await;
+
+    assert_eq!(result.unwrap().items.len(), 1);
+}
+
+#[tokio::test]
+async fn should_review_bypass_request() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/bypass-requests/secret-scanning/42"
+        )))
+        .and(body_json(json!({
+            "status": "deny",
+            "message": "Rotate the key instead"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "bypass_review_id": 7,
+            "status": "DENIED"
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "PATCH on /repos/org/some-repo/bypass-requests/secret-scanning/42 was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .secrets_scanning()
+        .review_bypass_request(42, BypassReview::Deny, "Rotate the key instead")
+        .await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn should_create_push_protection_bypass() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/secret-scanning/push-protection-bypasses"
+        )))
+        .and(body_json(json!({
+            "reason": "used_in_tests",
+            "placeholder_id": "2k4dM3Fd"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "reason": "used_in_tests",
+            "expire_at": "2020-11-06T21:22:50Z",
+            "token_type": "mailchimp_api_key"
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /repos/org/some-repo/secret-scanning/push-protection-bypasses was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .secrets_scanning()
+        .create_push_protection_bypass(BypassReason::UsedInTests, "2k4dM3Fd")
+        .await;
+
+    assert_eq!(result.unwrap().reason, BypassReason::UsedInTests);
+}
@@ -0,0 +1,262 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::security_advisories::AdvisoryVulnerability;
+use octocrab::params::security_advisories::{AdvisoryState, AdvisoryType, Ecosystem, Severity};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const OWNER: &str = "org";
+const REPO: &str = "some-repo";
+const GHSA_ID: &str = "GHSA-abcd-1234-efgh";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn repository_advisory(state: &str) -> serde_json::Value {
+    json!({
+        "ghsa_id": GHSA_ID,
+        "cve_id": null,
+        "url": "https://api.github.com/repos/org/some-repo/security-advisories/GHSA-abcd-1234-efgh",
+        "html_url": "https://github.com/org/some-repo/security/advisories/GHSA-abcd-1234-efgh",
+        "summary": "Remote code execution",
+        "description": "Templates are evaluated without sandboxing.",
+        "severity": "high",
+        "author": null,
+        "publisher": null,
+        "identifiers": [{ "type": "GHSA", "value": GHSA_ID }],
+        "state": state,
+        "created_at": "2023-01-01T00:00:00Z",
+        "updated_at": "2023-01-02T00:00:00Z",
+        "published_at": null,
+        "closed_at": null,
+        "withdrawn_at": null,
+        "vulnerabilities": [
+            {
+                "package": { "ecosystem": "npm", "name": "my-package" },
+                "vulnerable_version_range": "< 1.2.3",
+                "patched_versions": "1.2.3",
+                "vulnerable_functions": []
+            }
+        ],
+        "cvss": { "vector_string": null, "score": null },
+        "cwes": [],
+        "cwe_ids": [],
+        "credits": [],
+        "collaborating_users": null,
+        "private_fork": null
+    })
+}
+
+fn global_advisory() -> serde_json::Value {
+    json!({
+        "ghsa_id": GHSA_ID,
+        "cve_id": "CVE-2023-0001",
+        "url": "https://api.github.com/advisories/GHSA-abcd-1234-efgh",
+        "html_url": "https://github.com/advisories/GHSA-abcd-1234-efgh",
+        "repository_advisory_url": null,
+        "summary": "Memory corruption",
+        "description": "A buffer is read out of bounds.",
+        "type": "reviewed",
+        "severity": "critical",
+        "source_code_location": "https://github.com/org/some-repo",
+        "identifiers": [
+            { "type": "GHSA", "value": GHSA_ID },
+            { "type": "CVE", "value": "CVE-2023-0001" }
+        ],
+        "references": ["https://nvd.nist.gov/vuln/detail/CVE-2023-0001"],
+        "published_at": "2023-01-01T00:00:00Z",
+        "updated_at": "2023-01-02T00:00:00Z",
+        "github_reviewed_at": "2023-01-01T00:00:00Z",
+        "nvd_published_at": null,
+        "withdrawn_at": null,
+        "vulnerabilities": [
+            {
+                "package": { "ecosystem": "rust", "name": "some-crate" },
+                "vulnerable_version_range": "< 0.2.0",
+                "first_patched_version": "0.2.0",
+                "vulnerable_functions": []
+            }
+        ],
+        "cvss": { "vector_string": "CVSS:3.1/AV:N/AC:L/PR:N/UI:N/S:U/C:H/I:H/A:H", "score": 9.8 },
+        "cwes": [{ "cwe_id": "CWE-125", "name": "Out-of-bounds Read" }],
+        "credits": []
+    })
+}
+
+#[tokio::test]
+async fn should_create_repository_advisory() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!("/repos/{OWNER}/{REPO}/security-advisories")))
+        .and(body_json(json!({
+            "summary": "Remote code execution",
+            "description": "Templates are evaluated without sandboxing.",
+            "vulnerabilities": [
+                {
+                    "package": { "ecosystem": "npm", "name": "my-package" },
+                    "vulnerable_version_range": "< 1.2.3",
+                    "patched_versions": "1.2.3"
+                }
+            ],
+            "severity": "high",
+            "start_private_fork": true
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(repository_advisory("draft")))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /repos/org/some-repo/security-advisories was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .security_advisories()
+        .create(
+            "Remote code execution",
+            "Templates are evaluated without sandboxing.",
+            vec![AdvisoryVulnerability::new(Ecosystem::Npm, "my-package")
+                .vulnerable_version_range("< 1.2.3")
+                .patched_versions("1.2.3")],
+        )
+        .severity(Severity::High)
+        .start_private_fork(true)
+        .send()
+        .await;
+
+    let advisory = result.unwrap();
+    assert_eq!(advisory.ghsa_id, GHSA_ID);
+    assert_eq!(advisory.state, AdvisoryState::Draft);
+    assert_eq!(advisory.severity, Some(Severity::High));
+}
+
+#[tokio::test]
+async fn should_publish_repository_advisory() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/security-advisories/{GHSA_ID}"
+        )))
+        .and(body_json(json!({ "state": "published" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(repository_advisory("published")))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "PATCH on /repos/org/some-repo/security-advisories/GHSA-abcd-1234-efgh was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos
+        .security_advisories()
+        .update(GHSA_ID)
+        .state(AdvisoryState::Published)
+        .send()
+        .await;
+
+    assert_eq!(result.unwrap().state, AdvisoryState::Published);
+}
+
+#[tokio::test]
+async fn should_request_cve() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path(format!(
+            "/repos/{OWNER}/{REPO}/security-advisories/{GHSA_ID}/cve"
+        )))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({})))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /repos/org/some-repo/security-advisories/GHSA-abcd-1234-efgh/cve was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let repos = client.repos(OWNER, REPO);
+    let result = repos.security_advisories().request_cve(GHSA_ID).await;
+
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn should_list_global_advisories() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/advisories"))
+        .and(query_param("ecosystem", "rust"))
+        .and(query_param("severity", "critical"))
+        .and(query_param("affects", "some-crate,other-crate@1.0.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([global_advisory()])))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /advisories was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .advisories()
+        .list()
+        .ecosystem(Ecosystem::Rust)
+        .severity(Severity::Critical)
+        .affects(["some-crate", "other-crate@1.0.0"])
+        .send()
+        .await;
+
+    let advisories = result.unwrap().items;
+    assert_eq!(advisories.len(), 1);
+    assert_eq!(advisories[0].r#type, AdvisoryType::Reviewed);
+    assert_eq!(
+        advisories[0].vulnerabilities.as_ref().unwrap()[0]
+            .first_patched_version
+            .as_deref(),
+        Some("0.2.0")
+    );
+}
+
+#[tokio::test]
+async fn should_get_global_advisory() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/advisories/{GHSA_ID}")))
+        .respond_with(ResponseTemplate::new(200).set_body_json(global_advisory()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /advisories/GHSA-abcd-1234-efgh was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.advisories().get(GHSA_ID).await;
+
+    let advisory = result.unwrap();
+    assert_eq!(advisory.cve_id.as_deref(), Some("CVE-2023-0001"));
+    assert_eq!(advisory.cwes.unwrap()[0].cwe_id, "CWE-125");
+}
@@ -0,0 +1,76 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{Error, Octocrab, ServerVersion};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_reject_endpoints_missing_on_declared_version() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    setup_error_handler(&mock_server, "no request was expected").await;
+
+    let client = Octocrab::builder()
+        .base_uri(mock_server.uri())
+        .unwrap()
+        .enterprise_server_version(ServerVersion::new(3, 12))
+        .build()
+        .unwrap();
+    let result = client.orgs("org").roles().list().await;
+
+    match result {
+        Err(Error::UnsupportedOnThisServer {
+            endpoint,
+            version,
+            required,
+            ..
+        }) => {
+            assert_eq!(endpoint, "/orgs/org/organization-roles");
+            assert_eq!(version, ServerVersion::new(3, 12));
+            assert_eq!(required, Some(ServerVersion::new(3, 14)));
+        }
+        result => panic!("expected an unsupported endpoint, got {:?}", result),
+    }
+    assert!(mock_server.received_requests().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn should_detect_server_version() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/meta"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "verifiable_password_authentication": false,
+            "installed_version": "3.13.2"
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /meta was not received").await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let version = client.detect_server_version().await.unwrap();
+    assert_eq!(version, Some(ServerVersion::new(3, 13)));
+
+    let result = client.orgs("org").copilot().billing().await;
+    assert!(
+        matches!(
+            result,
+            Err(Error::UnsupportedOnThisServer { required: None, .. })
+        ),
+        "{:?}",
+        result
+    );
+}
@@ -0,0 +1,35 @@
+#![cfg(all(feature = "test-utils", feature = "default-client"))]
+
+use octocrab::Octocrab;
+
+// One test, as the tests of a file run in parallel and share the static
+// instance.
+#[tokio::test]
+async fn should_initialise_static_instance_once() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    octocrab::test_utils::ensure_crypto_provider_initialized();
+
+    octocrab::reset();
+    assert!(matches!(
+        octocrab::try_instance(),
+        Err(octocrab::Error::NotInitialised { .. })
+    ));
+
+    let first = octocrab::initialise(Octocrab::default()).unwrap();
+    assert!(std::sync::Arc::ptr_eq(
+        &first,
+        &octocrab::try_instance().unwrap()
+    ));
+    assert!(std::sync::Arc::ptr_eq(&first, &octocrab::instance()));
+
+    assert!(matches!(
+        octocrab::initialise(Octocrab::default()),
+        Err(octocrab::Error::AlreadyInitialised { .. })
+    ));
+    assert!(std::sync::Arc::ptr_eq(&first, &octocrab::instance()));
+
+    octocrab::reset();
+    assert!(octocrab::try_instance().is_err());
+    let second = octocrab::initialise(Octocrab::default()).unwrap();
+    assert!(!std::sync::Arc::ptr_eq(&first, &second));
+}
@@ -0,0 +1,37 @@
+//! Checks the `strict-models` feature itself. The other tests check the
+//! models against the recorded fixtures when run with the feature enabled.
+#![cfg(feature = "strict-models")]
+
+use octocrab::models::Label;
+use serde_json::json;
+
+fn label() -> serde_json::Value {
+    json!({
+        "id": 208045946,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": "https://api.github.com/repos/octocat/Hello-World/labels/bug",
+        "name": "bug",
+        "description": "Something isn't working",
+        "color": "f29513",
+        "default": true
+    })
+}
+
+#[test]
+fn should_deserialize_known_fields() {
+    let label: Label = serde_json::from_value(label()).unwrap();
+    assert_eq!(label.name, "bug");
+}
+
+#[test]
+fn should_reject_unknown_fields() {
+    let mut label = label();
+    label["archived"] = json!(false);
+
+    let error = serde_json::from_value::<Label>(label).unwrap_err();
+    assert!(
+        error.to_string().contains("unknown field `archived`"),
+        "{}",
+        error
+    );
+}
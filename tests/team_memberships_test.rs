@@ -0,0 +1,130 @@
+// Tests for calls to the /orgs/{org}/teams/{team}/memberships API.
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::teams::TeamMembershipState;
+use octocrab::params::teams::Role;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+const ORG: &str = "org";
+const TEAM: &str = "team-name";
+const USER: &str = "ferris";
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn membership(role: &str, state: &str) -> serde_json::Value {
+    json!({
+        "url": format!("https://api.github.com/teams/1/memberships/{USER}"),
+        "role": role,
+        "state": state,
+    })
+}
+
+fn membership_path() -> String {
+    format!("/orgs/{ORG}/teams/{TEAM}/memberships/{USER}")
+}
+
+#[tokio::test]
+async fn should_get_membership_role() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(membership_path()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(membership("maintainer", "active")))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on {} was not received", membership_path()),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let membership = client.teams(ORG).memberships(TEAM).get(USER).await.unwrap();
+    assert_eq!(membership.role, Role::Maintainer);
+    assert_eq!(membership.state, TeamMembershipState::Active);
+}
+
+#[tokio::test]
+async fn should_add_member_with_role() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PUT"))
+        .and(path(membership_path()))
+        .and(body_json(json!({ "role": "maintainer" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(membership("maintainer", "pending")))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("PUT on {} was not received", membership_path()),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let membership = client
+        .teams(ORG)
+        .memberships(TEAM)
+        .add_or_update(USER, Role::Maintainer)
+        .await
+        .unwrap();
+    assert_eq!(membership.role, Role::Maintainer);
+    assert_eq!(membership.state, TeamMembershipState::Pending);
+}
+
+#[tokio::test]
+async fn should_remove_member() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(membership_path()))
+        .respond_with(ResponseTemplate::new(204))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("DELETE on {} was not received", membership_path()),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.teams(ORG).memberships(TEAM).remove(USER).await;
+    assert!(
+        result.is_ok(),
+        "expected successful result, got {:#?}",
+        result
+    );
+}
+
+#[tokio::test]
+async fn should_fail_to_remove_member_of_other_org() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("DELETE"))
+        .and(path(membership_path()))
+        .respond_with(ResponseTemplate::new(403).set_body_json(json!({
+            "message": "Forbidden",
+            "documentation_url": "https://docs.github.com/rest/teams/members"
+        })))
+        .mount(&mock_server)
+        .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client.teams(ORG).memberships(TEAM).remove(USER).await;
+    assert!(result.is_err(), "expected error, got {:#?}", result);
+}
@@ -91,3 +91,56 @@ async fn should_add_or_update_team_repo() {
     );
     eprintln!("Result: {result:#?}");
 }
+
+#[tokio::test]
+async fn should_remove_team_repo_returns_error_when_not_managed() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let template = ResponseTemplate::new(404).set_body_json(serde_json::json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest/teams/teams#remove-a-repository-from-a-team"
+    }));
+    let mock_server = setup_api(template).await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .teams(ORG.to_owned())
+        .repos(TEAM.to_owned())
+        .remove(ORG.to_owned(), REPO.to_owned())
+        .await;
+    assert!(result.is_err(), "expected error, got {:#?}", result);
+}
+
+#[tokio::test]
+async fn should_list_team_repos() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let repos: serde_json::Value =
+        serde_json::from_str(include_str!("resources/user_repositories.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/orgs/{ORG}/teams/{TEAM}/repos")))
+        .and(wiremock::matchers::query_param("per_page", "50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&repos))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        &format!("GET on /orgs/{ORG}/teams/{TEAM}/repos was not received"),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let result = client
+        .teams(ORG.to_owned())
+        .repos(TEAM.to_owned())
+        .list()
+        .per_page(50)
+        .send()
+        .await;
+    let page = result.unwrap();
+    assert_eq!(page.items.len(), repos.as_array().unwrap().len());
+    assert_eq!(page.items[0].name, "actix-examples");
+}
@@ -0,0 +1,113 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{models::teams::IdpGroup, Octocrab};
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_list_idp_groups() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/orgs/octo-org/team-sync/groups"))
+        .and(query_param("q", "admins"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "groups": [{
+                "group_id": "123",
+                "group_name": "Octocat admins",
+                "group_description": "The people who configure your octoworld."
+            }]
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /orgs/octo-org/team-sync/groups was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let groups = client
+        .teams("octo-org")
+        .list_idp_groups()
+        .query("admins")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(groups.items.len(), 1);
+    assert_eq!(groups.items[0].group_name, "Octocat admins");
+}
+
+#[tokio::test]
+async fn should_get_and_update_group_mappings() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mapping = json!({
+        "groups": [{
+            "group_id": "123",
+            "group_name": "Octocat admins",
+            "group_description": "The people who configure your octoworld.",
+            "status": "synced",
+            "synced_at": "2019-06-03 22:27:15:000 -700"
+        }]
+    });
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/orgs/octo-org/teams/justice-league/team-sync/group-mappings",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&mapping))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    Mock::given(method("PATCH"))
+        .and(path(
+            "/orgs/octo-org/teams/justice-league/team-sync/group-mappings",
+        ))
+        .and(body_json(json!({
+            "groups": [{
+                "group_id": "123",
+                "group_name": "Octocat admins",
+                "group_description": "The people who configure your octoworld."
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&mapping))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "request on /orgs/octo-org/teams/justice-league/team-sync/group-mappings was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let teams = client.teams("octo-org");
+    let sync = teams.team_sync("justice-league");
+
+    let current = sync.group_mappings().await.unwrap();
+    assert_eq!(current.groups[0].status.as_deref(), Some("synced"));
+
+    let updated = sync
+        .update_group_mappings(vec![IdpGroup::new(
+            "123",
+            "Octocat admins",
+            "The people who configure your octoworld.",
+        )])
+        .await
+        .unwrap();
+    assert_eq!(updated.groups[0].group_id, "123");
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "test-utils")]
+
+use octocrab::models::Label;
+use octocrab::test_utils::{
+    github_error, mock_get, page_response, setup_error_handler, setup_octocrab,
+};
+use serde_json::json;
+use wiremock::{matchers::query_param, MockServer};
+
+fn label(name: &str) -> serde_json::Value {
+    json!({
+        "id": 208045946,
+        "node_id": "MDU6TGFiZWwyMDgwNDU5NDY=",
+        "url": format!("https://api.github.com/repos/owner/repo/labels/{name}"),
+        "name": name,
+        "color": "f29513",
+        "default": false
+    })
+}
+
+#[tokio::test]
+async fn should_page_through_mocked_pages() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    octocrab::test_utils::ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    let uri = format!("{}/repos/owner/repo/labels", mock_server.uri());
+    mock_get("/repos/owner/repo/labels")
+        .and(query_param("page", "2"))
+        .respond_with(page_response(&uri, [label("wontfix")], 2, 2))
+        .mount(&mock_server)
+        .await;
+    mock_get("/repos/owner/repo/labels")
+        .respond_with(page_response(&uri, [label("bug")], 1, 2))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/labels was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let page = client
+        .issues("owner", "repo")
+        .list_labels_for_repo()
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(page.number_of_pages(), Some(2));
+    let labels: Vec<Label> = client.all_pages(page).await.unwrap();
+
+    let names: Vec<_> = labels.iter().map(|label| label.name.as_str()).collect();
+    assert_eq!(names, ["bug", "wontfix"]);
+}
+
+#[tokio::test]
+async fn should_respond_with_github_errors() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    octocrab::test_utils::ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    mock_get("/repos/owner/repo/labels/bug")
+        .respond_with(github_error(404, "Not Found"))
+        .mount(&mock_server)
+        .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.issues("owner", "repo").get_label("bug").await;
+
+    match result {
+        Err(octocrab::Error::GitHub { source, .. }) => {
+            assert_eq!(source.status_code, 404);
+            assert_eq!(source.message, "Not Found");
+        }
+        result => panic!("expected a GitHub error, got {:?}", result),
+    }
+}
@@ -110,7 +110,7 @@ async fn should_respond_user_blocked() {
 #[tokio::test]
 async fn should_respond_user_unblocked() {
     /* status 204 for unblocked */
-    let template = ResponseTemplate::new(200);
+    let template = ResponseTemplate::new(204);
     let mock_server = setup_blocks_mock(
         "DELETE",
         format!("/user/blocks/{NOT_BLOCKED}").as_str(),
@@ -119,5 +119,65 @@ async fn should_respond_user_unblocked() {
     .await;
     let client = setup_octocrab(&mock_server.uri());
     let result = client.users("some-user").unblock_user(NOT_BLOCKED).await;
+    assert!(result.is_ok());
+}
+
+#[tokio::test]
+async fn should_fail_to_unblock_user_not_blocked() {
+    let template = ResponseTemplate::new(404).set_body_json(serde_json::json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest/users/blocking"
+    }));
+    let mock_server = setup_blocks_mock(
+        "DELETE",
+        format!("/user/blocks/{NOT_BLOCKED}").as_str(),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.users("some-user").unblock_user(NOT_BLOCKED).await;
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn should_report_user_blocked() {
+    let mock_server = setup_blocks_mock(
+        "GET",
+        format!("/user/blocks/{NOT_BLOCKED}").as_str(),
+        ResponseTemplate::new(204),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.users("some-user").is_blocked(NOT_BLOCKED).await;
+    assert!(result.unwrap());
+}
+
+#[tokio::test]
+async fn should_report_user_not_blocked() {
+    let mock_server = setup_blocks_mock(
+        "GET",
+        format!("/user/blocks/{NOT_BLOCKED}").as_str(),
+        ResponseTemplate::new(404),
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.users("some-user").is_blocked(NOT_BLOCKED).await;
+    assert!(!result.unwrap());
+}
+
+#[tokio::test]
+async fn should_fail_to_block_user_twice() {
+    let template = ResponseTemplate::new(422).set_body_json(serde_json::json!({
+        "message": "Blocked user has already been blocked",
+        "documentation_url": "https://docs.github.com/rest/users/blocking"
+    }));
+    let mock_server = setup_blocks_mock(
+        "PUT",
+        format!("/user/blocks/{NOT_BLOCKED}").as_str(),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client.users("some-user").block_user(NOT_BLOCKED).await;
     assert!(result.is_err());
 }
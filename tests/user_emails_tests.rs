@@ -146,3 +146,25 @@ async fn should_respond_to_emails_delete() {
         result
     );
 }
+
+#[tokio::test]
+async fn should_return_error_when_adding_invalid_email() {
+    let template =
+        ResponseTemplate::new(StatusCode::UNPROCESSABLE_ENTITY).set_body_json(serde_json::json!({
+            "message": "Validation Failed",
+            "documentation_url": "https://docs.github.com/rest/users/emails"
+        }));
+    let mock_server = setup_emails_mock("POST", "/user/emails", template).await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .users("some_user")
+        .emails()
+        .add(vec!["not-an-email".to_string()])
+        .await;
+    match result.unwrap_err() {
+        octocrab::Error::GitHub { source, .. } => {
+            assert_eq!(source.status_code, StatusCode::UNPROCESSABLE_ENTITY)
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
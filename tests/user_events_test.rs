@@ -0,0 +1,128 @@
+// Tests for calls to the /users/{username}/events APIs and event polling.
+mod mock_error;
+
+use std::time::Duration;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{etag::EntityTag, models::events, Octocrab};
+use serde::{Deserialize, Serialize};
+use wiremock::{
+    matchers::{header, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+#[derive(Serialize, Deserialize)]
+struct FakePage<T> {
+    items: Vec<T>,
+}
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn create_event() -> events::Event {
+    serde_json::from_str(include_str!("resources/create_event.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_list_received_public_events() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/received_events/public"))
+        .and(query_param("per_page", "50"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(vec![create_event()]))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /users/octocat/received_events/public was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .users("octocat")
+        .received_public_events()
+        .per_page(50)
+        .send()
+        .await;
+
+    let page = result.unwrap().value.unwrap();
+    assert_eq!(page.items, vec![create_event()]);
+}
+
+#[tokio::test]
+async fn should_poll_user_events_with_etag_and_poll_interval() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events/orgs/github"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(FakePage {
+                    items: vec![create_event()],
+                })
+                .insert_header("etag", "\"1234\"")
+                .insert_header("X-Poll-Interval", "60"),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /users/octocat/events/orgs/github was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let polled = client
+        .users("octocat")
+        .org_events("github")
+        .poll()
+        .await
+        .unwrap();
+
+    assert_eq!(polled.etag, Some(EntityTag::strong("1234".to_string())));
+    assert_eq!(polled.poll_interval, Some(Duration::from_secs(60)));
+    assert_eq!(polled.value.unwrap().items, vec![create_event()]);
+}
+
+#[tokio::test]
+async fn should_poll_no_events_when_not_modified() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/events"))
+        .and(header("If-None-Match", "\"1234\""))
+        .respond_with(
+            ResponseTemplate::new(304)
+                .insert_header("etag", "\"1234\"")
+                .insert_header("X-Poll-Interval", "120"),
+        )
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /users/octocat/events was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let polled = client
+        .users("octocat")
+        .events()
+        .etag(Some(EntityTag::strong("1234".to_string())))
+        .poll()
+        .await
+        .unwrap();
+
+    assert_eq!(polled.etag, Some(EntityTag::strong("1234".to_string())));
+    assert_eq!(polled.poll_interval, Some(Duration::from_secs(120)));
+    assert!(polled.value.is_none());
+}
@@ -136,3 +136,29 @@ async fn should_respond_to_git_ssh_key_delete() {
         result
     );
 }
+
+#[tokio::test]
+async fn should_return_error_when_deleting_missing_git_ssh_key() {
+    let template = ResponseTemplate::new(StatusCode::NOT_FOUND).set_body_json(serde_json::json!({
+        "message": "Not Found",
+        "documentation_url": "https://docs.github.com/rest/users/keys"
+    }));
+    let mock_server = setup_git_ssh_keys_mock(
+        "DELETE",
+        format!("/user/keys/{GIT_SSH_KEY_ID}").as_str(),
+        template,
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .users("some_user")
+        .git_ssh_keys()
+        .delete(GIT_SSH_KEY_ID)
+        .await;
+    match result.unwrap_err() {
+        octocrab::Error::GitHub { source, .. } => {
+            assert_eq!(source.status_code, StatusCode::NOT_FOUND)
+        }
+        other => panic!("Unexpected error: {:?}", other),
+    }
+}
@@ -0,0 +1,50 @@
+// Tests for calls to the /users/{username}/hovercard API.
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::params::users::HovercardSubjectType;
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_return_hovercard_for_subject() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users/octocat/hovercard"))
+        .and(query_param("subject_type", "pull_request"))
+        .and(query_param("subject_id", "1347"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "contexts": [
+                { "message": "Opened this pull request", "octicon": "git-pull-request" },
+                { "message": "Owns this repository", "octicon": "repo" }
+            ]
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /users/octocat/hovercard was not received",
+    )
+    .await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let hovercard = client
+        .users("octocat")
+        .hovercard(HovercardSubjectType::PullRequest, 1347)
+        .await
+        .unwrap();
+    assert_eq!(hovercard.contexts.len(), 2);
+    assert_eq!(hovercard.contexts[0].message, "Opened this pull request");
+    assert_eq!(hovercard.contexts[1].octicon, "repo");
+}
@@ -0,0 +1,96 @@
+// Tests for the /users, /user/{account_id} and PATCH /user APIs.
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::models::{SimpleUser, UserId};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{body_json, method, path, query_param},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+fn user_data() -> serde_json::Value {
+    serde_json::from_str(include_str!("resources/user_data.json")).unwrap()
+}
+
+#[tokio::test]
+async fn should_get_user_by_id() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/user/583231"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_data()))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /user/583231 was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let user = client.users_by_id(UserId(583231)).profile().await.unwrap();
+    assert_eq!(user.login, "octocat");
+}
+
+#[tokio::test]
+async fn should_list_users_since_id() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let users: Vec<SimpleUser> =
+        serde_json::from_str(include_str!("resources/user_blocks.json")).unwrap();
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/users"))
+        .and(query_param("since", "1000"))
+        .and(query_param("per_page", "30"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&users))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "GET on /users was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let page = client
+        .list_users()
+        .since(UserId(1000))
+        .per_page(30)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(page.items.len(), 1);
+    assert_eq!(page.items[0].login, "octocat");
+}
+
+#[tokio::test]
+async fn should_only_send_set_profile_fields() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mut updated = user_data();
+    updated["bio"] = json!("Rustacean");
+    updated["hireable"] = json!(true);
+    let mock_server = MockServer::start().await;
+    Mock::given(method("PATCH"))
+        .and(path("/user"))
+        .and(body_json(json!({ "bio": "Rustacean", "hireable": true })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(&updated))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(&mock_server, "PATCH on /user was not received").await;
+    let client = setup_octocrab(&mock_server.uri());
+
+    let profile = client
+        .current()
+        .update_profile()
+        .bio("Rustacean")
+        .hireable(true)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(profile.bio.as_deref(), Some("Rustacean"));
+    assert_eq!(profile.hireable, Some(true));
+}
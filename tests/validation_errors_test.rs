@@ -0,0 +1,65 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::{Error, Octocrab, ValidationErrorCode};
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_parse_validation_errors() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(path("/repos/owner/repo/labels"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(json!({
+            "message": "Validation Failed",
+            "errors": [
+                { "resource": "Label", "code": "already_exists", "field": "name" },
+                { "resource": "Label", "code": "invalid", "field": "color" },
+                { "resource": "Label", "code": "custom", "field": "name", "message": "name is too long" },
+                { "resource": "Label", "code": "too_colorful", "field": "color" },
+                "color is not a color"
+            ],
+            "documentation_url": "https://docs.github.com/rest/issues/labels#create-a-label"
+        })))
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "POST on /repos/owner/repo/labels was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let result = client
+        .issues("owner", "repo")
+        .create_label("bug", "d73a4a", "Something isn't working")
+        .await;
+
+    let Err(Error::GitHub { source, .. }) = result else {
+        panic!("expected a GitHub error, got {:?}", result);
+    };
+    let errors = source.validation_errors();
+    assert_eq!(errors.len(), 5);
+    assert_eq!(errors[0].resource.as_deref(), Some("Label"));
+    assert_eq!(errors[0].field.as_deref(), Some("name"));
+    assert_eq!(errors[0].code, ValidationErrorCode::AlreadyExists);
+    assert_eq!(errors[1].code, ValidationErrorCode::Invalid);
+    assert_eq!(errors[2].code, ValidationErrorCode::Custom);
+    assert_eq!(errors[2].message.as_deref(), Some("name is too long"));
+    assert_eq!(
+        errors[3].code,
+        ValidationErrorCode::Unknown("too_colorful".to_owned())
+    );
+    assert_eq!(errors[4].code, ValidationErrorCode::Custom);
+    assert_eq!(errors[4].message.as_deref(), Some("color is not a color"));
+}
@@ -0,0 +1,104 @@
+mod mock_error;
+
+use mock_error::{ensure_crypto_provider_initialized, setup_error_handler};
+use octocrab::Octocrab;
+use serde_json::json;
+use wiremock::{
+    matchers::{method, path},
+    Mock, MockServer, ResponseTemplate,
+};
+
+fn setup_octocrab(uri: &str) -> Octocrab {
+    Octocrab::builder().base_uri(uri).unwrap().build().unwrap()
+}
+
+#[tokio::test]
+async fn should_get_workflow_usage() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/actions/workflows/ci.yml/timing"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "billable": {
+                "UBUNTU": { "total_ms": 180000 },
+                "WINDOWS": { "total_ms": 240000 }
+            }
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/actions/workflows/ci.yml/timing was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let usage = client
+        .workflows("owner", "repo")
+        .get_workflow_usage("ci.yml")
+        .await
+        .unwrap();
+
+    assert!(usage.billable.macos.is_none());
+    let timings: Vec<_> = usage
+        .billable
+        .iter()
+        .map(|(os, timing)| (os, timing.total_ms))
+        .collect();
+    assert_eq!(timings, [("UBUNTU", 180000), ("WINDOWS", 240000)]);
+}
+
+#[tokio::test]
+async fn should_get_run_usage() {
+    #[cfg(all(feature = "rustls", not(target_arch = "wasm32")))]
+    ensure_crypto_provider_initialized();
+
+    let mock_server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path("/repos/owner/repo/actions/runs/30433642/timing"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "billable": {
+                "UBUNTU": {
+                    "total_ms": 180000,
+                    "jobs": 1,
+                    "job_runs": [{ "job_id": 1, "duration_ms": 180000 }]
+                },
+                "MACOS": {
+                    "total_ms": 240000,
+                    "jobs": 4,
+                    "job_runs": [
+                        { "job_id": 2, "duration_ms": 60000 },
+                        { "job_id": 3, "duration_ms": 60000 },
+                        { "job_id": 4, "duration_ms": 60000 },
+                        { "job_id": 5, "duration_ms": 60000 }
+                    ]
+                },
+                "WINDOWS": { "total_ms": 0, "jobs": 0 }
+            },
+            "run_duration_ms": 500000
+        })))
+        .expect(1)
+        .mount(&mock_server)
+        .await;
+    setup_error_handler(
+        &mock_server,
+        "GET on /repos/owner/repo/actions/runs/30433642/timing was not received",
+    )
+    .await;
+
+    let client = setup_octocrab(&mock_server.uri());
+    let usage = client
+        .workflows("owner", "repo")
+        .get_run_usage(30433642u64.into())
+        .await
+        .unwrap();
+
+    assert_eq!(usage.run_duration_ms, Some(500000));
+    let macos = usage.billable.macos.unwrap();
+    assert_eq!(macos.jobs, 4);
+    assert_eq!(macos.job_runs[0].job_id, 2u64.into());
+    assert!(usage.billable.windows.unwrap().job_runs.is_empty());
+}